@@ -0,0 +1,162 @@
+//! Show a window without OS decorations, where egui draws the title bar
+//! and the edges of the window can be dragged to resize it.
+
+use eframe::{egui, epi};
+
+#[derive(Default)]
+struct MyApp {}
+
+impl epi::App for MyApp {
+    fn name(&self) -> &str {
+        "Custom window frame"
+    }
+
+    fn clear_color(&self) -> egui::Rgba {
+        egui::Rgba::TRANSPARENT // Make sure we don't paint anything behind the rounded corners
+    }
+
+    fn update(&mut self, ctx: &egui::CtxRef, frame: &mut epi::Frame<'_>) {
+        custom_window_frame(ctx, frame, self.name(), |ui| {
+            ui.label("This is just the contents of the window.");
+            ui.label("Drag the title bar to move the window, and the edges to resize it.");
+        });
+    }
+}
+
+fn custom_window_frame(
+    ctx: &egui::CtxRef,
+    frame: &mut epi::Frame<'_>,
+    title: &str,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    use egui::*;
+    let text_color = ctx.style().visuals.text_color();
+
+    // Height of the title bar
+    let height = 28.0;
+
+    CentralPanel::default()
+        .frame(Frame::window(&ctx.style()).margin(vec2(0.0, 0.0)))
+        .show(ctx, |ui| {
+            let rect = ui.max_rect();
+            let painter = ui.painter();
+
+            // Paint the title:
+            painter.text(
+                rect.center_top() + vec2(0.0, height / 2.0),
+                Align2::CENTER_CENTER,
+                title,
+                TextStyle::Heading,
+                text_color,
+            );
+
+            // Paint the line under the title:
+            painter.line_segment(
+                [
+                    rect.left_top() + vec2(2.0, height),
+                    rect.right_top() + vec2(-2.0, height),
+                ],
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+
+            // Drag the title bar to move the window:
+            let title_bar_rect = {
+                let mut rect = rect;
+                rect.max.y = rect.min.y + height;
+                rect
+            };
+            ctx.add_window_region(title_bar_rect, WindowRegion::Drag);
+
+            // Add the close and minimize buttons:
+            let button_size = vec2(height, height);
+            let close_rect = Rect::from_min_size(rect.right_top() - vec2(height, 0.0), button_size);
+            let minimize_rect = close_rect.translate(vec2(-height, 0.0));
+            // The buttons are on top of the title bar, so clicking them should not move the window:
+            ctx.add_window_region(close_rect.union(minimize_rect), WindowRegion::Client);
+            if ui
+                .put(close_rect, Button::new("❌").frame(false))
+                .on_hover_text("Close the window")
+                .clicked()
+            {
+                frame.quit();
+            }
+            if ui
+                .put(minimize_rect, Button::new("🗕").frame(false))
+                .on_hover_text("Minimize the window")
+                .clicked()
+            {
+                frame.set_minimized(true);
+            }
+
+            resize_edges(ctx, rect);
+
+            // Add the contents:
+            let content_rect = {
+                let mut rect = rect;
+                rect.min.y = title_bar_rect.max.y;
+                rect
+            }
+            .shrink(4.0);
+            let mut content_ui = ui.child_ui(content_rect, *ui.layout());
+            add_contents(&mut content_ui);
+        });
+}
+
+/// Let the user resize the window by dragging its edges and corners.
+fn resize_edges(ctx: &egui::CtxRef, rect: egui::Rect) {
+    use egui::{ResizeDirection::*, *};
+
+    // How far into the window the edges can be grabbed:
+    let grab = 4.0;
+
+    let corner = Vec2::splat(2.0 * grab);
+
+    let edges = [
+        (
+            North,
+            Rect::from_x_y_ranges(rect.x_range(), rect.top()..=rect.top() + grab),
+        ),
+        (
+            South,
+            Rect::from_x_y_ranges(rect.x_range(), rect.bottom() - grab..=rect.bottom()),
+        ),
+        (
+            West,
+            Rect::from_x_y_ranges(rect.left()..=rect.left() + grab, rect.y_range()),
+        ),
+        (
+            East,
+            Rect::from_x_y_ranges(rect.right() - grab..=rect.right(), rect.y_range()),
+        ),
+        (NorthWest, Rect::from_min_size(rect.left_top(), corner)),
+        (
+            NorthEast,
+            Rect::from_min_size(rect.right_top() - vec2(corner.x, 0.0), corner),
+        ),
+        (
+            SouthWest,
+            Rect::from_min_size(rect.left_bottom() - vec2(0.0, corner.y), corner),
+        ),
+        (
+            SouthEast,
+            Rect::from_min_max(rect.right_bottom() - corner, rect.right_bottom()),
+        ),
+    ];
+
+    // Corners are added last, so they win over the edges:
+    for (direction, edge_rect) in edges.iter() {
+        ctx.add_window_region(*edge_rect, WindowRegion::Resize(*direction));
+    }
+}
+
+fn main() {
+    let options = eframe::NativeOptions {
+        // Hide the OS-specific "chrome" around the window:
+        decorated: false,
+        // To have rounded corners we need transparency:
+        transparent: true,
+        initial_window_size: Some(egui::vec2(320.0, 240.0)),
+        ..Default::default()
+    };
+    eframe::run_native(Box::new(MyApp::default()), options);
+}
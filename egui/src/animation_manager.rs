@@ -1,10 +1,11 @@
 use epaint::ahash::AHashMap;
 
-use crate::{emath::remap_clamp, Id, InputState};
+use crate::{emath::remap_clamp, Id, InputState, Visuals};
 
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: AHashMap<Id, BoolAnim>,
+    visuals: Option<VisualsAnim>,
 }
 
 #[derive(Clone, Debug)]
@@ -14,6 +15,15 @@ struct BoolAnim {
     toggle_time: f64,
 }
 
+/// A transition between two themes.
+#[derive(Clone, Debug)]
+struct VisualsAnim {
+    from: Visuals,
+    to: Visuals,
+    start_time: f64,
+    duration: f32,
+}
+
 impl AnimationManager {
     /// See `Context::animate_bool` for documentation
     pub fn animate_bool(
@@ -58,4 +68,43 @@ impl AnimationManager {
             }
         }
     }
+
+    /// See `Context::set_visuals_animated` for documentation
+    pub fn start_visuals_transition(
+        &mut self,
+        input: &InputState,
+        from: Visuals,
+        to: Visuals,
+        duration: f32,
+    ) {
+        self.visuals = Some(VisualsAnim {
+            from,
+            to,
+            start_time: input.time,
+            duration,
+        });
+    }
+
+    pub fn cancel_visuals_transition(&mut self) {
+        self.visuals = None;
+    }
+
+    /// The visuals to use this frame, if a theme transition is in progress.
+    ///
+    /// Returns `(visuals, done)`.
+    pub fn visuals_transition(&mut self, input: &InputState) -> Option<(Visuals, bool)> {
+        let anim = self.visuals.as_ref()?;
+        let t = remap_clamp(
+            (input.time - anim.start_time) as f32,
+            0.0..=anim.duration,
+            0.0..=1.0,
+        );
+        if t < 1.0 {
+            let t = t * t * (3.0 - 2.0 * t); // smoothstep: ease in and out
+            Some((anim.from.lerp(&anim.to, t), false))
+        } else {
+            let anim = self.visuals.take()?;
+            Some((anim.to, true))
+        }
+    }
 }
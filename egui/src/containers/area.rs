@@ -51,7 +51,7 @@ pub struct Area {
     default_pos: Option<Pos2>,
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
-    drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
 }
 
 impl Area {
@@ -65,7 +65,7 @@ impl Area {
             default_pos: None,
             new_pos: None,
             anchor: None,
-            drag_bounds: None,
+            constrain_rect: None,
         }
     }
 
@@ -150,12 +150,19 @@ impl Area {
         self.movable(false)
     }
 
-    /// Constrain the area up to which the window can be dragged.
-    pub fn drag_bounds(mut self, bounds: Rect) -> Self {
-        self.drag_bounds = Some(bounds);
+    /// Keep the area within this rectangle.
+    ///
+    /// Default: the screen, minus any panels and OS taskbars (see [`RawInput::work_area`]).
+    pub fn constrain_to(mut self, constrain_rect: Rect) -> Self {
+        self.constrain_rect = Some(constrain_rect);
         self
     }
 
+    #[deprecated = "Renamed constrain_to"]
+    pub fn drag_bounds(self, bounds: Rect) -> Self {
+        self.constrain_to(bounds)
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
@@ -170,7 +177,7 @@ pub(crate) struct Prepared {
     state: State,
     movable: bool,
     enabled: bool,
-    drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
 }
 
 impl Area {
@@ -184,7 +191,7 @@ impl Area {
             default_pos,
             new_pos,
             anchor,
-            drag_bounds,
+            constrain_rect,
         } = self;
 
         let layer_id = LayerId::new(order, id);
@@ -206,6 +213,10 @@ impl Area {
                 let screen = ctx.available_rect();
                 state.pos = anchor.align_size_within_rect(state.size, screen).min + offset;
             }
+        } else if !is_new {
+            // The screen may have shrunk since last frame (e.g. the window was resized or the
+            // taskbar moved), so constrain already now to not show anything off-screen this frame:
+            state.pos = constrain(ctx, state.rect(), constrain_rect).min;
         }
 
         state.pos = ctx.round_pos_to_pixels(state.pos);
@@ -215,7 +226,7 @@ impl Area {
             state,
             movable,
             enabled,
-            drag_bounds,
+            constrain_rect,
         }
     }
 
@@ -269,14 +280,16 @@ impl Prepared {
         &mut self.state
     }
 
-    pub(crate) fn drag_bounds(&self) -> Option<Rect> {
-        self.drag_bounds
+    pub(crate) fn constrain_rect(&self) -> Option<Rect> {
+        self.constrain_rect
     }
 
     pub(crate) fn content_ui(&self, ctx: &CtxRef) -> Ui {
         let max_rect = Rect::from_min_size(self.state.pos, Vec2::INFINITY);
         let shadow_radius = ctx.style().visuals.window_shadow.extrusion; // hacky
-        let bounds = self.drag_bounds.unwrap_or_else(|| ctx.input().screen_rect);
+        let bounds = self
+            .constrain_rect
+            .unwrap_or_else(|| ctx.input().screen_rect);
 
         let mut clip_rect = max_rect
             .expand(ctx.style().visuals.clip_rect_margin)
@@ -311,7 +324,7 @@ impl Prepared {
             mut state,
             movable,
             enabled,
-            drag_bounds,
+            constrain_rect,
         } = self;
 
         state.size = content_ui.min_rect().size();
@@ -337,11 +350,7 @@ impl Prepared {
             state.pos += ctx.input().pointer.delta();
         }
 
-        if let Some(bounds) = drag_bounds {
-            state.pos = ctx.constrain_window_rect_to_area(state.rect(), bounds).min;
-        } else {
-            state.pos = ctx.constrain_window_rect(state.rect()).min;
-        }
+        state.pos = constrain(ctx, state.rect(), constrain_rect).min;
 
         if (move_response.dragged() || move_response.clicked())
             || pointer_pressed_on_area(ctx, layer_id)
@@ -356,6 +365,14 @@ impl Prepared {
     }
 }
 
+fn constrain(ctx: &Context, rect: Rect, constrain_rect: Option<Rect>) -> Rect {
+    if let Some(constrain_rect) = constrain_rect {
+        ctx.constrain_window_rect_to_area(rect, constrain_rect)
+    } else {
+        ctx.constrain_window_rect(rect)
+    }
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.input().pointer.interact_pos() {
         ctx.input().pointer.any_pressed() && ctx.layer_id_at(pointer_pos) == Some(layer_id)
@@ -0,0 +1,267 @@
+//! An infinite canvas that can be panned and zoomed.
+
+use crate::{emath::RectTransform, *};
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    /// The world position shown at the center of the canvas.
+    center: Pos2,
+
+    /// Screen points per world unit.
+    zoom: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            center: Pos2::ZERO,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl State {
+    fn transform(&self, screen_rect: Rect) -> RectTransform {
+        let world_rect = Rect::from_center_size(self.center, screen_rect.size() / self.zoom);
+        RectTransform::from_to(world_rect, screen_rect)
+    }
+}
+
+/// A region with its own world coordinate system, which the user can pan (by dragging or scrolling)
+/// and zoom (by ctrl-scrolling or pinching, see [`InputState::zoom_delta`]).
+///
+/// The contents are given a [`RectTransform`] which maps world coordinates to screen coordinates.
+/// Use it to paint shapes at world positions, or to place child `Ui`:s at world rectangles.
+///
+/// Note that only positions are transformed: widgets and text are not scaled when zooming.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::*;
+/// Canvas::new("my_canvas").show(ui, |ui, to_screen| {
+///     let center = to_screen.transform_pos(pos2(0.0, 0.0));
+///     ui.painter().circle_filled(center, 10.0 * to_screen.scale().x, Color32::RED);
+///
+///     let world_rect = Rect::from_min_size(pos2(20.0, 20.0), vec2(100.0, 30.0));
+///     ui.allocate_ui_at_rect(to_screen.transform_rect(world_rect), |ui| {
+///         ui.label("Hello from world coordinates!");
+///     });
+/// });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .show()"]
+pub struct Canvas {
+    id_source: Id,
+    desired_size: Option<Vec2>,
+    default_center: Pos2,
+    default_zoom: f32,
+    center: Option<Pos2>,
+    zoom: Option<f32>,
+    zoom_range: std::ops::RangeInclusive<f32>,
+    allow_drag: bool,
+    allow_zoom: bool,
+}
+
+impl Canvas {
+    /// Give a unique id for each canvas within the same `Ui`.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            desired_size: None,
+            default_center: Pos2::ZERO,
+            default_zoom: 1.0,
+            center: None,
+            zoom: None,
+            zoom_range: 0.01..=100.0,
+            allow_drag: true,
+            allow_zoom: true,
+        }
+    }
+
+    /// Size of the canvas on screen. Default: fill all available space.
+    pub fn desired_size(mut self, desired_size: impl Into<Vec2>) -> Self {
+        self.desired_size = Some(desired_size.into());
+        self
+    }
+
+    /// The world position shown at the center of the canvas the first time it is shown.
+    pub fn default_center(mut self, center: impl Into<Pos2>) -> Self {
+        self.default_center = center.into();
+        self
+    }
+
+    /// Screen points per world unit the first time the canvas is shown. Default: `1.0`.
+    pub fn default_zoom(mut self, zoom: f32) -> Self {
+        self.default_zoom = zoom;
+        self
+    }
+
+    /// Move the view so that this world position is at the center of the canvas.
+    pub fn center(mut self, center: impl Into<Pos2>) -> Self {
+        self.center = Some(center.into());
+        self
+    }
+
+    /// Set the zoom level (screen points per world unit).
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    /// The allowed range of zoom levels. Default: `0.01..=100.0`.
+    pub fn zoom_range(mut self, zoom_range: std::ops::RangeInclusive<f32>) -> Self {
+        self.zoom_range = zoom_range;
+        self
+    }
+
+    /// Whether to allow dragging or scrolling the canvas to pan the view. Default: `true`.
+    pub fn allow_drag(mut self, allow_drag: bool) -> Self {
+        self.allow_drag = allow_drag;
+        self
+    }
+
+    /// Whether to allow zooming with ctrl-scroll or pinch gestures. Default: `true`.
+    pub fn allow_zoom(mut self, allow_zoom: bool) -> Self {
+        self.allow_zoom = allow_zoom;
+        self
+    }
+
+    /// Show the canvas.
+    ///
+    /// `add_contents` is given a `Ui` covering the canvas (clipped to it)
+    /// and the transform from world coordinates to screen coordinates.
+    ///
+    /// The returned [`Response`] is that of the canvas background,
+    /// so you can check it for e.g. clicks in empty space.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, &RectTransform) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            desired_size,
+            default_center,
+            default_zoom,
+            center,
+            zoom,
+            zoom_range,
+            allow_drag,
+            allow_zoom,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let size = desired_size.unwrap_or_else(|| ui.available_size_before_wrap_finite());
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+        let mut state = *ui.memory().id_data.get_or_insert_with(id, || State {
+            center: default_center,
+            zoom: default_zoom.clamp(*zoom_range.start(), *zoom_range.end()),
+        });
+        if let Some(center) = center {
+            state.center = center;
+        }
+        if let Some(zoom) = zoom {
+            state.zoom = zoom.clamp(*zoom_range.start(), *zoom_range.end());
+        }
+
+        if allow_drag {
+            if response.dragged() {
+                state.center -= response.drag_delta() / state.zoom;
+            }
+            if response.hovered() {
+                let mut frame_state = ui.ctx().frame_state();
+                let mut scroll_delta = frame_state.scroll_delta;
+                if ui.input().modifiers.shift
+                    && ui.style().interaction.shift_scrolls_horizontally
+                    && scroll_delta.x == 0.0
+                {
+                    // Some platforms (e.g. Mac) already turn shift-scrolling horizontal for us,
+                    // which is why we leave deltas that have any horizontal component alone.
+                    scroll_delta = vec2(scroll_delta.y, 0.0);
+                }
+                state.center -= scroll_delta / state.zoom;
+                // Clear scroll delta so no parent scroll will use it.
+                frame_state.scroll_delta = Vec2::ZERO;
+            }
+        }
+
+        if allow_zoom && response.hovered() {
+            if let Some(zoom_anchor) = ui.input().zoom_anchor() {
+                let zoom_factor = ui.input().zoom_delta();
+                if zoom_factor != 1.0 {
+                    // Keep the world position under the anchor fixed:
+                    let pointer_in_world = state.transform(rect).inverse() * zoom_anchor;
+                    let new_zoom =
+                        (state.zoom * zoom_factor).clamp(*zoom_range.start(), *zoom_range.end());
+                    state.center = pointer_in_world
+                        + (state.center - pointer_in_world) * state.zoom / new_zoom;
+                    state.zoom = new_zoom;
+                }
+            }
+        }
+
+        let to_screen = state.transform(rect);
+
+        let mut content_ui = ui.child_ui(rect, *ui.layout());
+        content_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+        let inner = add_contents(&mut content_ui, &to_screen);
+
+        ui.memory().id_data.insert(id, state);
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+#[test]
+fn test_shift_scroll_pans_horizontally() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 300.0));
+    let run = |harness: &mut Harness| {
+        let mut center = Pos2::ZERO;
+        harness.run(|ui| {
+            Canvas::new("canvas").show(ui, |_ui, to_screen| {
+                center = to_screen.from().center();
+            });
+        });
+        center
+    };
+
+    harness.pointer_move(pos2(150.0, 150.0));
+    run(&mut harness);
+    harness.scroll(vec2(0.0, -50.0));
+    assert_eq!(run(&mut harness), pos2(0.0, 50.0));
+
+    harness.set_modifiers(Modifiers {
+        shift: true,
+        ..Default::default()
+    });
+    harness.scroll(vec2(0.0, -50.0));
+    assert_eq!(run(&mut harness), pos2(50.0, 50.0));
+}
+
+#[test]
+fn test_zoom_is_clamped_to_zoom_range() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 300.0));
+    let mut run = |canvas: Canvas| {
+        let mut zoom = 0.0;
+        harness.run(|ui| {
+            canvas.show(ui, |_ui, to_screen| {
+                zoom = to_screen.to().width() / to_screen.from().width();
+            });
+        });
+        zoom
+    };
+
+    let zoom = run(Canvas::new("canvas")
+        .zoom_range(0.5..=2.0)
+        .default_zoom(10.0));
+    assert!((zoom - 2.0).abs() < 1e-4, "zoom: {}", zoom);
+    let zoom = run(Canvas::new("canvas").zoom_range(0.5..=2.0).zoom(0.1));
+    assert!((zoom - 0.5).abs() < 1e-4, "zoom: {}", zoom);
+}
@@ -121,6 +121,14 @@ pub(crate) fn paint_icon(ui: &mut Ui, openness: f32, response: &Response) {
     ui.painter().add(Shape::closed_line(points, stroke));
 }
 
+/// How many levels of nested [`CollapsingHeader`]s are still open by default,
+/// set by [`CollapsingHeader::default_open_depth`] while showing the body.
+#[derive(Clone, Copy, Debug, Default)]
+struct DefaultOpenDepth(Option<usize>);
+
+/// Paints the icon of a [`CollapsingHeader`], given the openness (0-1) and the response of the icon.
+pub type IconPainter = Box<dyn FnOnce(&mut Ui, f32, &Response)>;
+
 /// A header which can be collapsed/expanded, revealing a contained [`Ui`] region.
 ///
 ///
@@ -133,13 +141,25 @@ pub(crate) fn paint_icon(ui: &mut Ui, openness: f32, response: &Response) {
 ///
 /// // Short version:
 /// ui.collapsing("Heading", |ui| { ui.label("Contents"); });
+///
+/// // Open the first two levels of a tree by default:
+/// egui::CollapsingHeader::new("Tree")
+///     .default_open_depth(2)
+///     .show(ui, |ui| {
+///         ui.collapsing("Open by default", |ui| {
+///             ui.collapsing("Closed by default", |ui| ui.label("Leaf"));
+///         });
+///     });
 /// ```
 #[must_use = "You should call .show()"]
 pub struct CollapsingHeader {
     label: Label,
     default_open: bool,
+    default_open_depth: Option<usize>,
+    open: Option<bool>,
     id_source: Id,
     enabled: bool,
+    icon: Option<IconPainter>,
 }
 
 impl CollapsingHeader {
@@ -155,8 +175,11 @@ impl CollapsingHeader {
         Self {
             label,
             default_open: false,
+            default_open_depth: None,
+            open: None,
             id_source,
             enabled: true,
+            icon: None,
         }
     }
 
@@ -167,6 +190,28 @@ impl CollapsingHeader {
         self
     }
 
+    /// Open this header and the nested `CollapsingHeader`s in it by default,
+    /// down to `depth` levels (counting this one).
+    ///
+    /// `.default_open_depth(0)` means closed, `.default_open_depth(1)` is the same as `.default_open(true)`.
+    /// Nested headers inherit what is left of the depth, unless they set their own.
+    pub fn default_open_depth(mut self, depth: usize) -> Self {
+        self.default_open_depth = Some(depth);
+        self
+    }
+
+    /// Calling `.open(Some(true))` will make the collapsing header open this frame (or stay open).
+    ///
+    /// Calling `.open(Some(false))` will make the collapsing header close this frame (or stay closed).
+    ///
+    /// Calling `.open(None)` has no effect (default).
+    ///
+    /// This is useful for e.g. "Expand all" and "Collapse all" buttons.
+    pub fn open(mut self, open: Option<bool>) -> Self {
+        self.open = open;
+        self
+    }
+
     /// Explicitly set the source of the `Id` of this widget, instead of using title label.
     /// This is useful if the title label is dynamic or not unique.
     pub fn id_source(mut self, id_source: impl Hash) -> Self {
@@ -188,12 +233,39 @@ impl CollapsingHeader {
         self.enabled = enabled;
         self
     }
+
+    /// Use the provided function to paint the icon instead of the default triangle.
+    ///
+    /// The function is called with the openness (0 for closed, 1 for open, with tweening)
+    /// and a [`Response`] whose `rect` is where the icon should go.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::CollapsingHeader::new("Heading")
+    ///     .icon(|ui, openness, response| {
+    ///         let icon = if openness < 0.5 { "➕" } else { "➖" };
+    ///         ui.painter().text(
+    ///             response.rect.center(),
+    ///             egui::Align2::CENTER_CENTER,
+    ///             icon,
+    ///             egui::TextStyle::Button,
+    ///             ui.style().interact(response).text_color(),
+    ///         );
+    ///     })
+    ///     .show(ui, |ui| ui.label("Contents"));
+    /// ```
+    pub fn icon(mut self, icon_fn: impl FnOnce(&mut Ui, f32, &Response) + 'static) -> Self {
+        self.icon = Some(Box::new(icon_fn));
+        self
+    }
 }
 
 struct Prepared {
     id: Id,
     header_response: Response,
     state: State,
+    /// What to pass on to nested headers.
+    child_default_open_depth: Option<usize>,
 }
 
 impl CollapsingHeader {
@@ -205,10 +277,18 @@ impl CollapsingHeader {
         let Self {
             mut label,
             default_open,
+            default_open_depth,
+            open,
             id_source,
             enabled: _,
+            icon,
         } = self;
 
+        let default_open_depth = default_open_depth
+            .or_else(|| ui.memory().data_temp.get_or_default::<DefaultOpenDepth>().0);
+        let default_open = default_open || default_open_depth.map_or(false, |depth| depth > 0);
+        let child_default_open_depth = default_open_depth.map(|depth| depth.saturating_sub(1));
+
         label.text_style = label
             .text_style
             .or(ui.style().override_text_style)
@@ -244,6 +324,12 @@ impl CollapsingHeader {
             state.toggle(ui);
             header_response.mark_changed();
         }
+        if let Some(open) = open {
+            if open != state.open {
+                state.toggle(ui);
+                header_response.mark_changed();
+            }
+        }
         header_response
             .widget_info(|| WidgetInfo::labeled(WidgetType::CollapsingHeader, &galley.text));
 
@@ -271,7 +357,11 @@ impl CollapsingHeader {
                 ..header_response.clone()
             };
             let openness = state.openness(ui.ctx(), id);
-            paint_icon(ui, openness, &icon_response);
+            if let Some(icon) = icon {
+                icon(ui, openness, &icon_response);
+            } else {
+                paint_icon(ui, openness, &icon_response);
+            }
         }
 
         ui.painter().galley(text_pos, galley, text_color);
@@ -280,6 +370,7 @@ impl CollapsingHeader {
             id,
             header_response,
             state,
+            child_default_open_depth,
         }
     }
 
@@ -297,13 +388,23 @@ impl CollapsingHeader {
                 id,
                 header_response,
                 mut state,
+                child_default_open_depth,
             } = self.begin(ui);
 
             let ret_response = state.add_contents(ui, id, |ui| {
                 ui.indent(id, |ui| {
                     // make as wide as the header:
                     ui.expand_to_include_x(header_response.rect.right());
-                    add_contents(ui)
+
+                    let parent_default_open_depth = std::mem::replace(
+                        ui.memory()
+                            .data_temp
+                            .get_mut_or_default::<DefaultOpenDepth>(),
+                        DefaultOpenDepth(child_default_open_depth),
+                    );
+                    let ret = add_contents(ui);
+                    ui.memory().data_temp.insert(parent_default_open_depth);
+                    ret
                 })
                 .inner
             });
@@ -0,0 +1,434 @@
+//! A popup for searching and running commands, like `Ctrl+Shift+P` in many editors.
+
+use crate::{
+    util::{fuzzy_match, FuzzyMatch},
+    *,
+};
+
+/// How many recently chosen commands we remember.
+const MAX_RECENT: usize = 10;
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    open: bool,
+    was_open: bool,
+    query: String,
+    /// Index into the filtered list.
+    selected: usize,
+    /// Names of the recently chosen commands, most recent first.
+    recent: Vec<String>,
+}
+
+/// An action that can be chosen in a [`CommandPalette`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Command {
+    name: String,
+    shortcut: Option<String>,
+}
+
+impl Command {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            shortcut: None,
+        }
+    }
+
+    /// Show a keyboard shortcut (e.g. `"Ctrl+S"`) next to the command.
+    ///
+    /// This is only a hint: you need to check for the shortcut yourself.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn shortcut(mut self, shortcut: impl ToString) -> Self {
+        self.shortcut = Some(shortcut.to_string());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A modal popup with a search field for quickly finding and running a [`Command`].
+///
+/// Opened with `Ctrl+Shift+P` (`Cmd+Shift+P` on Mac) by default.
+/// The commands are filtered with fuzzy matching as you type,
+/// and recently chosen commands are listed first.
+/// Use the arrow keys and Enter (or the mouse) to choose a command, and Escape to close the palette.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// use egui::{Command, CommandPalette};
+/// let commands = [
+///     Command::new("Open file").shortcut("Ctrl+O"),
+///     Command::new("Save").shortcut("Ctrl+S"),
+///     Command::new("Toggle dark mode"),
+/// ];
+/// if let Some(index) = CommandPalette::new("command_palette").show(ctx, &commands) {
+///     println!("Run {:?}", commands[index].name());
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct CommandPalette<'open> {
+    id_source: Id,
+    open: Option<&'open mut bool>,
+    shortcut: Option<(Modifiers, Key)>,
+    width: f32,
+    max_height: f32,
+}
+
+impl<'open> CommandPalette<'open> {
+    /// `id_source`: Something unique, e.g. `"command_palette"`.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            open: None,
+            shortcut: Some((
+                Modifiers {
+                    command: true,
+                    shift: true,
+                    ..Default::default()
+                },
+                Key::P,
+            )),
+            width: 400.0,
+            max_height: 300.0,
+        }
+    }
+
+    /// Control whether the palette is open yourself, e.g. from a menu.
+    ///
+    /// The shortcut sets `*open` to `true`, and choosing a command or closing the palette sets it to `false`.
+    /// If you don't call this, the palette keeps track of it itself.
+    pub fn open(mut self, open: &'open mut bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+
+    /// The keyboard shortcut that opens (and closes) the palette, or `None` for no shortcut.
+    /// Only [`Modifiers::alt`], [`Modifiers::shift`] and [`Modifiers::command`] are compared.
+    ///
+    /// Default: `Ctrl+Shift+P` (`Cmd+Shift+P` on Mac).
+    pub fn shortcut(mut self, shortcut: Option<(Modifiers, Key)>) -> Self {
+        self.shortcut = shortcut;
+        self
+    }
+
+    /// Width of the palette.
+    /// Default: `400.0`.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Maximum height of the list of commands. It will scroll if there are more.
+    /// Default: `300.0`.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Show the palette (if open).
+    ///
+    /// Returns the index of the chosen command, if any.
+    pub fn show(self, ctx: &CtxRef, commands: &[Command]) -> Option<usize> {
+        let Self {
+            id_source,
+            open,
+            shortcut,
+            width,
+            max_height,
+        } = self;
+
+        let id = id_source;
+        let mut state = ctx
+            .memory()
+            .id_data
+            .get::<State>(&id)
+            .cloned()
+            .unwrap_or_default();
+        let mut is_open = open.as_deref().copied().unwrap_or(state.open);
+
+        if let Some((modifiers, key)) = shortcut {
+            let input = ctx.input();
+            if input.key_pressed(key)
+                && input.modifiers.alt == modifiers.alt
+                && input.modifiers.shift == modifiers.shift
+                && input.modifiers.command == modifiers.command
+            {
+                is_open = !is_open;
+            }
+        }
+
+        let mut chosen = None;
+        if is_open {
+            if !state.was_open {
+                state.query.clear();
+                state.selected = 0;
+            }
+
+            chosen = palette_ui(
+                ctx,
+                id,
+                &mut state,
+                &mut is_open,
+                commands,
+                width,
+                max_height,
+            );
+
+            if let Some(index) = chosen {
+                let name = &commands[index].name;
+                state.recent.retain(|recent| recent != name);
+                state.recent.insert(0, name.clone());
+                state.recent.truncate(MAX_RECENT);
+                is_open = false;
+            }
+        }
+
+        if let Some(open) = open {
+            *open = is_open;
+        }
+        state.open = is_open;
+        state.was_open = is_open;
+        ctx.memory().id_data.insert(id, state);
+
+        chosen
+    }
+}
+
+/// The commands matching the query, best first.
+fn filter_commands(
+    query: &str,
+    recent: &[String],
+    commands: &[Command],
+) -> Vec<(usize, FuzzyMatch)> {
+    let mut matches: Vec<(usize, FuzzyMatch)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| Some((index, fuzzy_match(query, &command.name)?)))
+        .collect();
+    let recency = |index: usize| {
+        recent
+            .iter()
+            .position(|name| *name == commands[index].name)
+            .unwrap_or(usize::MAX)
+    };
+    // Stable sort, so commands that are otherwise equal keep their order:
+    matches.sort_by_key(|(index, m)| (std::cmp::Reverse(m.score), recency(*index)));
+    matches
+}
+
+fn palette_ui(
+    ctx: &CtxRef,
+    id: Id,
+    state: &mut State,
+    is_open: &mut bool,
+    commands: &[Command],
+    width: f32,
+    max_height: f32,
+) -> Option<usize> {
+    let matches = filter_commands(&state.query, &state.recent, commands);
+    state.selected = state.selected.min(matches.len().saturating_sub(1));
+
+    let mut chosen = None;
+    let mut scroll_to_selected = false;
+    {
+        let input = ctx.input();
+        if input.key_pressed(Key::ArrowDown) {
+            state.selected = (state.selected + 1).min(matches.len().saturating_sub(1));
+            scroll_to_selected = true;
+        }
+        if input.key_pressed(Key::ArrowUp) {
+            state.selected = state.selected.saturating_sub(1);
+            scroll_to_selected = true;
+        }
+        if input.key_pressed(Key::Enter) {
+            chosen = matches.get(state.selected).map(|(index, _)| *index);
+        }
+        if input.key_pressed(Key::Escape) {
+            *is_open = false;
+        }
+    }
+
+    let screen_rect = ctx.input().screen_rect();
+    Area::new(id)
+        .order(Order::Foreground)
+        .fixed_pos(screen_rect.min)
+        .show(ctx, |ui| {
+            // Dim everything behind the palette, and block clicks from reaching it:
+            let background = ui.allocate_rect(screen_rect, Sense::click());
+            ui.painter()
+                .rect_filled(screen_rect, 0.0, Color32::from_black_alpha(96));
+
+            let width = width.at_most(screen_rect.width() - 32.0);
+            let top = screen_rect.top() + screen_rect.height() / 6.0;
+            let max_rect = Rect::from_min_max(
+                pos2(screen_rect.center().x - width / 2.0, top),
+                pos2(screen_rect.center().x + width / 2.0, screen_rect.bottom()),
+            );
+            let mut palette_ui = ui.child_ui(max_rect, Layout::top_down_justified(Align::Min));
+            let palette_rect = Frame::popup(ui.style())
+                .show(&mut palette_ui, |ui| {
+                    ui.set_width(width);
+
+                    let query = ui.add(
+                        TextEdit::singleline(&mut state.query)
+                            .id(id.with("query"))
+                            .hint_text("Type a command…"),
+                    );
+                    query.request_focus();
+                    if query.changed() {
+                        state.selected = 0;
+                    }
+
+                    ui.separator();
+
+                    if matches.is_empty() {
+                        ui.add(Label::new("No matching commands").weak());
+                    }
+
+                    ScrollArea::from_max_height(max_height).show(ui, |ui| {
+                        for (row, (index, m)) in matches.iter().enumerate() {
+                            let index = *index;
+                            let is_selected = row == state.selected;
+                            let response =
+                                command_ui(ui, &commands[index], &m.indices, is_selected);
+                            if is_selected && scroll_to_selected {
+                                response.scroll_to_me(Align::Center);
+                            }
+                            if response.clicked() {
+                                chosen = Some(index);
+                            }
+                        }
+                    });
+                })
+                .response
+                .rect;
+
+            let clicked_outside = background.clicked()
+                && background
+                    .interact_pointer_pos
+                    .map_or(false, |pos| !palette_rect.contains(pos));
+            if clicked_outside {
+                *is_open = false;
+            }
+        });
+
+    chosen
+}
+
+fn command_ui(ui: &mut Ui, command: &Command, matched: &[usize], is_selected: bool) -> Response {
+    let padding = ui.spacing().button_padding.x;
+    let size = vec2(ui.available_width(), ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    response.widget_info(|| {
+        WidgetInfo::selected(WidgetType::SelectableLabel, is_selected, &command.name)
+    });
+
+    let visuals = ui.visuals();
+    let corner_radius = visuals.widgets.hovered.corner_radius;
+    if is_selected {
+        ui.painter()
+            .rect_filled(rect, corner_radius, visuals.widgets.active.bg_fill);
+    } else if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, corner_radius, visuals.widgets.hovered.bg_fill);
+    }
+
+    let text_color = if is_selected {
+        visuals.strong_text_color()
+    } else {
+        visuals.text_color()
+    };
+    let galley = ui
+        .fonts()
+        .layout_single_line(TextStyle::Button, command.name.clone());
+    let pos = pos2(rect.left() + padding, rect.center().y - galley.size.y / 2.0);
+    crate::util::fuzzy::paint_char_highlights(
+        ui.painter(),
+        pos,
+        &galley,
+        matched,
+        visuals.selection.bg_fill,
+    );
+    ui.painter().galley(pos, galley, text_color);
+    if let Some(shortcut) = &command.shortcut {
+        ui.painter().text(
+            rect.right_center() - vec2(padding, 0.0),
+            Align2::RIGHT_CENTER,
+            shortcut,
+            TextStyle::Button,
+            visuals.weak_text_color(),
+        );
+    }
+
+    response
+}
+
+#[test]
+fn test_filter_commands() {
+    let commands = [
+        Command::new("Open file"),
+        Command::new("Copy from"),
+        Command::new("Save"),
+    ];
+    let indices = |query: &str, recent: &[String]| -> Vec<usize> {
+        filter_commands(query, recent, &commands)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect()
+    };
+
+    assert_eq!(indices("", &[]), vec![0, 1, 2]);
+    assert_eq!(indices("", &["Save".to_owned()]), vec![2, 0, 1]);
+    assert_eq!(indices("of", &[]), vec![0, 1]);
+    assert_eq!(indices("sv", &[]), vec![2]);
+}
+
+#[test]
+fn test_command_palette_keyboard() {
+    use crate::harness::Harness;
+
+    let commands = [
+        Command::new("Open file"),
+        Command::new("Save"),
+        Command::new("Save as"),
+    ];
+    let mut harness = Harness::new(vec2(600.0, 400.0));
+    let mut open = true;
+    let run = |harness: &mut Harness, open: &mut bool| {
+        let mut chosen = None;
+        harness.run_ctx(|ctx| {
+            chosen = CommandPalette::new("palette")
+                .open(open)
+                .show(ctx, &commands);
+        });
+        chosen
+    };
+
+    run(&mut harness, &mut open);
+    harness.type_text("sa");
+    run(&mut harness, &mut open);
+    harness.key_press(Key::ArrowDown);
+    run(&mut harness, &mut open);
+    harness.key_press(Key::Enter);
+    assert_eq!(run(&mut harness, &mut open), Some(2));
+    assert!(!open);
+
+    // Reopen with the shortcut. The recently chosen command is now first:
+    harness.set_modifiers(Modifiers {
+        command: true,
+        shift: true,
+        ..Default::default()
+    });
+    harness.key_press(Key::P);
+    run(&mut harness, &mut open);
+    harness.set_modifiers(Default::default());
+    harness.key_press(Key::Enter);
+    assert_eq!(run(&mut harness, &mut open), Some(2));
+}
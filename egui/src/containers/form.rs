@@ -0,0 +1,309 @@
+//! Labeled form fields with validation and a submit button.
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// Width of the widest label, measured last frame.
+    label_width: f32,
+}
+
+/// A form of labeled fields, laid out in two aligned columns using a [`Grid`].
+///
+/// After adding a field you can validate it with [`FormUi::validate`].
+/// Invalid fields are outlined and their errors are shown below them,
+/// as well as in a summary at the bottom of the form.
+/// Clicking an error in the summary scrolls to the field and gives it keyboard focus.
+///
+/// The submit button is disabled until all fields are valid.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut name = String::new();
+/// # let mut age = 42;
+/// let response = egui::Form::new("new_user")
+///     .submit_text("Create user")
+///     .show(ui, |form| {
+///         form.field("Name", |ui| ui.text_edit_singleline(&mut name));
+///         form.validate(!name.is_empty(), "Name must not be empty");
+///
+///         form.field("Age", |ui| ui.add(egui::DragValue::new(&mut age)));
+///         form.validate(age >= 18, "You must be at least 18 years old");
+///     });
+///
+/// if response.submitted {
+///     // create the user
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Form {
+    id_source: Id,
+    right_align_labels: bool,
+    submit_text: String,
+    summary: bool,
+}
+
+impl Form {
+    /// Create a new [`Form`] with a locally unique identifier.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            right_align_labels: true,
+            submit_text: "Submit".to_owned(),
+            summary: true,
+        }
+    }
+
+    /// If `true`, the labels are aligned to the right, next to their fields.
+    /// Default: `true`.
+    pub fn right_align_labels(mut self, right_align_labels: bool) -> Self {
+        self.right_align_labels = right_align_labels;
+        self
+    }
+
+    /// The text on the submit button.
+    /// Default: `"Submit"`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn submit_text(mut self, submit_text: impl ToString) -> Self {
+        self.submit_text = submit_text.to_string();
+        self
+    }
+
+    /// Show a summary of all validation errors above the submit button.
+    /// Default: `true`.
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut FormUi<'_>) -> R,
+    ) -> FormResponse<R> {
+        let Self {
+            id_source,
+            right_align_labels,
+            submit_text,
+            summary,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let state = ui
+            .memory()
+            .id_data_temp
+            .get::<State>(&id)
+            .copied()
+            .unwrap_or_default();
+
+        let InnerResponse { inner, response } = ui.vertical(|ui| {
+            let (inner, widest_label, errors) = Grid::new(id)
+                .num_columns(2)
+                .show(ui, |ui| {
+                    let mut form = FormUi {
+                        ui,
+                        right_align_labels,
+                        label_width: state.label_width,
+                        widest_label: 0.0,
+                        last_field: None,
+                        errors: Vec::new(),
+                    };
+                    let inner = add_contents(&mut form);
+                    (inner, form.widest_label, form.errors)
+                })
+                .inner;
+
+            if widest_label != state.label_width {
+                ui.memory().id_data_temp.insert(
+                    id,
+                    State {
+                        label_width: widest_label,
+                    },
+                );
+                ui.ctx().request_repaint();
+            }
+
+            if summary && !errors.is_empty() {
+                ui.add_space(ui.spacing().item_spacing.y);
+                let error_color = ui.visuals().error_fg_color;
+                for (error, field) in &errors {
+                    let response = ui
+                        .add(
+                            Label::new(format!("⚠ {}", error))
+                                .text_color(error_color)
+                                .sense(Sense::click()),
+                        )
+                        .on_hover_text("Go to field");
+                    if response.clicked() {
+                        if let Some(field) = field {
+                            field.scroll_to_me(Align::Center);
+                            field.request_focus();
+                        }
+                    }
+                }
+            }
+
+            let submit = ui
+                .add(Button::new(submit_text).enabled(errors.is_empty()))
+                .on_disabled_hover_text("Fix the errors in the form first");
+
+            (inner, submit.clicked(), errors)
+        });
+        let (inner, submitted, errors) = inner;
+
+        FormResponse {
+            inner,
+            response,
+            submitted,
+            errors,
+        }
+    }
+}
+
+/// Returned by [`Form::show`].
+pub struct FormResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the whole form.
+    pub response: Response,
+
+    /// Was the submit button clicked? Only possible when all fields are valid.
+    pub submitted: bool,
+
+    /// The validation errors, together with the fields they belong to.
+    errors: Vec<(String, Option<Response>)>,
+}
+
+impl<R> FormResponse<R> {
+    /// Did all fields pass validation?
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The validation errors, in the order they were added.
+    pub fn errors(&self) -> impl Iterator<Item = &str> {
+        self.errors.iter().map(|(error, _)| error.as_str())
+    }
+
+    /// Scroll to the first field that failed validation, and give it keyboard focus.
+    ///
+    /// Does nothing if all fields are valid.
+    pub fn scroll_to_first_error(&self) {
+        if let Some(field) = self.errors.iter().find_map(|(_, field)| field.as_ref()) {
+            field.scroll_to_me(Align::Center);
+            field.request_focus();
+        }
+    }
+}
+
+/// Passed to the closure of [`Form::show`]. Used to add fields and validate them.
+pub struct FormUi<'a> {
+    ui: &'a mut Ui,
+    right_align_labels: bool,
+    /// Width of the label column, measured last frame.
+    label_width: f32,
+    /// Widest label seen so far this frame.
+    widest_label: f32,
+    /// The most recently added field.
+    last_field: Option<Response>,
+    errors: Vec<(String, Option<Response>)>,
+}
+
+impl<'a> FormUi<'a> {
+    /// The underlying [`Ui`] of the [`Grid`]. Remember to call [`Ui::end_row`] after adding two cells.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// Are all fields so far valid?
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Add a labeled field.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn field(
+        &mut self,
+        label: impl ToString,
+        add_field: impl FnOnce(&mut Ui) -> Response,
+    ) -> Response {
+        let label = Label::new(label);
+        let label_rect = if self.right_align_labels {
+            let size = vec2(self.label_width, self.ui.spacing().interact_size.y);
+            self.ui
+                .allocate_ui_with_layout(size, Layout::right_to_left(), |ui| ui.add(label).rect)
+                .inner
+        } else {
+            self.ui.add(label).rect
+        };
+        self.widest_label = self.widest_label.max(label_rect.width());
+
+        let response = add_field(self.ui);
+        self.ui.end_row();
+        self.last_field = Some(response.clone());
+        response
+    }
+
+    /// Validate the most recently added field.
+    ///
+    /// If `valid` is `false`, the field is outlined and the error is shown below it.
+    /// Returns `valid`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn validate(&mut self, valid: bool, error: impl ToString) -> bool {
+        if !valid {
+            let error = error.to_string();
+            let error_color = self.ui.visuals().error_fg_color;
+
+            if let Some(field) = &self.last_field {
+                let rounding = self.ui.visuals().widgets.inactive.corner_radius;
+                self.ui
+                    .painter()
+                    .rect_stroke(field.rect.expand(1.0), rounding, (1.0, error_color));
+            }
+
+            self.ui.label("");
+            self.ui.add(
+                Label::new(format!("⚠ {}", error))
+                    .text_color(error_color)
+                    .small(),
+            );
+            self.ui.end_row();
+
+            self.errors.push((error, self.last_field.clone()));
+        }
+        valid
+    }
+}
+
+#[test]
+fn test_form_validation() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 200.0));
+    let run = |harness: &mut Harness, name: &str| {
+        let mut result = None;
+        harness.run(|ui| {
+            let response = Form::new("form").show(ui, |form| {
+                let mut name = name.to_owned();
+                form.field("Name", |ui| ui.text_edit_singleline(&mut name));
+                form.validate(!name.is_empty(), "Name must not be empty");
+            });
+            let submit_pos = response.response.rect.left_bottom() + vec2(8.0, -8.0);
+            result = Some((response.errors().count(), response.submitted, submit_pos));
+        });
+        result.unwrap()
+    };
+
+    let (num_errors, _, submit_pos) = run(&mut harness, "");
+    assert_eq!(num_errors, 1);
+
+    // The submit button is disabled while there are errors:
+    harness.click(submit_pos);
+    assert!(!run(&mut harness, "").1);
+
+    let (num_errors, _, submit_pos) = run(&mut harness, "Alice");
+    assert_eq!(num_errors, 0);
+    harness.click(submit_pos);
+    assert!(run(&mut harness, "Alice").1);
+}
@@ -24,7 +24,7 @@ impl Frame {
     pub fn group(style: &Style) -> Self {
         Self {
             margin: Vec2::new(8.0, 6.0),
-            corner_radius: style.visuals.widgets.noninteractive.corner_radius,
+            corner_radius: frame_corner_radius(style),
             stroke: style.visuals.widgets.noninteractive.bg_stroke,
             ..Default::default()
         }
@@ -63,7 +63,7 @@ impl Frame {
     pub fn menu(style: &Style) -> Self {
         Self {
             margin: Vec2::splat(1.0),
-            corner_radius: style.visuals.widgets.noninteractive.corner_radius,
+            corner_radius: frame_corner_radius(style),
             shadow: style.visuals.popup_shadow,
             fill: style.visuals.window_fill(),
             stroke: style.visuals.window_stroke(),
@@ -73,7 +73,7 @@ impl Frame {
     pub fn popup(style: &Style) -> Self {
         Self {
             margin: style.spacing.window_padding,
-            corner_radius: style.visuals.widgets.noninteractive.corner_radius,
+            corner_radius: frame_corner_radius(style),
             shadow: style.visuals.popup_shadow,
             fill: style.visuals.window_fill(),
             stroke: style.visuals.window_stroke(),
@@ -84,7 +84,7 @@ impl Frame {
     pub fn dark_canvas(style: &Style) -> Self {
         Self {
             margin: Vec2::new(10.0, 10.0),
-            corner_radius: style.visuals.widgets.noninteractive.corner_radius,
+            corner_radius: frame_corner_radius(style),
             fill: Color32::from_black_alpha(250),
             stroke: style.visuals.window_stroke(),
             ..Default::default()
@@ -92,6 +92,13 @@ impl Frame {
     }
 }
 
+fn frame_corner_radius(style: &Style) -> f32 {
+    style
+        .visuals
+        .frame_corner_radius
+        .unwrap_or(style.visuals.widgets.noninteractive.corner_radius)
+}
+
 impl Frame {
     pub fn fill(mut self, fill: Color32) -> Self {
         self.fill = fill;
@@ -206,3 +213,21 @@ impl Prepared {
         ui.allocate_rect(outer_rect, Sense::hover())
     }
 }
+
+#[test]
+fn test_frame_corner_radius() {
+    let mut style = Style::default();
+    assert_eq!(
+        Frame::popup(&style).corner_radius,
+        style.visuals.widgets.noninteractive.corner_radius
+    );
+
+    style.visuals.frame_corner_radius = Some(7.0);
+    assert_eq!(Frame::group(&style).corner_radius, 7.0);
+    assert_eq!(Frame::menu(&style).corner_radius, 7.0);
+    assert_eq!(Frame::group(&style).corner_radius(1.0).corner_radius, 1.0);
+
+    style.visuals = style.visuals.square_corners();
+    assert_eq!(Frame::popup(&style).corner_radius, 0.0);
+    assert_eq!(Frame::window(&style).corner_radius, 0.0);
+}
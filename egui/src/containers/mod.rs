@@ -3,24 +3,38 @@
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
 pub(crate) mod area;
+pub(crate) mod canvas;
 pub(crate) mod collapsing_header;
 mod combo_box;
+pub(crate) mod command_palette;
+pub(crate) mod form;
 pub(crate) mod frame;
 pub(crate) mod panel;
 pub mod popup;
+pub(crate) mod property_grid;
 pub(crate) mod resize;
+pub(crate) mod responsive;
 pub(crate) mod scroll_area;
+pub(crate) mod status_bar;
+pub(crate) mod tour;
 pub(crate) mod window;
 
 pub use {
     area::Area,
+    canvas::Canvas,
     collapsing_header::*,
     combo_box::*,
+    command_palette::{Command, CommandPalette},
+    form::{Form, FormResponse, FormUi},
     frame::Frame,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
+    property_grid::{PropertyGrid, PropertyGridUi},
     resize::Resize,
+    responsive::Responsive,
     scroll_area::ScrollArea,
+    status_bar::{StatusBar, StatusBarUi},
+    tour::{Tour, TourStep, TourTarget},
     window::Window,
 };
 
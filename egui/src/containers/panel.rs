@@ -16,6 +16,21 @@ use crate::*;
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 struct PanelState {
     rect: Rect,
+
+    /// The size of the screen when `rect` was stored,
+    /// so that panels with a [`Size::Relative`] size can follow the screen size.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    screen_size: Vec2,
+}
+
+/// The stored width or height of a panel, adjusted for any change in the screen size.
+fn follow_screen(length: f32, stored_screen_length: f32, screen_length: f32, size: Size) -> f32 {
+    if size.is_relative() && stored_screen_length > 0.0 {
+        // Keep the same fraction of the screen:
+        length * screen_length / stored_screen_length
+    } else {
+        length
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -73,7 +88,7 @@ pub struct SidePanel {
     id: Id,
     frame: Option<Frame>,
     resizable: bool,
-    default_width: f32,
+    default_width: Size,
     width_range: RangeInclusive<f32>,
 }
 
@@ -95,7 +110,7 @@ impl SidePanel {
             id: Id::new(id_source),
             frame: None,
             resizable: true,
-            default_width: 200.0,
+            default_width: Size::Absolute(200.0),
             width_range: 96.0..=f32::INFINITY,
         }
     }
@@ -108,11 +123,20 @@ impl SidePanel {
     }
 
     /// The initial wrapping width of the `SidePanel`.
-    pub fn default_width(mut self, default_width: f32) -> Self {
-        self.default_width = default_width;
+    ///
+    /// With [`Size::Relative`] the panel stays the same fraction of the screen width
+    /// when the screen is resized.
+    pub fn default_width(mut self, default_width: impl Into<Size>) -> Self {
+        self.default_width = default_width.into();
         self
     }
 
+    /// A fixed width, that the user can't change.
+    /// Same as `.default_width(width).resizable(false)`.
+    pub fn width(self, width: impl Into<Size>) -> Self {
+        self.default_width(width).resizable(false)
+    }
+
     pub fn min_width(mut self, min_width: f32) -> Self {
         self.width_range = min_width..=(*self.width_range.end());
         self
@@ -154,11 +178,17 @@ impl SidePanel {
         let layer_id = LayerId::background();
 
         let available_rect = ctx.available_rect();
+        let screen_size = ctx.input().screen_rect().size();
         let mut panel_rect = available_rect;
         {
-            let mut width = default_width;
+            let mut width = default_width.resolve(screen_size.x);
             if let Some(state) = ctx.memory().id_data.get::<PanelState>(&id) {
-                width = state.rect.width();
+                width = follow_screen(
+                    state.rect.width(),
+                    state.screen_size.x,
+                    screen_size.x,
+                    default_width,
+                );
             }
             width = clamp_to_range(width, width_range.clone()).at_most(available_rect.width());
             side.set_rect_width(&mut panel_rect, width);
@@ -212,7 +242,9 @@ impl SidePanel {
         });
 
         let rect = inner_response.response.rect;
-        ctx.memory().id_data.insert(id, PanelState { rect });
+        ctx.memory()
+            .id_data
+            .insert(id, PanelState { rect, screen_size });
 
         if resize_hover || is_resizing {
             let stroke = if is_resizing {
@@ -297,7 +329,7 @@ pub struct TopBottomPanel {
     id: Id,
     frame: Option<Frame>,
     resizable: bool,
-    default_height: Option<f32>,
+    default_height: Option<Size>,
     height_range: RangeInclusive<f32>,
 }
 
@@ -333,11 +365,20 @@ impl TopBottomPanel {
 
     /// The initial height of the `SidePanel`.
     /// Defaults to [`style::Spacing::interact_size`].y.
-    pub fn default_height(mut self, default_height: f32) -> Self {
-        self.default_height = Some(default_height);
+    ///
+    /// With [`Size::Relative`] the panel stays the same fraction of the screen height
+    /// when the screen is resized.
+    pub fn default_height(mut self, default_height: impl Into<Size>) -> Self {
+        self.default_height = Some(default_height.into());
         self
     }
 
+    /// A fixed height, that the user can't change.
+    /// Same as `.default_height(height).resizable(false)`.
+    pub fn height(self, height: impl Into<Size>) -> Self {
+        self.default_height(height).resizable(false)
+    }
+
     pub fn min_height(mut self, min_height: f32) -> Self {
         self.height_range = min_height..=(*self.height_range.end());
         self
@@ -379,13 +420,21 @@ impl TopBottomPanel {
         let layer_id = LayerId::background();
 
         let available_rect = ctx.available_rect();
+        let screen_size = ctx.input().screen_rect().size();
         let mut panel_rect = available_rect;
         {
+            let default_height =
+                default_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y.into());
             let state = ctx.memory().id_data.get::<PanelState>(&id).copied();
             let mut height = if let Some(state) = state {
-                state.rect.height()
+                follow_screen(
+                    state.rect.height(),
+                    state.screen_size.y,
+                    screen_size.y,
+                    default_height,
+                )
             } else {
-                default_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y)
+                default_height.resolve(screen_size.y)
             };
             height = clamp_to_range(height, height_range.clone()).at_most(available_rect.height());
             side.set_rect_height(&mut panel_rect, height);
@@ -440,7 +489,9 @@ impl TopBottomPanel {
         });
 
         let rect = inner_response.response.rect;
-        ctx.memory().id_data.insert(id, PanelState { rect });
+        ctx.memory()
+            .id_data
+            .insert(id, PanelState { rect, screen_size });
 
         if resize_hover || is_resizing {
             let stroke = if is_resizing {
@@ -551,3 +602,25 @@ fn clamp_to_range(x: f32, range: RangeInclusive<f32>) -> f32 {
         range.start().max(*range.end()),
     )
 }
+
+#[test]
+fn test_relative_panel_width_follows_screen() {
+    let mut harness = crate::harness::Harness::new(vec2(800.0, 400.0));
+    let panel_width = |harness: &mut crate::harness::Harness| {
+        let mut width = 0.0;
+        harness.run_ctx(|ctx| {
+            width = SidePanel::left("left_panel")
+                .default_width(Size::Relative(0.25))
+                .frame(Frame::none())
+                .show(ctx, |ui| ui.set_min_width(ui.available_width()))
+                .response
+                .rect
+                .width();
+        });
+        width
+    };
+
+    assert_eq!(panel_width(&mut harness), 200.0);
+    harness.set_screen_size(vec2(1200.0, 400.0));
+    assert_eq!(panel_width(&mut harness), 300.0);
+}
@@ -103,10 +103,23 @@ pub fn show_tooltip_under<R>(
 /// Returns `None` if the tooltip could not be placed.
 pub fn show_tooltip_at<R>(
     ctx: &CtxRef,
-    mut id: Id,
+    id: Id,
     suggested_position: Option<Pos2>,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
+    let max_width = ctx.style().spacing.tooltip_width;
+    show_tooltip_at_impl(ctx, id, suggested_position, max_width, false, add_contents)
+        .map(|inner_response| inner_response.inner)
+}
+
+fn show_tooltip_at_impl<R>(
+    ctx: &CtxRef,
+    mut id: Id,
+    suggested_position: Option<Pos2>,
+    max_width: f32,
+    interactable: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<InnerResponse<R>> {
     let mut tooltip_rect = Rect::NOTHING;
 
     let position = if let Some((stored_id, stored_tooltip_rect)) = ctx.frame_state().tooltip_rect {
@@ -131,14 +144,16 @@ pub fn show_tooltip_at<R>(
     let position = position.min(ctx.input().screen_rect().right_bottom() - expected_size);
     let position = position.max(ctx.input().screen_rect().left_top());
 
-    let InnerResponse { inner, response } = show_tooltip_area(ctx, id, position, add_contents);
+    let inner_response =
+        show_tooltip_area(ctx, id, position, max_width, interactable, add_contents);
+    let rect = inner_response.response.rect;
     ctx.memory()
         .data_temp
         .get_mut_or_default::<crate::containers::popup::MonoState>()
-        .set_tooltip_size(id, response.rect.size());
+        .set_tooltip_size(id, rect.size());
 
-    ctx.frame_state().tooltip_rect = Some((id, tooltip_rect.union(response.rect)));
-    Some(inner)
+    ctx.frame_state().tooltip_rect = Some((id, tooltip_rect.union(rect)));
+    Some(inner_response)
 }
 
 /// Show some text at the current pointer position (if any).
@@ -166,23 +181,153 @@ fn show_tooltip_area<R>(
     ctx: &CtxRef,
     id: Id,
     window_pos: Pos2,
+    max_width: f32,
+    interactable: bool,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> InnerResponse<R> {
     use containers::*;
     Area::new(id)
         .order(Order::Tooltip)
         .fixed_pos(window_pos)
-        .interactable(false)
+        .interactable(interactable)
         .show(ctx, |ui| {
             Frame::popup(&ctx.style())
                 .show(ui, |ui| {
-                    ui.set_max_width(ui.spacing().tooltip_width);
+                    ui.set_max_width(max_width);
                     add_contents(ui)
                 })
                 .inner
         })
 }
 
+// ----------------------------------------------------------------------------
+
+/// A tooltip with more options than [`Response::on_hover_ui`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let response = ui.button("Hover me");
+/// egui::Tooltip::new()
+///     .delay(0.5)
+///     .max_width(200.0)
+///     .interactable(true)
+///     .show_for(&response, |ui| {
+///         ui.label("Move the mouse here to click the link:");
+///         ui.hyperlink("https://github.com/emilk/egui");
+///     });
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[must_use = "You should call .show_for()"]
+pub struct Tooltip {
+    max_width: Option<f32>,
+    delay: Option<f32>,
+    interactable: bool,
+    at_pointer: bool,
+}
+
+impl Tooltip {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Maximum width of the tooltip. Default: [`crate::style::Spacing::tooltip_width`].
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// How long the widget must be hovered before the tooltip shows, in seconds.
+    /// Default: [`crate::style::Interaction::tooltip_delay`].
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// If `true`, the tooltip stays open while the pointer is over it,
+    /// so that its contents can be clicked, scrolled or selected.
+    /// Default: `false`.
+    pub fn interactable(mut self, interactable: bool) -> Self {
+        self.interactable = interactable;
+        self
+    }
+
+    /// Show the tooltip next to the pointer instead of below the widget.
+    /// Default: `false`.
+    pub fn at_pointer(mut self, at_pointer: bool) -> Self {
+        self.at_pointer = at_pointer;
+        self
+    }
+
+    /// Show the tooltip if the widget of the given [`Response`] is hovered.
+    ///
+    /// Returns `None` if the tooltip is not shown.
+    pub fn show_for<R>(
+        self,
+        response: &Response,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<R> {
+        let Self {
+            max_width,
+            delay,
+            interactable,
+            at_pointer,
+        } = self;
+        let ctx = &response.ctx;
+        let id = response.id.with("__tooltip");
+        let rect_id = id.with("rect");
+
+        // Keep an interactable tooltip open while the pointer is over it,
+        // or in the gap between it and the widget:
+        let pointer_over_tooltip = interactable && {
+            let last_rect = ctx.memory().id_data_temp.get::<Rect>(&rect_id).copied();
+            match (last_rect, ctx.input().pointer.hover_pos()) {
+                (Some(last_rect), Some(pointer_pos)) => last_rect
+                    .union(response.rect)
+                    .expand(4.0)
+                    .contains(pointer_pos),
+                _ => false,
+            }
+        };
+
+        let delay = delay.unwrap_or_else(|| ctx.style().interaction.tooltip_delay);
+        if !pointer_over_tooltip && !response.should_show_hover_ui_after(delay) {
+            ctx.memory().id_data_temp.remove(&rect_id);
+            return None;
+        }
+
+        let suggested_position = if at_pointer {
+            let last_rect = ctx.memory().id_data_temp.get::<Rect>(&rect_id).copied();
+            match last_rect {
+                // Don't chase the pointer while it is moving towards the tooltip:
+                Some(last_rect) if pointer_over_tooltip => Some(last_rect.min),
+                _ => ctx
+                    .input()
+                    .pointer
+                    .hover_pos()
+                    .map(|pointer_pos| pointer_pos + vec2(16.0, 16.0)),
+            }
+        } else {
+            Some(response.rect.left_bottom() + vec2(-2.0, 4.0))
+        };
+        let max_width = max_width.unwrap_or_else(|| ctx.style().spacing.tooltip_width);
+
+        let InnerResponse { inner, response } = show_tooltip_at_impl(
+            ctx,
+            id,
+            suggested_position,
+            max_width,
+            interactable,
+            add_contents,
+        )?;
+        if interactable {
+            ctx.memory().id_data_temp.insert(rect_id, response.rect);
+        }
+        Some(inner)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Shows a popup below another widget.
 ///
 /// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
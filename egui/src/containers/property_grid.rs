@@ -0,0 +1,250 @@
+//! A two-column name/editor inspector, like the property panels in editors.
+
+use crate::*;
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    /// Width of the name column, measured last frame.
+    name_width: f32,
+
+    /// The text in the search field.
+    filter: String,
+}
+
+/// A list of named properties, each with an editor.
+///
+/// The names are shown in one column and the editors in another.
+/// The editor column is aligned across all categories, even nested ones.
+///
+/// Properties can be grouped into collapsible categories with [`PropertyGridUi::category`],
+/// and filtered by name with an optional search field.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut name = String::new();
+/// # let mut age = 42;
+/// # let mut scale = 1.0_f32;
+/// egui::PropertyGrid::new("my_properties")
+///     .search_field(true)
+///     .show(ui, |grid| {
+///         grid.property("Name", |ui| ui.text_edit_singleline(&mut name));
+///         grid.category("Details", |grid| {
+///             grid.property("Age", |ui| ui.add(egui::DragValue::new(&mut age)));
+///             grid.property_with_reset("Scale", &mut scale, 1.0, |ui, scale| {
+///                 ui.add(egui::Slider::new(scale, 0.1..=10.0))
+///             });
+///         });
+///     });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct PropertyGrid {
+    id_source: Id,
+    search_field: bool,
+    filter: Option<String>,
+    min_name_width: Option<f32>,
+}
+
+impl PropertyGrid {
+    /// Create a new [`PropertyGrid`] with a locally unique identifier.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            search_field: false,
+            filter: None,
+            min_name_width: None,
+        }
+    }
+
+    /// Show a search field above the properties that filters them by name.
+    /// Default: `false`.
+    pub fn search_field(mut self, search_field: bool) -> Self {
+        self.search_field = search_field;
+        self
+    }
+
+    /// Only show properties whose name contains this text (case insensitive).
+    ///
+    /// This overrides the text in the search field (if any).
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn filter(mut self, filter: impl ToString) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Minimum width of the name column.
+    /// Default: [`crate::style::Spacing::interact_size`]`.x`.
+    pub fn min_name_width(mut self, min_name_width: f32) -> Self {
+        self.min_name_width = Some(min_name_width);
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut PropertyGridUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            search_field,
+            filter,
+            min_name_width,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = ui.memory().id_data.get_or_default::<State>(id).clone();
+        let min_name_width = min_name_width.unwrap_or_else(|| ui.spacing().interact_size.x);
+
+        ui.vertical(|ui| {
+            if search_field {
+                ui.horizontal(|ui| {
+                    ui.label("🔍");
+                    ui.text_edit_singleline(&mut state.filter);
+                    if ui
+                        .add(Button::new("🗙").small().enabled(!state.filter.is_empty()))
+                        .on_hover_text("Clear search")
+                        .clicked()
+                    {
+                        state.filter.clear();
+                    }
+                });
+            }
+
+            let filter = filter.unwrap_or_else(|| state.filter.clone());
+            let mut grid = PropertyGridUi {
+                left: ui.min_rect().left(),
+                name_width: state.name_width.max(min_name_width),
+                widest_name: min_name_width,
+                filter: filter.to_lowercase(),
+                ui,
+            };
+            let inner = add_contents(&mut grid);
+            state.name_width = grid.widest_name;
+
+            ui.memory().id_data.insert(id, state);
+            inner
+        })
+    }
+}
+
+/// Passed to the closure of [`PropertyGrid::show`]. Used to add properties and categories.
+pub struct PropertyGridUi<'a> {
+    ui: &'a mut Ui,
+    /// Left edge of the whole grid. Names are measured from here.
+    left: f32,
+    /// Width of the name column.
+    name_width: f32,
+    /// Widest name seen so far this frame.
+    widest_name: f32,
+    /// Lowercase search filter. Empty means show everything.
+    filter: String,
+}
+
+impl<'a> PropertyGridUi<'a> {
+    /// The underlying [`Ui`], e.g. for adding a separator between properties.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// Is the grid currently filtered by a search term?
+    pub fn is_filtered(&self) -> bool {
+        !self.filter.is_empty()
+    }
+
+    /// Does the given property name pass the current filter?
+    pub fn is_visible(&self, name: &str) -> bool {
+        self.filter.is_empty() || name.to_lowercase().contains(&self.filter)
+    }
+
+    /// Add a named property with the given editor.
+    ///
+    /// Returns `None` if the property was filtered out.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn property<R>(
+        &mut self,
+        name: impl ToString,
+        add_editor: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<R> {
+        let name = name.to_string();
+        if !self.is_visible(&name) {
+            return None;
+        }
+
+        let left = self.left;
+        let name_width = self.name_width;
+        let widest_name = &mut self.widest_name;
+
+        let inner = self.ui.horizontal(|ui| {
+            let label_response = ui.label(name);
+            *widest_name = widest_name.max(label_response.rect.right() - left);
+
+            // Line up the editor with the editors of all other properties:
+            let padding = left + name_width - label_response.rect.right();
+            if padding > 0.0 {
+                ui.add_space(padding);
+            }
+
+            add_editor(ui)
+        });
+        Some(inner.inner)
+    }
+
+    /// Add a named property with an editor and a button to reset the value to `default`.
+    ///
+    /// The returned response is marked as changed if either the editor or the reset button changed the value.
+    /// Returns `None` if the property was filtered out.
+    pub fn property_with_reset<T: PartialEq>(
+        &mut self,
+        name: impl ToString,
+        value: &mut T,
+        default: T,
+        add_editor: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> Option<Response> {
+        self.property(name, |ui| {
+            let mut response = add_editor(ui, value);
+            let reset = ui
+                .add(Button::new("⟲").small().enabled(*value != default))
+                .on_hover_text("Reset to default");
+            if reset.clicked() {
+                *value = default;
+                response.mark_changed();
+            }
+            response
+        })
+    }
+
+    /// Add a collapsible category of properties.
+    ///
+    /// While the grid is filtered, categories are flattened
+    /// so that all matching properties are visible.
+    pub fn category<R>(
+        &mut self,
+        name: impl ToString,
+        add_contents: impl FnOnce(&mut PropertyGridUi<'_>) -> R,
+    ) -> Option<R> {
+        if self.is_filtered() {
+            return Some(add_contents(self));
+        }
+
+        let left = self.left;
+        let name_width = self.name_width;
+        let widest_name = &mut self.widest_name;
+
+        CollapsingHeader::new(name)
+            .default_open(true)
+            .show(self.ui, |ui| {
+                let mut grid = PropertyGridUi {
+                    ui,
+                    left,
+                    name_width,
+                    widest_name: *widest_name,
+                    filter: String::new(),
+                };
+                let inner = add_contents(&mut grid);
+                *widest_name = grid.widest_name;
+                inner
+            })
+            .body_returned
+    }
+}
@@ -0,0 +1,132 @@
+//! Pick between layout variants depending on the width of the screen.
+
+use crate::*;
+
+/// Picks one of several layout variants depending on the width of the screen,
+/// e.g. to turn a side panel into a collapsible overlay on phones.
+///
+/// Each variant is used from a minimum screen width and up.
+/// The widest variant that fits is picked, with some hysteresis,
+/// so that resizing the window back and forth across a threshold doesn't make the layout flap.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum MainLayout {
+///     Phone,
+///     Desktop,
+/// }
+///
+/// let layout = egui::Responsive::new("main_layout", MainLayout::Phone)
+///     .at_least(600.0, MainLayout::Desktop)
+///     .pick(ctx);
+///
+/// if layout == MainLayout::Desktop {
+///     egui::SidePanel::left("side_panel").show(ctx, |ui| {
+///         ui.label("Always visible");
+///     });
+/// } else {
+///     // A collapsible overlay:
+///     egui::Window::new("Side panel").show(ctx, |ui| {
+///         ui.label("Hidden when collapsed");
+///     });
+/// }
+/// ```
+#[must_use = "You should call .pick()"]
+pub struct Responsive<L> {
+    id: Id,
+    /// `(min_width, variant)`, sorted by `min_width`.
+    variants: Vec<(f32, L)>,
+    hysteresis: f32,
+}
+
+impl<L: Clone> Responsive<L> {
+    /// `id_source`: Something unique, e.g. `"main_layout"`.
+    ///
+    /// `narrowest` is used when no other variant fits.
+    pub fn new(id_source: impl std::hash::Hash, narrowest: L) -> Self {
+        Self {
+            id: Id::new(id_source),
+            variants: vec![(f32::NEG_INFINITY, narrowest)],
+            hysteresis: 16.0,
+        }
+    }
+
+    /// Use `variant` when the screen is at least `min_width` points wide
+    /// (and no variant with a larger `min_width` fits).
+    pub fn at_least(mut self, min_width: f32, variant: L) -> Self {
+        let index = self
+            .variants
+            .iter()
+            .position(|(width, _)| *width > min_width)
+            .unwrap_or(self.variants.len());
+        self.variants.insert(index, (min_width, variant));
+        self
+    }
+
+    /// How far past a threshold the screen width must go before we switch variant.
+    /// Default: `16.0` points.
+    pub fn hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis.at_least(0.0);
+        self
+    }
+
+    /// The variant to use this frame.
+    pub fn pick(self, ctx: &CtxRef) -> L {
+        let Self {
+            id,
+            variants,
+            hysteresis,
+        } = self;
+
+        let width = ctx.input().screen_rect().width();
+        let previous = ctx.memory().id_data_temp.get::<usize>(&id).copied();
+        let index = match previous {
+            Some(previous)
+                if previous < variants.len()
+                    && variants[previous].0 - hysteresis <= width
+                    && variants
+                        .get(previous + 1)
+                        .map_or(true, |(next_width, _)| width < next_width + hysteresis) =>
+            {
+                previous
+            }
+            _ => variants
+                .iter()
+                .rposition(|(min_width, _)| *min_width <= width)
+                .unwrap_or(0),
+        };
+        ctx.memory().id_data_temp.insert(id, index);
+
+        variants[index].1.clone()
+    }
+}
+
+#[test]
+fn test_responsive_hysteresis() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(800.0, 400.0));
+    let mut pick_at = |width: f32| {
+        harness.set_screen_size(vec2(width, 400.0));
+        let mut picked = "";
+        harness.run_ctx(|ctx| {
+            picked = Responsive::new("layout", "phone")
+                .at_least(1000.0, "desktop")
+                .at_least(600.0, "tablet")
+                .hysteresis(20.0)
+                .pick(ctx);
+        });
+        picked
+    };
+
+    assert_eq!(pick_at(800.0), "tablet");
+    assert_eq!(pick_at(590.0), "tablet"); // within the hysteresis
+    assert_eq!(pick_at(570.0), "phone");
+    assert_eq!(pick_at(610.0), "phone"); // within the hysteresis
+    assert_eq!(pick_at(630.0), "tablet");
+    assert_eq!(pick_at(1500.0), "desktop");
+    assert_eq!(pick_at(300.0), "phone");
+}
@@ -14,6 +14,10 @@ pub(crate) struct State {
     pub vel: Vec2,
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top: Option<f32>,
+
+    /// Were we scrolled all the way to the bottom at the end of last frame?
+    /// Used by [`ScrollArea::stick_to_bottom`].
+    scroll_stuck_to_bottom: bool,
 }
 
 impl Default for State {
@@ -23,6 +27,7 @@ impl Default for State {
             show_scroll: false,
             vel: Vec2::ZERO,
             scroll_start_offset_from_top: None,
+            scroll_stuck_to_bottom: true,
         }
     }
 }
@@ -37,6 +42,9 @@ pub struct ScrollArea {
     id_source: Option<Id>,
     offset: Option<Vec2>,
     scrolling_enabled: bool,
+    stick_to_bottom: bool,
+    minimap: bool,
+    markers: Vec<(f32, Color32)>,
 }
 
 impl ScrollArea {
@@ -53,6 +61,9 @@ impl ScrollArea {
             id_source: None,
             offset: None,
             scrolling_enabled: true,
+            stick_to_bottom: false,
+            minimap: false,
+            markers: vec![],
         }
     }
 
@@ -88,6 +99,37 @@ impl ScrollArea {
         self.scrolling_enabled = enable;
         self
     }
+
+    /// If `true`, the scroll area stays scrolled to the bottom when the contents grow,
+    /// as long as the user hasn't scrolled up. Useful for logs and chats.
+    ///
+    /// Starts out scrolled to the bottom.
+    /// Default: `false`.
+    pub fn stick_to_bottom(mut self, stick_to_bottom: bool) -> Self {
+        self.stick_to_bottom = stick_to_bottom;
+        self
+    }
+
+    /// If `true`, show a scaled-down overview of the contents next to the scroll bar,
+    /// like in many code editors. Click or drag it to scroll there.
+    ///
+    /// The overview is made from what the contents painted this frame,
+    /// so with [`Self::show_rows`] or [`Self::show_viewport`] it only shows the visible part.
+    /// Default: `false`.
+    pub fn minimap(mut self, minimap: bool) -> Self {
+        self.minimap = minimap;
+        self
+    }
+
+    /// Put a small colored marker on the scroll bar, e.g. for a search hit, an error or a bookmark.
+    /// Clicking the marker scrolls there.
+    ///
+    /// `offset` is measured from the top of the contents, in the same way as [`Self::scroll_offset`].
+    /// The markers are only shown when the scroll bar is.
+    pub fn marker(mut self, offset: f32, color: Color32) -> Self {
+        self.markers.push((offset, color));
+        self
+    }
 }
 
 struct Prepared {
@@ -101,6 +143,12 @@ struct Prepared {
     /// `viewport.min == ZERO` means we scrolled to the top.
     viewport: Rect,
     scrolling_enabled: bool,
+    stick_to_bottom: bool,
+    /// Zero if there is no minimap.
+    minimap_width: f32,
+    /// Index of the first shape painted by the contents, for the minimap.
+    first_shape: usize,
+    markers: Vec<(f32, Color32)>,
 }
 
 impl ScrollArea {
@@ -111,6 +159,9 @@ impl ScrollArea {
             id_source,
             offset,
             scrolling_enabled,
+            stick_to_bottom,
+            minimap,
+            markers,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -142,7 +193,9 @@ impl ScrollArea {
             available_outer.height().at_most(max_height),
         );
 
-        let inner_size = outer_size - vec2(current_scroll_bar_width, 0.0);
+        let minimap_width = if minimap { MINIMAP_WIDTH } else { 0.0 };
+
+        let inner_size = outer_size - vec2(current_scroll_bar_width + minimap_width, 0.0);
         let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
 
         let mut content_ui = ui.child_ui(
@@ -154,11 +207,17 @@ impl ScrollArea {
         );
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
         content_clip_rect = content_clip_rect.intersect(ui.clip_rect());
-        content_clip_rect.max.x = ui.clip_rect().max.x - current_scroll_bar_width; // Nice handling of forced resizing beyond the possible
+        content_clip_rect.max.x = ui.clip_rect().max.x - current_scroll_bar_width - minimap_width; // Nice handling of forced resizing beyond the possible
         content_ui.set_clip_rect(content_clip_rect);
 
         let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
 
+        let first_shape = if minimap {
+            ctx.graphics().list(ui.layer_id()).lock().len()
+        } else {
+            0
+        };
+
         Prepared {
             id,
             state,
@@ -168,6 +227,10 @@ impl ScrollArea {
             content_ui,
             viewport,
             scrolling_enabled,
+            stick_to_bottom,
+            minimap_width,
+            first_shape,
+            markers,
         }
     }
 
@@ -246,12 +309,30 @@ impl Prepared {
             content_ui,
             viewport: _,
             scrolling_enabled,
+            stick_to_bottom,
+            minimap_width,
+            first_shape,
+            markers,
         } = self;
 
         let content_size = content_ui.min_size();
+        let content_shapes = first_shape..ui.ctx().graphics().list(ui.layer_id()).lock().len();
+
+        if stick_to_bottom && state.scroll_stuck_to_bottom {
+            // Follow the contents if they grew since last frame:
+            let bottom = (content_size.y - inner_rect.height()).at_least(0.0);
+            if state.offset.y != bottom {
+                state.offset.y = bottom;
+                ui.ctx().request_repaint();
+            }
+        }
 
         // We take the scroll target so only this ScrollArea will use it.
-        let scroll_target = content_ui.ctx().frame_state().scroll_target.take();
+        let scroll_target = {
+            let mut frame_state = content_ui.ctx().frame_state();
+            let scroll_target = frame_state.scroll_target.take();
+            scroll_target.or_else(|| frame_state.take_scroll_to_widget(content_ui.min_rect()))
+        };
         if let Some((scroll_y, align)) = scroll_target {
             let center_factor = align.to_factor();
 
@@ -281,6 +362,7 @@ impl Prepared {
             // The window that egui sits in can't be expanded by egui, so we need to respect it:
             let max_x = ui.input().screen_rect().right()
                 - current_scroll_bar_width
+                - minimap_width
                 - ui.spacing().item_spacing.x;
             inner_rect.max.x = inner_rect.max.x.at_most(max_x);
             // TODO: when we support it, we should maybe auto-enable
@@ -291,7 +373,7 @@ impl Prepared {
 
         let outer_rect = Rect::from_min_size(
             inner_rect.min,
-            inner_rect.size() + vec2(current_scroll_bar_width, 0.0),
+            inner_rect.size() + vec2(current_scroll_bar_width + minimap_width, 0.0),
         );
 
         let content_is_too_small = content_size.y > inner_rect.height();
@@ -306,16 +388,18 @@ impl Prepared {
             let content_response = ui.interact(inner_rect, id.with("area"), sense);
 
             let input = ui.input();
+            let interaction = &ui.style().interaction;
             if content_response.dragged() {
                 state.offset.y -= input.pointer.delta().y;
                 state.vel = input.pointer.velocity();
             } else {
-                let stop_speed = 20.0; // Pixels per second.
-                let friction_coeff = 1000.0; // Pixels per second squared.
                 let dt = input.unstable_dt;
 
-                let friction = friction_coeff * dt;
-                if friction > state.vel.length() || state.vel.length() < stop_speed {
+                let friction = interaction.scroll_friction * dt;
+                if !interaction.kinetic_scrolling
+                    || friction > state.vel.length()
+                    || state.vel.length() < interaction.scroll_stop_speed
+                {
                     state.vel = Vec2::ZERO;
                 } else {
                     state.vel -= friction * state.vel.normalized();
@@ -342,6 +426,22 @@ impl Prepared {
             }
         }
 
+        if minimap_width > 0.0 {
+            let minimap_rect = Rect::from_min_size(
+                inner_rect.right_top(),
+                vec2(minimap_width, inner_rect.height()),
+            );
+            minimap_ui(
+                ui,
+                id.with("minimap"),
+                minimap_rect,
+                content_shapes,
+                content_ui.max_rect().min,
+                content_size,
+                &mut state,
+            );
+        }
+
         let show_scroll_this_frame = content_is_too_small || always_show_scroll;
 
         let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
@@ -355,7 +455,7 @@ impl Prepared {
             let animation_t = current_scroll_bar_width / max_scroll_bar_width;
             // margin between contents and scroll bar
             let margin = animation_t * ui.spacing().item_spacing.x;
-            let left = inner_rect.right() + margin;
+            let left = inner_rect.right() + minimap_width + margin;
             let right = outer_rect.right();
             let top = inner_rect.top();
             let bottom = inner_rect.bottom();
@@ -401,6 +501,21 @@ impl Prepared {
                 state.scroll_start_offset_from_top = None;
             }
 
+            if response.clicked() {
+                if let Some(pointer_pos) = response.interact_pointer_pos() {
+                    // Jump to the clicked marker, if any:
+                    let distance = |offset: f32| (from_content(offset) - pointer_pos.y).abs();
+                    let clicked_marker = markers
+                        .iter()
+                        .map(|&(offset, _)| offset)
+                        .filter(|&offset| distance(offset) <= MARKER_HEIGHT)
+                        .min_by(|a, b| distance(*a).partial_cmp(&distance(*b)).unwrap());
+                    if let Some(offset) = clicked_marker {
+                        state.offset.y = offset - 0.5 * inner_rect.height();
+                    }
+                }
+            }
+
             let unbounded_offset_y = state.offset.y;
             state.offset.y = state.offset.y.max(0.0);
             state.offset.y = state.offset.y.min(max_offset);
@@ -428,17 +543,29 @@ impl Prepared {
                 &ui.style().visuals.widgets.inactive
             };
 
+            let corner_radius = ui
+                .visuals()
+                .scroll_handle_corner_radius
+                .unwrap_or(visuals.corner_radius);
             ui.painter().add(epaint::Shape::rect_filled(
                 outer_scroll_rect,
-                visuals.corner_radius,
+                corner_radius,
                 ui.visuals().extreme_bg_color,
             ));
 
             ui.painter().add(epaint::Shape::rect_filled(
                 handle_rect,
-                visuals.corner_radius,
+                corner_radius,
                 visuals.bg_fill,
             ));
+
+            for &(offset, color) in &markers {
+                let marker_rect = Rect::from_center_size(
+                    pos2(outer_scroll_rect.center().x, from_content(offset)),
+                    vec2(outer_scroll_rect.width(), MARKER_HEIGHT),
+                );
+                ui.painter().rect_filled(marker_rect, 0.0, color);
+            }
         }
 
         let size = vec2(
@@ -454,6 +581,7 @@ impl Prepared {
         state.offset.y = state.offset.y.min(content_size.y - inner_rect.height());
         state.offset.y = state.offset.y.max(0.0);
         state.show_scroll = show_scroll_this_frame;
+        state.scroll_stuck_to_bottom = state.offset.y + 1.0 >= content_size.y - inner_rect.height();
 
         ui.memory().id_data.insert(id, state);
     }
@@ -462,3 +590,322 @@ impl Prepared {
 fn max_scroll_bar_width_with_margin(ui: &Ui) -> f32 {
     ui.spacing().item_spacing.x + ui.spacing().scroll_bar_width
 }
+
+const MINIMAP_WIDTH: f32 = 80.0;
+
+/// Height of the markers added with [`ScrollArea::marker`].
+const MARKER_HEIGHT: f32 = 3.0;
+
+/// Paint a scaled-down version of the shapes painted by the contents,
+/// and scroll to where the user clicks or drags.
+fn minimap_ui(
+    ui: &Ui,
+    id: Id,
+    rect: Rect,
+    content_shapes: std::ops::Range<usize>,
+    content_origin: Pos2,
+    content_size: Vec2,
+    state: &mut State,
+) {
+    let viewport_height = rect.height();
+    let scale = (rect.width() / content_size.x.at_least(1.0)).at_most(0.25);
+
+    // If the minimap is too tall to fit it scrolls along with the contents:
+    let max_offset = (content_size.y - viewport_height).at_least(0.0);
+    let max_minimap_offset = (content_size.y * scale - rect.height()).at_least(0.0);
+    let minimap_offset = if max_offset > 0.0 {
+        state.offset.y.clamp(0.0, max_offset) / max_offset * max_minimap_offset
+    } else {
+        0.0
+    };
+    let to_minimap = |content_rect: Rect| {
+        let min = content_rect.min - content_origin;
+        let max = content_rect.max - content_origin;
+        let offset = rect.min - vec2(0.0, minimap_offset);
+        Rect::from_min_max(offset + min * scale, offset + max * scale)
+    };
+
+    let response = ui.interact(rect, id, Sense::click_and_drag());
+    if let Some(pointer_pos) = response.interact_pointer_pos() {
+        // Center the view on the pointer:
+        let content_y = (pointer_pos.y - rect.top() + minimap_offset) / scale;
+        state.offset.y = content_y - 0.5 * viewport_height;
+        state.vel = Vec2::ZERO;
+    }
+
+    let mut blocks = vec![];
+    {
+        let paint_list = ui.ctx().graphics().list(ui.layer_id()).clone();
+        let paint_list = paint_list.lock();
+        for shape in paint_list.shapes(content_shapes) {
+            minimap_blocks(shape, &mut blocks);
+        }
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+    for (block, color) in blocks {
+        let mut block = to_minimap(block);
+        if block.intersects(rect) {
+            block.max.y = block.max.y.at_least(block.min.y + 1.0);
+            painter.rect_filled(block, 0.0, color.linear_multiply(0.5));
+        }
+    }
+
+    let view = Rect::from_min_size(
+        content_origin + vec2(0.0, state.offset.y),
+        vec2(content_size.x, viewport_height),
+    );
+    let visuals = ui.style().interact(&response);
+    painter.rect(
+        to_minimap(view).intersect(rect),
+        0.0,
+        visuals.bg_fill.linear_multiply(0.4),
+        visuals.bg_stroke,
+    );
+}
+
+/// A simplified version of the shape for the minimap: rectangles of a single color.
+/// Text becomes one block per row.
+fn minimap_blocks(shape: &Shape, blocks: &mut Vec<(Rect, Color32)>) {
+    let color_of = |fill: Color32, stroke: Stroke| {
+        if fill != Color32::TRANSPARENT {
+            fill
+        } else {
+            stroke.color
+        }
+    };
+
+    match shape {
+        Shape::Noop => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                minimap_blocks(shape, blocks);
+            }
+        }
+        Shape::Text {
+            pos, galley, color, ..
+        } => {
+            for row in &galley.rows {
+                if row.max_x() > row.min_x() {
+                    blocks.push((row.rect().translate(pos.to_vec2()), *color));
+                }
+            }
+        }
+        Shape::Circle { fill, stroke, .. }
+        | Shape::Path { fill, stroke, .. }
+        | Shape::Rect { fill, stroke, .. } => {
+            blocks.push((shape.visual_bounding_rect(), color_of(*fill, *stroke)));
+        }
+        Shape::LineSegment { stroke, .. } => {
+            blocks.push((shape.visual_bounding_rect(), stroke.color));
+        }
+        Shape::Mesh(mesh) => {
+            if let Some(vertex) = mesh.vertices.first() {
+                blocks.push((mesh.calc_bounds(), vertex.color));
+            }
+        }
+        Shape::NineSlice(nine_slice) => {
+            blocks.push((nine_slice.rect, nine_slice.tint));
+        }
+    }
+}
+
+#[test]
+fn test_minimap_navigation() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 200.0));
+    let run = |harness: &mut Harness| {
+        let mut viewport = Rect::NOTHING;
+        let mut left = 0.0;
+        harness.run(|ui| {
+            left = ui.max_rect().left();
+            ScrollArea::auto_sized()
+                .minimap(true)
+                .show_viewport(ui, |ui, rect| {
+                    viewport = rect;
+                    for i in 0..100 {
+                        ui.label(format!("Line {}", i));
+                    }
+                });
+        });
+        (viewport, left)
+    };
+
+    for _ in 0..3 {
+        run(&mut harness);
+    }
+    let (viewport, left) = run(&mut harness);
+    assert_eq!(viewport.min.y, 0.0);
+
+    // Press near the bottom of the minimap to scroll down:
+    let minimap_pos = pos2(left + viewport.width() + 0.5 * MINIMAP_WIDTH, 180.0);
+    harness.pointer_move(minimap_pos);
+    harness.pointer_button(minimap_pos, true);
+    run(&mut harness);
+    harness.pointer_button(minimap_pos, false);
+    assert!(run(&mut harness).0.min.y > 0.0);
+}
+
+#[test]
+fn test_scroll_bar_marker() {
+    use crate::harness::Harness;
+
+    let marker_offset = 1000.0;
+    let mut harness = Harness::new(vec2(300.0, 200.0));
+    let run = |harness: &mut Harness| {
+        let mut result = (Rect::NOTHING, Rect::NOTHING, 0.0);
+        harness.run(|ui| {
+            let outer = ui.max_rect();
+            ScrollArea::from_max_height(100.0)
+                .marker(marker_offset, Color32::RED)
+                .show_viewport(ui, |ui, viewport| {
+                    for i in 0..100 {
+                        ui.label(format!("Line {}", i));
+                    }
+                    result = (outer, viewport, ui.min_rect().height());
+                });
+        });
+        result
+    };
+
+    for _ in 0..3 {
+        run(&mut harness);
+    }
+    let (outer, viewport, content_height) = run(&mut harness);
+    assert_eq!(viewport.min.y, 0.0);
+
+    let marker_pos = pos2(
+        outer.right() - 0.5 * harness.ctx().style().spacing.scroll_bar_width,
+        outer.top() + 100.0 * marker_offset / content_height,
+    );
+    harness.click(marker_pos);
+    run(&mut harness);
+    let (_, viewport, _) = run(&mut harness);
+    assert!(
+        (viewport.center().y - marker_offset).abs() < 1.0,
+        "{:?}",
+        viewport
+    );
+}
+
+#[test]
+fn test_kinetic_scrolling() {
+    use crate::harness::Harness;
+
+    fn fling(kinetic_scrolling: bool) -> (f32, f32) {
+        let mut harness = Harness::new(vec2(300.0, 200.0));
+        let mut style = (*harness.ctx().style()).clone();
+        style.interaction.kinetic_scrolling = kinetic_scrolling;
+        harness.ctx().set_style(style);
+
+        let run = |harness: &mut Harness| {
+            let mut offset = 0.0;
+            harness.run(|ui| {
+                ScrollArea::auto_sized().show_viewport(ui, |ui, viewport| {
+                    for i in 0..1000 {
+                        ui.label(format!("Line {}", i));
+                    }
+                    offset = viewport.min.y;
+                });
+            });
+            offset
+        };
+
+        run(&mut harness);
+        let mut pos = pos2(100.0, 150.0);
+        harness.pointer_button(pos, true);
+        for _ in 0..5 {
+            run(&mut harness);
+            pos.y -= 20.0;
+            harness.pointer_move(pos);
+        }
+        harness.pointer_button(pos, false);
+        let released = run(&mut harness);
+        for _ in 0..5 {
+            run(&mut harness);
+        }
+        (released, run(&mut harness))
+    }
+
+    let (released, later) = fling(true);
+    assert!(later > released, "{} {}", released, later);
+
+    let (released, later) = fling(false);
+    assert_eq!(later, released);
+}
+
+#[test]
+fn test_scroll_to_widget() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 200.0));
+    let run = |harness: &mut Harness, scroll_to: Option<usize>| {
+        let mut ids = vec![];
+        let mut viewport = Rect::NOTHING;
+        let mut top = 0.0;
+        harness.run(|ui| {
+            top = ui.max_rect().top();
+            ScrollArea::from_max_height(100.0).show_viewport(ui, |ui, rect| {
+                viewport = rect;
+                for i in 0..100 {
+                    ids.push(ui.button(format!("Button {}", i)).id);
+                }
+            });
+            // After the ScrollArea, so this happens next frame:
+            if let Some(i) = scroll_to {
+                ui.ctx().scroll_to_widget(ids[i], Align::Center);
+            }
+        });
+        (ids, viewport, top)
+    };
+
+    let (ids, viewport, _) = run(&mut harness, None);
+    assert_eq!(viewport.min.y, 0.0);
+    run(&mut harness, Some(80));
+    run(&mut harness, None); // scrolls
+    let (_, viewport, top) = run(&mut harness, None);
+    assert!(viewport.min.y > 0.0);
+
+    // The button is now in the middle of the visible part of the ScrollArea:
+    let button_rect = harness.widget_rect(ids[80]).unwrap();
+    assert!((button_rect.center().y - (top + 50.0)).abs() < button_rect.height());
+}
+
+#[test]
+fn test_shift_scroll_in_vertical_scroll_area() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 200.0));
+    let run = |harness: &mut Harness| {
+        let mut offset = 0.0;
+        harness.run(|ui| {
+            ScrollArea::auto_sized().show_viewport(ui, |ui, viewport| {
+                for i in 0..100 {
+                    ui.label(format!("Line {}", i));
+                }
+                offset = viewport.min.y;
+            });
+        });
+        offset
+    };
+
+    for _ in 0..3 {
+        run(&mut harness);
+    }
+    harness.pointer_move(pos2(100.0, 100.0));
+    harness.scroll(vec2(0.0, -50.0));
+    run(&mut harness);
+    let scrolled = run(&mut harness); // the viewport is one frame behind
+    assert!(scrolled > 0.0);
+
+    // Shift only turns scrolling horizontal where there is something to scroll horizontally:
+    harness.set_modifiers(Modifiers {
+        shift: true,
+        ..Default::default()
+    });
+    harness.scroll(vec2(0.0, -50.0));
+    run(&mut harness);
+    assert!(run(&mut harness) > scrolled);
+}
@@ -0,0 +1,243 @@
+//! A bar along the bottom of the screen for status messages, progress and other small indicators.
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// Width of the center section, measured last frame.
+    center_width: f32,
+}
+
+/// A bar along the bottom of the screen, with a left, center and right section.
+///
+/// The right section is laid out first, then the center section, and the left section gets the remaining space.
+/// Long messages in the left section are cut short with an ellipsis (hover them to see the full text),
+/// and anything else that doesn't fit is clipped.
+///
+/// Add it before any [`CentralPanel`], like any other [`TopBottomPanel`].
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// egui::StatusBar::new("status_bar").show(ctx, |bar| {
+///     bar.message("Loaded 1 234 files from /home/user/projects");
+///     bar.progress(0.25, "Indexing…");
+///     bar.right(|ui| {
+///         ui.label("Ln 12, Col 4");
+///     });
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct StatusBar {
+    id_source: Id,
+    frame: Option<Frame>,
+    progress_width: f32,
+}
+
+impl StatusBar {
+    /// `id_source`: Something unique, e.g. `"my_status_bar"`.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            frame: None,
+            progress_width: 120.0,
+        }
+    }
+
+    /// Change the background color, margins, etc.
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Width of the progress bar added with [`StatusBarUi::progress`].
+    /// Default: `120.0`.
+    pub fn progress_width(mut self, progress_width: f32) -> Self {
+        self.progress_width = progress_width;
+        self
+    }
+
+    pub fn show<'a, R>(
+        self,
+        ctx: &CtxRef,
+        add_contents: impl FnOnce(&mut StatusBarUi<'a>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            frame,
+            progress_width,
+        } = self;
+
+        let mut panel = TopBottomPanel::bottom(id_source);
+        if let Some(frame) = frame {
+            panel = panel.frame(frame);
+        }
+
+        panel.show(ctx, |ui| {
+            let mut bar = StatusBarUi {
+                progress_width,
+                left: Vec::new(),
+                center: Vec::new(),
+                right: Vec::new(),
+            };
+            let inner = add_contents(&mut bar);
+            bar.ui(ui, id_source);
+            inner
+        })
+    }
+}
+
+type Section<'a> = Vec<Box<dyn FnOnce(&mut Ui) + 'a>>;
+
+/// Passed to the closure of [`StatusBar::show`]. Used to fill the sections of the bar.
+///
+/// The sections are laid out once the closure returns, so you can add to them in any order.
+pub struct StatusBarUi<'a> {
+    progress_width: f32,
+    left: Section<'a>,
+    center: Section<'a>,
+    right: Section<'a>,
+}
+
+impl<'a> StatusBarUi<'a> {
+    /// Add contents to the left section, laid out left-to-right.
+    pub fn left(&mut self, add_contents: impl FnOnce(&mut Ui) + 'a) {
+        self.left.push(Box::new(add_contents));
+    }
+
+    /// Add contents to the center section, laid out left-to-right.
+    pub fn center(&mut self, add_contents: impl FnOnce(&mut Ui) + 'a) {
+        self.center.push(Box::new(add_contents));
+    }
+
+    /// Add contents to the right section, laid out right-to-left
+    /// (so the first thing you add ends up furthest to the right).
+    pub fn right(&mut self, add_contents: impl FnOnce(&mut Ui) + 'a) {
+        self.right.push(Box::new(add_contents));
+    }
+
+    /// Show a message in the left section.
+    ///
+    /// If the message doesn't fit it is cut short with an ellipsis. Hover it to see the full text.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn message(&mut self, message: impl ToString) {
+        let message = message.to_string();
+        self.left(move |ui| {
+            ui.add(Label::new(message).elide(true));
+        });
+    }
+
+    /// Show a progress bar with a text in the right section.
+    ///
+    /// `progress` is in the `[0, 1]` range, where `1` means "completed".
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn progress(&mut self, progress: f32, text: impl ToString) {
+        let progress_width = self.progress_width;
+        let text = text.to_string();
+        self.right(move |ui| {
+            ui.add(
+                ProgressBar::new(progress)
+                    .desired_width(progress_width)
+                    .text(text)
+                    .animate(true),
+            );
+        });
+    }
+
+    fn ui(self, ui: &mut Ui, id_source: Id) {
+        let Self {
+            progress_width: _,
+            left,
+            center,
+            right,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let state = ui
+            .memory()
+            .id_data_temp
+            .get::<State>(&id)
+            .copied()
+            .unwrap_or_default();
+
+        let spacing = ui.spacing().item_spacing.x;
+        let available = ui.available_rect_before_wrap();
+        let bar_rect = Rect::from_min_size(
+            available.min,
+            vec2(available.width(), ui.spacing().interact_size.y),
+        );
+
+        let add_section = |ui: &mut Ui, rect: Rect, layout: Layout, section: Section<'a>| {
+            let mut section_ui = ui.child_ui(rect, layout);
+            section_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+            for add_contents in section {
+                add_contents(&mut section_ui);
+            }
+            section_ui.min_rect()
+        };
+
+        // Right first, since it has the highest priority:
+        let right_edge = if right.is_empty() {
+            bar_rect.right()
+        } else {
+            add_section(ui, bar_rect, Layout::right_to_left(), right).left() - 2.0 * spacing
+        };
+
+        let left_edge = if center.is_empty() {
+            right_edge
+        } else {
+            // Try to center it in the whole bar:
+            let width = state.center_width;
+            let left = (bar_rect.center().x - 0.5 * width)
+                .at_most(right_edge - width)
+                .at_least(bar_rect.left());
+            let rect = Rect::from_x_y_ranges(left..=right_edge, bar_rect.y_range());
+            let used = add_section(ui, rect, Layout::left_to_right(), center);
+            if used.width() != state.center_width {
+                ui.memory().id_data_temp.insert(
+                    id,
+                    State {
+                        center_width: used.width(),
+                    },
+                );
+                ui.ctx().request_repaint();
+            }
+            left - 2.0 * spacing
+        };
+
+        if !left.is_empty() {
+            let rect = Rect::from_x_y_ranges(
+                bar_rect.left()..=left_edge.at_least(bar_rect.left()),
+                bar_rect.y_range(),
+            );
+            add_section(ui, rect, Layout::left_to_right(), left);
+        }
+
+        ui.allocate_rect(bar_rect, Sense::hover());
+    }
+}
+
+#[test]
+fn test_status_bar_elides_message() {
+    use crate::harness::Harness;
+
+    let long_message =
+        "This message is much too long to fit in a status bar that is only two hundred points wide";
+
+    let mut harness = Harness::new(vec2(200.0, 100.0));
+    let mut message_rect = Rect::NOTHING;
+    harness.run_ctx(|ctx| {
+        StatusBar::new("status_bar").show(ctx, |bar| {
+            bar.left(|ui| {
+                message_rect = ui.add(Label::new(long_message).elide(true)).rect;
+            });
+            bar.right(|ui| {
+                ui.label("right");
+            });
+        });
+    });
+
+    assert!(message_rect.width() > 0.0);
+    assert!(message_rect.right() < 200.0);
+}
@@ -0,0 +1,311 @@
+//! Guided tours: walk the user through your UI one widget at a time.
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// What a [`TourStep`] points at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TourTarget {
+    /// The widget with this [`Id`], e.g. [`Response::id`].
+    ///
+    /// The rectangle of the widget is looked up with [`Context::widget_rect`].
+    Widget(Id),
+
+    /// A fixed area of the screen.
+    Rect(Rect),
+}
+
+/// One step of a [`Tour`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TourStep {
+    target: TourTarget,
+    text: String,
+    advance_on_click: bool,
+}
+
+impl TourStep {
+    /// Point at the widget with the given [`Id`].
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn widget(id: Id, text: impl ToString) -> Self {
+        Self::new(TourTarget::Widget(id), text.to_string())
+    }
+
+    /// Point at an area of the screen.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn rect(rect: Rect, text: impl ToString) -> Self {
+        Self::new(TourTarget::Rect(rect), text.to_string())
+    }
+
+    fn new(target: TourTarget, text: String) -> Self {
+        Self {
+            target,
+            text,
+            advance_on_click: false,
+        }
+    }
+
+    /// Go to the next step when the user clicks the target (instead of a "Next" button).
+    pub fn advance_on_click(mut self, advance_on_click: bool) -> Self {
+        self.advance_on_click = advance_on_click;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    active: bool,
+    step: usize,
+}
+
+/// An onboarding overlay that highlights one widget at a time.
+///
+/// The rest of the screen is dimmed and a bubble with an explanation is shown next to the target.
+/// The user advances with the "Next" button, or by clicking the target (see [`TourStep::advance_on_click`]).
+///
+/// Create the tour each frame and call [`Self::show`] after adding all your other UI.
+/// Nothing is shown until you call [`Self::start`].
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// let mut save_button_id = None;
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     if ui.button("Start tour").clicked() {
+///         egui::Tour::new("intro").start(ctx);
+///     }
+///     save_button_id = Some(ui.button("Save").id);
+/// });
+///
+/// let save_step = egui::TourStep::widget(save_button_id.unwrap(), "Click here to save your work.");
+/// egui::Tour::new("intro")
+///     .step(save_step.advance_on_click(true))
+///     .show(ctx);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tour {
+    id: Id,
+    steps: Vec<TourStep>,
+}
+
+impl Tour {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            steps: Default::default(),
+        }
+    }
+
+    /// Add a step to the end of the tour.
+    pub fn step(mut self, step: TourStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    fn state(&self, ctx: &Context) -> State {
+        ctx.memory()
+            .id_data_temp
+            .get::<State>(&self.id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn set_state(&self, ctx: &Context, state: State) {
+        ctx.memory().id_data_temp.insert(self.id, state);
+    }
+
+    /// Start the tour from the first step.
+    pub fn start(&self, ctx: &Context) {
+        self.set_state(
+            ctx,
+            State {
+                active: true,
+                step: 0,
+            },
+        );
+    }
+
+    /// End the tour.
+    pub fn stop(&self, ctx: &Context) {
+        self.set_state(ctx, State::default());
+    }
+
+    pub fn is_active(&self, ctx: &Context) -> bool {
+        self.state(ctx).active
+    }
+
+    /// The index of the step currently shown, if the tour is active.
+    pub fn current_step(&self, ctx: &Context) -> Option<usize> {
+        let state = self.state(ctx);
+        if state.active {
+            Some(state.step)
+        } else {
+            None
+        }
+    }
+
+    /// Show the current step, if the tour is active.
+    ///
+    /// Returns the index of the step that was shown.
+    pub fn show(&self, ctx: &CtxRef) -> Option<usize> {
+        let mut state = self.state(ctx);
+        if !state.active {
+            return None;
+        }
+        let shown_step = state.step;
+        let step = match self.steps.get(shown_step) {
+            Some(step) => step,
+            None => {
+                self.stop(ctx);
+                return None;
+            }
+        };
+
+        let screen_rect = ctx.input().screen_rect();
+        let target_rect = match step.target {
+            TourTarget::Widget(id) => ctx.widget_rect(id),
+            TourTarget::Rect(rect) => Some(rect),
+        };
+        let spotlight = target_rect.map(|rect| rect.expand(4.0));
+
+        if step.advance_on_click {
+            if let (Some(spotlight), Some(pos)) = (spotlight, ctx.input().pointer.interact_pos()) {
+                if ctx.input().pointer.any_click() && spotlight.contains(pos) {
+                    state.step += 1;
+                }
+            }
+        }
+
+        // Put the bubble below the target, or above it if there is no room:
+        let margin = 8.0;
+        let area = Area::new(self.id.with("bubble")).order(Order::Foreground);
+        let bubble_size = ctx
+            .memory()
+            .areas
+            .get(area.layer().id)
+            .map_or(Vec2::ZERO, |state| state.size);
+        let bubble_pos = match spotlight {
+            Some(spotlight) => {
+                let y = if spotlight.bottom() + margin + bubble_size.y <= screen_rect.bottom() {
+                    spotlight.bottom() + margin
+                } else {
+                    spotlight.top() - margin - bubble_size.y
+                };
+                let x = spotlight
+                    .left()
+                    .at_most(screen_rect.right() - bubble_size.x)
+                    .at_least(screen_rect.left());
+                pos2(x, y)
+            }
+            None => screen_rect.center() - 0.5 * bubble_size,
+        };
+
+        let num_steps = self.steps.len();
+        area.fixed_pos(bubble_pos).show(ctx, |ui| {
+            paint_dimming(ui, screen_rect, spotlight);
+
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_max_width(ui.spacing().tooltip_width.min(320.0));
+                ui.label(&step.text);
+                ui.add_space(ui.spacing().item_spacing.y);
+                ui.horizontal(|ui| {
+                    ui.label(format!("{}/{}", shown_step + 1, num_steps));
+                    if shown_step > 0 && ui.button("Back").clicked() {
+                        state.step = shown_step - 1;
+                    }
+                    if !step.advance_on_click {
+                        let next = if shown_step + 1 == num_steps {
+                            "Done"
+                        } else {
+                            "Next"
+                        };
+                        if ui.button(next).clicked() {
+                            state.step = shown_step + 1;
+                        }
+                    }
+                    if ui.button("Skip tour").clicked() {
+                        state.active = false;
+                    }
+                });
+            });
+        });
+
+        if state.step >= num_steps {
+            state = State::default();
+        }
+        self.set_state(ctx, state);
+        Some(shown_step)
+    }
+}
+
+/// Dim everything except the spotlight, and outline the spotlight.
+fn paint_dimming(ui: &Ui, screen_rect: Rect, spotlight: Option<Rect>) {
+    let mut painter = ui.painter().clone();
+    painter.set_clip_rect(screen_rect);
+    let dim = Color32::from_black_alpha(160);
+    match spotlight {
+        Some(spotlight) => {
+            let spotlight = spotlight.intersect(screen_rect);
+            let above =
+                Rect::from_min_max(screen_rect.min, pos2(screen_rect.right(), spotlight.top()));
+            let below = Rect::from_min_max(
+                pos2(screen_rect.left(), spotlight.bottom()),
+                screen_rect.max,
+            );
+            let left = Rect::from_min_max(
+                pos2(screen_rect.left(), spotlight.top()),
+                pos2(spotlight.left(), spotlight.bottom()),
+            );
+            let right = Rect::from_min_max(
+                pos2(spotlight.right(), spotlight.top()),
+                pos2(screen_rect.right(), spotlight.bottom()),
+            );
+            for &rect in &[above, below, left, right] {
+                if rect.is_positive() {
+                    painter.rect_filled(rect, 0.0, dim);
+                }
+            }
+            painter.rect_stroke(spotlight, 4.0, ui.visuals().selection.stroke);
+        }
+        None => {
+            painter.rect_filled(screen_rect, 0.0, dim);
+        }
+    }
+}
+
+#[test]
+fn test_tour() {
+    let mut harness = crate::harness::Harness::new(vec2(400.0, 300.0));
+    let mut ids = vec![];
+    let mut shown = None;
+    let mut run = |harness: &mut crate::harness::Harness, ids: &mut Vec<Id>| {
+        harness.run_ctx(|ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                *ids = vec![ui.button("First").id, ui.button("Second").id];
+            });
+            if let [first, second] = ids[..] {
+                shown = Tour::new("tour")
+                    .step(TourStep::widget(first, "Click me").advance_on_click(true))
+                    .step(TourStep::widget(second, "Then look here"))
+                    .show(ctx);
+            }
+        });
+        shown
+    };
+
+    assert_eq!(run(&mut harness, &mut ids), None);
+
+    let tour = Tour::new("tour");
+    tour.start(harness.ctx());
+    assert_eq!(run(&mut harness, &mut ids), Some(0));
+
+    assert!(harness.click_widget(ids[0]));
+    assert_eq!(run(&mut harness, &mut ids), Some(0));
+    assert_eq!(tour.current_step(harness.ctx()), Some(1));
+    assert_eq!(run(&mut harness, &mut ids), Some(1));
+
+    tour.stop(harness.ctx());
+    assert!(!tour.is_active(harness.ctx()));
+    assert_eq!(run(&mut harness, &mut ids), None);
+}
@@ -1,10 +1,15 @@
 // WARNING: the code in here is horrible. It is a behemoth that needs breaking up into simpler parts.
 
+use std::sync::Arc;
+
 use crate::{widgets::*, *};
 use epaint::*;
 
 use super::*;
 
+/// Widgets added to the title bar with [`Window::title_bar_ui`].
+type TitleBarUi<'open> = Box<dyn FnOnce(&mut Ui) + 'open>;
+
 /// Builder for a floating window which can be dragged, closed, collapsed, resized and scrolled (off by default).
 ///
 /// You can customize:
@@ -13,6 +18,7 @@ use super::*;
 /// * if the window has a scroll area (off by default)
 /// * if the window can be collapsed (minimized) to just the title bar (yes, by default)
 /// * if there should be a close button (none by default)
+/// * extra title bar buttons: pin and help (none by default), and your own widgets
 ///
 /// ```
 /// # let mut ctx = egui::CtxRef::default();
@@ -28,9 +34,18 @@ pub struct Window<'open> {
     area: Area,
     frame: Option<Frame>,
     resize: Resize,
+    /// Overrides the default size of [`Self::resize`] once we know the screen size.
+    default_width: Option<Size>,
+    default_height: Option<Size>,
     scroll: Option<ScrollArea>,
     collapsible: bool,
+    collapsed_preview: bool,
     with_title_bar: bool,
+    pinnable: bool,
+    help_text: Option<String>,
+    confirm_close: Option<Box<dyn FnOnce() -> bool + 'open>>,
+    title_bar_ui: Option<TitleBarUi<'open>>,
+    dialog: bool,
 }
 
 impl<'open> Window<'open> {
@@ -51,9 +66,17 @@ impl<'open> Window<'open> {
                 .with_stroke(false)
                 .min_size([96.0, 32.0])
                 .default_size([340.0, 420.0]), // Default inner size of a window
+            default_width: None,
+            default_height: None,
             scroll: None,
             collapsible: true,
+            collapsed_preview: false,
             with_title_bar: true,
+            pinnable: false,
+            help_text: None,
+            confirm_close: None,
+            title_bar_ui: None,
+            dialog: false,
         }
     }
 
@@ -142,17 +165,19 @@ impl<'open> Window<'open> {
     /// Set initial size of the window.
     pub fn default_size(mut self, default_size: impl Into<Vec2>) -> Self {
         self.resize = self.resize.default_size(default_size);
+        self.default_width = None;
+        self.default_height = None;
         self
     }
 
-    /// Set initial width of the window.
-    pub fn default_width(mut self, default_width: f32) -> Self {
-        self.resize = self.resize.default_width(default_width);
+    /// Set initial width of the window, e.g. `Size::Relative(0.5)` for half the screen width.
+    pub fn default_width(mut self, default_width: impl Into<Size>) -> Self {
+        self.default_width = Some(default_width.into());
         self
     }
-    /// Set initial height of the window.
-    pub fn default_height(mut self, default_height: f32) -> Self {
-        self.resize = self.resize.default_height(default_height);
+    /// Set initial height of the window, e.g. `Size::Relative(0.5)` for half the screen height.
+    pub fn default_height(mut self, default_height: impl Into<Size>) -> Self {
+        self.default_height = Some(default_height.into());
         self
     }
 
@@ -191,6 +216,18 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// When the window is collapsed, show a scaled-down preview of its contents when hovering the title.
+    ///
+    /// This helps finding the right window when many are collapsed.
+    /// The preview shows the contents as they were when the window was collapsed.
+    /// Default: `false`.
+    ///
+    /// Note that this keeps a copy of everything painted in the window while it is collapsed.
+    pub fn collapsed_preview(mut self, collapsed_preview: bool) -> Self {
+        self.collapsed_preview = collapsed_preview;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     pub fn title_bar(mut self, title_bar: bool) -> Self {
@@ -198,6 +235,83 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Add a pin button to the title bar.
+    /// A pinned window stays on top of all other windows.
+    pub fn pinnable(mut self, pinnable: bool) -> Self {
+        self.pinnable = pinnable;
+        self
+    }
+
+    /// Add a `?` button to the title bar which shows this text when hovered.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn help_text(mut self, help_text: impl ToString) -> Self {
+        self.help_text = Some(help_text.to_string());
+        self
+    }
+
+    /// Called when the user presses the close button (see [`Self::open`]).
+    ///
+    /// Return `false` to keep the window open, e.g. to first ask the user to save their changes.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let mut open = true;
+    /// # let mut show_save_dialog = false;
+    /// let has_unsaved_changes = true;
+    /// egui::Window::new("Document")
+    ///     .open(&mut open)
+    ///     .confirm_close(|| {
+    ///         show_save_dialog = has_unsaved_changes;
+    ///         !has_unsaved_changes
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Lorem ipsum");
+    ///     });
+    /// ```
+    pub fn confirm_close(mut self, confirm_close: impl FnOnce() -> bool + 'open) -> Self {
+        self.confirm_close = Some(Box::new(confirm_close));
+        self
+    }
+
+    /// Add your own widgets to the right side of the title bar, next to the built-in buttons.
+    ///
+    /// The widgets are laid out right-to-left, and should be no taller than the title.
+    pub fn title_bar_ui(mut self, add_contents: impl FnOnce(&mut Ui) + 'open) -> Self {
+        self.title_bar_ui = Some(Box::new(add_contents));
+        self
+    }
+
+    /// Treat the contents as a dialog: tab stays within the window,
+    /// Enter clicks the [`Ui::default_button`] and Escape the [`Ui::cancel_button`].
+    /// See [`Ui::dialog_scope`].
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let mut open = true;
+    /// egui::Window::new("Delete file?")
+    ///     .dialog(true)
+    ///     .open(&mut open)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("This can not be undone.");
+    ///         ui.horizontal(|ui| {
+    ///             if ui.default_button("Delete").clicked() {
+    ///                 // delete the file
+    ///             }
+    ///             if ui.cancel_button("Cancel").clicked() {
+    ///                 // close the dialog
+    ///             }
+    ///         });
+    ///     });
+    /// ```
+    pub fn dialog(mut self, dialog: bool) -> Self {
+        self.dialog = dialog;
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -223,11 +337,18 @@ impl<'open> Window<'open> {
         self
     }
 
-    /// Constrain the area up to which the window can be dragged.
-    pub fn drag_bounds(mut self, bounds: Rect) -> Self {
-        self.area = self.area.drag_bounds(bounds);
+    /// Keep the window within this rectangle, both when moving and resizing it.
+    ///
+    /// Default: the screen, minus any panels and OS taskbars (see [`RawInput::work_area`]).
+    pub fn constrain_to(mut self, constrain_rect: Rect) -> Self {
+        self.area = self.area.constrain_to(constrain_rect);
         self
     }
+
+    #[deprecated = "Renamed constrain_to"]
+    pub fn drag_bounds(self, bounds: Rect) -> Self {
+        self.constrain_to(bounds)
+    }
 }
 
 impl<'open> Window<'open> {
@@ -241,7 +362,7 @@ impl<'open> Window<'open> {
         self.show_impl(ctx, Box::new(add_contents))
     }
 
-    fn show_impl<'c, R>(
+    fn show_impl<'c, R: 'c>(
         self,
         ctx: &CtxRef,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
@@ -251,14 +372,36 @@ impl<'open> Window<'open> {
             open,
             area,
             frame,
-            resize,
+            mut resize,
+            default_width,
+            default_height,
             scroll,
             collapsible,
+            collapsed_preview,
             with_title_bar,
+            pinnable,
+            help_text,
+            confirm_close,
+            title_bar_ui,
+            dialog,
         } = self;
 
+        let add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c> = if dialog {
+            Box::new(move |ui: &mut Ui| ui.dialog_scope(add_contents).inner)
+        } else {
+            add_contents
+        };
+
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
 
+        let screen_size = ctx.input().screen_rect().size();
+        if let Some(default_width) = default_width {
+            resize = resize.default_width(default_width.resolve(screen_size.x));
+        }
+        if let Some(default_height) = default_height {
+            resize = resize.default_height(default_height.resolve(screen_size.y));
+        }
+
         let is_open = !matches!(open, Some(false)) || ctx.memory().everything_is_visible();
         area.show_open_close_animation(ctx, &frame, is_open);
 
@@ -270,6 +413,13 @@ impl<'open> Window<'open> {
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
         let collapsing_id = area_id.with("collapsing");
+        let pin_id = area_id.with("pinned");
+        let title_bar_ui_id = area_id.with("title_bar_ui");
+        let preview_id = area_id.with("collapsed_preview");
+
+        if pinnable && *ctx.memory().id_data.get_or_default::<bool>(pin_id) {
+            ctx.memory().areas.move_to_top(area_layer_id);
+        }
 
         let is_collapsed = with_title_bar
             && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or_default();
@@ -301,7 +451,7 @@ impl<'open> Window<'open> {
                     0.0
                 };
                 let margins = 2.0 * frame.margin + vec2(0.0, title_bar_height);
-                let bounds = area.drag_bounds();
+                let constrain_rect = area.constrain_rect();
 
                 interact(
                     window_interaction,
@@ -310,7 +460,7 @@ impl<'open> Window<'open> {
                     area_layer_id,
                     area.state_mut(),
                     resize_id,
-                    bounds,
+                    constrain_rect,
                 )
             })
         } else {
@@ -331,12 +481,30 @@ impl<'open> Window<'open> {
                 collapsing_id,
                 default_expanded,
             );
-            let show_close_button = open.is_some();
+            let extras = TitleBarExtras {
+                close: open,
+                confirm_close,
+                pin_id: if pinnable { Some(pin_id) } else { None },
+                help_text,
+                custom_ui: title_bar_ui.map(|add_contents| (title_bar_ui_id, add_contents)),
+            };
             let title_bar = if with_title_bar {
+                let custom_ui_width = if extras.custom_ui.is_some() {
+                    frame
+                        .content_ui
+                        .memory()
+                        .id_data_temp
+                        .get::<f32>(&title_bar_ui_id)
+                        .copied()
+                        .unwrap_or_default()
+                } else {
+                    0.0
+                };
                 let title_bar = show_title_bar(
                     &mut frame.content_ui,
                     title_label,
-                    show_close_button,
+                    extras.num_buttons(),
+                    custom_ui_width,
                     collapsing_id,
                     &mut collapsing,
                     collapsible,
@@ -370,14 +538,34 @@ impl<'open> Window<'open> {
             // END FRAME --------------------------------
 
             if let Some(title_bar) = title_bar {
-                title_bar.ui(
+                let title_response = title_bar.ui(
                     &mut area_content_ui,
                     outer_rect,
                     &content_response,
-                    open,
+                    extras,
                     &mut collapsing,
                     collapsible,
                 );
+                if collapsed_preview && is_collapsed && title_response.hovered() {
+                    let preview = ctx
+                        .memory()
+                        .id_data_temp
+                        .get::<Preview>(&preview_id)
+                        .cloned()
+                        // The text would point at glyphs that have been evicted from the font atlas since:
+                        .filter(|preview| {
+                            preview.atlas_generation == ctx.fonts().atlas_generation()
+                        });
+                    if let Some(mut preview) = preview {
+                        let tex_size = ctx.fonts().texture().size();
+                        if preview.tessellate(ctx, tex_size) {
+                            ctx.memory()
+                                .id_data_temp
+                                .insert(preview_id, preview.clone());
+                        }
+                        Tooltip::new().show_for(&title_response, |ui| preview.ui(ui));
+                    }
+                }
             }
 
             area_content_ui
@@ -406,6 +594,21 @@ impl<'open> Window<'open> {
         };
         let full_response = area.end(ctx, area_content_ui);
 
+        // Take a snapshot of the contents in the frame the window is collapsed:
+        let just_collapsed =
+            !is_collapsed && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or(true);
+        if collapsed_preview && just_collapsed {
+            let paint_list = ctx.graphics().list(area_layer_id).clone();
+            let shapes = paint_list.lock().clipped_shapes();
+            let preview = Preview {
+                rect: full_response.rect,
+                shapes: Arc::new(shapes),
+                atlas_generation: ctx.fonts().atlas_generation(),
+                meshes: None,
+            };
+            ctx.memory().id_data_temp.insert(preview_id, preview);
+        }
+
         let inner_response = InnerResponse {
             inner: content_inner,
             response: full_response,
@@ -505,13 +708,13 @@ fn interact(
     area_layer_id: LayerId,
     area_state: &mut area::State,
     resize_id: Id,
-    drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
 ) -> Option<WindowInteraction> {
     let new_rect = move_and_resize_window(ctx, &window_interaction)?;
     let new_rect = ctx.round_rect_to_pixels(new_rect);
 
-    let new_rect = if let Some(bounds) = drag_bounds {
-        ctx.constrain_window_rect_to_area(new_rect, bounds)
+    let new_rect = if let Some(constrain_rect) = constrain_rect {
+        ctx.constrain_window_rect_to_area(new_rect, constrain_rect)
     } else {
         ctx.constrain_window_rect(new_rect)
     };
@@ -741,6 +944,29 @@ fn paint_frame_interaction(
 
 // ----------------------------------------------------------------------------
 
+/// The optional parts of the title bar, right-aligned.
+struct TitleBarExtras<'open> {
+    /// Show a close button which sets this to `false`.
+    close: Option<&'open mut bool>,
+    confirm_close: Option<Box<dyn FnOnce() -> bool + 'open>>,
+
+    /// Show a pin button which toggles the `bool` stored under this id.
+    pin_id: Option<Id>,
+
+    help_text: Option<String>,
+
+    /// User widgets, and the id under which we remember their width.
+    custom_ui: Option<(Id, TitleBarUi<'open>)>,
+}
+
+impl<'open> TitleBarExtras<'open> {
+    fn num_buttons(&self) -> usize {
+        self.close.is_some() as usize
+            + self.pin_id.is_some() as usize
+            + self.help_text.is_some() as usize
+    }
+}
+
 struct TitleBar {
     id: Id,
     title_label: Label,
@@ -752,7 +978,8 @@ struct TitleBar {
 fn show_title_bar(
     ui: &mut Ui,
     title_label: Label,
-    show_close_button: bool,
+    num_right_buttons: usize,
+    custom_ui_width: f32,
     collapsing_id: Id,
     collapsing: &mut collapsing_header::State,
     collapsible: bool,
@@ -782,9 +1009,12 @@ fn show_title_bar(
 
         let title_galley = title_label.layout(ui);
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title is centered):
-            2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size.x
+        let minimum_width = if collapsible || num_right_buttons > 0 || custom_ui_width > 0.0 {
+            // Make room for the same width on both sides (since title is centered):
+            let button_width = button_size.x + item_spacing.x;
+            let left = pad + button_width;
+            let right = pad + num_right_buttons.max(1) as f32 * button_width + custom_ui_width;
+            2.0 * left.max(right) + title_galley.size.x
         } else {
             pad + title_galley.size.x + pad
         };
@@ -812,22 +1042,71 @@ impl TitleBar {
         ui: &mut Ui,
         outer_rect: Rect,
         content_response: &Option<Response>,
-        open: Option<&mut bool>,
+        extras: TitleBarExtras<'_>,
         collapsing: &mut collapsing_header::State,
         collapsible: bool,
-    ) {
+    ) -> Response {
         if let Some(content_response) = &content_response {
             // Now we know how large we got to be:
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
-        if let Some(open) = open {
-            // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
-                *open = false;
+        // Add the buttons now that we know our full width:
+        let TitleBarExtras {
+            close,
+            confirm_close,
+            pin_id,
+            help_text,
+            custom_ui,
+        } = extras;
+        let mut button_index = 0;
+
+        if let Some(open) = close {
+            let rect = self.button_rect(ui, button_index);
+            button_index += 1;
+            if close_button(ui, rect).clicked() {
+                let close = confirm_close.map_or(true, |confirm_close| confirm_close());
+                if close {
+                    *open = false;
+                }
             }
         }
 
+        if let Some(pin_id) = pin_id {
+            let rect = self.button_rect(ui, button_index);
+            button_index += 1;
+            let pinned = *ui.memory().id_data.get_or_default::<bool>(pin_id);
+            let id = ui.auto_id_with("window_pin_button");
+            let response = text_button(ui, rect, id, "📌", pinned).on_hover_text(if pinned {
+                "Unpin"
+            } else {
+                "Keep on top"
+            });
+            if response.clicked() {
+                ui.memory().id_data.insert(pin_id, !pinned);
+            }
+        }
+
+        if let Some(help_text) = help_text {
+            let rect = self.button_rect(ui, button_index);
+            button_index += 1;
+            let id = ui.auto_id_with("window_help_button");
+            text_button(ui, rect, id, "?", false).on_hover_text(help_text);
+        }
+
+        if let Some((custom_ui_id, add_contents)) = custom_ui {
+            let right = if button_index == 0 {
+                self.rect.right() - self.button_pad(ui)
+            } else {
+                self.button_rect(ui, button_index - 1).left() - ui.spacing().item_spacing.x
+            };
+            let rect = Rect::from_x_y_ranges(self.rect.left()..=right, self.rect.y_range());
+            let mut child_ui = ui.child_ui(rect, Layout::right_to_left());
+            add_contents(&mut child_ui);
+            let width = child_ui.min_rect().width();
+            ui.memory().id_data_temp.insert(custom_ui_id, width);
+        }
+
         // Always have inactive style for the window.
         // It is VERY annoying to e.g. change it when moving the window.
         let style = ui.visuals().widgets.inactive;
@@ -852,30 +1131,98 @@ impl TitleBar {
             );
         }
 
-        if ui
-            .interact(self.rect, self.id, Sense::click())
-            .double_clicked()
-            && collapsible
-        {
+        let response = ui.interact(self.rect, self.id, Sense::click());
+        if response.double_clicked() && collapsible {
             collapsing.toggle(ui);
         }
+        response
+    }
+
+    fn button_pad(&self, ui: &Ui) -> f32 {
+        (self.rect.height() - ui.spacing().icon_width) / 2.0 // calculated so that the icon is on the diagonal (if window padding is symmetrical)
     }
 
-    fn close_button_ui(&self, ui: &mut Ui) -> Response {
+    /// Where to put a button, counting from the right.
+    fn button_rect(&self, ui: &Ui, index: usize) -> Rect {
         let button_size = Vec2::splat(ui.spacing().icon_width);
-        let pad = (self.rect.height() - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
-        let button_rect = Rect::from_min_size(
+        let offset = index as f32 * (button_size.x + ui.spacing().item_spacing.x);
+        Rect::from_min_size(
             pos2(
-                self.rect.right() - pad - button_size.x,
+                self.rect.right() - self.button_pad(ui) - button_size.x - offset,
                 self.rect.center().y - 0.5 * button_size.y,
             ),
             button_size,
-        );
+        )
+    }
+}
+
+/// What was painted in a window when it was collapsed, for [`Window::collapsed_preview`].
+#[derive(Clone)]
+struct Preview {
+    rect: Rect,
+    shapes: Arc<Vec<ClippedShape>>,
+
+    /// The [`epaint::text::Fonts::atlas_generation`] the text in [`Self::shapes`] was laid out with.
+    atlas_generation: u64,
 
-        close_button(ui, button_rect)
+    /// [`Self::shapes`] tessellated for a font texture of the given size.
+    /// The texture coordinates of text depend on it, so we need to redo it when the texture grows.
+    meshes: Option<([usize; 2], Arc<Vec<ClippedMesh>>)>,
+}
+
+impl Preview {
+    /// Tessellate the shapes, unless we already have for this texture size.
+    /// Returns `true` if it did anything.
+    fn tessellate(&mut self, ctx: &Context, tex_size: [usize; 2]) -> bool {
+        if matches!(&self.meshes, Some((size, _)) if *size == tex_size) {
+            return false;
+        }
+        let options = ctx.memory().options.tessellation_options;
+        let shapes = self.shapes.as_ref().clone();
+        let meshes = epaint::tessellator::tessellate_shapes(shapes, options, tex_size);
+        self.meshes = Some((tex_size, Arc::new(meshes)));
+        true
+    }
+
+    /// Paint the contents at most [`crate::style::Spacing::tooltip_width`] wide.
+    fn ui(&self, ui: &mut Ui) {
+        let scale = (ui.spacing().tooltip_width / self.rect.width()).min(1.0);
+        let (rect, _) = ui.allocate_exact_size(scale * self.rect.size(), Sense::hover());
+        let to_preview = |pos: Pos2| rect.min + scale * (pos - self.rect.min);
+
+        let meshes = match &self.meshes {
+            Some((_, meshes)) => meshes,
+            None => return,
+        };
+        for ClippedMesh(clip_rect, mesh) in meshes.iter() {
+            let mut mesh = mesh.clone();
+            for vertex in &mut mesh.vertices {
+                vertex.pos = to_preview(vertex.pos);
+            }
+            let clip_rect =
+                Rect::from_min_max(to_preview(clip_rect.min), to_preview(clip_rect.max));
+            ui.painter_at(clip_rect.intersect(rect))
+                .add(Shape::Mesh(mesh));
+        }
     }
 }
 
+/// A title bar button showing a single character.
+fn text_button(ui: &mut Ui, rect: Rect, id: Id, text: &str, selected: bool) -> Response {
+    let response = ui.interact(rect, id, Sense::click());
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact_selectable(&response, selected);
+    ui.painter().text(
+        rect.center(),
+        Align2::CENTER_CENTER,
+        text,
+        TextStyle::Button,
+        visuals.text_color(),
+    );
+    response
+}
+
 fn close_button(ui: &mut Ui, rect: Rect) -> Response {
     let close_id = ui.auto_id_with("window_close_button");
     let response = ui.interact(rect, close_id, Sense::click());
@@ -890,3 +1237,101 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[test]
+fn test_window_stays_within_work_area() {
+    use crate::harness::Harness;
+
+    fn show_window(harness: &mut Harness, constrain_rect: Option<Rect>) -> Rect {
+        let mut window_rect = Rect::NOTHING;
+        harness.run_ctx(|ctx| {
+            let mut window = Window::new("window").default_pos([300.0, 200.0]);
+            if let Some(constrain_rect) = constrain_rect {
+                window = window.constrain_to(constrain_rect);
+            }
+            window_rect = window
+                .show(ctx, |ui| ui.label("Hello"))
+                .unwrap()
+                .response
+                .rect;
+        });
+        window_rect
+    }
+
+    let mut harness = Harness::new(vec2(400.0, 300.0));
+    show_window(&mut harness, None);
+    show_window(&mut harness, None);
+
+    // Shrink the screen and add a taskbar at the bottom.
+    // The window should not be outside of it for even a single frame:
+    let work_area = Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 120.0));
+    harness.set_screen_size(vec2(200.0, 150.0));
+    harness.set_work_area(Some(work_area));
+    let window_rect = show_window(&mut harness, None);
+    assert!(work_area.contains_rect(window_rect), "{:?}", window_rect);
+
+    // Narrower than the work area, but still wide enough for the window:
+    let constrain_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(150.0, 100.0));
+    show_window(&mut harness, Some(constrain_rect));
+    let window_rect = show_window(&mut harness, Some(constrain_rect));
+    assert!(
+        constrain_rect.contains_rect(window_rect),
+        "{:?}",
+        window_rect
+    );
+}
+
+#[test]
+fn test_collapsed_preview() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(600.0, 600.0));
+    let mut window_rect = Rect::NOTHING;
+    let mut run = |harness: &mut Harness| {
+        let frame = harness.run_ctx(|ctx| {
+            window_rect = Window::new("window")
+                .default_pos([10.0, 10.0])
+                .collapsed_preview(true)
+                .show(ctx, |ui| {
+                    for i in 0..20 {
+                        ui.label(format!("Line {}", i));
+                    }
+                })
+                .unwrap()
+                .response
+                .rect;
+        });
+        let num_vertices: usize = frame
+            .clipped_meshes
+            .iter()
+            .map(|ClippedMesh(_, mesh)| mesh.vertices.len())
+            .sum();
+        (num_vertices, window_rect)
+    };
+
+    run(&mut harness);
+    let (_, expanded_rect) = run(&mut harness);
+
+    // Double-click the title to collapse:
+    let title_pos = expanded_rect.left_top() + vec2(30.0, 12.0);
+    harness.click(title_pos);
+    run(&mut harness);
+    harness.click(title_pos);
+    run(&mut harness);
+
+    harness.pointer_move(pos2(500.0, 500.0));
+    run(&mut harness);
+    let (not_hovered, collapsed_rect) = run(&mut harness);
+    assert!(collapsed_rect.height() < expanded_rect.height());
+
+    harness.pointer_move(title_pos);
+    run(&mut harness);
+    let (hovered, _) = run(&mut harness);
+    // At least one glyph per line:
+    assert!(
+        hovered > not_hovered + 20 * 4,
+        "{} {}",
+        not_hovered,
+        hovered
+    );
+}
@@ -9,6 +9,7 @@ use crate::{
     animation_manager::AnimationManager,
     data::output::Output,
     frame_state::FrameState,
+    hooks::Hooks,
     input_state::*,
     layers::GraphicLayers,
     mutex::{Mutex, MutexGuard},
@@ -102,6 +103,8 @@ impl CtxRef {
         let mut self_: Context = (*self.0).clone();
         self_.begin_frame_mut(new_input);
         *self = Self(Arc::new(self_));
+
+        crate::virtual_keyboard::show(self);
     }
 
     // ---------------------------------------------------------------------
@@ -164,10 +167,87 @@ impl CtxRef {
                 .at_least(Vec2::splat(0.0))
                 .at_most(Vec2::splat(5.0)),
         ); // make it easier to click
-        let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
+
+        // Widgets that are clipped away (e.g. scrolled out of view) can't be hovered,
+        // and should not occlude anything, so skip the hit testing:
+        let hovered = if clip_rect.intersects(interact_rect) {
+            let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
+            self.check_occlusion(layer_id, id, HitShape::Rect(interact_rect), sense, hovered)
+        } else {
+            false
+        };
+        self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
+    }
+
+    /// Use `ui.interact_with_shape` instead
+    pub(crate) fn interact_with_shape(
+        &self,
+        clip_rect: Rect,
+        layer_id: LayerId,
+        id: Id,
+        shape: &HitShape,
+        sense: Sense,
+        enabled: bool,
+    ) -> Response {
+        let hovered = if let Some(pointer_pos) = self.input.pointer.interact_pos() {
+            clip_rect.contains(pointer_pos)
+                && shape.contains(pointer_pos)
+                && self.layer_id_at(pointer_pos) == Some(layer_id)
+        } else {
+            false
+        };
+        let rect = shape.bounding_rect();
+        let hovered = self.check_occlusion(layer_id, id, shape.clone(), sense, hovered);
         self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
     }
 
+    /// Remember the interactive widget, and return `false` if it is hovered but
+    /// covered by another widget in the same layer that was added after it (last frame).
+    ///
+    /// Containers such as [`ScrollArea`] and [`Window`] sense drags over their whole area,
+    /// but are interacted with after their contents.
+    /// To not block their contents, a widget never covers widgets that it fully contains.
+    fn check_occlusion(
+        &self,
+        layer_id: LayerId,
+        id: Id,
+        shape: HitShape,
+        sense: Sense,
+        hovered: bool,
+    ) -> bool {
+        if !sense.click && !sense.drag {
+            return hovered;
+        }
+
+        let mut frame_state = self.frame_state();
+        frame_state.interactive_widgets.push((layer_id, id, shape));
+
+        if !hovered {
+            return false;
+        }
+        let pointer_pos = match self.input.pointer.interact_pos() {
+            Some(pointer_pos) => pointer_pos,
+            None => return hovered,
+        };
+
+        let prev = &frame_state.prev_interactive_widgets;
+        if let Some(index) = prev.iter().rposition(|(_, prev_id, _)| *prev_id == id) {
+            let rect = prev[index].2.bounding_rect();
+            let occluded =
+                prev[index + 1..]
+                    .iter()
+                    .any(|(other_layer_id, other_id, other_shape)| {
+                        *other_layer_id == layer_id
+                            && *other_id != id
+                            && other_shape.contains(pointer_pos)
+                            && !other_shape.bounding_rect().contains_rect(rect)
+                    });
+            !occluded
+        } else {
+            hovered
+        }
+    }
+
     /// You specify if a thing is hovered, and the function gives a `Response`.
     pub(crate) fn interact_with_hovered(
         &self,
@@ -191,15 +271,18 @@ impl CtxRef {
             clicked: Default::default(),
             double_clicked: Default::default(),
             dragged: false,
+            drag_started: false,
             drag_released: false,
             is_pointer_button_down_on: false,
             interact_pointer_pos: None,
             changed: false, // must be set by the widget itself
+            forced_state: None,
         };
 
         if !enabled || !sense.focusable || !layer_id.allow_interaction() {
             // Not interested or allowed input:
             self.memory().surrender_focus(id);
+            self.call_response_hooks(&response);
             return response;
         }
 
@@ -211,12 +294,12 @@ impl CtxRef {
             self.memory().interested_in_focus(id);
         }
 
-        if sense.click
-            && response.has_focus()
-            && (self.input().key_pressed(Key::Space) || self.input().key_pressed(Key::Enter))
-        {
-            // Space/enter works like a primary click for e.g. selected buttons
-            response.clicked[PointerButton::Primary as usize] = true;
+        if sense.click && response.has_focus() {
+            self.memory().use_enter_key();
+            if self.input().key_pressed(Key::Space) || self.input().key_pressed(Key::Enter) {
+                // Space/enter works like a primary click for e.g. selected buttons
+                response.clicked[PointerButton::Primary as usize] = true;
+            }
         }
 
         self.register_interaction_id(id, rect);
@@ -234,7 +317,7 @@ impl CtxRef {
             for pointer_event in &self.input.pointer.pointer_events {
                 match pointer_event {
                     PointerEvent::Moved(_) => {}
-                    PointerEvent::Pressed(_) => {
+                    PointerEvent::Pressed { .. } => {
                         if hovered {
                             if sense.click && memory.interaction.click_id.is_none() {
                                 // potential start of a click
@@ -257,10 +340,11 @@ impl CtxRef {
                                 memory.window_interaction = None; // HACK: stop moving windows (if any)
                                 response.is_pointer_button_down_on = true;
                                 response.dragged = true;
+                                response.drag_started = true;
                             }
                         }
                     }
-                    PointerEvent::Released(click) => {
+                    PointerEvent::Released { click, .. } => {
                         response.drag_released = response.dragged;
                         response.dragged = false;
 
@@ -289,9 +373,44 @@ impl CtxRef {
             self.memory().surrender_focus(id);
         }
 
+        self.call_response_hooks(&response);
         response
     }
 
+    fn call_response_hooks(&self, response: &Response) {
+        // Clone the hooks so that they can use the context, e.g. to add more hooks:
+        let hooks = self.hooks.lock().on_response.clone();
+        for hook in &hooks {
+            hook(response);
+        }
+    }
+
+    /// Where the widget with the given [`Id`] is on screen.
+    ///
+    /// Uses the rectangle from the current frame if the widget has already been added,
+    /// else the one from the previous frame.
+    /// Returns `None` if the widget was not shown in either frame.
+    ///
+    /// Only widgets that can be interacted with (e.g. buttons, but not labels) are tracked.
+    pub fn widget_rect(&self, id: Id) -> Option<Rect> {
+        let frame_state = self.frame_state();
+        frame_state
+            .used_ids
+            .get(&id)
+            .or_else(|| frame_state.prev_used_ids.get(&id))
+            .copied()
+    }
+
+    /// Scroll the [`crate::ScrollArea`] containing the widget with the given [`Id`] so that the widget is shown,
+    /// e.g. to jump to a search result, without having the [`Response`] of the widget at hand.
+    ///
+    /// If the widget or its `ScrollArea` has already been added this frame, the scrolling happens next frame.
+    /// Only widgets that can be interacted with are tracked, see [`Self::widget_rect`].
+    /// See also [`Response::scroll_to_me`].
+    pub fn scroll_to_widget(&self, id: Id, align: Align) {
+        self.frame_state().scroll_to_widget = Some((id, align, true));
+    }
+
     /// Get a full-screen painter for a new or existing layer
     pub fn layer_painter(&self, layer_id: LayerId) -> Painter {
         Painter::new(self.clone(), layer_id, self.input.screen_rect())
@@ -339,6 +458,8 @@ pub struct Context {
 
     paint_stats: Arc<Mutex<PaintStats>>,
 
+    hooks: Arc<Mutex<Hooks>>,
+
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: AtomicU32,
 }
@@ -354,6 +475,7 @@ impl Clone for Context {
             graphics: self.graphics.clone(),
             output: self.output.clone(),
             paint_stats: self.paint_stats.clone(),
+            hooks: self.hooks.clone(),
             repaint_requests: self.repaint_requests.load(SeqCst).into(),
         }
     }
@@ -388,6 +510,25 @@ impl Context {
         self.output.lock()
     }
 
+    /// Mark a part of the window as a title bar (to move the window) or an edge (to resize it),
+    /// for apps that draw their own window decorations. See [`Output::window_regions`].
+    ///
+    /// Regions added later are on top, so add a [`crate::WindowRegion::Client`] region on top of
+    /// any buttons in your title bar.
+    /// The mouse cursor is set for the [`crate::WindowRegion::Resize`] regions.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let title_bar_rect = egui::Rect::from_min_size(Default::default(), egui::vec2(320.0, 28.0));
+    /// # let close_button_rect = egui::Rect::from_min_size(Default::default(), egui::vec2(28.0, 28.0));
+    /// ctx.add_window_region(title_bar_rect, egui::WindowRegion::Drag);
+    /// ctx.add_window_region(close_button_rect, egui::WindowRegion::Client);
+    /// ```
+    pub fn add_window_region(&self, rect: Rect, region: crate::WindowRegion) {
+        self.output().window_regions.push((rect, region));
+    }
+
     pub(crate) fn frame_state(&self) -> MutexGuard<'_, FrameState> {
         self.frame_state.lock()
     }
@@ -456,6 +597,7 @@ impl Context {
     /// ctx.set_style(style);
     /// ```
     pub fn set_style(&self, style: impl Into<Arc<Style>>) {
+        self.animation_manager.lock().cancel_visuals_transition();
         self.memory().options.style = style.into();
     }
 
@@ -469,9 +611,137 @@ impl Context {
     /// ctx.set_visuals(egui::Visuals::light()); // Switch to light mode
     /// ```
     pub fn set_visuals(&self, visuals: crate::Visuals) {
+        self.animation_manager.lock().cancel_visuals_transition();
         std::sync::Arc::make_mut(&mut self.memory().options.style).visuals = visuals;
     }
 
+    /// Like [`Self::set_visuals`], but fades from the current visuals to the new ones
+    /// over `duration` seconds, instead of switching theme in one jarring flip.
+    ///
+    /// Colors and sizes are interpolated with [`crate::Visuals::lerp`].
+    /// If `reduce_motion` is set in [`crate::Memory::options`], the visuals are switched at once.
+    ///
+    /// Example:
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// ctx.set_visuals_animated(egui::Visuals::light(), 0.3); // Fade to light mode
+    /// ```
+    pub fn set_visuals_animated(&self, visuals: crate::Visuals, duration: f32) {
+        if duration <= 0.0 || self.memory().options.reduce_motion {
+            self.set_visuals(visuals);
+            return;
+        }
+        let from = self.style().visuals.clone();
+        self.animation_manager.lock().start_visuals_transition(
+            &self.input,
+            from,
+            visuals,
+            duration,
+        );
+        self.request_repaint();
+    }
+
+    /// How numbers and dates are shown by [`crate::DragValue`], [`crate::Slider`], plots etc.
+    pub fn value_formatter(&self) -> Arc<dyn crate::util::format::ValueFormatter> {
+        self.memory().options.value_formatter.clone()
+    }
+
+    /// Change how numbers and dates are shown by all widgets.
+    ///
+    /// Example:
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// use egui::util::format::NumberFormatter;
+    /// ctx.set_value_formatter(NumberFormatter {
+    ///     thousands_separator: Some(' '),
+    ///     decimal_separator: ',',
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn set_value_formatter(
+        &self,
+        value_formatter: impl crate::util::format::ValueFormatter + 'static,
+    ) {
+        self.memory().options.value_formatter = Arc::new(value_formatter);
+    }
+
+    /// Call `hook` with every [`Response`] created from now on,
+    /// e.g. to collect analytics, or to build tutorials and automation on top of your UI.
+    ///
+    /// The hook is called when the widget checks for interaction,
+    /// so [`Response::changed`] is not yet set.
+    ///
+    /// ```
+    /// # let ctx = egui::CtxRef::default();
+    /// ctx.on_response(|response| {
+    ///     if response.clicked() {
+    ///         println!("Clicked {:?} at {:?}", response.id, response.rect);
+    ///     }
+    /// });
+    /// ```
+    pub fn on_response(&self, hook: impl Fn(&Response) + Send + Sync + 'static) {
+        self.hooks.lock().on_response.push(Arc::new(hook));
+    }
+
+    /// Call `hook` at the start of every frame, with the input for that frame.
+    pub fn on_begin_frame(&self, hook: impl Fn(&InputState) + Send + Sync + 'static) {
+        self.hooks.lock().on_begin_frame.push(Arc::new(hook));
+    }
+
+    /// Call `hook` at the end of every frame, with the output of that frame.
+    pub fn on_end_frame(&self, hook: impl Fn(&Output) + Send + Sync + 'static) {
+        self.hooks.lock().on_end_frame.push(Arc::new(hook));
+    }
+
+    /// Undo the last change made with [`Ui::undoable`].
+    ///
+    /// The old value is written back when the widget is shown next frame.
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        self.request_repaint();
+        self.memory()
+            .data_temp
+            .get_mut_or_default::<crate::undo::UndoStack>()
+            .undo()
+    }
+
+    /// Redo the last change undone with [`Self::undo`].
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&self) -> bool {
+        self.request_repaint();
+        self.memory()
+            .data_temp
+            .get_mut_or_default::<crate::undo::UndoStack>()
+            .redo()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.memory()
+            .data_temp
+            .get_mut_or_default::<crate::undo::UndoStack>()
+            .can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.memory()
+            .data_temp
+            .get_mut_or_default::<crate::undo::UndoStack>()
+            .can_redo()
+    }
+
+    /// Forget everything that could be undone or redone, e.g. after loading a new document.
+    pub fn clear_undo_history(&self) {
+        self.memory()
+            .data_temp
+            .insert(crate::undo::UndoStack::default());
+    }
+
+    /// Remove all hooks added with [`Self::on_response`], [`Self::on_begin_frame`] and [`Self::on_end_frame`].
+    pub fn clear_hooks(&self) {
+        *self.hooks.lock() = Default::default();
+    }
+
     /// The number of physical pixels for each logical point.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -514,24 +784,31 @@ impl Context {
     // ---------------------------------------------------------------------
 
     /// Constrain the position of a window/area
-    /// so it fits within the screen.
+    /// so it fits within the screen, outside of any panels and OS taskbars.
     pub(crate) fn constrain_window_rect(&self, window: Rect) -> Rect {
-        self.constrain_window_rect_to_area(window, self.available_rect())
+        let work_area = self.input().work_area();
+        let area = self.available_rect().intersect(work_area);
+        if area.is_positive() {
+            self.constrain_window_rect_to_area(window, area)
+        } else {
+            self.constrain_window_rect_to_area(window, work_area)
+        }
     }
 
     /// Constrain the position of a window/area
     /// so it fits within the provided boundary.
     pub(crate) fn constrain_window_rect_to_area(&self, window: Rect, mut area: Rect) -> Rect {
+        let work_area = self.input().work_area();
         if window.width() > area.width() {
             // Allow overlapping side bars.
             // This is important for small screens, e.g. mobiles running the web demo.
-            area.max.x = self.input().screen_rect().max.x;
-            area.min.x = self.input().screen_rect().min.x;
+            area.max.x = work_area.max.x;
+            area.min.x = work_area.min.x;
         }
         if window.height() > area.height() {
             // Allow overlapping top/bottom bars:
-            area.max.y = self.input().screen_rect().max.y;
-            area.min.y = self.input().screen_rect().min.y;
+            area.max.y = work_area.max.y;
+            area.min.y = work_area.min.y;
         }
 
         let mut pos = window.min;
@@ -552,17 +829,34 @@ impl Context {
 
     // ---------------------------------------------------------------------
 
-    fn begin_frame_mut(&mut self, new_raw_input: RawInput) {
-        self.memory().begin_frame(&self.input, &new_raw_input);
+    fn begin_frame_mut(&mut self, mut new_raw_input: RawInput) {
+        {
+            let mut memory = self.memory();
+            if let Some(id) = memory.virtual_keyboard.begin_frame(&mut new_raw_input) {
+                memory.request_focus(id);
+            }
+            memory.begin_frame(&self.input, &new_raw_input);
+        }
 
         let mut input = std::mem::take(&mut self.input);
         if let Some(new_pixels_per_point) = self.memory().new_pixels_per_point.take() {
             input.pixels_per_point = new_pixels_per_point;
         }
 
-        self.input = input.begin_frame(new_raw_input);
+        self.input = input.begin_frame(new_raw_input, &self.style().interaction);
         self.frame_state.lock().begin_frame(&self.input);
 
+        let visuals_transition = self
+            .animation_manager
+            .lock()
+            .visuals_transition(&self.input);
+        if let Some((visuals, done)) = visuals_transition {
+            std::sync::Arc::make_mut(&mut self.memory().options.style).visuals = visuals;
+            if !done {
+                self.request_repaint();
+            }
+        }
+
         {
             // Load new fonts if required:
             let new_font_definitions = self.memory().new_font_definitions.take();
@@ -575,7 +869,23 @@ impl Context {
                 }
             };
 
-            if self.fonts.is_none() || new_font_definitions.is_some() || pixels_per_point_changed {
+            // Evict all glyphs if there is no room for more:
+            let font_atlas_is_full = self
+                .fonts
+                .as_ref()
+                .map_or(false, |fonts| fonts.atlas_is_full());
+
+            if pixels_per_point_changed && self.fonts.is_some() {
+                // E.g. the window moved to a monitor with a different DPI.
+                // Sizes measured last frame (columns, labels, …) need another frame to settle:
+                self.request_repaint();
+            }
+
+            if self.fonts.is_none()
+                || new_font_definitions.is_some()
+                || pixels_per_point_changed
+                || font_atlas_is_full
+            {
                 self.fonts = Some(Arc::new(Fonts::from_definitions(
                     pixels_per_point,
                     new_font_definitions.unwrap_or_else(|| {
@@ -598,6 +908,11 @@ impl Context {
                 interactable: true,
             },
         );
+
+        let hooks = self.hooks.lock().on_begin_frame.clone();
+        for hook in &hooks {
+            hook(&self.input);
+        }
     }
 
     /// Call at the end of each frame.
@@ -609,21 +924,145 @@ impl Context {
             self.request_repaint();
         }
 
+        {
+            let text_input_focus = self.output().text_cursor_pos.and(self.memory().focus());
+            self.memory().virtual_keyboard.end_frame(text_input_focus);
+        }
+
         self.memory()
             .end_frame(&self.input, &self.frame_state().used_ids);
 
         self.fonts().end_frame();
 
         let mut output: Output = std::mem::take(&mut self.output());
+        if let Some(pos) = self.input.pointer.hover_pos() {
+            if let Some(crate::WindowRegion::Resize(direction)) = output.window_region_at(pos) {
+                output.cursor_icon = direction.cursor_icon();
+            }
+        }
         if self.repaint_requests.load(SeqCst) > 0 {
             self.repaint_requests.fetch_sub(1, SeqCst);
             output.needs_repaint = true;
         }
 
+        let hooks = self.hooks.lock().on_end_frame.clone();
+        for hook in &hooks {
+            hook(&output);
+        }
+
+        if self.style().debug.inspect_shapes {
+            self.paint_shape_inspector();
+        }
+
         let shapes = self.drain_paint_lists();
         (output, shapes)
     }
 
+    /// See [`crate::style::DebugOptions::inspect_shapes`].
+    fn paint_shape_inspector(&self) {
+        #[derive(Clone, Copy, Default)]
+        struct ShapeInspector {
+            frozen_pos: Option<Pos2>,
+        }
+
+        let hover_pos = self.input.pointer.hover_pos();
+        let toggle_freeze = self.input.modifiers.alt && self.input.pointer.any_click();
+        let frozen_pos = {
+            let mut memory = self.memory();
+            let state = memory.data_temp.get_mut_or_default::<ShapeInspector>();
+            if toggle_freeze {
+                state.frozen_pos = if state.frozen_pos.is_some() {
+                    None
+                } else {
+                    hover_pos
+                };
+            }
+            state.frozen_pos
+        };
+        let pos = match frozen_pos.or(hover_pos) {
+            Some(pos) => pos,
+            None => return,
+        };
+
+        let mut hits = vec![];
+        {
+            let memory = self.memory();
+            self.graphics()
+                .for_each_shape(memory.areas.order(), |layer_id, clipped_shape| {
+                    let ClippedShape(clip_rect, shape) = clipped_shape;
+                    let rect = shape.visual_bounding_rect();
+                    if clip_rect.contains(pos) && rect.contains(pos) {
+                        hits.push((layer_id, shape_kind(shape), *clip_rect, rect));
+                    }
+                });
+        }
+
+        // The widget a shape belongs to is guessed as the smallest widget containing it:
+        let used_ids = &self.frame_state().used_ids;
+        let widget_at = |rect: Rect| {
+            used_ids
+                .iter()
+                .filter(|(_, widget_rect)| widget_rect.contains(rect.center()))
+                .min_by(|(_, a), (_, b)| {
+                    a.area()
+                        .partial_cmp(&b.area())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(id, _)| id.short_debug_format())
+        };
+
+        let mut text = format!(
+            "{} shapes at {:?} ({}):",
+            hits.len(),
+            pos,
+            if frozen_pos.is_some() {
+                "frozen, alt-click to unfreeze"
+            } else {
+                "alt-click to freeze"
+            }
+        );
+        const MAX_SHAPES: usize = 20;
+        // Topmost first:
+        for (layer_id, kind, clip_rect, rect) in hits.iter().rev().take(MAX_SHAPES) {
+            text += &format!(
+                "\n{:<11} {:?} {} clip: {:?} widget: {}",
+                kind,
+                layer_id.order,
+                layer_id.id.short_debug_format(),
+                clip_rect,
+                widget_at(*rect).unwrap_or_else(|| "-".to_owned()),
+            );
+        }
+        if hits.len() > MAX_SHAPES {
+            text += &format!("\n… and {} more", hits.len() - MAX_SHAPES);
+        }
+
+        // Like `CtxRef::debug_painter`, which we can't use from here:
+        let screen_rect = self.input.screen_rect();
+        let galley = self.fonts().layout_no_wrap(TextStyle::Monospace, text);
+        let text_rect = Rect::from_min_size(pos + vec2(16.0, 16.0), galley.size);
+        let mut graphics = self.graphics();
+        let mut list = graphics.list(LayerId::debug()).lock();
+        if let Some(&(_, _, _, rect)) = hits.last() {
+            let stroke = (1.0, Color32::from_rgb(255, 0, 255));
+            list.add(screen_rect, Shape::rect_stroke(rect, 0.0, stroke));
+        }
+        let background = Color32::from_black_alpha(240);
+        list.add(
+            screen_rect,
+            Shape::rect_filled(text_rect.expand(2.0), 0.0, background),
+        );
+        list.add(
+            screen_rect,
+            Shape::Text {
+                pos: text_rect.min,
+                galley,
+                color: Color32::WHITE,
+                fake_italics: false,
+            },
+        );
+    }
+
     fn drain_paint_lists(&self) -> Vec<ClippedShape> {
         let memory = self.memory();
         self.graphics().drain(memory.areas.order()).collect()
@@ -631,19 +1070,31 @@ impl Context {
 
     /// Tessellate the given shapes into triangle meshes.
     pub fn tessellate(&self, shapes: Vec<ClippedShape>) -> Vec<ClippedMesh> {
-        let mut tessellation_options = self.memory().options.tessellation_options;
-        tessellation_options.pixels_per_point = self.pixels_per_point();
-        tessellation_options.aa_size = 1.0 / self.pixels_per_point();
-        let paint_stats = PaintStats::from_shapes(&shapes); // TODO: internal allocations
-        let clipped_meshes = tessellator::tessellate_shapes(
-            shapes,
-            tessellation_options,
-            self.fonts().texture().size(),
-        );
-        *self.paint_stats.lock() = paint_stats.with_clipped_meshes(&clipped_meshes);
+        let clipped_meshes = self.tessellation_job(shapes).tessellate();
+        let mut paint_stats = self.paint_stats.lock();
+        *paint_stats = paint_stats.with_clipped_meshes(&clipped_meshes);
         clipped_meshes
     }
 
+    /// Everything needed to tessellate the given shapes, without needing the [`Context`].
+    ///
+    /// Use this if you want to tessellate somewhere else, e.g. on another thread
+    /// or in a web worker. Call [`TessellationJob::tessellate`] there.
+    /// Otherwise, just use [`Self::tessellate`].
+    ///
+    /// The paint stats in the settings UI will only count the shapes, not the resulting triangles.
+    pub fn tessellation_job(&self, shapes: Vec<ClippedShape>) -> TessellationJob {
+        let mut options = self.memory().options.tessellation_options;
+        options.pixels_per_point = self.pixels_per_point();
+        options.aa_size = 1.0 / self.pixels_per_point();
+        *self.paint_stats.lock() = PaintStats::from_shapes(&shapes); // TODO: internal allocations
+        TessellationJob {
+            shapes,
+            options,
+            tex_size: self.fonts().texture().size(),
+        }
+    }
+
     // ---------------------------------------------------------------------
 
     /// How much space is used by panels and windows.
@@ -757,8 +1208,14 @@ impl Context {
     /// Calling this with `value = false` will always yield a number less than one, quickly going towards zero.
     ///
     /// The function will call [`Self::request_repaint()`] when appropriate.
+    ///
+    /// If `reduce_motion` is set in [`crate::Memory::options`], this will jump straight to the end value.
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
-        let animation_time = self.style().animation_time;
+        let animation_time = if self.memory().options.reduce_motion {
+            0.0
+        } else {
+            self.style().animation_time
+        };
         let animated_value =
             self.animation_manager
                 .lock()
@@ -956,3 +1413,162 @@ impl Context {
         self.set_style(style);
     }
 }
+
+fn shape_kind(shape: &Shape) -> &'static str {
+    match shape {
+        Shape::Noop => "Noop",
+        Shape::Vec(_) => "Vec",
+        Shape::Circle { .. } => "Circle",
+        Shape::LineSegment { .. } => "LineSegment",
+        Shape::Path { .. } => "Path",
+        Shape::Rect { .. } => "Rect",
+        Shape::Text { .. } => "Text",
+        Shape::Mesh(_) => "Mesh",
+        Shape::NineSlice(_) => "NineSlice",
+    }
+}
+
+#[test]
+fn test_overlapping_widgets() {
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 200.0));
+    let below = Rect::from_min_size(pos2(10.0, 10.0), vec2(50.0, 50.0));
+    let above = Rect::from_min_size(pos2(40.0, 40.0), vec2(50.0, 50.0));
+
+    let run = |harness: &mut crate::harness::Harness| {
+        let mut hovered = (false, false);
+        harness.run(|ui| {
+            let below = ui.interact(below, Id::new("below"), Sense::click());
+            let above = ui.interact(above, Id::new("above"), Sense::click());
+            hovered = (below.hovered(), above.hovered());
+        });
+        hovered
+    };
+
+    harness.pointer_move(pos2(50.0, 50.0));
+    run(&mut harness);
+    assert_eq!(run(&mut harness), (false, true));
+
+    harness.pointer_move(pos2(20.0, 20.0));
+    assert_eq!(run(&mut harness), (true, false));
+}
+
+#[test]
+fn test_hooks() {
+    use std::sync::atomic::AtomicUsize;
+
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 100.0));
+    let clicks = Arc::new(AtomicUsize::new(0));
+    let frames = Arc::new(AtomicUsize::new(0));
+    {
+        let clicks = clicks.clone();
+        harness.ctx().on_response(move |response| {
+            if response.clicked() {
+                clicks.fetch_add(1, SeqCst);
+            }
+        });
+        let frames = frames.clone();
+        harness.ctx().on_end_frame(move |_output| {
+            frames.fetch_add(1, SeqCst);
+        });
+    }
+
+    let frame = harness.run(|ui| {
+        assert!(!ui.button("Click me").clicked());
+    });
+    harness.click(frame.contents_rect.unwrap().center());
+    harness.run(|ui| {
+        assert!(ui.button("Click me").clicked());
+    });
+    assert_eq!(clicks.load(SeqCst), 1);
+    assert_eq!(frames.load(SeqCst), 2);
+
+    harness.ctx().clear_hooks();
+    harness.run(|_ui| {});
+    assert_eq!(frames.load(SeqCst), 2);
+}
+
+#[test]
+fn test_repaint_after_dpi_change() {
+    let mut ctx = CtxRef::default();
+    let mut run = |pixels_per_point: Option<f32>| {
+        ctx.begin_frame(RawInput {
+            pixels_per_point,
+            ..Default::default()
+        });
+        ctx.end_frame().0.needs_repaint
+    };
+
+    for _ in 0..3 {
+        run(None);
+    }
+    assert!(!run(None));
+
+    // E.g. the window was moved to a high-DPI monitor:
+    assert!(run(Some(2.0)));
+}
+
+#[test]
+fn test_window_regions() {
+    use crate::{ResizeDirection, WindowRegion};
+
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 200.0));
+    let title_bar = Rect::from_min_size(pos2(0.0, 0.0), vec2(200.0, 20.0));
+    let close_button = Rect::from_min_size(pos2(180.0, 0.0), vec2(20.0, 20.0));
+    let right_edge = Rect::from_min_size(pos2(196.0, 0.0), vec2(4.0, 200.0));
+
+    harness.pointer_move(pos2(198.0, 100.0));
+    let output = harness
+        .run_ctx(|ctx| {
+            ctx.add_window_region(title_bar, WindowRegion::Drag);
+            ctx.add_window_region(close_button, WindowRegion::Client);
+            ctx.add_window_region(right_edge, WindowRegion::Resize(ResizeDirection::East));
+        })
+        .output;
+
+    // The last region added is on top:
+    assert_eq!(
+        output.window_region_at(pos2(10.0, 10.0)),
+        Some(WindowRegion::Drag)
+    );
+    assert_eq!(
+        output.window_region_at(pos2(185.0, 10.0)),
+        Some(WindowRegion::Client)
+    );
+    assert_eq!(
+        output.window_region_at(pos2(198.0, 10.0)),
+        Some(WindowRegion::Resize(ResizeDirection::East))
+    );
+    assert_eq!(output.window_region_at(pos2(100.0, 100.0)), None);
+
+    // Hovering an edge shows the resize cursor:
+    assert_eq!(output.cursor_icon, CursorIcon::ResizeHorizontal);
+}
+
+#[test]
+fn test_set_visuals_animated() {
+    use crate::Visuals;
+
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 200.0)).dt(0.1);
+    harness.run_ctx(|ctx| ctx.set_visuals(Visuals::dark()));
+    harness.run_ctx(|ctx| ctx.set_visuals_animated(Visuals::light(), 1.0));
+
+    let dark_fill = Visuals::dark().window_fill();
+    let light_fill = Visuals::light().window_fill();
+
+    // Half-way through, the window fill is in between the two themes:
+    let frame = harness.run_frames(5, |_| {});
+    assert!(frame.output.needs_repaint);
+    let fill = harness.ctx().style().visuals.window_fill();
+    assert!(dark_fill.r() < fill.r() && fill.r() < light_fill.r());
+    assert!(!harness.ctx().style().visuals.dark_mode);
+
+    let frame = harness.run_until_stable(100, |_| {});
+    assert!(!frame.output.needs_repaint);
+    assert_eq!(harness.ctx().style().visuals, Visuals::light());
+
+    // Switching instantly cancels any ongoing transition:
+    harness.run_ctx(|ctx| ctx.set_visuals_animated(Visuals::dark(), 1.0));
+    harness.run_ctx(|ctx| ctx.set_visuals(Visuals::light()));
+    harness.run_frames(20, |_| {});
+    assert_eq!(harness.ctx().style().visuals, Visuals::light());
+}
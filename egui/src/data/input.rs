@@ -12,13 +12,22 @@ use crate::emath::*;
 /// All coordinates are in points (logical pixels) with origin (0, 0) in the top left corner.
 #[derive(Clone, Debug)]
 pub struct RawInput {
-    /// How many points (logical pixels) the user scrolled
+    /// How many points (logical pixels) the user scrolled,
+    /// e.g. with a touchpad or other precision scrolling device.
     pub scroll_delta: Vec2,
 
-    /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
+    /// How many lines the user scrolled, e.g. with a mouse wheel.
+    ///
+    /// egui converts this to points using [`crate::style::Interaction::scroll_line_height`].
+    pub scroll_delta_lines: Vec2,
+
+    /// Zoom scale factor this frame (e.g. from a pinch gesture).
     /// * `zoom = 1`: no change (default).
     /// * `zoom < 1`: pinch together
     /// * `zoom > 1`: pinch spread
+    ///
+    /// You don't need to convert ctrl-scroll to zoom: egui does that for you
+    /// (see [`crate::InputState::zoom_delta`]).
     pub zoom_delta: f32,
 
     #[deprecated = "Use instead: `screen_rect: Some(Rect::from_pos_size(Default::default(), screen_size))`"]
@@ -51,6 +60,36 @@ pub struct RawInput {
     /// Which modifier keys are down at the start of the frame?
     pub modifiers: Modifiers,
 
+    /// Set this to `false` on touch devices without a physical keyboard.
+    ///
+    /// egui will then show an on-screen keyboard whenever a text field has focus.
+    /// Default: `true`.
+    pub has_hardware_keyboard: bool,
+
+    /// The part of the screen that is not covered by OS taskbars, docks, notches etc, in points.
+    ///
+    /// Windows are kept within it. `None` means all of [`Self::screen_rect`].
+    /// This is state (not an event), so it is kept by [`Self::take`]: only set it when it changes.
+    pub work_area: Option<Rect>,
+
+    /// Set this to `true` if your painter interpolates vertex colors in linear space,
+    /// i.e. converts the sRGBA vertex colors to linear space in the vertex shader (not the fragment shader).
+    ///
+    /// egui expects this, and also expects blending to happen in linear space with premultiplied alpha.
+    /// If your painter can't do this, consider turning on
+    /// [`epaint::TessellationOptions::subdivide_gradients`].
+    /// This is only a description of the integration: egui does not change its output based on it.
+    /// Default: `false`.
+    pub interpolates_colors_in_linear_space: bool,
+
+    /// Files are being dragged over the window, but not yet dropped.
+    ///
+    /// This is state (not an event): keep it set as long as the files are hovering.
+    pub hovered_files: Vec<HoveredFile>,
+
+    /// Files that were dropped onto the window this frame.
+    pub dropped_files: Vec<DroppedFile>,
+
     /// In-order events received this frame.
     ///
     /// There is currently no way to know if egui handles a particular event,
@@ -64,6 +103,7 @@ impl Default for RawInput {
         #![allow(deprecated)] // for screen_size
         Self {
             scroll_delta: Vec2::ZERO,
+            scroll_delta_lines: Vec2::ZERO,
             zoom_delta: 1.0,
             screen_size: Default::default(),
             screen_rect: None,
@@ -71,6 +111,11 @@ impl Default for RawInput {
             time: None,
             predicted_dt: 1.0 / 60.0,
             modifiers: Modifiers::default(),
+            has_hardware_keyboard: true,
+            work_area: None,
+            interpolates_colors_in_linear_space: false,
+            hovered_files: Default::default(),
+            dropped_files: Default::default(),
             events: vec![],
         }
     }
@@ -84,6 +129,7 @@ impl RawInput {
         self.zoom_delta = 1.0;
         RawInput {
             scroll_delta: std::mem::take(&mut self.scroll_delta),
+            scroll_delta_lines: std::mem::take(&mut self.scroll_delta_lines),
             zoom_delta: zoom,
             screen_size: self.screen_size,
             screen_rect: self.screen_rect.take(),
@@ -91,11 +137,38 @@ impl RawInput {
             time: self.time.take(),
             predicted_dt: self.predicted_dt,
             modifiers: self.modifiers,
+            has_hardware_keyboard: self.has_hardware_keyboard,
+            work_area: self.work_area,
+            interpolates_colors_in_linear_space: self.interpolates_colors_in_linear_space,
+            hovered_files: self.hovered_files.clone(),
+            dropped_files: std::mem::take(&mut self.dropped_files),
             events: std::mem::take(&mut self.events),
         }
     }
 }
 
+/// A file about to be dropped into egui.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HoveredFile {
+    /// Set by the `egui_glium` backend.
+    pub path: Option<std::path::PathBuf>,
+    /// With the `egui_web` backend, this is set to the mime-type of the file (if available).
+    pub mime: String,
+}
+
+/// A file dropped into egui.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DroppedFile {
+    /// Set by the `egui_glium` backend.
+    pub path: Option<std::path::PathBuf>,
+    /// Name of the file. Set by the `egui_web` backend.
+    pub name: String,
+    /// Set by the `egui_web` backend.
+    pub last_modified: Option<std::time::SystemTime>,
+    /// Set by the `egui_web` backend.
+    pub bytes: Option<std::sync::Arc<[u8]>>,
+}
+
 /// An input event generated by the integration.
 ///
 /// This only covers events that egui cares about.
@@ -164,10 +237,25 @@ pub enum PointerButton {
     Secondary = 1,
     /// The tertiary mouse button is usually the middle mouse button (e.g. clicking the scroll wheel).
     Middle = 2,
+    /// The first extra mouse button on some mice. In web browsers, it is often used as "back".
+    Extra1 = 3,
+    /// The second extra mouse button on some mice. In web browsers, it is often used as "forward".
+    Extra2 = 4,
+}
+
+impl PointerButton {
+    /// All the pointer buttons, in order.
+    pub const ALL: [PointerButton; NUM_POINTER_BUTTONS] = [
+        PointerButton::Primary,
+        PointerButton::Secondary,
+        PointerButton::Middle,
+        PointerButton::Extra1,
+        PointerButton::Extra2,
+    ];
 }
 
 /// Number of pointer buttons supported by egui, i.e. the number of possible states of [`PointerButton`].
-pub const NUM_POINTER_BUTTONS: usize = 3;
+pub const NUM_POINTER_BUTTONS: usize = 5;
 
 /// State of the modifier keys. These must be fed to egui.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -287,6 +375,7 @@ impl RawInput {
         #![allow(deprecated)] // for screen_size
         let Self {
             scroll_delta,
+            scroll_delta_lines,
             zoom_delta,
             screen_size: _,
             screen_rect,
@@ -294,10 +383,19 @@ impl RawInput {
             time,
             predicted_dt,
             modifiers,
+            has_hardware_keyboard,
+            work_area,
+            interpolates_colors_in_linear_space,
+            hovered_files,
+            dropped_files,
             events,
         } = self;
 
         ui.label(format!("scroll_delta: {:?} points", scroll_delta));
+        ui.label(format!(
+            "scroll_delta_lines: {:?} lines",
+            scroll_delta_lines
+        ));
         ui.label(format!("zoom_delta: {:.3?} x", zoom_delta));
         ui.label(format!("screen_rect: {:?} points", screen_rect));
         ui.label(format!("pixels_per_point: {:?}", pixels_per_point))
@@ -311,6 +409,14 @@ impl RawInput {
         }
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
         ui.label(format!("modifiers: {:#?}", modifiers));
+        ui.label(format!("has_hardware_keyboard: {}", has_hardware_keyboard));
+        ui.label(format!("work_area: {:?} points", work_area));
+        ui.label(format!(
+            "interpolates_colors_in_linear_space: {}",
+            interpolates_colors_in_linear_space
+        ));
+        ui.label(format!("hovered_files: {}", hovered_files.len()));
+        ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("events: {:?}", events))
             .on_hover_text("key presses etc");
     }
@@ -28,6 +28,19 @@ pub struct Output {
 
     /// Position of text edit cursor (used for IME).
     pub text_cursor_pos: Option<crate::Pos2>,
+
+    /// Is there a mutable [`crate::TextEdit`] under the pointer?
+    ///
+    /// Used by `egui_web` to show the on-screen keyboard when the user taps a text field.
+    pub mutable_text_under_cursor: bool,
+
+    /// Parts of the window that move or resize the window when the user presses the primary mouse button on them,
+    /// for apps that draw their own title bar and window edges. Later regions are on top of earlier ones.
+    ///
+    /// The backend should check these when the primary mouse button is pressed (see [`Self::window_region_at`])
+    /// and let the OS move or resize the window, which is smoother than moving it each frame.
+    /// Add to it with [`crate::Context::add_window_region`].
+    pub window_regions: Vec<(crate::Rect, WindowRegion)>,
 }
 
 impl Output {
@@ -38,21 +51,87 @@ impl Output {
     }
 
     /// This can be used by a text-to-speech system to describe the events (if any).
+    ///
+    /// Only the last event is described.
+    /// Use [`Self::events_descriptions`] if you want to queue up all of them.
     pub fn events_description(&self) -> String {
         // only describe last event:
         if let Some(event) = self.events.iter().rev().next() {
-            match event {
-                OutputEvent::Clicked(widget_info)
-                | OutputEvent::DoubleClicked(widget_info)
-                | OutputEvent::FocusGained(widget_info)
-                | OutputEvent::TextSelectionChanged(widget_info)
-                | OutputEvent::ValueChanged(widget_info) => {
-                    return widget_info.description();
-                }
-            }
+            return event.description();
         }
         Default::default()
     }
+
+    /// A human-readable description of each event this frame, in order.
+    ///
+    /// This can be fed to a text-to-speech engine.
+    pub fn events_descriptions(&self) -> impl Iterator<Item = String> + '_ {
+        self.events.iter().map(OutputEvent::description)
+    }
+
+    /// The top-most of the [`Self::window_regions`] that contains `pos` (in points), if any.
+    pub fn window_region_at(&self, pos: crate::Pos2) -> Option<WindowRegion> {
+        window_region_at(&self.window_regions, pos)
+    }
+}
+
+/// The top-most of the `window_regions` that contains `pos`, see [`Output::window_regions`].
+pub fn window_region_at(
+    window_regions: &[(crate::Rect, WindowRegion)],
+    pos: crate::Pos2,
+) -> Option<WindowRegion> {
+    window_regions
+        .iter()
+        .rev()
+        .find(|(rect, _)| rect.contains(pos))
+        .map(|(_, region)| *region)
+}
+
+/// What happens when the user presses the primary mouse button on a part of the window.
+/// See [`Output::window_regions`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WindowRegion {
+    /// Normal egui contents, e.g. a button on the title bar.
+    Client,
+
+    /// Move the window, e.g. a custom title bar.
+    Drag,
+
+    /// Resize the window, e.g. its edges and corners.
+    Resize(ResizeDirection),
+}
+
+/// Which edge or corner of the window to drag to resize it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ResizeDirection {
+    /// The top edge.
+    North,
+    /// The bottom edge.
+    South,
+    /// The right edge.
+    East,
+    /// The left edge.
+    West,
+    /// The top-right corner.
+    NorthEast,
+    /// The top-left corner.
+    NorthWest,
+    /// The bottom-right corner.
+    SouthEast,
+    /// The bottom-left corner.
+    SouthWest,
+}
+
+impl ResizeDirection {
+    /// The mouse cursor to show when hovering this edge or corner.
+    pub fn cursor_icon(self) -> CursorIcon {
+        match self {
+            Self::North | Self::South => CursorIcon::ResizeVertical,
+            Self::East | Self::West => CursorIcon::ResizeHorizontal,
+            Self::NorthEast | Self::SouthWest => CursorIcon::ResizeNeSw,
+            Self::NorthWest | Self::SouthEast => CursorIcon::ResizeNwSe,
+        }
+    }
 }
 
 #[derive(Clone, PartialEq)]
@@ -222,6 +301,26 @@ pub enum OutputEvent {
     ValueChanged(WidgetInfo),
 }
 
+impl OutputEvent {
+    /// The widget this event is about.
+    pub fn widget_info(&self) -> &WidgetInfo {
+        match self {
+            OutputEvent::Clicked(info)
+            | OutputEvent::DoubleClicked(info)
+            | OutputEvent::FocusGained(info)
+            | OutputEvent::TextSelectionChanged(info)
+            | OutputEvent::ValueChanged(info) => info,
+        }
+    }
+
+    /// This can be used by a text-to-speech system to describe the event.
+    ///
+    /// Describes the widget in its current state, e.g. `"Volume: slider 30"`.
+    pub fn description(&self) -> String {
+        self.widget_info().description()
+    }
+}
+
 impl std::fmt::Debug for OutputEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -400,6 +499,7 @@ impl WidgetInfo {
             WidgetType::ColorButton => "color button",
             WidgetType::ImageButton => "image button",
             WidgetType::CollapsingHeader => "collapsing header",
+            WidgetType::MenuItem => "menu item",
             WidgetType::Label | WidgetType::Other => "",
         };
 
@@ -9,6 +9,17 @@ pub(crate) struct FrameState {
     /// Used to debug `Id` clashes of widgets.
     pub(crate) used_ids: ahash::AHashMap<Id, Rect>,
 
+    /// [`Self::used_ids`] from the previous frame.
+    /// Used to look up where a widget is before it has been added this frame.
+    pub(crate) prev_used_ids: ahash::AHashMap<Id, Rect>,
+
+    /// Widgets that sense clicks or drags, in the order they were interacted with this frame.
+    pub(crate) interactive_widgets: Vec<(LayerId, Id, HitShape)>,
+
+    /// [`Self::interactive_widgets`] from the previous frame.
+    /// Used to find out if a widget is covered by one added after it.
+    pub(crate) prev_interactive_widgets: Vec<(LayerId, Id, HitShape)>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The `CentralPanel` does not change this.
     /// This is the area available to Window's.
@@ -29,18 +40,26 @@ pub(crate) struct FrameState {
     /// Cleared by the first `ScrollArea` that makes use of it.
     pub(crate) scroll_delta: Vec2,
     pub(crate) scroll_target: Option<(f32, Align)>,
+
+    /// See [`crate::Context::scroll_to_widget`].
+    /// Kept for one more frame if no `ScrollArea` used it (the `bool` is set in the frame it was requested).
+    pub(crate) scroll_to_widget: Option<(Id, Align, bool)>,
 }
 
 impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            prev_used_ids: Default::default(),
+            interactive_widgets: Default::default(),
+            prev_interactive_widgets: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
             tooltip_rect: None,
             scroll_delta: Vec2::ZERO,
             scroll_target: None,
+            scroll_to_widget: None,
         }
     }
 }
@@ -49,21 +68,43 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            prev_used_ids,
+            interactive_widgets,
+            prev_interactive_widgets,
             available_rect,
             unused_rect,
             used_by_panels,
             tooltip_rect,
             scroll_delta,
             scroll_target,
+            scroll_to_widget,
         } = self;
 
+        std::mem::swap(used_ids, prev_used_ids);
         used_ids.clear();
+        *prev_interactive_widgets = std::mem::take(interactive_widgets);
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
         *tooltip_rect = None;
         *scroll_delta = input.scroll_delta;
         *scroll_target = None;
+        *scroll_to_widget = scroll_to_widget
+            .filter(|&(_, _, requested_last_frame)| requested_last_frame)
+            .map(|(id, align, _)| (id, align, false));
+    }
+
+    /// If the widget of [`Self::scroll_to_widget`] is within `content_rect`,
+    /// return where to scroll to, like [`Self::scroll_target`].
+    pub(crate) fn take_scroll_to_widget(&mut self, content_rect: Rect) -> Option<(f32, Align)> {
+        let (id, align, _) = self.scroll_to_widget?;
+        let rect = *self.used_ids.get(&id)?;
+        if content_rect.expand(0.5).contains_rect(rect) {
+            self.scroll_to_widget = None;
+            Some((lerp(rect.y_range(), align.to_factor()), align))
+        } else {
+            None
+        }
     }
 
     /// How much space is still available after panels has been added.
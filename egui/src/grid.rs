@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
+
 use crate::*;
 
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
 pub(crate) struct State {
     col_widths: Vec<f32>,
     row_heights: Vec<f32>,
+    /// The widths of the columns the user has resized, see [`Grid::resizable`].
+    resized_col_widths: BTreeMap<usize, f32>,
 }
 
 impl State {
@@ -54,10 +59,28 @@ pub(crate) struct GridLayout {
     min_cell_size: Vec2,
     max_cell_size: Vec2,
     striped: bool,
+    keyboard_navigation: bool,
+    copy_as: Option<TableFormat>,
+    resizable: bool,
+    cell_align: Align2,
+    column_aligns: BTreeMap<usize, Align2>,
 
     // Cursor:
     col: usize,
     row: usize,
+    /// How many columns the next cell spans.
+    span: usize,
+    /// The alignment of the next cell, if different from that of its column.
+    next_cell_align: Option<Align2>,
+
+    /// The last focusable widget of each cell, for keyboard navigation.
+    focusable: Vec<((usize, usize), Id)>,
+    /// [`Memory::last_interested_in_focus`] when we last checked it.
+    last_interested: Option<Id>,
+    /// The rectangle of each cell so far, to find out which cell a focusable widget is in.
+    cell_rects: Vec<((usize, usize), Rect)>,
+    /// The plain text of each cell, collected when [`Self::copy_as`] is set.
+    cell_texts: BTreeMap<(usize, usize), String>,
 }
 
 impl GridLayout {
@@ -72,12 +95,17 @@ impl GridLayout {
             "Grid not yet available for right-to-left layouts"
         );
 
+        let curr_state = State {
+            resized_col_widths: prev_state.resized_col_widths.clone(),
+            ..Default::default()
+        };
+
         Self {
             ctx: ui.ctx().clone(),
             style: ui.style().clone(),
             id,
             prev_state,
-            curr_state: State::default(),
+            curr_state,
             initial_available,
 
             num_columns: None,
@@ -85,9 +113,21 @@ impl GridLayout {
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
             striped: false,
+            keyboard_navigation: true,
+            copy_as: None,
+            resizable: false,
+            cell_align: Align2::LEFT_CENTER,
+            column_aligns: Default::default(),
 
             col: 0,
             row: 0,
+            span: 1,
+            next_cell_align: None,
+
+            focusable: Default::default(),
+            last_interested: ui.memory().last_interested_in_focus(),
+            cell_rects: Default::default(),
+            cell_texts: Default::default(),
         }
     }
 }
@@ -104,8 +144,72 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    /// The width of the columns spanned by the next cell, including the spacing between them.
+    fn span_width(&self, col_width: impl Fn(usize) -> f32) -> f32 {
+        (self.col..self.col + self.span).map(col_width).sum::<f32>()
+            + (self.span - 1) as f32 * self.spacing.x
+    }
+
+    /// Let the next cell span this many columns.
+    pub(crate) fn set_span(&mut self, columns: usize) {
+        self.span = columns.at_least(1);
+    }
+
+    /// Set the plain text of the next cell, for when the grid is copied as a table.
+    /// Unless `overwrite`, text set earlier for the same cell is kept.
+    pub(crate) fn set_cell_text(&mut self, text: &str, overwrite: bool) {
+        if self.copy_as.is_some() {
+            let cell = self.cell();
+            if overwrite || !self.cell_texts.contains_key(&cell) {
+                self.cell_texts.insert(cell, text.to_owned());
+            }
+        }
+    }
+
+    /// The `(row, column)` of the cell that will be added next.
+    pub(crate) fn cell(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
-        self.max_cell_size.x.is_finite()
+        self.max_cell_size.x.is_finite() || self.resized_col_width(self.col).is_some()
+    }
+
+    /// The width the user has given the column by dragging, if any.
+    fn resized_col_width(&self, col: usize) -> Option<f32> {
+        if self.resizable {
+            self.curr_state.resized_col_widths.get(&col).copied()
+        } else {
+            None
+        }
+    }
+
+    /// A column resized by the user keeps its width, whatever is in it.
+    fn set_min_col_width(&mut self, col: usize, width: f32) {
+        let width = self.resized_col_width(col).unwrap_or(width);
+        self.curr_state.set_min_col_width(col, width);
+    }
+
+    /// Give the column this width, or its automatic width back if `None`.
+    fn resize_column(&mut self, col: usize, width: Option<f32>) {
+        if let Some(width) = width {
+            let width = width.at_least(self.min_cell_size.x);
+            self.curr_state.resized_col_widths.insert(col, width);
+        } else {
+            self.curr_state.resized_col_widths.remove(&col);
+        }
+    }
+
+    /// The gaps between the columns shown last frame, as `(column to the left, x of the gap)`.
+    fn column_gaps(&self) -> Vec<(usize, f32)> {
+        let mut x = self.initial_available.min.x;
+        let num_columns = self.prev_state.col_widths.len();
+        (0..num_columns.saturating_sub(1))
+            .map(|col| {
+                x += self.prev_col_width(col) + self.spacing.x;
+                (col, x - 0.5 * self.spacing.x)
+            })
+            .collect()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
@@ -114,20 +218,24 @@ impl GridLayout {
     }
 
     pub(crate) fn available_rect_finite(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = self
+            .num_columns
+            .map_or(false, |num_columns| self.col + self.span >= num_columns);
 
         let width = if is_last_column {
             (self.initial_available.right() - region.cursor.left()).at_most(self.max_cell_size.x)
         } else if self.max_cell_size.x.is_finite() {
             // TODO: should probably heed `prev_state` here too
-            self.max_cell_size.x
+            self.span_width(|col| self.resized_col_width(col).unwrap_or(self.max_cell_size.x))
         } else {
             // If we want to allow width-filling widgets like `Separator` in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
-            self.prev_state
-                .col_width(self.col)
-                .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+            self.span_width(|col| {
+                self.resized_col_width(col)
+                    .or_else(|| self.prev_state.col_width(col))
+                    .or_else(|| self.curr_state.col_width(col))
+                    .unwrap_or(self.min_cell_size.x)
+            })
         };
 
         let available = region.max_rect.intersect(region.cursor);
@@ -141,16 +249,23 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
+        let width = self.span_width(|col| self.prev_state.col_width(col).unwrap_or(0.0));
         let height = self.prev_row_height(self.row);
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
 
-    #[allow(clippy::unused_self)]
+    /// Align the contents of the next cell with this, instead of the alignment of its column.
+    pub(crate) fn set_next_cell_align(&mut self, align: Align2) {
+        self.next_cell_align = Some(align);
+    }
+
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO: allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        let align = self
+            .next_cell_align
+            .or_else(|| self.column_aligns.get(&self.col).copied())
+            .unwrap_or(self.cell_align);
+        align.align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -162,7 +277,7 @@ impl GridLayout {
         let debug_expand_height = self.style.debug.show_expand_height;
         if debug_expand_width || debug_expand_height {
             let rect = widget_rect;
-            let too_wide = rect.width() > self.prev_col_width(self.col);
+            let too_wide = rect.width() > self.span_width(|col| self.prev_col_width(col));
             let too_high = rect.height() > self.prev_row_height(self.row);
 
             if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
@@ -180,18 +295,45 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().at_least(self.min_cell_size.x));
+        let width = widget_rect.width().at_least(self.min_cell_size.x);
+        if self.span == 1 {
+            self.set_min_col_width(self.col, width);
+        } else {
+            // A cell spanning several columns only widens the last of them,
+            // and only by as much as it doesn't fit in the others:
+            let last_col = self.col + self.span - 1;
+            let other_cols_width = (self.col..last_col)
+                .map(|col| self.prev_col_width(col) + self.spacing.x)
+                .sum::<f32>();
+            for col in self.col..last_col {
+                self.set_min_col_width(col, self.min_cell_size.x);
+            }
+            self.set_min_col_width(
+                last_col,
+                (width - other_cols_width).at_least(self.min_cell_size.x),
+            );
+        }
         self.curr_state.set_min_row_height(
             self.row,
             widget_rect.height().at_least(self.min_cell_size.y),
         );
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        if self.keyboard_navigation {
+            self.cell_rects.push(((self.row, self.col), widget_rect));
+            self.note_focusable();
+        }
+
+        cursor.min.x += self.span_width(|col| self.prev_col_width(col)) + self.spacing.x;
+        self.col += self.span;
+        self.span = 1;
+        self.next_cell_align = None;
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
+        if self.keyboard_navigation {
+            self.note_focusable();
+        }
+
         cursor.min.x = self.initial_available.min.x;
         cursor.min.y += self.spacing.y;
         cursor.min.y += self
@@ -201,6 +343,8 @@ impl GridLayout {
 
         self.col = 0;
         self.row += 1;
+        self.span = 1;
+        self.next_cell_align = None;
 
         if self.striped && self.row % 2 == 1 {
             if let Some(height) = self.prev_state.row_height(self.row) {
@@ -215,7 +359,16 @@ impl GridLayout {
         }
     }
 
-    pub(crate) fn save(&self) {
+    pub(crate) fn save(&mut self) {
+        if self.keyboard_navigation {
+            self.note_focusable();
+            self.move_focus_with_arrow_keys();
+        }
+
+        if let Some(format) = self.copy_as {
+            self.ctx.output().copied_text = format.table(&self.cell_texts);
+        }
+
         if self.curr_state != self.prev_state {
             self.ctx
                 .memory()
@@ -224,6 +377,212 @@ impl GridLayout {
             self.ctx.request_repaint();
         }
     }
+
+    /// Remember which cell the last widget interested in focus is in.
+    ///
+    /// A widget usually allocates its cell before it asks for focus, while a nested [`Ui`]
+    /// asks for focus before its cell is allocated, so we go by where the widget is.
+    fn note_focusable(&mut self) {
+        let last_interested = self.ctx.memory().last_interested_in_focus();
+        if last_interested == self.last_interested {
+            return;
+        }
+        self.last_interested = last_interested;
+        let id = match last_interested {
+            Some(id) => id,
+            None => return,
+        };
+
+        let widget_rect = self
+            .ctx
+            .frame_state()
+            .interactive_widgets
+            .iter()
+            .rev()
+            .find(|(_, widget_id, _)| *widget_id == id)
+            .map(|(_, _, shape)| shape.bounding_rect());
+        let cell = match widget_rect {
+            Some(widget_rect) => self
+                .cell_rects
+                .iter()
+                .rev()
+                .find(|(_, rect)| rect.contains(widget_rect.center()))
+                .map(|&(cell, _)| cell),
+            None => self.cell_rects.last().map(|&(cell, _)| cell),
+        };
+        if let Some(cell) = cell {
+            self.focusable.retain(|&(c, _)| c != cell);
+            self.focusable.push((cell, id));
+        }
+    }
+
+    /// If a widget in the grid has focus, let the arrow keys move focus to a neighboring cell.
+    fn move_focus_with_arrow_keys(&self) {
+        let focused = match self.ctx.memory().focus() {
+            Some(focused) => focused,
+            None => return,
+        };
+        let (row, col) = match self.focusable.iter().find(|(_, id)| *id == focused) {
+            Some(&(cell, _)) => cell,
+            None => return,
+        };
+        if self.ctx.memory().arrow_keys_used() || self.ctx.input().modifiers.any() {
+            return;
+        }
+
+        let arrows = [
+            Key::ArrowUp,
+            Key::ArrowDown,
+            Key::ArrowLeft,
+            Key::ArrowRight,
+        ];
+        let key = match arrows
+            .iter()
+            .find(|&&key| self.ctx.input().key_pressed(key))
+        {
+            Some(&key) => key,
+            None => return,
+        };
+
+        let distance = |a: usize, b: usize| a.max(b) - a.min(b);
+        let target = if key == Key::ArrowUp || key == Key::ArrowDown {
+            // Prefer the same column, but settle for the closest one:
+            self.focusable
+                .iter()
+                .filter(|((r, _), _)| {
+                    if key == Key::ArrowUp {
+                        *r < row
+                    } else {
+                        *r > row
+                    }
+                })
+                .min_by_key(|((r, c), _)| (distance(*r, row), distance(*c, col)))
+        } else {
+            self.focusable
+                .iter()
+                .filter(|((r, c), _)| {
+                    *r == row
+                        && if key == Key::ArrowLeft {
+                            *c < col
+                        } else {
+                            *c > col
+                        }
+                })
+                .min_by_key(|((_, c), _)| distance(*c, col))
+        };
+
+        if let Some(&(_, id)) = target {
+            self.ctx.memory().request_focus(id);
+            self.ctx.request_repaint();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A plain text format for copying the contents of a [`Grid`], see [`Grid::copy_as`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableFormat {
+    /// Comma-separated values. Cells with commas, quotes or newlines are quoted.
+    Csv,
+
+    /// Tab-separated values, e.g. for pasting into a spreadsheet.
+    /// Tabs and newlines in cells are replaced with spaces.
+    Tsv,
+}
+
+impl TableFormat {
+    fn escape(self, text: &str) -> String {
+        match self {
+            Self::Csv => {
+                if text.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+                    format!("\"{}\"", text.replace('"', "\"\""))
+                } else {
+                    text.to_owned()
+                }
+            }
+            Self::Tsv => text.replace(|c| c == '\t' || c == '\n' || c == '\r', " "),
+        }
+    }
+
+    /// The rows of a table in this format, each a list of cells.
+    ///
+    /// ```
+    /// use egui::TableFormat;
+    /// let rows = TableFormat::Csv.parse("a,\"b, c\"\n1,2\n");
+    /// assert_eq!(rows, vec![vec!["a", "b, c"], vec!["1", "2"]]);
+    /// ```
+    pub fn parse(self, text: &str) -> Vec<Vec<String>> {
+        match self {
+            Self::Csv => {
+                let mut rows = vec![];
+                let mut row = vec![];
+                let mut cell = String::new();
+                let mut in_quotes = false;
+                let mut chars = text.chars().peekable();
+                while let Some(c) = chars.next() {
+                    if in_quotes {
+                        if c != '"' {
+                            cell.push(c);
+                        } else if chars.peek() == Some(&'"') {
+                            chars.next();
+                            cell.push('"');
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else {
+                        match c {
+                            '"' => in_quotes = true,
+                            ',' => row.push(std::mem::take(&mut cell)),
+                            '\r' => {}
+                            '\n' => {
+                                row.push(std::mem::take(&mut cell));
+                                rows.push(std::mem::take(&mut row));
+                            }
+                            _ => cell.push(c),
+                        }
+                    }
+                }
+                if !cell.is_empty() || !row.is_empty() {
+                    row.push(cell);
+                    rows.push(row);
+                }
+                rows
+            }
+            Self::Tsv => text
+                .lines()
+                .map(|line| line.split('\t').map(str::to_owned).collect())
+                .collect(),
+        }
+    }
+
+    /// One line per row, from the first row with any text to the last one.
+    /// Cells without text are left empty.
+    fn table(self, cells: &BTreeMap<(usize, usize), String>) -> String {
+        let separator = match self {
+            Self::Csv => ",",
+            Self::Tsv => "\t",
+        };
+        let (first_row, last_row) = match (cells.keys().next(), cells.keys().next_back()) {
+            (Some(&(first, _)), Some(&(last, _))) => (first, last),
+            _ => return String::new(),
+        };
+        let num_columns = cells.keys().map(|&(_, col)| col + 1).max().unwrap_or(0);
+
+        let mut table = String::new();
+        for row in first_row..=last_row {
+            let line: Vec<String> = (0..num_columns)
+                .map(|col| {
+                    cells
+                        .get(&(row, col))
+                        .map_or_else(String::new, |text| self.escape(text))
+                })
+                .collect();
+            table += &line.join(separator);
+            table += "\n";
+        }
+        table
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -231,11 +590,16 @@ impl GridLayout {
 /// A simple grid layout.
 ///
 /// The cells are always layed out left to right, top-down.
-/// The contents of each cell will be aligned to the left and center.
+/// The contents of each cell will be aligned to the left and center,
+/// unless you change it with [`Grid::cell_alignment`], [`Grid::column_alignment`] or [`Ui::grid_cell_alignment`].
 ///
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// Call [`Ui::grid_span`] to let a cell span several columns, e.g. for a wide header.
+///
+/// With [`Grid::copy_as`] the text of the cells can be copied to the clipboard as CSV or TSV.
+///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
@@ -263,6 +627,11 @@ pub struct Grid {
     max_cell_size: Vec2,
     spacing: Option<Vec2>,
     start_row: usize,
+    keyboard_navigation: bool,
+    copy_as: Option<TableFormat>,
+    resizable: bool,
+    cell_align: Align2,
+    column_aligns: BTreeMap<usize, Align2>,
 }
 
 impl Grid {
@@ -277,6 +646,11 @@ impl Grid {
             max_cell_size: Vec2::INFINITY,
             spacing: None,
             start_row: 0,
+            keyboard_navigation: true,
+            copy_as: None,
+            resizable: false,
+            cell_align: Align2::LEFT_CENTER,
+            column_aligns: Default::default(),
         }
     }
 
@@ -328,6 +702,78 @@ impl Grid {
         self.start_row = start_row;
         self
     }
+
+    /// If `true`, the arrow keys move keyboard focus between the widgets in neighboring cells,
+    /// unless the focused widget uses the arrow keys itself (see [`crate::Memory::use_arrow_keys`]).
+    /// Default: `true`.
+    pub fn keyboard_navigation(mut self, keyboard_navigation: bool) -> Self {
+        self.keyboard_navigation = keyboard_navigation;
+        self
+    }
+
+    /// If `Some`, copy the text of the cells to the clipboard this frame, as a table in the given format.
+    /// Default: `None`.
+    ///
+    /// [`Label`]s give the text of their cell.
+    /// For other cells, set the text with [`Ui::grid_cell_text`] before adding the contents.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::{Grid, TableFormat};
+    /// let copy = ui.button("Copy as CSV").clicked();
+    /// Grid::new("prices")
+    ///     .copy_as(copy.then(|| TableFormat::Csv))
+    ///     .show(ui, |ui| {
+    ///         ui.label("Apples");
+    ///         ui.grid_cell_text("1.5");
+    ///         ui.add(egui::DragValue::new(&mut 1.5));
+    ///         ui.end_row();
+    ///     });
+    /// ```
+    pub fn copy_as(mut self, format: Option<TableFormat>) -> Self {
+        self.copy_as = format;
+        self
+    }
+
+    /// If `true`, the user can drag the gaps between the columns to resize them.
+    /// Double-clicking a gap gives the column to its left its automatic width back.
+    ///
+    /// Text in a resized column is wrapped to fit it.
+    /// The widths are remembered between frames (and sessions, with the `persistence` feature).
+    /// Default: `false`.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// How to align the contents of each cell within it.
+    /// Default: [`Align2::LEFT_CENTER`].
+    pub fn cell_alignment(mut self, align: Align2) -> Self {
+        self.cell_align = align;
+        self
+    }
+
+    /// How to align the contents of the cells of one column, e.g. [`Align2::RIGHT_CENTER`] for numbers.
+    /// Overrides [`Self::cell_alignment`] for that column.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::Grid::new("prices")
+    ///     .column_alignment(1, egui::Align2::RIGHT_CENTER)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Apples");
+    ///         ui.label("1.50");
+    ///         ui.end_row();
+    ///
+    ///         ui.label("Watermelons");
+    ///         ui.label("12.00");
+    ///         ui.end_row();
+    ///     });
+    /// ```
+    pub fn column_alignment(mut self, col: usize, align: Align2) -> Self {
+        self.column_aligns.insert(col, align);
+        self
+    }
 }
 
 impl Grid {
@@ -341,6 +787,11 @@ impl Grid {
             max_cell_size,
             spacing,
             start_row,
+            keyboard_navigation,
+            copy_as,
+            resizable,
+            cell_align,
+            column_aligns,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -360,15 +811,632 @@ impl Grid {
                     max_cell_size,
                     spacing,
                     row: start_row,
+                    keyboard_navigation,
+                    copy_as,
+                    resizable,
+                    cell_align,
+                    column_aligns,
                     ..GridLayout::new(ui, id)
                 };
 
                 ui.set_grid(grid);
                 let r = add_contents(ui);
+                if resizable {
+                    resize_columns(ui, id);
+                }
                 ui.save_grid();
                 r
             })
             .inner
         })
     }
+
+    /// Like [`Self::show`], but also gives you a [`CellEditor`] for adding
+    /// spreadsheet-like editable cells to the grid.
+    pub fn show_editable<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, &mut CellEditor) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_source).with("cell_editor");
+        let mut cell_editor = CellEditor::load(ui.ctx(), id);
+        let response = self.show(ui, |ui| add_contents(ui, &mut cell_editor));
+        cell_editor.store(ui.ctx());
+        response
+    }
+}
+
+/// Let the user drag the gaps between the columns of the grid in `ui` to resize them.
+fn resize_columns(ui: &mut Ui, id: Id) {
+    let gaps = match ui.grid() {
+        Some(grid) => grid.column_gaps(),
+        None => return,
+    };
+    let y_range = ui.min_rect().y_range();
+    let grab_radius = ui.style().interaction.resize_grab_radius_side;
+
+    for (col, x) in gaps {
+        let rect = Rect::from_x_y_ranges(x - grab_radius..=x + grab_radius, y_range.clone());
+        let response = ui.interact(
+            rect,
+            id.with("resize_column").with(col),
+            Sense::click_and_drag(),
+        );
+
+        if let Some(grid) = ui.grid_mut() {
+            if response.double_clicked() {
+                grid.resize_column(col, None);
+            } else if response.dragged() && response.drag_delta().x != 0.0 {
+                let width = grid
+                    .resized_col_width(col)
+                    .unwrap_or_else(|| grid.prev_col_width(col));
+                grid.resize_column(col, Some(width + response.drag_delta().x));
+            }
+        }
+
+        if response.hovered() || response.dragged() {
+            ui.output().cursor_icon = CursorIcon::ResizeHorizontal;
+            let stroke = if response.dragged() {
+                ui.visuals().widgets.active.bg_stroke
+            } else {
+                ui.visuals().widgets.hovered.bg_stroke
+            };
+            ui.painter()
+                .line_segment([pos2(x, *y_range.start()), pos2(x, *y_range.end())], stroke);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Default)]
+struct CellEditState {
+    editing: Option<Editing>,
+    /// Start editing this cell as soon as it is shown.
+    start: Option<(usize, usize)>,
+}
+
+#[derive(Clone, Debug)]
+struct Editing {
+    cell: (usize, usize),
+    text: String,
+    value: f64,
+    /// Have we given the editor keyboard focus yet?
+    focused: bool,
+}
+
+/// Spreadsheet-like editing of the cells of a [`Grid`]. Get one from [`Grid::show_editable`].
+///
+/// An editable cell is shown as a label until it is double-clicked (or focused and <kbd>Enter</kbd> is pressed),
+/// at which point it turns into an editor.
+/// <kbd>Enter</kbd> (or clicking elsewhere) commits the edit, and <kbd>Escape</kbd> cancels it.
+/// <kbd>Tab</kbd> commits and moves on to editing the next editable cell
+/// (<kbd>Shift</kbd>+<kbd>Tab</kbd>: the previous one).
+///
+/// The value you pass in is only written to when the edit is committed,
+/// at which point the returned [`Response`] is marked as [`Response::changed`].
+///
+/// Tables copied from a spreadsheet can be pasted into the cells with [`Self::paste`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut people = vec![("Alice".to_owned(), 32_u32)];
+/// egui::Grid::new("people").show_editable(ui, |ui, cells| {
+///     for (name, age) in &mut people {
+///         cells.text(ui, name);
+///         cells.value(ui, age);
+///         ui.end_row();
+///     }
+/// });
+/// ```
+pub struct CellEditor {
+    id: Id,
+    state: CellEditState,
+    /// The editable cells, in the order they were added this frame.
+    cells: Vec<(usize, usize)>,
+    /// The cell in which tab was pressed, and if it was forwards.
+    tab: Option<((usize, usize), bool)>,
+    /// The cell with keyboard focus, unless it is being edited.
+    focused: Option<(usize, usize)>,
+}
+
+impl CellEditor {
+    fn load(ctx: &CtxRef, id: Id) -> Self {
+        let state = ctx
+            .memory()
+            .id_data_temp
+            .get::<CellEditState>(&id)
+            .cloned()
+            .unwrap_or_default();
+        Self {
+            id,
+            state,
+            cells: Default::default(),
+            tab: None,
+            focused: None,
+        }
+    }
+
+    fn store(self, ctx: &CtxRef) {
+        let Self {
+            id,
+            mut state,
+            cells,
+            tab,
+            focused: _,
+        } = self;
+
+        if let Some((cell, forward)) = tab {
+            if let Some(index) = cells.iter().position(|&c| c == cell) {
+                let next = if forward {
+                    cells.get(index + 1)
+                } else {
+                    index.checked_sub(1).and_then(|i| cells.get(i))
+                };
+                state.start = next.copied();
+                ctx.request_repaint();
+            }
+        }
+
+        ctx.memory().id_data_temp.insert(id, state);
+    }
+
+    /// Paste a table into the cells, e.g. one copied from a spreadsheet.
+    ///
+    /// When one of the cells has keyboard focus (without being edited) and text is pasted into it,
+    /// the text is parsed as TSV if it has any tabs, and as CSV otherwise.
+    /// `on_cell` is then called with the `(row, column, text)` of each pasted value,
+    /// with the first value going into the focused cell.
+    /// Values that don't land in an editable cell are dropped.
+    /// Text typed into a focused cell is handled the same way.
+    ///
+    /// Call this after adding the cells. Returns `true` if anything was pasted.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut rows = vec![["a".to_owned(), "b".to_owned()]];
+    /// egui::Grid::new("table").show_editable(ui, |ui, cells| {
+    ///     for [a, b] in &mut rows {
+    ///         cells.text(ui, a);
+    ///         cells.text(ui, b);
+    ///         ui.end_row();
+    ///     }
+    ///     cells.paste(ui, |row, col, text| rows[row][col] = text.to_owned());
+    /// });
+    /// ```
+    pub fn paste(&self, ui: &Ui, mut on_cell: impl FnMut(usize, usize, &str)) -> bool {
+        let (first_row, first_col) = match self.focused {
+            Some(cell) if self.state.editing.is_none() => cell,
+            _ => return false,
+        };
+
+        let mut pasted = false;
+        let events = ui.input().events.clone();
+        for event in &events {
+            if let Event::Text(text) = event {
+                let format = if text.contains('\t') {
+                    TableFormat::Tsv
+                } else {
+                    TableFormat::Csv
+                };
+                for (i, values) in format.parse(text).iter().enumerate() {
+                    for (j, value) in values.iter().enumerate() {
+                        // Outside of a grid the row is `usize::MAX`.
+                        if let Some(row) = first_row.checked_add(i) {
+                            let col = first_col + j;
+                            if self.cells.contains(&(row, col)) {
+                                on_cell(row, col, value);
+                                pasted = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        pasted
+    }
+
+    /// The `(row, column)` of the cell currently being edited, if any.
+    pub fn editing_cell(&self) -> Option<(usize, usize)> {
+        self.state.editing.as_ref().map(|editing| editing.cell)
+    }
+
+    /// A cell with some text, edited with a [`TextEdit`].
+    pub fn text(&mut self, ui: &mut Ui, text: &mut String) -> Response {
+        let display = text.clone();
+        self.cell(
+            ui,
+            display,
+            0.0,
+            |ui, editing, id| ui.add(TextEdit::singleline(&mut editing.text).id(id)),
+            |editing| *text = editing.text.clone(),
+        )
+    }
+
+    /// A cell with a number, edited with a [`DragValue`].
+    pub fn value<Num: emath::Numeric>(&mut self, ui: &mut Ui, value: &mut Num) -> Response {
+        let display = emath::format_with_decimals_in_range(value.to_f64(), 0..=6);
+        self.cell(
+            ui,
+            display,
+            value.to_f64(),
+            |ui, editing, _id| {
+                let mut drag_value =
+                    DragValue::new(&mut editing.value).clamp_range(Num::MIN..=Num::MAX);
+                if Num::INTEGRAL {
+                    drag_value = drag_value.max_decimals(0);
+                }
+                ui.add(drag_value)
+            },
+            |editing| {
+                *value = if Num::INTEGRAL {
+                    Num::from_f64(editing.value.round())
+                } else {
+                    Num::from_f64(editing.value)
+                }
+            },
+        )
+    }
+
+    fn cell(
+        &mut self,
+        ui: &mut Ui,
+        display: String,
+        value: f64,
+        editor: impl FnOnce(&mut Ui, &mut Editing, Id) -> Response,
+        commit: impl FnOnce(&Editing),
+    ) -> Response {
+        // Outside of a grid (e.g. nested in a `ui.horizontal`) we just number the cells in order:
+        let cell = ui
+            .grid()
+            .map_or((usize::MAX, self.cells.len()), |grid| grid.cell());
+        self.cells.push(cell);
+
+        if self.state.start == Some(cell) {
+            self.state.start = None;
+            self.state.editing = Some(Editing {
+                cell,
+                text: display.clone(),
+                value,
+                focused: false,
+            });
+        }
+
+        match &mut self.state.editing {
+            Some(editing) if editing.cell == cell => {
+                let mut response = editor(ui, editing, self.id.with(cell));
+                if !editing.focused {
+                    response.request_focus();
+                    editing.focused = true;
+                }
+
+                let input = ui.input();
+                let cancel = input.key_pressed(Key::Escape);
+                let tab = input.key_pressed(Key::Tab);
+                let clicked_elsewhere = input.pointer.any_pressed() && !response.hovered();
+                let done = tab || input.key_pressed(Key::Enter) || clicked_elsewhere;
+                let forward = !input.modifiers.shift;
+
+                if cancel {
+                    self.state.editing = None;
+                } else if done {
+                    commit(editing);
+                    response.mark_changed();
+                    self.state.editing = None;
+                    if tab {
+                        self.tab = Some((cell, forward));
+                    }
+                }
+                response
+            }
+            _ => {
+                let response = ui.add(Label::new(display).sense(Sense::click()));
+                if response.has_focus() {
+                    self.focused = Some(cell);
+                }
+                let enter = response.has_focus() && ui.input().key_pressed(Key::Enter);
+                if response.double_clicked() || enter {
+                    self.state.start = Some(cell);
+                    ui.ctx().request_repaint();
+                }
+                response
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cell_editor() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 100.0));
+    let mut row = ("a".to_owned(), "b".to_owned(), 7_i32);
+    let run = |harness: &mut Harness, row: &mut (String, String, i32)| {
+        let mut first_cell = Rect::NOTHING;
+        let mut editing = None;
+        harness.run(|ui| {
+            Grid::new("table").show_editable(ui, |ui, cells| {
+                first_cell = cells.text(ui, &mut row.0).rect;
+                cells.text(ui, &mut row.1);
+                cells.value(ui, &mut row.2);
+                ui.end_row();
+                editing = cells.editing_cell();
+            });
+        });
+        (first_cell, editing)
+    };
+
+    let (first_cell, editing) = run(&mut harness, &mut row);
+    assert_eq!(editing, None);
+
+    // Double-click to start editing:
+    harness.click(first_cell.center());
+    run(&mut harness, &mut row);
+    harness.click(first_cell.center());
+    run(&mut harness, &mut row);
+    assert_eq!(run(&mut harness, &mut row).1, Some((0, 0)));
+
+    // Nothing is written until we commit:
+    harness.type_text("!");
+    run(&mut harness, &mut row);
+    assert_eq!(row.0, "a");
+
+    // Tab commits and moves on to the next cell:
+    harness.key_press(Key::Tab);
+    run(&mut harness, &mut row);
+    assert_eq!(row.0.len(), 2);
+    assert!(row.0.contains('!'));
+    assert_eq!(run(&mut harness, &mut row).1, Some((0, 1)));
+
+    // Escape cancels:
+    harness.type_text("?");
+    run(&mut harness, &mut row);
+    harness.key_press(Key::Escape);
+    assert_eq!(run(&mut harness, &mut row).1, None);
+    assert_eq!(row.1, "b");
+}
+
+#[test]
+fn test_grid_keyboard_navigation() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 100.0));
+    let mut text = String::new();
+    let mut run = |harness: &mut Harness| {
+        let mut ids = vec![];
+        harness.run(|ui| {
+            Grid::new("grid").show(ui, |ui| {
+                ids.push(ui.button("a").id);
+                ids.push(ui.button("b").id);
+                ui.end_row();
+                ids.push(ui.text_edit_singleline(&mut text).id);
+                ids.push(ui.button("d").id);
+                ui.end_row();
+            });
+        });
+        ids
+    };
+
+    let ids = run(&mut harness);
+    let focus = |harness: &Harness| harness.ctx().memory().focus();
+    harness.ctx().memory().request_focus(ids[0]);
+    run(&mut harness);
+
+    harness.key_press(Key::ArrowRight);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[1]));
+
+    harness.key_press(Key::ArrowDown);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[3]));
+
+    harness.key_press(Key::ArrowLeft);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[2]));
+
+    // The text edit uses the arrow keys itself:
+    harness.key_press(Key::ArrowUp);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[2]));
+}
+
+#[test]
+fn test_grid_span() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(400.0, 100.0));
+    let run = |harness: &mut Harness| {
+        let mut rects = vec![];
+        harness.run(|ui| {
+            Grid::new("grid").show(ui, |ui| {
+                ui.grid_span(3);
+                rects.push(ui.label("A header wider than the first two columns").rect);
+                ui.end_row();
+
+                rects.push(ui.label("a").rect);
+                rects.push(ui.label("b").rect);
+                rects.push(ui.label("c").rect);
+                ui.end_row();
+
+                ui.grid_span(2);
+                rects.push(ui.separator().rect);
+                rects.push(ui.label("f").rect);
+                ui.end_row();
+            });
+        });
+        rects
+    };
+
+    run(&mut harness);
+    let rects = run(&mut harness);
+    let (header, a, b, c, separator, f) =
+        (rects[0], rects[1], rects[2], rects[3], rects[4], rects[5]);
+
+    // The header only widens the last column:
+    assert!(b.right() < 0.5 * header.right());
+    assert!(c.left() < header.right());
+
+    // The cell after a span starts in the right column:
+    assert_eq!(f.left(), c.left());
+    assert_eq!(separator.left(), a.left());
+    assert!(separator.right() > b.left());
+    assert!(separator.right() < c.left());
+}
+
+#[test]
+fn test_grid_copy_as() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 100.0));
+    let mut run = |format| {
+        harness
+            .run(|ui| {
+                Grid::new("grid").copy_as(format).show(ui, |ui| {
+                    ui.label("Name");
+                    ui.label("Comment");
+                    ui.end_row();
+
+                    ui.label("Alice");
+                    ui.grid_cell_text("Likes \"tabs\",\tnot spaces");
+                    ui.add(Button::new("Edit"));
+                    ui.end_row();
+
+                    ui.add(Button::new("No text"));
+                    ui.end_row();
+
+                    ui.label("Bob");
+                    ui.end_row();
+                });
+            })
+            .output
+            .copied_text
+    };
+
+    assert_eq!(run(None), "");
+    assert_eq!(
+        run(Some(TableFormat::Csv)),
+        "Name,Comment\nAlice,\"Likes \"\"tabs\"\",\tnot spaces\"\n,\nBob,\n"
+    );
+    assert_eq!(
+        run(Some(TableFormat::Tsv)),
+        "Name\tComment\nAlice\tLikes \"tabs\", not spaces\n\t\nBob\t\n"
+    );
+}
+
+#[test]
+fn test_grid_resizable() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(400.0, 100.0));
+    // A few frames, for the layout to catch up with the new widths:
+    let run = |harness: &mut Harness| {
+        let mut second_column = Rect::NOTHING;
+        harness.run_frames(3, |ui| {
+            Grid::new("grid").resizable(true).show(ui, |ui| {
+                ui.label("First");
+                second_column = ui.label("Second").rect;
+                ui.end_row();
+            });
+        });
+        second_column
+    };
+
+    let before = run(&mut harness);
+    let gap = pos2(before.left() - 4.0, before.center().y);
+    harness.pointer_move(gap);
+    harness.pointer_button(gap, true);
+    run(&mut harness);
+    harness.pointer_move(gap + vec2(50.0, 0.0));
+    run(&mut harness);
+    harness.pointer_button(gap + vec2(50.0, 0.0), false);
+    let resized = run(&mut harness);
+    assert!((resized.left() - (before.left() + 50.0)).abs() < 0.01);
+
+    // Double-click the gap to get the automatic width back:
+    let gap = pos2(resized.left() - 4.0, resized.center().y);
+    harness.click(gap);
+    run(&mut harness);
+    harness.click(gap);
+    assert!((run(&mut harness).left() - before.left()).abs() < 0.01);
+}
+
+#[test]
+fn test_cell_editor_paste() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 100.0));
+    let mut table = vec![vec!["a".to_owned(), "b".to_owned()]; 2];
+    let mut run = |harness: &mut Harness| {
+        let mut first_cell = None;
+        let mut pasted = vec![];
+        harness.run(|ui| {
+            Grid::new("table").show_editable(ui, |ui, cells| {
+                for row in &mut table {
+                    for text in row.iter_mut() {
+                        let id = cells.text(ui, text).id;
+                        first_cell = first_cell.or(Some(id));
+                    }
+                    ui.end_row();
+                }
+                cells.paste(ui, |row, col, text| {
+                    pasted.push((row, col, text.to_owned()))
+                });
+            });
+        });
+        (first_cell.unwrap(), pasted)
+    };
+
+    let (first_cell, _) = run(&mut harness);
+    harness.ctx().memory().request_focus(first_cell);
+    run(&mut harness);
+
+    // Rows and columns outside of the table are dropped:
+    harness.type_text("1\t2\t3\n4\t5\t6\n7\t8\t9\n");
+    let pasted = run(&mut harness).1;
+    let expected = vec![
+        (0, 0, "1".to_owned()),
+        (0, 1, "2".to_owned()),
+        (1, 0, "4".to_owned()),
+        (1, 1, "5".to_owned()),
+    ];
+    assert_eq!(pasted, expected);
+
+    harness.type_text("x,\"y, z\"");
+    assert_eq!(
+        run(&mut harness).1,
+        vec![(0, 0, "x".to_owned()), (0, 1, "y, z".to_owned())]
+    );
+}
+
+#[test]
+fn test_grid_alignment() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(400.0, 200.0));
+    let mut rects = vec![];
+    harness.run_frames(2, |ui| {
+        rects.clear();
+        Grid::new("grid")
+            .column_alignment(1, Align2::RIGHT_CENTER)
+            .show(ui, |ui| {
+                rects.push(ui.label("Name").rect);
+                rects.push(ui.label("A long number: 12345").rect);
+                ui.end_row();
+
+                rects.push(ui.label("Short name, long cell").rect);
+                rects.push(ui.label("1").rect);
+                ui.end_row();
+
+                rects.push(ui.label("x").rect);
+                ui.grid_cell_alignment(Align2::LEFT_CENTER);
+                rects.push(ui.label("2").rect);
+                ui.end_row();
+            });
+    });
+
+    assert!((rects[3].right() - rects[1].right()).abs() < 0.01);
+    assert!(rects[3].left() > rects[1].left());
+    assert_eq!(rects[5].left(), rects[1].left());
+    // The first column is still left-aligned:
+    assert_eq!(rects[2].left(), rects[0].left());
 }
@@ -0,0 +1,502 @@
+//! Handles for moving points, resizing rectangles and turning angles on a canvas.
+
+use std::f32::consts::{PI, TAU};
+use std::hash::Hash;
+
+use crate::{emath::RectTransform, *};
+
+/// How much a rectangle edited with [`Handles::rect`] changed, in user coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RectDelta {
+    /// How much [`Rect::min`] moved.
+    pub min: Vec2,
+
+    /// How much [`Rect::max`] moved.
+    pub max: Vec2,
+}
+
+impl RectDelta {
+    /// Did anything change?
+    pub fn is_zero(&self) -> bool {
+        self.min == Vec2::ZERO && self.max == Vec2::ZERO
+    }
+}
+
+/// Draggable handles for editing geometry in user coordinates, e.g. for annotation or layout tools.
+///
+/// The handles are given a [`RectTransform`] from user coordinates to screen coordinates,
+/// such as the one given by [`Canvas::show`].
+/// Each handle returns the change of the edited value in user coordinates.
+///
+/// While dragging:
+/// * hold down shift to move only horizontally or vertically, keep the aspect ratio when resizing,
+///   or snap angles to 15°.
+/// * hold down alt to resize a rectangle around its center.
+/// * hold down ctrl (cmd on Mac) to temporarily turn off snapping (see [`Self::snap`]).
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut point = egui::pos2(10.0, 10.0);
+/// # let mut rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 50.0));
+/// # let mut angle = 0.0;
+/// use egui::*;
+/// Canvas::new("canvas").show(ui, |ui, to_screen| {
+///     let handles = Handles::new(to_screen).snap(5.0);
+///     let delta = handles.point(ui, "point", &mut point).inner;
+///     handles.rect(ui, "rect", &mut rect);
+///     handles.rotation(ui, "rotation", rect.center(), 40.0, &mut angle);
+/// });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .point(), .rect() or .rotation()"]
+pub struct Handles<'a> {
+    to_screen: &'a RectTransform,
+    snap: Option<f32>,
+    angle_snap: Option<f32>,
+    handle_size: Option<f32>,
+}
+
+impl<'a> Handles<'a> {
+    pub fn new(to_screen: &'a RectTransform) -> Self {
+        Self {
+            to_screen,
+            snap: None,
+            angle_snap: None,
+            handle_size: None,
+        }
+    }
+
+    /// Snap positions to multiples of `grid`, in user coordinates.
+    pub fn snap(mut self, grid: f32) -> Self {
+        self.snap = Some(grid);
+        self
+    }
+
+    /// Snap angles to multiples of this many radians.
+    pub fn angle_snap(mut self, radians: f32) -> Self {
+        self.angle_snap = Some(radians);
+        self
+    }
+
+    /// The size of each handle on screen.
+    /// Default: half of [`crate::style::Spacing::interact_size`]`.y`.
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = Some(handle_size);
+        self
+    }
+
+    /// A draggable point.
+    ///
+    /// Returns how much the point moved this frame.
+    pub fn point(&self, ui: &mut Ui, id_source: impl Hash, pos: &mut Pos2) -> InnerResponse<Vec2> {
+        let id = ui.make_persistent_id(Id::new(id_source));
+        let screen_pos = self.to_screen.transform_pos(*pos);
+        let response = ui
+            .interact(self.handle_rect(ui, screen_pos), id, Sense::drag())
+            .on_hover_cursor(CursorIcon::Grab);
+
+        let mut new_pos = *pos;
+        if let Some(drag) = self.drag(ui, &response, *pos) {
+            let delta = constrain_to_axis(drag.delta(), ui.input().modifiers.shift);
+            new_pos = self.snap_pos(ui, drag.start + delta);
+        }
+        let delta = new_pos - *pos;
+        *pos = new_pos;
+
+        let screen_pos = self.to_screen.transform_pos(new_pos);
+        let visuals = ui.style().interact(&response);
+        ui.painter().circle(
+            screen_pos,
+            0.5 * self.handle_size_or_default(ui),
+            visuals.bg_fill,
+            visuals.fg_stroke,
+        );
+
+        InnerResponse::new(delta, response)
+    }
+
+    /// Handles on the corners and edges of a rectangle to resize it,
+    /// and on the rectangle itself to move it.
+    ///
+    /// Returns how much the rectangle changed this frame.
+    pub fn rect(
+        &self,
+        ui: &mut Ui,
+        id_source: impl Hash,
+        rect: &mut Rect,
+    ) -> InnerResponse<RectDelta> {
+        let id = ui.make_persistent_id(Id::new(id_source));
+        let modifiers = ui.input().modifiers;
+        let screen_rect = self.screen_rect(*rect);
+        let mut new_rect = *rect;
+
+        // The body first, so that the handles are on top of it:
+        let mut response = ui
+            .interact(screen_rect, id, Sense::drag())
+            .on_hover_cursor(CursorIcon::Move);
+        if let Some(drag) = self.drag(ui, &response, *rect) {
+            let delta = constrain_to_axis(drag.delta(), modifiers.shift);
+            let min = self.snap_pos(ui, drag.start.min + delta);
+            new_rect = drag.start.translate(min - drag.start.min);
+        }
+
+        const SIDES: [(i8, i8); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+        for &side in &SIDES {
+            let screen_pos = self.to_screen.transform_pos(handle_pos(*rect, side));
+            let delta_from_center = screen_pos - screen_rect.center();
+            let cursor = match side {
+                (0, _) => CursorIcon::ResizeVertical,
+                (_, 0) => CursorIcon::ResizeHorizontal,
+                _ if delta_from_center.x * delta_from_center.y > 0.0 => CursorIcon::ResizeNwSe,
+                _ => CursorIcon::ResizeNeSw,
+            };
+            let handle_response = ui
+                .interact(
+                    self.handle_rect(ui, screen_pos),
+                    id.with(side),
+                    Sense::drag(),
+                )
+                .on_hover_cursor(cursor);
+            if let Some(drag) = self.drag(ui, &handle_response, *rect) {
+                let snap = |x: f32| self.snap_value(ui, x);
+                new_rect = resize_rect(
+                    drag.start,
+                    side,
+                    drag.delta(),
+                    modifiers.shift,
+                    modifiers.alt,
+                    snap,
+                );
+            }
+            response |= handle_response;
+        }
+
+        let delta = RectDelta {
+            min: new_rect.min - rect.min,
+            max: new_rect.max - rect.max,
+        };
+        *rect = new_rect;
+
+        let screen_rect = self.screen_rect(new_rect);
+        let painter = ui.painter();
+        painter.rect_stroke(screen_rect, 0.0, ui.visuals().selection.stroke);
+        let visuals = ui.style().interact(&response);
+        for &side in &SIDES {
+            let screen_pos = self.to_screen.transform_pos(handle_pos(new_rect, side));
+            painter.rect(
+                self.handle_rect(ui, screen_pos),
+                0.0,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+        }
+
+        InnerResponse::new(delta, response)
+    }
+
+    /// A handle that turns around `center` (in user coordinates),
+    /// shown `radius` points from the center in the direction of `angle`.
+    ///
+    /// The angle is in radians, measured in user coordinates from the positive X axis towards the positive Y axis.
+    ///
+    /// Returns how much the angle changed this frame.
+    pub fn rotation(
+        &self,
+        ui: &mut Ui,
+        id_source: impl Hash,
+        center: Pos2,
+        radius: f32,
+        angle: &mut f32,
+    ) -> InnerResponse<f32> {
+        let id = ui.make_persistent_id(Id::new(id_source));
+        let handle_screen_pos = |angle: f32| {
+            let center_screen = self.to_screen.transform_pos(center);
+            let direction =
+                self.to_screen.transform_pos(center + Vec2::angled(angle)) - center_screen;
+            center_screen + radius * direction.normalized()
+        };
+        let response = ui
+            .interact(
+                self.handle_rect(ui, handle_screen_pos(*angle)),
+                id,
+                Sense::drag(),
+            )
+            .on_hover_cursor(CursorIcon::Grab);
+
+        let mut new_angle = *angle;
+        if let Some(drag) = self.drag(ui, &response, *angle) {
+            let pointer_angle = |pos: Pos2| (pos - center).angle();
+            new_angle = drag.start + pointer_angle(drag.pointer) - pointer_angle(drag.origin);
+            let snap = self.angle_snap.or_else(|| {
+                if ui.input().modifiers.shift {
+                    Some(15.0_f32.to_radians())
+                } else {
+                    None
+                }
+            });
+            if let Some(snap) = snap.filter(|&snap| snap > 0.0) {
+                new_angle = (new_angle / snap).round() * snap;
+            }
+        }
+        // Take the shortest way around, so the delta is never more than half a turn:
+        let delta = (new_angle - *angle + PI).rem_euclid(TAU) - PI;
+        *angle += delta;
+
+        let visuals = ui.style().interact(&response);
+        let handle_pos = handle_screen_pos(*angle);
+        let painter = ui.painter();
+        painter.line_segment(
+            [self.to_screen.transform_pos(center), handle_pos],
+            ui.visuals().selection.stroke,
+        );
+        painter.circle(
+            handle_pos,
+            0.5 * self.handle_size_or_default(ui),
+            visuals.bg_fill,
+            visuals.fg_stroke,
+        );
+
+        InnerResponse::new(delta, response)
+    }
+
+    /// Works even if the transform flips an axis.
+    fn screen_rect(&self, rect: Rect) -> Rect {
+        Rect::from_two_pos(
+            self.to_screen.transform_pos(rect.min),
+            self.to_screen.transform_pos(rect.max),
+        )
+    }
+
+    fn handle_size_or_default(&self, ui: &Ui) -> f32 {
+        self.handle_size
+            .unwrap_or_else(|| 0.5 * ui.spacing().interact_size.y)
+    }
+
+    /// The area of a handle centered at `screen_pos`.
+    fn handle_rect(&self, ui: &Ui, screen_pos: Pos2) -> Rect {
+        Rect::from_center_size(screen_pos, Vec2::splat(self.handle_size_or_default(ui)))
+    }
+
+    fn snap_value(&self, ui: &Ui, value: f32) -> f32 {
+        match self.snap {
+            Some(grid) if grid > 0.0 && !ui.input().modifiers.command => {
+                (value / grid).round() * grid
+            }
+            _ => value,
+        }
+    }
+
+    fn snap_pos(&self, ui: &Ui, pos: Pos2) -> Pos2 {
+        pos2(self.snap_value(ui, pos.x), self.snap_value(ui, pos.y))
+    }
+
+    /// If the handle is being dragged, returns where the drag started
+    /// and the value when it started.
+    ///
+    /// New values are computed from the start of the drag, rather than by adding up
+    /// the movement of each frame, so that snapping does not accumulate errors.
+    fn drag<T>(&self, ui: &Ui, response: &Response, value: T) -> Option<Drag<T>>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let from_screen = self.to_screen.inverse();
+        let pointer = from_screen.transform_pos(ui.input().pointer.interact_pos()?);
+        if response.drag_started() {
+            let origin = ui
+                .input()
+                .pointer
+                .press_origin()
+                .map_or(pointer, |pos| from_screen.transform_pos(pos));
+            ui.memory()
+                .id_data_temp
+                .insert(response.id, (origin, value));
+        }
+        if !response.dragged() {
+            return None;
+        }
+        let (origin, start) = ui
+            .memory()
+            .id_data_temp
+            .get::<(Pos2, T)>(&response.id)
+            .cloned()?;
+        Some(Drag {
+            origin,
+            pointer,
+            start,
+        })
+    }
+}
+
+/// An ongoing drag of a handle, in user coordinates.
+struct Drag<T> {
+    /// Where the pointer was pressed.
+    origin: Pos2,
+
+    /// Where the pointer is now.
+    pointer: Pos2,
+
+    /// The value when the drag started.
+    start: T,
+}
+
+impl<T> Drag<T> {
+    fn delta(&self) -> Vec2 {
+        self.pointer - self.origin
+    }
+}
+
+fn constrain_to_axis(delta: Vec2, constrain: bool) -> Vec2 {
+    if !constrain {
+        delta
+    } else if delta.x.abs() > delta.y.abs() {
+        vec2(delta.x, 0.0)
+    } else {
+        vec2(0.0, delta.y)
+    }
+}
+
+/// `side` is `-1` for the min side, `0` for the middle and `1` for the max side along each axis.
+fn handle_pos(rect: Rect, side: (i8, i8)) -> Pos2 {
+    let pick = |min: f32, max: f32, side: i8| match side {
+        -1 => min,
+        0 => 0.5 * (min + max),
+        _ => max,
+    };
+    pos2(
+        pick(rect.min.x, rect.max.x, side.0),
+        pick(rect.min.y, rect.max.y, side.1),
+    )
+}
+
+/// Move the sides of `start` given by `side` (see [`handle_pos`]) by `delta`.
+fn resize_rect(
+    start: Rect,
+    side: (i8, i8),
+    delta: Vec2,
+    keep_aspect: bool,
+    symmetric: bool,
+    snap: impl Fn(f32) -> f32,
+) -> Rect {
+    let resize_axis = |min: f32, max: f32, side: i8, delta: f32| match side {
+        -1 => {
+            let new_min = snap(min + delta);
+            let new_max = if symmetric {
+                max - (new_min - min)
+            } else {
+                max
+            };
+            (new_min, new_max)
+        }
+        1 => {
+            let new_max = snap(max + delta);
+            let new_min = if symmetric {
+                min - (new_max - max)
+            } else {
+                min
+            };
+            (new_min, new_max)
+        }
+        _ => (min, max),
+    };
+    let mut x = resize_axis(start.min.x, start.max.x, side.0, delta.x);
+    let mut y = resize_axis(start.min.y, start.max.y, side.1, delta.y);
+
+    let is_corner = side.0 != 0 && side.1 != 0;
+    if keep_aspect && is_corner && start.width() > 0.0 && start.height() > 0.0 {
+        let scale_x = (x.1 - x.0) / start.width();
+        let scale_y = (y.1 - y.0) / start.height();
+        let scale = if scale_x.abs() > scale_y.abs() {
+            scale_x
+        } else {
+            scale_y
+        };
+        // Keep the opposite side (or the center) where it was:
+        let fit_axis = |min: f32, max: f32, side: i8| {
+            let size = scale * (max - min);
+            if symmetric {
+                let center = 0.5 * (min + max);
+                (center - 0.5 * size, center + 0.5 * size)
+            } else if side < 0 {
+                (max - size, max)
+            } else {
+                (min, min + size)
+            }
+        };
+        x = fit_axis(start.min.x, start.max.x, side.0);
+        y = fit_axis(start.min.y, start.max.y, side.1);
+    }
+
+    Rect::from_two_pos(pos2(x.0, y.0), pos2(x.1, y.1))
+}
+
+#[test]
+fn test_resize_rect() {
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+    let no_snap = |x: f32| x;
+    let snap_10 = |x: f32| (x / 10.0).round() * 10.0;
+
+    // Right edge:
+    assert_eq!(
+        resize_rect(rect, (1, 0), vec2(12.0, 30.0), false, false, no_snap),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(112.0, 50.0))
+    );
+    assert_eq!(
+        resize_rect(rect, (1, 0), vec2(12.0, 30.0), false, false, snap_10),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(110.0, 50.0))
+    );
+
+    // Top left corner, around the center:
+    assert_eq!(
+        resize_rect(rect, (-1, -1), vec2(10.0, 5.0), false, true, no_snap),
+        Rect::from_min_max(pos2(10.0, 5.0), pos2(90.0, 45.0))
+    );
+
+    // Bottom right corner, keeping the aspect ratio:
+    assert_eq!(
+        resize_rect(rect, (1, 1), vec2(100.0, 10.0), true, false, no_snap),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 100.0))
+    );
+
+    // Dragging past the opposite side flips the rectangle:
+    assert_eq!(
+        resize_rect(rect, (-1, 0), vec2(150.0, 0.0), false, false, no_snap),
+        Rect::from_min_max(pos2(100.0, 0.0), pos2(150.0, 50.0))
+    );
+}
+
+#[test]
+fn test_point_handle_drag() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(200.0, 200.0));
+    let to_screen = RectTransform::identity(Rect::from_min_size(Pos2::ZERO, vec2(200.0, 200.0)));
+    let mut point = pos2(50.0, 50.0);
+    let mut total_delta = Vec2::ZERO;
+    let mut run = |harness: &mut Harness, point: &mut Pos2| {
+        harness.run(|ui| {
+            let handles = Handles::new(&to_screen).snap(10.0);
+            total_delta += handles.point(ui, "point", point).inner;
+        });
+    };
+
+    run(&mut harness, &mut point);
+    harness.pointer_move(pos2(50.0, 50.0));
+    harness.pointer_button(pos2(50.0, 50.0), true);
+    run(&mut harness, &mut point);
+    harness.pointer_move(pos2(63.0, 58.0));
+    run(&mut harness, &mut point);
+    harness.pointer_button(pos2(63.0, 58.0), false);
+    run(&mut harness, &mut point);
+
+    assert_eq!(point, pos2(60.0, 60.0));
+    assert_eq!(total_delta, vec2(10.0, 10.0));
+}
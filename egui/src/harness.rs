@@ -0,0 +1,332 @@
+//! Run egui without an integration, e.g. for testing widgets and layouts.
+//!
+//! ```
+//! let mut harness = egui::harness::Harness::new(egui::vec2(200.0, 100.0));
+//! let mut clicked = false;
+//!
+//! // Run a first frame to find out where the button is:
+//! let frame = harness.run(|ui| clicked |= ui.button("Click me").clicked());
+//! let button_rect = frame.contents_rect.unwrap();
+//!
+//! harness.click(button_rect.center());
+//! harness.run(|ui| clicked |= ui.button("Click me").clicked());
+//! assert!(clicked);
+//! ```
+
+use crate::*;
+
+/// What came out of running one frame in a [`Harness`].
+pub struct HarnessFrame {
+    /// The platform output (cursor icon, copied text, events, …).
+    pub output: Output,
+
+    /// What egui wants painted, before tessellation.
+    pub shapes: Vec<epaint::ClippedShape>,
+
+    /// The tessellated [`Self::shapes`].
+    pub clipped_meshes: Vec<ClippedMesh>,
+
+    /// The area used by the contents added with [`Harness::run`].
+    ///
+    /// `None` when using [`Harness::run_ctx`].
+    pub contents_rect: Option<Rect>,
+}
+
+/// Runs egui headless over several frames, with scripted input.
+///
+/// Queue up input with e.g. [`Self::click`] and [`Self::type_text`],
+/// then call [`Self::run`] to run a frame with that input.
+/// Every frame advances the time by [`Self::dt`] seconds.
+pub struct Harness {
+    ctx: CtxRef,
+    screen_size: Vec2,
+    work_area: Option<Rect>,
+    pixels_per_point: f32,
+    time: f64,
+    dt: f32,
+    modifiers: Modifiers,
+
+    /// Input for the next frame.
+    events: Vec<Event>,
+    scroll_delta: Vec2,
+}
+
+impl Harness {
+    /// A harness with a screen of the given size (in points).
+    pub fn new(screen_size: Vec2) -> Self {
+        Self {
+            ctx: Default::default(),
+            screen_size,
+            work_area: None,
+            pixels_per_point: 1.0,
+            time: 0.0,
+            dt: 1.0 / 60.0,
+            modifiers: Default::default(),
+            events: Default::default(),
+            scroll_delta: Vec2::ZERO,
+        }
+    }
+
+    /// The scale factor used when tessellating and rasterizing. Default: `1.0`.
+    pub fn pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// The time step between frames, in seconds. Default: 1/60.
+    pub fn dt(mut self, dt: f32) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    /// The context, e.g. for changing the style or reading the memory.
+    pub fn ctx(&self) -> &CtxRef {
+        &self.ctx
+    }
+
+    /// Resize the screen for the following frames, e.g. to test what happens when the user resizes the window.
+    pub fn set_screen_size(&mut self, screen_size: Vec2) {
+        self.screen_size = screen_size;
+    }
+
+    /// The part of the screen not covered by OS taskbars etc in the following frames.
+    /// See [`RawInput::work_area`].
+    pub fn set_work_area(&mut self, work_area: Option<Rect>) {
+        self.work_area = work_area;
+    }
+
+    /// Add a raw input event to the next frame.
+    pub fn event(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Modifier keys held down during the following frames.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers;
+    }
+
+    /// Move the pointer in the next frame.
+    pub fn pointer_move(&mut self, pos: Pos2) {
+        self.event(Event::PointerMoved(pos));
+    }
+
+    /// Press or release the primary pointer button in the next frame.
+    pub fn pointer_button(&mut self, pos: Pos2, pressed: bool) {
+        self.event(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: self.modifiers,
+        });
+    }
+
+    /// Move the pointer to `pos`, then press and release the primary button, all in the next frame.
+    pub fn click(&mut self, pos: Pos2) {
+        self.pointer_move(pos);
+        self.pointer_button(pos, true);
+        self.pointer_button(pos, false);
+    }
+
+    /// Click the center of the widget with the given [`Id`] in the next frame.
+    ///
+    /// Returns `false` if there was no such widget in the last frame (see [`Self::widget_rect`]).
+    pub fn click_widget(&mut self, id: Id) -> bool {
+        if let Some(rect) = self.widget_rect(id) {
+            self.click(rect.center());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Where the widget with the given [`Id`] was in the last frame.
+    ///
+    /// Only widgets that can be interacted with (e.g. buttons, but not labels) are tracked.
+    /// Get the `Id` from the widget's [`Response::id`].
+    pub fn widget_rect(&self, id: Id) -> Option<Rect> {
+        self.ctx.widget_rect(id)
+    }
+
+    /// Press and release the given key in the next frame.
+    pub fn key_press(&mut self, key: Key) {
+        for &pressed in &[true, false] {
+            self.event(Event::Key {
+                key,
+                pressed,
+                modifiers: self.modifiers,
+            });
+        }
+    }
+
+    /// Scroll by this many points in the next frame, e.g. `vec2(0.0, -50.0)` to scroll down.
+    ///
+    /// Hold down shift with [`Self::set_modifiers`] to test shift-scrolling.
+    pub fn scroll(&mut self, delta: Vec2) {
+        self.scroll_delta += delta;
+    }
+
+    /// Type the given text in the next frame.
+    pub fn type_text(&mut self, text: &str) {
+        self.event(Event::Text(text.to_owned()));
+    }
+
+    /// Run one frame with the queued input, adding the contents to a [`CentralPanel`].
+    pub fn run(&mut self, add_contents: impl FnOnce(&mut Ui)) -> HarnessFrame {
+        self.run_frame(|ctx| {
+            // The panel always fills the screen, so measure the contents in a scope:
+            let contents_rect = CentralPanel::default()
+                .show(ctx, |ui| ui.scope(add_contents).response.rect)
+                .inner;
+            Some(contents_rect)
+        })
+    }
+
+    /// Run one frame with the queued input, giving you full control of the [`CtxRef`].
+    pub fn run_ctx(&mut self, run_ui: impl FnOnce(&CtxRef)) -> HarnessFrame {
+        self.run_frame(|ctx| {
+            run_ui(ctx);
+            None
+        })
+    }
+
+    fn run_frame(&mut self, run_ui: impl FnOnce(&CtxRef) -> Option<Rect>) -> HarnessFrame {
+        let raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, self.screen_size)),
+            work_area: self.work_area,
+            pixels_per_point: Some(self.pixels_per_point),
+            time: Some(self.time),
+            predicted_dt: self.dt,
+            modifiers: self.modifiers,
+            scroll_delta: std::mem::take(&mut self.scroll_delta),
+            events: std::mem::take(&mut self.events),
+            ..Default::default()
+        };
+        self.time += self.dt as f64;
+
+        self.ctx.begin_frame(raw_input);
+        let contents_rect = run_ui(&self.ctx);
+        let (output, shapes) = self.ctx.end_frame();
+        let clipped_meshes = self.ctx.tessellate(shapes.clone());
+
+        HarnessFrame {
+            output,
+            shapes,
+            clipped_meshes,
+            contents_rect,
+        }
+    }
+
+    /// Run `num_frames` frames with the same contents, e.g. to let a window settle.
+    ///
+    /// Any queued input is used in the first frame. Returns the last frame.
+    pub fn run_frames(
+        &mut self,
+        num_frames: usize,
+        mut add_contents: impl FnMut(&mut Ui),
+    ) -> HarnessFrame {
+        let mut frame = self.run(&mut add_contents);
+        for _ in 1..num_frames {
+            frame = self.run(&mut add_contents);
+        }
+        frame
+    }
+
+    /// Run frames without any new input until egui no longer asks for a repaint
+    /// (e.g. when all animations are done), or `max_frames` is reached.
+    ///
+    /// Returns the last frame.
+    pub fn run_until_stable(
+        &mut self,
+        max_frames: usize,
+        mut add_contents: impl FnMut(&mut Ui),
+    ) -> HarnessFrame {
+        let mut frame = self.run(&mut add_contents);
+        for _ in 1..max_frames {
+            if !frame.output.needs_repaint {
+                break;
+            }
+            frame = self.run(&mut add_contents);
+        }
+        frame
+    }
+
+    /// Paint the given frame into an image, using the software rasterizer.
+    #[cfg(feature = "software_rasterizer")]
+    pub fn rasterize(&self, frame: &HarnessFrame) -> epaint::rasterizer::Image {
+        let size = [
+            (self.screen_size.x * self.pixels_per_point).round() as usize,
+            (self.screen_size.y * self.pixels_per_point).round() as usize,
+        ];
+        epaint::rasterizer::rasterize(
+            size,
+            self.pixels_per_point,
+            Color32::BLACK,
+            &frame.clipped_meshes,
+            &self.ctx.texture(),
+        )
+    }
+}
+
+#[test]
+fn test_harness_checkbox() {
+    let mut harness = Harness::new(vec2(200.0, 100.0));
+    let mut checked = false;
+
+    let frame = harness.run(|ui| {
+        ui.checkbox(&mut checked, "Check me");
+    });
+    assert!(!checked);
+    assert!(!frame.clipped_meshes.is_empty());
+
+    let rect = frame.contents_rect.unwrap();
+    harness.click(rect.center());
+    harness.run(|ui| {
+        ui.checkbox(&mut checked, "Check me");
+    });
+    assert!(checked);
+}
+
+#[test]
+fn test_harness_click_widget() {
+    use std::cell::Cell;
+
+    let mut harness = Harness::new(vec2(200.0, 100.0));
+    let clicks = Cell::new(0);
+    let button_id = Cell::new(None);
+
+    let add_contents = |ui: &mut Ui| {
+        let response = ui.button("Click me");
+        clicks.set(clicks.get() + response.clicked() as usize);
+        button_id.set(Some(response.id));
+    };
+    harness.run_frames(2, add_contents);
+
+    let button_id = button_id.get().unwrap();
+    assert!(harness.widget_rect(button_id).is_some());
+    assert!(harness.click_widget(button_id));
+    harness.run(add_contents);
+    assert_eq!(clicks.get(), 1);
+
+    assert!(!harness.click_widget(Id::new("no such widget")));
+}
+
+#[test]
+fn test_harness_text_edit() {
+    let mut harness = Harness::new(vec2(200.0, 100.0));
+    let mut text = String::new();
+
+    let frame = harness.run(|ui| {
+        ui.text_edit_singleline(&mut text);
+    });
+
+    harness.click(frame.contents_rect.unwrap().center());
+    harness.run(|ui| {
+        ui.text_edit_singleline(&mut text);
+    });
+
+    harness.type_text("hello");
+    harness.run(|ui| {
+        ui.text_edit_singleline(&mut text);
+    });
+    assert_eq!(text, "hello");
+}
@@ -0,0 +1,115 @@
+use crate::{Pos2, Rect};
+
+/// The region of the screen a widget responds to, for when a [`Rect`] is not a good fit.
+///
+/// Use with [`crate::Ui::interact_with_shape`], e.g. so that a round knob
+/// does not respond to clicks in the corners of its bounding rectangle.
+///
+/// All coordinates are screen coordinates in points.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HitShape {
+    Rect(Rect),
+
+    /// A rectangle with rounded corners.
+    RoundedRect {
+        rect: Rect,
+        corner_radius: f32,
+    },
+
+    Circle {
+        center: Pos2,
+        radius: f32,
+    },
+
+    /// A convex polygon. The points can be in clockwise or counter-clockwise order.
+    ConvexPolygon(Vec<Pos2>),
+}
+
+impl From<Rect> for HitShape {
+    fn from(rect: Rect) -> Self {
+        Self::Rect(rect)
+    }
+}
+
+impl HitShape {
+    /// The smallest rectangle containing the whole shape.
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            Self::Rect(rect) | Self::RoundedRect { rect, .. } => *rect,
+            Self::Circle { center, radius } => {
+                Rect::from_center_size(*center, crate::Vec2::splat(2.0 * radius))
+            }
+            Self::ConvexPolygon(points) => {
+                let mut rect = Rect::NOTHING;
+                for &point in points {
+                    rect.extend_with(point);
+                }
+                rect
+            }
+        }
+    }
+
+    /// Is the given position inside (or on the edge of) the shape?
+    pub fn contains(&self, pos: Pos2) -> bool {
+        match self {
+            Self::Rect(rect) => rect.contains(pos),
+            Self::RoundedRect {
+                rect,
+                corner_radius,
+            } => {
+                let corner_radius = corner_radius.min(0.5 * rect.width().min(rect.height()));
+                let inner = rect.shrink(corner_radius);
+                rect.contains(pos) && inner.clamp(pos).distance(pos) <= corner_radius
+            }
+            Self::Circle { center, radius } => center.distance_sq(pos) <= radius * radius,
+            Self::ConvexPolygon(points) => {
+                if points.len() < 3 {
+                    return false;
+                }
+                let mut has_positive = false;
+                let mut has_negative = false;
+                for (i, &a) in points.iter().enumerate() {
+                    let b = points[(i + 1) % points.len()];
+                    let edge = b - a;
+                    let to_pos = pos - a;
+                    let cross = edge.x * to_pos.y - edge.y * to_pos.x;
+                    has_positive |= cross > 0.0;
+                    has_negative |= cross < 0.0;
+                }
+                !(has_positive && has_negative)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hit_shape() {
+    use crate::{pos2, vec2};
+
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(20.0, 10.0));
+    let rounded = HitShape::RoundedRect {
+        rect,
+        corner_radius: 5.0,
+    };
+    assert!(rounded.contains(pos2(10.0, 5.0)));
+    assert!(rounded.contains(pos2(10.0, 0.0)));
+    assert!(!rounded.contains(pos2(0.5, 0.5)));
+    assert!(!rounded.contains(pos2(21.0, 5.0)));
+
+    let circle = HitShape::Circle {
+        center: pos2(10.0, 10.0),
+        radius: 10.0,
+    };
+    assert!(circle.contains(pos2(10.0, 1.0)));
+    assert!(!circle.contains(pos2(1.0, 1.0)));
+    assert_eq!(
+        circle.bounding_rect(),
+        Rect::from_min_size(pos2(0.0, 0.0), vec2(20.0, 20.0))
+    );
+
+    let triangle = HitShape::ConvexPolygon(vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(0.0, 10.0)]);
+    assert!(triangle.contains(pos2(2.0, 2.0)));
+    assert!(!triangle.contains(pos2(8.0, 8.0)));
+    let reversed = HitShape::ConvexPolygon(vec![pos2(0.0, 10.0), pos2(10.0, 0.0), pos2(0.0, 0.0)]);
+    assert!(reversed.contains(pos2(2.0, 2.0)));
+}
@@ -0,0 +1,17 @@
+//! Callbacks registered on a [`Context`], see [`Context::on_response`].
+
+use std::sync::Arc;
+
+use crate::*;
+
+type ResponseHook = Arc<dyn Fn(&Response) + Send + Sync>;
+type BeginFrameHook = Arc<dyn Fn(&InputState) + Send + Sync>;
+type EndFrameHook = Arc<dyn Fn(&Output) + Send + Sync>;
+
+/// All hooks registered on a [`Context`].
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    pub on_response: Vec<ResponseHook>,
+    pub on_begin_frame: Vec<BeginFrameHook>,
+    pub on_end_frame: Vec<EndFrameHook>,
+}
@@ -5,8 +5,8 @@ use crate::{emath::*, util::History};
 use std::collections::{BTreeMap, HashSet};
 
 pub use crate::data::input::Key;
-pub use touch_state::MultiTouchInfo;
 use touch_state::TouchState;
+pub use touch_state::{MultiTouchInfo, Touch};
 
 /// If the pointer moves more than this, it is no longer a click (but maybe a drag)
 const MAX_CLICK_DIST: f32 = 6.0; // TODO: move to settings
@@ -29,9 +29,15 @@ pub struct InputState {
     /// (We keep a separate `TouchState` for each encountered touch device.)
     touch_states: BTreeMap<TouchDeviceId, TouchState>,
 
-    /// How many pixels the user scrolled.
+    /// How many points the user scrolled,
+    /// with [`RawInput::scroll_delta_lines`] converted to points and [`crate::style::Interaction::scroll_speed`] applied.
+    ///
+    /// Scrolling while holding down ctrl (or cmd on Mac) zooms instead, see [`Self::zoom_delta`].
     pub scroll_delta: Vec2,
 
+    /// Zoom factor from [`RawInput::zoom_delta`] and ctrl-scroll this frame.
+    zoom_factor_delta: f32,
+
     /// Position and size of the egui area.
     pub screen_rect: Rect,
 
@@ -57,7 +63,11 @@ pub struct InputState {
     // The keys that are currently being held down.
     pub keys_down: HashSet<Key>,
 
-    /// In-order events received this frame
+    /// In-order events received this frame.
+    ///
+    /// Consecutive [`Event::PointerMoved`] are merged into the last one,
+    /// and events with non-finite positions are dropped.
+    /// If you need every pointer position (e.g. for drawing), use [`Self::pointer_moves`].
     pub events: Vec<Event>,
 }
 
@@ -68,6 +78,7 @@ impl Default for InputState {
             pointer: Default::default(),
             touch_states: Default::default(),
             scroll_delta: Default::default(),
+            zoom_factor_delta: 1.0,
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
             pixels_per_point: 1.0,
             time: 0.0,
@@ -81,8 +92,13 @@ impl Default for InputState {
 }
 
 impl InputState {
+    /// `interaction` controls the smoothing and prediction of the pointer while dragging.
     #[must_use]
-    pub fn begin_frame(mut self, new: RawInput) -> InputState {
+    pub fn begin_frame(
+        mut self,
+        new: RawInput,
+        interaction: &crate::style::Interaction,
+    ) -> InputState {
         #![allow(deprecated)] // for screen_size
 
         let time = new
@@ -100,7 +116,16 @@ impl InputState {
         for touch_state in self.touch_states.values_mut() {
             touch_state.begin_frame(time, &new, self.pointer.interact_pos);
         }
-        let pointer = self.pointer.begin_frame(time, &new);
+        let mut scroll_delta = interaction.scroll_speed
+            * (new.scroll_delta + interaction.scroll_line_height * new.scroll_delta_lines);
+        let mut zoom_factor_delta = new.zoom_delta;
+        if new.modifiers.ctrl || new.modifiers.command {
+            // Treat as zoom instead:
+            zoom_factor_delta *= (scroll_delta.y / 200.0).exp();
+            scroll_delta = Vec2::ZERO;
+        }
+        let events = sanitize_events(&new.events);
+        let pointer = self.pointer.begin_frame(time, &events, interaction);
         let mut keys_down = self.keys_down;
         for event in &new.events {
             if let Event::Key { key, pressed, .. } = event {
@@ -114,7 +139,8 @@ impl InputState {
         InputState {
             pointer,
             touch_states: self.touch_states,
-            scroll_delta: new.scroll_delta,
+            scroll_delta,
+            zoom_factor_delta,
             screen_rect,
             pixels_per_point: new.pixels_per_point.unwrap_or(self.pixels_per_point),
             time,
@@ -122,7 +148,7 @@ impl InputState {
             predicted_dt: new.predicted_dt,
             modifiers: new.modifiers,
             keys_down,
-            events: new.events.clone(), // TODO: remove clone() and use raw.events
+            events,
             raw: new,
         }
     }
@@ -132,10 +158,34 @@ impl InputState {
         self.screen_rect
     }
 
+    /// The part of [`Self::screen_rect`] not covered by OS taskbars etc (see [`RawInput::work_area`]).
+    pub fn work_area(&self) -> Rect {
+        match self.raw.work_area {
+            Some(work_area) if work_area.intersects(self.screen_rect) => {
+                work_area.intersect(self.screen_rect)
+            }
+            _ => self.screen_rect,
+        }
+    }
+
+    /// Every position the pointer moved to this frame, in order.
+    ///
+    /// High-rate mice and touch screens can report many positions each frame.
+    /// These are merged in [`Self::events`] and [`Self::pointer`],
+    /// but a drawing program may want all of them for smooth strokes.
+    pub fn pointer_moves(&self) -> impl Iterator<Item = Pos2> + '_ {
+        self.raw.events.iter().filter_map(|event| match event {
+            Event::PointerMoved(pos) if pos.is_finite() => Some(*pos),
+            _ => None,
+        })
+    }
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
     /// * `zoom > 1`: pinch spread
+    ///
+    /// Zoom around [`Self::zoom_anchor`].
     #[inline(always)]
     pub fn zoom_delta(&self) -> f32 {
         // If a multi touch gesture is detected, it measures the exact and linear proportions of
@@ -144,7 +194,17 @@ impl InputState {
         // synthesized from an original touch gesture.
         self.multi_touch()
             .map(|touch| touch.zoom_delta)
-            .unwrap_or(self.raw.zoom_delta)
+            .unwrap_or(self.zoom_factor_delta)
+    }
+
+    /// The position to zoom around, i.e. the one that should stay put when zooming with [`Self::zoom_delta`].
+    ///
+    /// This is where the pointer is, or where a pinch gesture started.
+    /// `None` if there is no pointer.
+    pub fn zoom_anchor(&self) -> Option<Pos2> {
+        self.multi_touch()
+            .map(|touch| touch.start_pos)
+            .or_else(|| self.pointer.hover_pos())
     }
 
     /// 2D non-proportional zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
@@ -168,11 +228,16 @@ impl InputState {
         // synthesized from an original touch gesture.
         self.multi_touch()
             .map(|touch| touch.zoom_delta_2d)
-            .unwrap_or_else(|| Vec2::splat(self.raw.zoom_delta))
+            .unwrap_or_else(|| Vec2::splat(self.zoom_factor_delta))
     }
 
     pub fn wants_repaint(&self) -> bool {
-        self.pointer.wants_repaint() || self.scroll_delta != Vec2::ZERO || !self.events.is_empty()
+        self.pointer.wants_repaint()
+            || self.scroll_delta != Vec2::ZERO
+            || self.zoom_factor_delta != 1.0
+            || !self.events.is_empty()
+            || !self.raw.hovered_files.is_empty()
+            || !self.raw.dropped_files.is_empty()
     }
 
     /// Was the given key pressed this frame?
@@ -216,6 +281,29 @@ impl InputState {
         })
     }
 
+    /// Files that are currently being dragged over the window, but not yet dropped.
+    ///
+    /// Use this to e.g. highlight a drop target.
+    #[inline(always)]
+    pub fn hovered_files(&self) -> &[HoveredFile] {
+        &self.raw.hovered_files
+    }
+
+    /// Files that were dropped onto the window this frame.
+    ///
+    /// ```
+    /// # let ctx = egui::CtxRef::default();
+    /// for file in ctx.input().dropped_files() {
+    ///     if let Some(path) = &file.path {
+    ///         // open the file at `path`
+    ///     }
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn dropped_files(&self) -> &[DroppedFile] {
+        &self.raw.dropped_files
+    }
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -270,6 +358,25 @@ impl InputState {
         }
     }
 
+    /// All fingers (or pens) currently on a touch device, plus those lifted this frame.
+    ///
+    /// Use this for custom multi-finger interactions, like drawing with several fingers at once.
+    /// For the common gestures (pinch, rotate, pan), [`Self::multi_touch`] is easier.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// for touch in ui.input().touches() {
+    ///     if touch.phase == egui::TouchPhase::Start {
+    ///         println!("Finger {:?} touched down at {:?}", touch.id, touch.pos);
+    ///     }
+    /// }
+    /// ```
+    pub fn touches(&self) -> impl Iterator<Item = &Touch> + '_ {
+        self.touch_states
+            .values()
+            .flat_map(|touch_state| touch_state.touches())
+    }
+
     /// Scans `events` for device IDs of touch devices we have not seen before,
     /// and creates a new `TouchState` for each such device.
     fn create_touch_states_for_new_devices(&mut self, events: &[Event]) {
@@ -305,19 +412,36 @@ impl Click {
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum PointerEvent {
     Moved(Pos2),
-    Pressed(Pos2),
-    Released(Option<Click>),
+    Pressed {
+        position: Pos2,
+        button: PointerButton,
+    },
+    Released {
+        /// `None` if the button was held down too long, or moved too much, to be a click.
+        click: Option<Click>,
+        button: PointerButton,
+    },
 }
 
 impl PointerEvent {
     pub fn is_press(&self) -> bool {
-        matches!(self, PointerEvent::Pressed(_))
+        matches!(self, PointerEvent::Pressed { .. })
     }
     pub fn is_release(&self) -> bool {
-        matches!(self, PointerEvent::Released(_))
+        matches!(self, PointerEvent::Released { .. })
     }
     pub fn is_click(&self) -> bool {
-        matches!(self, PointerEvent::Released(Some(_click)))
+        matches!(self, PointerEvent::Released { click: Some(_), .. })
+    }
+
+    /// The button that was pressed or released, if any.
+    pub fn button(&self) -> Option<PointerButton> {
+        match self {
+            PointerEvent::Moved(_) => None,
+            PointerEvent::Pressed { button, .. } | PointerEvent::Released { button, .. } => {
+                Some(*button)
+            }
+        }
     }
 }
 
@@ -342,6 +466,13 @@ pub struct PointerState {
     /// How much the pointer moved compared to last frame, in points.
     delta: Vec2,
 
+    /// Smoothed and/or predicted pointer position during a drag,
+    /// if [`crate::style::Interaction::pointer_smoothing`] or `pointer_prediction` is on.
+    drag_pos: Option<Pos2>,
+
+    /// Time of the last frame.
+    time: f64,
+
     /// Current velocity of pointer.
     velocity: Vec2,
 
@@ -373,6 +504,8 @@ impl Default for PointerState {
             latest_pos: None,
             interact_pos: None,
             delta: Vec2::ZERO,
+            drag_pos: None,
+            time: 0.0,
             velocity: Vec2::ZERO,
             pos_history: History::new(1000, 0.1),
             down: Default::default(),
@@ -386,13 +519,20 @@ impl Default for PointerState {
 
 impl PointerState {
     #[must_use]
-    pub(crate) fn begin_frame(mut self, time: f64, new: &RawInput) -> PointerState {
+    pub(crate) fn begin_frame(
+        mut self,
+        time: f64,
+        events: &[Event],
+        interaction: &crate::style::Interaction,
+    ) -> PointerState {
         self.pointer_events.clear();
 
-        let old_pos = self.latest_pos;
+        let dt = (time - self.time) as f32;
+        self.time = time;
+        let old_pos = self.drag_pos.or(self.latest_pos);
         self.interact_pos = self.latest_pos;
 
-        for event in &new.events {
+        for event in events {
             match event {
                 Event::PointerMoved(pos) => {
                     let pos = *pos;
@@ -431,7 +571,10 @@ impl PointerState {
                     if pressed {
                         self.press_origin = Some(pos);
                         self.could_be_click = true;
-                        self.pointer_events.push(PointerEvent::Pressed(pos));
+                        self.pointer_events.push(PointerEvent::Pressed {
+                            position: pos,
+                            button,
+                        });
                     } else {
                         let clicked = self.could_be_click;
 
@@ -451,7 +594,8 @@ impl PointerState {
                             None
                         };
 
-                        self.pointer_events.push(PointerEvent::Released(click));
+                        self.pointer_events
+                            .push(PointerEvent::Released { click, button });
 
                         self.press_origin = None;
                         self.could_be_click = false;
@@ -467,12 +611,6 @@ impl PointerState {
             }
         }
 
-        self.delta = if let (Some(old_pos), Some(new_pos)) = (old_pos, self.latest_pos) {
-            new_pos - old_pos
-        } else {
-            Vec2::ZERO
-        };
-
         if let Some(pos) = self.latest_pos {
             self.pos_history.add(time, pos);
         } else {
@@ -489,9 +627,49 @@ impl PointerState {
             Vec2::default()
         };
 
+        self.drag_pos = self.smoothed_drag_pos(dt, interaction);
+        if self.drag_pos.is_some() {
+            self.interact_pos = self.drag_pos;
+        }
+
+        self.delta =
+            if let (Some(old_pos), Some(new_pos)) = (old_pos, self.drag_pos.or(self.latest_pos)) {
+                new_pos - old_pos
+            } else {
+                Vec2::ZERO
+            };
+
         self
     }
 
+    /// Where we report the pointer to be during a drag, if smoothing or prediction is on.
+    fn smoothed_drag_pos(&self, dt: f32, interaction: &crate::style::Interaction) -> Option<Pos2> {
+        let smoothing = interaction.pointer_smoothing;
+        let prediction = interaction.pointer_prediction;
+        if smoothing <= 0.0 && prediction <= 0.0 {
+            return None;
+        }
+        let pos = self.latest_pos?;
+        self.press_origin?; // not dragging
+        if self.any_pressed() {
+            return Some(pos); // start of a drag: no history to go on
+        }
+
+        let target = pos + prediction * self.velocity;
+        let previous = self.drag_pos.unwrap_or(pos);
+        let t = if smoothing > 0.0 {
+            1.0 - (-dt / smoothing).exp()
+        } else {
+            1.0
+        };
+        let smoothed = previous + t * (target - previous);
+        if smoothed.distance(target) < 0.1 {
+            Some(target) // close enough: stop repainting
+        } else {
+            Some(smoothed)
+        }
+    }
+
     fn wants_repaint(&self) -> bool {
         !self.pointer_events.is_empty() || self.delta != Vec2::ZERO
     }
@@ -581,17 +759,36 @@ impl PointerState {
         self.pointer_events.iter().any(|event| event.is_click())
     }
 
-    // /// Was this button pressed (`!down -> down`) this frame?
-    // /// This can sometimes return `true` even if `any_down() == false`
-    // /// because a press can be shorted than one frame.
-    // pub fn button_pressed(&self, button: PointerButton) -> bool {
-    //     self.pointer_events.iter().any(|event| event.is_press())
-    // }
+    /// Was this button pressed (`!down -> down`) this frame?
+    /// This can sometimes return `true` even if `button_down(button) == false`
+    /// because a press can be shorted than one frame.
+    pub fn button_pressed(&self, button: PointerButton) -> bool {
+        self.pointer_events
+            .iter()
+            .any(|event| event.is_press() && event.button() == Some(button))
+    }
+
+    /// Was this button released (`down -> !down`) this frame?
+    pub fn button_released(&self, button: PointerButton) -> bool {
+        self.pointer_events
+            .iter()
+            .any(|event| event.is_release() && event.button() == Some(button))
+    }
+
+    /// Was this button clicked this frame?
+    pub fn button_clicked(&self, button: PointerButton) -> bool {
+        self.pointer_events
+            .iter()
+            .any(|event| event.is_click() && event.button() == Some(button))
+    }
 
-    // /// Was this button released (`down -> !down`) this frame?
-    // pub fn button_released(&self, button: PointerButton) -> bool {
-    //     self.pointer_events.iter().any(|event| event.is_release())
-    // }
+    /// Was this button double-clicked this frame?
+    pub fn button_double_clicked(&self, button: PointerButton) -> bool {
+        self.pointer_events.iter().any(|event| {
+            matches!(event, PointerEvent::Released { click: Some(click), .. }
+                if click.button == button && click.is_double())
+        })
+    }
 
     /// Is this button currently down?
     #[inline(always)]
@@ -605,6 +802,178 @@ impl PointerState {
     }
 }
 
+/// Drop events with non-finite positions, and merge consecutive [`Event::PointerMoved`].
+fn sanitize_events(events: &[Event]) -> Vec<Event> {
+    let mut sanitized: Vec<Event> = Vec::with_capacity(events.len());
+    for event in events {
+        match event {
+            Event::PointerMoved(pos) | Event::PointerButton { pos, .. } if !pos.is_finite() => {
+                continue;
+            }
+            Event::PointerMoved(_) => {
+                if let Some(Event::PointerMoved(_)) = sanitized.last() {
+                    sanitized.pop();
+                }
+            }
+            _ => {}
+        }
+        sanitized.push(event.clone());
+    }
+    sanitized
+}
+
+#[test]
+fn test_sanitize_events() {
+    let events = vec![
+        Event::PointerMoved(pos2(1.0, 1.0)),
+        Event::PointerMoved(pos2(2.0, 2.0)),
+        Event::PointerMoved(pos2(f32::NAN, 2.0)),
+        Event::PointerButton {
+            pos: pos2(2.0, 2.0),
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Default::default(),
+        },
+        Event::PointerMoved(pos2(3.0, 3.0)),
+        Event::PointerMoved(pos2(4.0, 4.0)),
+    ];
+    let sanitized = sanitize_events(&events);
+    assert_eq!(sanitized.len(), 3);
+    assert_eq!(sanitized[0], Event::PointerMoved(pos2(2.0, 2.0)));
+    assert_eq!(sanitized[2], Event::PointerMoved(pos2(4.0, 4.0)));
+}
+
+#[test]
+fn test_scroll_delta_lines_and_speed() {
+    let interaction = crate::style::Interaction {
+        scroll_line_height: 10.0,
+        scroll_speed: 2.0,
+        ..Default::default()
+    };
+
+    let raw = RawInput {
+        scroll_delta: vec2(0.0, 3.0),
+        scroll_delta_lines: vec2(1.0, -1.0),
+        ..Default::default()
+    };
+    let input = InputState::default().begin_frame(raw, &interaction);
+    assert_eq!(input.scroll_delta, vec2(20.0, -14.0));
+}
+
+#[test]
+fn test_ctrl_scroll_zooms() {
+    let interaction = crate::style::Interaction::default();
+    let scroll = |ctrl: bool| {
+        let raw = RawInput {
+            scroll_delta: vec2(0.0, 50.0),
+            modifiers: Modifiers {
+                ctrl,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        InputState::default().begin_frame(raw, &interaction)
+    };
+
+    let input = scroll(false);
+    assert_eq!(input.scroll_delta, vec2(0.0, 50.0));
+    assert_eq!(input.zoom_delta(), 1.0);
+
+    let input = scroll(true);
+    assert_eq!(input.scroll_delta, Vec2::ZERO);
+    assert!(input.zoom_delta() > 1.0);
+}
+
+#[test]
+fn test_touches() {
+    let interaction = crate::style::Interaction::default();
+    let touch = |id: u64, phase: TouchPhase, x: f32| Event::Touch {
+        device_id: TouchDeviceId(0),
+        id: TouchId(id),
+        phase,
+        pos: pos2(x, 0.0),
+        force: 0.0,
+    };
+    let frame = |input: InputState, events: Vec<Event>| {
+        let raw = RawInput {
+            events,
+            ..Default::default()
+        };
+        input.begin_frame(raw, &interaction)
+    };
+    let phases = |input: &InputState| -> Vec<(u64, TouchPhase)> {
+        input.touches().map(|t| (t.id.0, t.phase)).collect()
+    };
+
+    let input = frame(
+        InputState::default(),
+        vec![
+            touch(1, TouchPhase::Start, 10.0),
+            touch(2, TouchPhase::Start, 20.0),
+        ],
+    );
+    assert_eq!(
+        phases(&input),
+        vec![(1, TouchPhase::Start), (2, TouchPhase::Start)]
+    );
+
+    let input = frame(input, vec![touch(2, TouchPhase::Move, 30.0)]);
+    assert_eq!(
+        phases(&input),
+        vec![(1, TouchPhase::Move), (2, TouchPhase::Move)]
+    );
+    assert_eq!(input.touches().nth(1).unwrap().pos, pos2(30.0, 0.0));
+
+    let input = frame(input, vec![touch(1, TouchPhase::End, 10.0)]);
+    assert_eq!(
+        phases(&input),
+        vec![(1, TouchPhase::End), (2, TouchPhase::Move)]
+    );
+
+    let input = frame(input, vec![]);
+    assert_eq!(phases(&input), vec![(2, TouchPhase::Move)]);
+}
+
+#[test]
+fn test_pointer_smoothing() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(200.0, 200.0));
+    let mut style = (*harness.ctx().style()).clone();
+    style.interaction.pointer_smoothing = 0.05;
+    harness.ctx().set_style(style);
+
+    let run = |harness: &mut Harness| {
+        let mut pointer = PointerState::default();
+        harness.run_ctx(|ctx| pointer = ctx.input().pointer.clone());
+        pointer
+    };
+
+    harness.pointer_button(pos2(0.0, 0.0), true);
+    run(&mut harness);
+    harness.pointer_move(pos2(100.0, 0.0));
+    let first = run(&mut harness);
+    let x = first.interact_pos().unwrap().x;
+    assert!(0.0 < x && x < 100.0, "{}", x);
+    assert_eq!(first.delta().x, x);
+
+    // Catches up with the pointer, even if it doesn't move:
+    let mut total_delta = first.delta();
+    let mut last = first;
+    for _ in 0..60 {
+        last = run(&mut harness);
+        total_delta += last.delta();
+    }
+    assert_eq!(last.interact_pos(), Some(pos2(100.0, 0.0)));
+    assert!((total_delta - vec2(100.0, 0.0)).length() < 1e-3);
+
+    // No smoothing when not dragging:
+    harness.pointer_button(pos2(100.0, 0.0), false);
+    run(&mut harness);
+    harness.pointer_move(pos2(50.0, 0.0));
+    assert_eq!(run(&mut harness).interact_pos(), Some(pos2(50.0, 0.0)));
+}
+
 impl InputState {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
@@ -612,6 +981,7 @@ impl InputState {
             pointer,
             touch_states,
             scroll_delta,
+            zoom_factor_delta,
             screen_rect,
             pixels_per_point,
             time,
@@ -638,6 +1008,7 @@ impl InputState {
         }
 
         ui.label(format!("scroll_delta: {:?} points", scroll_delta));
+        ui.label(format!("zoom_factor_delta: {:.3?} x", zoom_factor_delta));
         ui.label(format!("screen_rect: {:?} points", screen_rect));
         ui.label(format!(
             "{:?} physical pixels for each logical point",
@@ -662,6 +1033,8 @@ impl PointerState {
             latest_pos,
             interact_pos,
             delta,
+            drag_pos,
+            time: _,
             velocity,
             pos_history: _,
             down,
@@ -674,6 +1047,7 @@ impl PointerState {
         ui.label(format!("latest_pos: {:?}", latest_pos));
         ui.label(format!("interact_pos: {:?}", interact_pos));
         ui.label(format!("delta: {:?}", delta));
+        ui.label(format!("drag_pos: {:?}", drag_pos));
         ui.label(format!(
             "velocity: [{:3.0} {:3.0}] points/sec",
             velocity.x, velocity.y
@@ -61,6 +61,34 @@ pub struct MultiTouchInfo {
     pub force: f32,
 }
 
+/// A finger (or pen) on a touch device. See [`crate::InputState::touches`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Touch {
+    /// The touch device this touch is on.
+    pub device_id: TouchDeviceId,
+
+    /// Unique identifier of this touch. It stays the same from when the finger touches the surface
+    /// until it is lifted.
+    pub id: TouchId,
+
+    /// [`TouchPhase::Start`] the frame the finger touched the surface,
+    /// [`TouchPhase::End`] or [`TouchPhase::Cancel`] the frame it was lifted,
+    /// and [`TouchPhase::Move`] in between (even if it did not move).
+    pub phase: TouchPhase,
+
+    /// Current position of the touch.
+    ///
+    /// This is in the coordinates of the touch device, which is the screen (in points)
+    /// for touch screens, but not necessarily for e.g. touch pads.
+    pub pos: Pos2,
+
+    /// Current force of the touch, in the interval `[0.0 ..= 1.0]`.
+    ///
+    /// A value of `0.0` either indicates a very light touch, or it means that the device
+    /// is not capable of measuring the touch force.
+    pub force: f32,
+}
+
 /// The current state (for a specific touch device) of touch events and gestures.
 #[derive(Clone)]
 pub(crate) struct TouchState {
@@ -74,6 +102,8 @@ pub(crate) struct TouchState {
     ///
     /// Refer to [`ActiveTouch`].
     active_touches: BTreeMap<TouchId, ActiveTouch>,
+    /// The active touches, plus those that ended this frame.
+    touches: Vec<Touch>,
     /// If a gesture has been recognized (i.e. when exactly two fingers touch the surface), this
     /// holds state information
     gesture_state: Option<GestureState>,
@@ -118,11 +148,14 @@ impl TouchState {
         Self {
             device_id,
             active_touches: Default::default(),
+            touches: Default::default(),
             gesture_state: None,
         }
     }
 
     pub fn begin_frame(&mut self, time: f64, new: &RawInput, pointer_pos: Option<Pos2>) {
+        self.touches.clear();
+        let mut started = vec![];
         let mut added_or_removed_touches = false;
         for event in &new.events {
             match *event {
@@ -135,6 +168,7 @@ impl TouchState {
                 } if device_id == self.device_id => match phase {
                     TouchPhase::Start => {
                         self.active_touches.insert(id, ActiveTouch { pos, force });
+                        started.push(id);
                         added_or_removed_touches = true;
                     }
                     TouchPhase::Move => {
@@ -145,12 +179,32 @@ impl TouchState {
                     }
                     TouchPhase::End | TouchPhase::Cancel => {
                         self.active_touches.remove(&id);
+                        self.touches.push(Touch {
+                            device_id,
+                            id,
+                            phase,
+                            pos,
+                            force,
+                        });
                         added_or_removed_touches = true;
                     }
                 },
                 _ => (),
             }
         }
+        for (&id, touch) in &self.active_touches {
+            self.touches.push(Touch {
+                device_id: self.device_id,
+                id,
+                phase: if started.contains(&id) {
+                    TouchPhase::Start
+                } else {
+                    TouchPhase::Move
+                },
+                pos: touch.pos,
+                force: touch.force,
+            });
+        }
         // This needs to be called each frame, even if there are no new touch events.
         // Otherwise, we would send the same old delta information multiple times:
         self.update_gesture(time, pointer_pos);
@@ -168,6 +222,10 @@ impl TouchState {
         self.gesture_state.is_some()
     }
 
+    pub fn touches(&self) -> &[Touch] {
+        &self.touches
+    }
+
     pub fn info(&self) -> Option<MultiTouchInfo> {
         self.gesture_state.as_ref().map(|state| {
             // state.previous can be `None` when the number of simultaneous touches has just
@@ -58,20 +58,85 @@ impl Widget for &epaint::Texture {
 impl Widget for &mut epaint::text::FontDefinitions {
     fn ui(self, ui: &mut Ui) -> Response {
         ui.vertical(|ui| {
-            for (text_style, (_family, size)) in self.family_and_size.iter_mut() {
-                // TODO: radio button for family
-                ui.add(
-                    Slider::new(size, 4.0..=40.0)
-                        .max_decimals(0)
-                        .text(format!("{:?}", text_style)),
-                );
+            Grid::new("family_and_size").show(ui, |ui| {
+                for (text_style, (family, size)) in self.family_and_size.iter_mut() {
+                    ui.label(format!("{:?}", text_style));
+                    ui.radio_value(family, FontFamily::Proportional, "Proportional");
+                    ui.radio_value(family, FontFamily::Monospace, "Monospace");
+                    ui.add(Slider::new(size, 4.0..=40.0).max_decimals(0));
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            for (family, fonts) in &self.fonts_for_family {
+                ui.label(format!("{:?}: {}", family, fonts.join(", ")))
+                    .on_hover_text("The first font is the primary, the rest are fallbacks.");
             }
+            add_dropped_fonts(ui, self);
+
             crate::reset_button(ui, self);
         })
         .response
     }
 }
 
+/// Lets the user add fonts by dropping `.ttf` and `.otf` files onto the window.
+fn add_dropped_fonts(ui: &mut Ui, definitions: &mut epaint::text::FontDefinitions) {
+    #[derive(Clone, Default)]
+    struct State {
+        family: Option<FontFamily>,
+        error: Option<String>,
+    }
+
+    let id = Id::new("add_dropped_fonts");
+    let mut state = ui
+        .memory()
+        .id_data_temp
+        .get::<State>(&id)
+        .cloned()
+        .unwrap_or_default();
+    let mut family = state.family.unwrap_or(FontFamily::Proportional);
+
+    ui.horizontal(|ui| {
+        ui.label("Drop a .ttf or .otf file onto the window to make it the primary font of:");
+        ui.radio_value(&mut family, FontFamily::Proportional, "Proportional");
+        ui.radio_value(&mut family, FontFamily::Monospace, "Monospace");
+    });
+
+    let dropped_files = ui.input().raw.dropped_files.clone();
+    for file in dropped_files {
+        let name = match &file.path {
+            Some(path) => path
+                .file_name()
+                .map_or_else(String::new, |name| name.to_string_lossy().into_owned()),
+            None => file.name.clone(),
+        };
+        let bytes = match (&file.bytes, &file.path) {
+            (Some(bytes), _) => Ok(bytes.to_vec()),
+            (None, Some(path)) => std::fs::read(path).map_err(|err| err.to_string()),
+            (None, None) => Err("No file contents".to_owned()),
+        };
+        state.error = bytes
+            .and_then(|bytes| {
+                definitions.insert_primary_font(
+                    name.clone(),
+                    std::borrow::Cow::Owned(bytes),
+                    family,
+                )
+            })
+            .err()
+            .map(|err| format!("Failed to add {:?}: {}", name, err));
+    }
+
+    if let Some(error) = &state.error {
+        ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+
+    state.family = Some(family);
+    ui.memory().id_data_temp.insert(id, state);
+}
+
 impl Widget for &epaint::stats::PaintStats {
     fn ui(self, ui: &mut Ui) -> Response {
         ui.vertical(|ui| {
@@ -128,12 +193,22 @@ impl Widget for &mut epaint::TessellationOptions {
                 aa_size: _,
                 anti_alias,
                 coarse_tessellation_culling,
+                subdivide_gradients,
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
+                debug_paint_wireframe,
                 debug_ignore_clip_rects,
             } = self;
             ui.checkbox(anti_alias, "Antialias")
                 .on_hover_text("Turn off for small performance gain.");
+            let hint = if ui.input().raw.interpolates_colors_in_linear_space {
+                "Not needed: the integration interpolates vertex colors in linear space."
+            } else {
+                "Turn on if gradients band and anti-aliased edges look too dark.\n\
+                 The integration does not report interpolating vertex colors in linear space."
+            };
+            ui.checkbox(subdivide_gradients, "Subdivide gradients")
+                .on_hover_text(hint);
             ui.collapsing("debug", |ui| {
                 ui.checkbox(
                     coarse_tessellation_culling,
@@ -142,6 +217,7 @@ impl Widget for &mut epaint::TessellationOptions {
                 ui.checkbox(debug_ignore_clip_rects, "Ignore clip rectangles");
                 ui.checkbox(debug_paint_clip_rects, "Paint clip rectangles");
                 ui.checkbox(debug_paint_text_rects, "Paint text bounds");
+                ui.checkbox(debug_paint_wireframe, "Paint triangle wireframes");
             });
         })
         .response
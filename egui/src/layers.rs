@@ -89,25 +89,92 @@ impl LayerId {
 pub struct ShapeIdx(usize);
 
 /// A list of [`Shape`]s paired with a clip rectangle.
+///
+/// Each shape also has a z index. Shapes with a higher z are painted on top of those with a lower z,
+/// and shapes with the same z are painted in the order they were added.
 #[derive(Clone, Default)]
-pub struct PaintList(Vec<ClippedShape>);
+pub struct PaintList {
+    shapes: Vec<ClippedShape>,
+
+    /// The z index of each shape.
+    z: Vec<i32>,
+}
 
 impl PaintList {
     #[inline(always)]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.shapes.is_empty()
+    }
+
+    /// The number of [`Shape`]s added so far.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// The [`Shape`]s in the given range of indices, e.g. everything added after some point.
+    pub(crate) fn shapes(&self, range: std::ops::Range<usize>) -> impl Iterator<Item = &Shape> {
+        self.shapes[range]
+            .iter()
+            .map(|ClippedShape(_, shape)| shape)
+    }
+
+    /// All the [`Shape`]s added so far, with their clip rectangles, in the order they will be painted.
+    pub(crate) fn clipped_shapes(&self) -> Vec<ClippedShape> {
+        let mut shapes = Vec::with_capacity(self.shapes.len());
+        self.for_each_in_painting_order(|shape| shapes.push(shape.clone()));
+        shapes
+    }
+
+    /// Visit the shapes in the order they will be painted (see [`Self::drain`]).
+    fn for_each_in_painting_order(&self, mut f: impl FnMut(&ClippedShape)) {
+        if self.z.iter().all(|&z| z == 0) {
+            self.shapes.iter().for_each(f);
+        } else {
+            let mut order: Vec<usize> = (0..self.shapes.len()).collect();
+            order.sort_by_key(|&i| self.z[i]); // stable, so shapes with the same z keep their order
+            for i in order {
+                f(&self.shapes[i]);
+            }
+        }
+    }
+
+    /// Take out all the shapes, in the order they should be painted.
+    fn drain(&mut self) -> Vec<ClippedShape> {
+        if self.z.iter().all(|&z| z == 0) {
+            self.z.clear();
+            std::mem::take(&mut self.shapes)
+        } else {
+            let mut shapes: Vec<(i32, ClippedShape)> =
+                self.z.drain(..).zip(self.shapes.drain(..)).collect();
+            shapes.sort_by_key(|(z, _)| *z); // stable, so shapes with the same z keep their order
+            shapes.into_iter().map(|(_, shape)| shape).collect()
+        }
     }
 
     /// Returns the index of the new [`Shape`] that can be used with `PaintList::set`.
     #[inline(always)]
     pub fn add(&mut self, clip_rect: Rect, shape: Shape) -> ShapeIdx {
-        let idx = ShapeIdx(self.0.len());
-        self.0.push(ClippedShape(clip_rect, shape));
+        self.add_with_z(0, clip_rect, shape)
+    }
+
+    /// Add a [`Shape`] at the given z index, above everything with a lower z
+    /// and below everything with a higher z.
+    pub fn add_with_z(&mut self, z: i32, clip_rect: Rect, shape: Shape) -> ShapeIdx {
+        let idx = ShapeIdx(self.shapes.len());
+        self.shapes.push(ClippedShape(clip_rect, shape));
+        self.z.push(z);
         idx
     }
 
-    pub fn extend(&mut self, clip_rect: Rect, mut shapes: Vec<Shape>) {
-        self.0
+    pub fn extend(&mut self, clip_rect: Rect, shapes: Vec<Shape>) {
+        self.extend_with_z(0, clip_rect, shapes);
+    }
+
+    /// Add many [`Shape`]s at the given z index.
+    pub fn extend_with_z(&mut self, z: i32, clip_rect: Rect, mut shapes: Vec<Shape>) {
+        self.z.resize(self.z.len() + shapes.len(), z);
+        self.shapes
             .extend(shapes.drain(..).map(|shape| ClippedShape(clip_rect, shape)))
     }
 
@@ -120,12 +187,12 @@ impl PaintList {
     /// and then later setting it using `paint_list.set(idx, cr, frame);`.
     #[inline(always)]
     pub fn set(&mut self, idx: ShapeIdx, clip_rect: Rect, shape: Shape) {
-        self.0[idx.0] = ClippedShape(clip_rect, shape);
+        self.shapes[idx.0] = ClippedShape(clip_rect, shape);
     }
 
     /// Translate each [`Shape`] and clip rectangle by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
-        for ClippedShape(clip_rect, shape) in &mut self.0 {
+        for ClippedShape(clip_rect, shape) in &mut self.shapes {
             *clip_rect = clip_rect.translate(delta);
             shape.translate(delta);
         }
@@ -157,17 +224,45 @@ impl GraphicLayers {
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        all_shapes.extend(list.lock().0.drain(..));
+                        all_shapes.extend(list.lock().drain());
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
             for shapes in order_map.values_mut() {
-                all_shapes.extend(shapes.lock().0.drain(..));
+                all_shapes.extend(shapes.lock().drain());
             }
         }
 
         all_shapes.into_iter()
     }
+
+    /// Visit all shapes in the order they will be painted (see [`Self::drain`]).
+    pub fn for_each_shape(
+        &self,
+        area_order: &[LayerId],
+        mut f: impl FnMut(LayerId, &ClippedShape),
+    ) {
+        for &order in &Order::ALL {
+            let order_map = &self.0[order as usize];
+
+            for layer_id in area_order {
+                if layer_id.order == order {
+                    if let Some(list) = order_map.get(&layer_id.id) {
+                        list.lock()
+                            .for_each_in_painting_order(|shape| f(*layer_id, shape));
+                    }
+                }
+            }
+
+            for (id, list) in order_map {
+                let layer_id = LayerId::new(order, *id);
+                if !area_order.contains(&layer_id) {
+                    list.lock()
+                        .for_each_in_painting_order(|shape| f(layer_id, shape));
+                }
+            }
+        }
+    }
 }
@@ -326,6 +326,10 @@ mod context;
 mod data;
 mod frame_state;
 pub(crate) mod grid;
+mod handles;
+pub mod harness;
+mod hit_shape;
+mod hooks;
 mod id;
 mod input_state;
 mod introspection;
@@ -337,9 +341,12 @@ mod painter;
 pub(crate) mod placer;
 mod response;
 mod sense;
+mod size;
 pub mod style;
 mod ui;
+mod undo;
 pub mod util;
+mod virtual_keyboard;
 pub mod widgets;
 
 pub use epaint;
@@ -355,7 +362,8 @@ pub use emath::{lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos
 pub use epaint::{
     color, mutex,
     text::{FontDefinitions, FontFamily, TextStyle},
-    ClippedMesh, Color32, Rgba, Shape, Stroke, Texture, TextureId,
+    ClippedMesh, Color32, Insets, NineSlice, Rgba, Shape, Stroke, TessellationJob, Texture,
+    TextureFilter, TextureId, TextureOptions, TextureWrapMode,
 };
 
 pub use {
@@ -363,18 +371,21 @@ pub use {
     context::{Context, CtxRef},
     data::{
         input::*,
-        output::{self, CursorIcon, Output, WidgetInfo},
+        output::{self, CursorIcon, Output, ResizeDirection, WidgetInfo, WindowRegion},
     },
-    grid::Grid,
+    grid::{CellEditor, Grid, TableFormat},
+    handles::{Handles, RectDelta},
+    hit_shape::HitShape,
     id::Id,
-    input_state::{InputState, MultiTouchInfo, PointerState},
+    input_state::{InputState, MultiTouchInfo, PointerState, Touch},
     layers::{LayerId, Order},
     layout::*,
     memory::Memory,
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{Style, Visuals},
+    size::Size,
+    style::{Style, Visuals, WidgetState},
     ui::Ui,
     widgets::*,
 };
@@ -520,6 +531,7 @@ pub enum WidgetType {
     ColorButton,
     ImageButton,
     CollapsingHeader,
+    MenuItem,
 
     /// If you cannot fit any of the above slots.
     ///
@@ -62,6 +62,9 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) drag_value: crate::widgets::drag_value::MonoState,
 
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) virtual_keyboard: crate::virtual_keyboard::VirtualKeyboard,
+
     pub(crate) areas: Areas,
 
     /// Which popup-window is open (if any)?
@@ -76,7 +79,7 @@ pub struct Memory {
 // ----------------------------------------------------------------------------
 
 /// Some global options that you can read and write.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
 pub struct Options {
@@ -84,13 +87,35 @@ pub struct Options {
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) style: std::sync::Arc<Style>,
 
+    /// How numbers and dates are shown by widgets.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) value_formatter: std::sync::Arc<dyn crate::util::format::ValueFormatter>,
+
     /// Controls the tessellator.
-    pub(crate) tessellation_options: epaint::TessellationOptions,
+    pub tessellation_options: epaint::TessellationOptions,
 
     /// This does not at all change the behavior of egui,
     /// but is a signal to any backend that we want the [`crate::Output::events`] read out loud.
     /// Screen readers is an experimental feature of egui, and not supported on all platforms.
     pub screen_reader: bool,
+
+    /// If `true`, all animations (collapsing headers, scroll bars, window open/close etc)
+    /// complete instantly.
+    ///
+    /// This is an accessibility option for users who are sensitive to motion.
+    pub reduce_motion: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            value_formatter: std::sync::Arc::new(crate::util::format::NumberFormatter::default()),
+            tessellation_options: Default::default(),
+            screen_reader: false,
+            reduce_motion: false,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -140,6 +165,9 @@ pub(crate) struct Focus {
     /// Give focus to this widget next frame
     id_next_frame: Option<Id>,
 
+    /// What had keyboard focus at the start of this frame, before any widget could change it?
+    id_at_begin_frame: Option<Id>,
+
     /// If set, the next widget that is interested in focus will automatically get it.
     /// Probably because the user pressed Tab.
     give_to_next: bool,
@@ -155,6 +183,43 @@ pub(crate) struct Focus {
 
     /// Set at the beginning of the frame, set to `false` when "used".
     pressed_shift_tab: bool,
+
+    /// Has the focused widget used the arrow keys this frame?
+    /// If so, they should not also move focus between the cells of a [`crate::Grid`].
+    arrow_keys_used: bool,
+
+    /// Whether each widget had focus when it last checked, during the previous frame.
+    ///
+    /// This is what `gained_focus`/`lost_focus` compare against, so that they also notice focus
+    /// that was moved after the widget was shown (e.g. by a button further down).
+    seen_previous_frame: epaint::ahash::AHashMap<Id, bool>,
+
+    /// Whether each widget had focus when it last checked, during this frame.
+    seen_this_frame: epaint::ahash::AHashMap<Id, bool>,
+
+    /// Was tab pressed this frame (even if it has been used)?
+    tab_this_frame: bool,
+
+    /// Was shift-tab pressed this frame (even if it has been used)?
+    shift_tab_this_frame: bool,
+
+    /// The focused widget uses the Enter key itself, see [`Memory::use_enter_key`].
+    enter_key_user: Option<Id>,
+
+    /// Keeps tab and shift-tab within a dialog, see [`crate::Ui::dialog_scope`].
+    trap: Option<FocusTrap>,
+}
+
+/// The widgets interested in focus within a [`crate::Ui::dialog_scope`] this frame.
+#[derive(Clone, Debug, Default)]
+struct FocusTrap {
+    /// Keep tab and shift-tab within the trap? Otherwise we just keep track of whether it has focus.
+    confine: bool,
+    first: Option<Id>,
+    last: Option<Id>,
+    contains_focus: bool,
+    /// Shift-tab was pressed on the first widget, so we should wrap around to the last one.
+    wrap_to_last: bool,
 }
 
 impl Interaction {
@@ -193,12 +258,14 @@ impl Focus {
 
     fn begin_frame(&mut self, new_input: &crate::data::input::RawInput) {
         self.id_previous_frame = self.id;
+        self.seen_previous_frame = std::mem::take(&mut self.seen_this_frame);
         if let Some(id) = self.id_next_frame.take() {
             self.id = Some(id);
         }
 
         self.pressed_tab = false;
         self.pressed_shift_tab = false;
+        self.arrow_keys_used = false;
         for event in &new_input.events {
             if matches!(
                 event,
@@ -228,6 +295,9 @@ impl Focus {
                 }
             }
         }
+        self.tab_this_frame = self.pressed_tab;
+        self.shift_tab_this_frame = self.pressed_shift_tab;
+        self.id_at_begin_frame = self.id;
     }
 
     pub(crate) fn end_frame(&mut self, used_ids: &epaint::ahash::AHashMap<Id, Rect>) {
@@ -246,6 +316,21 @@ impl Focus {
         self.id_previous_frame == Some(id)
     }
 
+    /// Did the widget have focus the last time it checked, before this frame?
+    fn had_focus_when_last_seen(&self, id: Id) -> bool {
+        self.seen_previous_frame
+            .get(&id)
+            .copied()
+            .unwrap_or_else(|| self.had_focus_last_frame(id))
+    }
+
+    /// Does the widget have focus? Remembers the answer for next frame.
+    fn see(&mut self, id: Id) -> bool {
+        let has_focus = self.id == Some(id);
+        self.seen_this_frame.insert(id, has_focus);
+        has_focus
+    }
+
     fn interested_in_focus(&mut self, id: Id) {
         if self.give_to_next && !self.had_focus_last_frame(id) {
             self.id = Some(id);
@@ -256,7 +341,10 @@ impl Focus {
                 self.give_to_next = true;
                 self.pressed_tab = false;
             } else if self.pressed_shift_tab && !self.is_focus_locked {
-                self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
+                match &mut self.trap {
+                    Some(trap) if trap.confine && trap.first.is_none() => trap.wrap_to_last = true,
+                    _ => self.id_next_frame = self.last_interested, // frame-delay so gained_focus works
+                }
                 self.pressed_shift_tab = false;
             }
         } else if self.pressed_tab && self.id == None && !self.give_to_next {
@@ -265,6 +353,59 @@ impl Focus {
         }
 
         self.last_interested = Some(id);
+        let has_focus = self.see(id);
+
+        if let Some(trap) = &mut self.trap {
+            trap.first.get_or_insert(id);
+            trap.last = Some(id);
+            trap.contains_focus |= has_focus;
+        }
+    }
+
+    fn begin_focus_trap(&mut self, confine: bool) -> bool {
+        if self.trap.is_some() {
+            false // We don't support nested traps
+        } else {
+            self.trap = Some(FocusTrap {
+                confine,
+                ..Default::default()
+            });
+            true
+        }
+    }
+
+    fn end_focus_trap(&mut self) -> bool {
+        let trap = match self.trap.take() {
+            Some(trap) => trap,
+            None => return false,
+        };
+
+        if !trap.confine {
+            trap.contains_focus
+        } else if trap.wrap_to_last {
+            // Shift-tab on the first widget:
+            self.id_next_frame = trap.last;
+            true
+        } else if self.give_to_next && trap.last.is_some() {
+            // Tab on the last widget:
+            self.id = trap.first;
+            self.give_to_next = false;
+            true
+        } else if !trap.contains_focus
+            && (self.tab_this_frame || self.shift_tab_this_frame)
+            && trap.first.is_some()
+        {
+            // Tab while the focus was outside of the trap:
+            self.id = if self.shift_tab_this_frame {
+                trap.last
+            } else {
+                trap.first
+            };
+            self.give_to_next = false;
+            true
+        } else {
+            trap.contains_focus
+        }
     }
 }
 
@@ -299,14 +440,25 @@ impl Memory {
         self.interaction.focus.id_previous_frame == Some(id)
     }
 
-    /// True if the given widget had keyboard focus last frame, but not this one.
-    pub(crate) fn lost_focus(&self, id: Id) -> bool {
-        self.had_focus_last_frame(id) && !self.has_focus(id)
+    /// True if the given widget had keyboard focus when it was last shown, but has lost it since,
+    /// e.g. to validate a field once the user is done with it.
+    ///
+    /// This is `true` for one frame. See also [`crate::Response::lost_focus`].
+    pub fn lost_focus(&mut self, id: Id) -> bool {
+        let focus = &mut self.interaction.focus;
+        let had_focus = focus.had_focus_when_last_seen(id);
+        let has_focus = focus.see(id);
+        had_focus && !has_focus
     }
 
-    /// True if the given widget has keyboard focus this frame, but didn't last frame.
-    pub(crate) fn gained_focus(&self, id: Id) -> bool {
-        !self.had_focus_last_frame(id) && self.has_focus(id)
+    /// True if the given widget has keyboard focus, but didn't when it was last shown.
+    ///
+    /// This is `true` for one frame. See also [`crate::Response::gained_focus`].
+    pub fn gained_focus(&mut self, id: Id) -> bool {
+        let focus = &mut self.interaction.focus;
+        let had_focus = focus.had_focus_when_last_seen(id);
+        let has_focus = focus.see(id);
+        !had_focus && has_focus
     }
 
     /// Does this widget have keyboard focus?
@@ -334,12 +486,18 @@ impl Memory {
         }
     }
 
-    /// Give keyboard focus to a specific widget.
+    /// Give keyboard focus to a specific widget, e.g. to focus the search box when a window opens.
+    ///
+    /// This takes precedence over any tab navigation this frame.
+    /// The widget will report [`crate::Response::gained_focus`], even if it was already shown this frame.
     /// See also [`crate::Response::request_focus`].
     #[inline(always)]
     pub fn request_focus(&mut self, id: Id) {
-        self.interaction.focus.id = Some(id);
-        self.interaction.focus.is_focus_locked = false;
+        let focus = &mut self.interaction.focus;
+        focus.id = Some(id);
+        focus.id_next_frame = None;
+        focus.give_to_next = false;
+        focus.is_focus_locked = false;
     }
 
     /// Surrender keyboard focus for a specific widget.
@@ -359,6 +517,65 @@ impl Memory {
         self.interaction.focus.interested_in_focus(id);
     }
 
+    /// The last widget that was interested in keyboard focus.
+    pub(crate) fn last_interested_in_focus(&self) -> Option<Id> {
+        self.interaction.focus.last_interested
+    }
+
+    /// Call this if the focused widget uses the arrow keys this frame, e.g. to move a text cursor.
+    ///
+    /// This stops the arrow keys from also moving focus between the cells of a [`crate::Grid`].
+    pub fn use_arrow_keys(&mut self) {
+        self.interaction.focus.arrow_keys_used = true;
+    }
+
+    /// Has the focused widget used the arrow keys this frame? See [`Self::use_arrow_keys`].
+    pub fn arrow_keys_used(&self) -> bool {
+        self.interaction.focus.arrow_keys_used
+    }
+
+    /// Call this if the focused widget uses the Enter key, e.g. to insert a newline or to click itself.
+    ///
+    /// This stops Enter from also clicking the [`crate::Ui::default_button`] of a dialog.
+    /// It only needs to be called once while the widget has focus.
+    pub fn use_enter_key(&mut self) {
+        let focus = &mut self.interaction.focus;
+        focus.enter_key_user = focus.id;
+    }
+
+    /// Undo [`Self::use_enter_key`], which is called for all focused clickable widgets.
+    /// Used by single-line `TextEdit`:s, where Enter should rather submit the dialog.
+    pub(crate) fn release_enter_key(&mut self) {
+        let focus = &mut self.interaction.focus;
+        if focus.enter_key_user == focus.id {
+            focus.enter_key_user = None;
+        }
+    }
+
+    /// Does the focused widget use the Enter key? See [`Self::use_enter_key`].
+    pub fn enter_key_used(&self) -> bool {
+        let focus = &self.interaction.focus;
+        focus.id.is_some() && focus.enter_key_user == focus.id
+    }
+
+    /// Which widget had keyboard focus at the start of this frame?
+    pub(crate) fn focus_at_begin_frame(&self) -> Option<Id> {
+        self.interaction.focus.id_at_begin_frame
+    }
+
+    /// Keep track of whether any of the widgets added until [`Self::end_focus_trap`] has keyboard focus.
+    /// If `confine` is set, also keep tab and shift-tab within them.
+    ///
+    /// Returns `false` (and does nothing) if a trap is already active.
+    pub(crate) fn begin_focus_trap(&mut self, confine: bool) -> bool {
+        self.interaction.focus.begin_focus_trap(confine)
+    }
+
+    /// Returns `true` if one of the widgets in the trap has keyboard focus.
+    pub(crate) fn end_focus_trap(&mut self) -> bool {
+        self.interaction.focus.end_focus_trap()
+    }
+
     /// Stop editing of active `TextEdit` (if any).
     #[inline(always)]
     pub fn stop_text_input(&mut self) {
@@ -390,6 +607,11 @@ impl Memory {
         self.popup == Some(popup_id) || self.everything_is_visible()
     }
 
+    /// Is any popup open, e.g. the list of a [`crate::ComboBox`]?
+    pub fn any_popup_open(&self) -> bool {
+        self.popup.is_some()
+    }
+
     pub fn open_popup(&mut self, popup_id: Id) {
         self.popup = Some(popup_id);
     }
@@ -537,6 +759,31 @@ impl Areas {
 
 // ----------------------------------------------------------------------------
 
+#[test]
+fn test_focus_changes_after_widget_is_shown() {
+    use crate::{vec2, Sense};
+
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 200.0));
+    let id = Id::new("field");
+    // Show the widget, then change focus (e.g. from a button further down):
+    let mut run = |change_focus: fn(&mut Memory, Id)| {
+        let mut changes = (false, false);
+        harness.run(|ui| {
+            let response = ui.interact(ui.max_rect(), id, Sense::click());
+            changes = (response.gained_focus(), response.lost_focus());
+            change_focus(&mut *ui.memory(), id);
+        });
+        changes
+    };
+
+    assert_eq!(run(Memory::request_focus), (false, false));
+    assert_eq!(run(|_, _| {}), (true, false));
+    assert_eq!(run(|_, _| {}), (false, false));
+    assert_eq!(run(Memory::surrender_focus), (false, false));
+    assert_eq!(run(|_, _| {}), (false, true));
+    assert_eq!(run(|_, _| {}), (false, false));
+}
+
 #[cfg(test)]
 #[test]
 fn memory_impl_send_sync() {
@@ -1,19 +1,31 @@
-//! Menu bar functionality (very basic so far).
+//! Menu bar functionality.
 //!
 //! Usage:
 //! ```
-//! fn show_menu(ui: &mut egui::Ui) {
-//!     use egui::{menu, Button};
+//! fn show_menu(ui: &mut egui::Ui, word_wrap: &mut bool, zoom: &mut u32) {
+//!     use egui::menu::{self, MenuItem};
 //!
 //!     menu::bar(ui, |ui| {
 //!         menu::menu(ui, "File", |ui| {
 //!             if ui.button("Open").clicked() {
 //!                 // ...
 //!             }
+//!             if ui.add(MenuItem::new("Save").shortcut("Ctrl+S")).clicked() {
+//!                 // ...
+//!             }
+//!             ui.add(MenuItem::new("Revert").enabled(false));
+//!         });
+//!         menu::menu(ui, "View", |ui| {
+//!             menu::checkbox_item(ui, word_wrap, "Word wrap");
+//!             ui.separator();
+//!             menu::radio_item(ui, zoom, 100, "100%");
+//!             menu::radio_item(ui, zoom, 200, "200%");
 //!         });
 //!     });
 //! }
 //! ```
+//!
+//! Clicking a menu item closes the menu. Clicking a disabled item leaves it open.
 
 use crate::{widgets::*, *};
 use epaint::Stroke;
@@ -36,6 +48,10 @@ impl BarState {
     }
 }
 
+/// Set by disabled menu items when clicked, so that the menu stays open.
+#[derive(Clone, Copy, Debug, Default)]
+struct KeepMenuOpen(bool);
+
 /// The menu bar goes well in a [`TopBottomPanel::top`],
 /// but can also be placed in a `Window`.
 /// In the latter case you may want to wrap it in `Frame`.
@@ -108,6 +124,8 @@ fn menu_impl<'c, R>(
             .fixed_pos(button_response.rect.left_bottom());
         let frame = Frame::menu(ui.style());
 
+        ui.memory().data_temp.insert(KeepMenuOpen(false));
+
         let inner = area
             .show(ui.ctx(), |ui| {
                 frame
@@ -128,8 +146,12 @@ fn menu_impl<'c, R>(
             })
             .inner;
 
+        let keep_open = ui.memory().data_temp.get_or_default::<KeepMenuOpen>().0;
+
         // TODO: this prevents sub-menus in menus. We should fix that.
-        if ui.input().key_pressed(Key::Escape) || button_response.clicked_elsewhere() {
+        if ui.input().key_pressed(Key::Escape)
+            || (button_response.clicked_elsewhere() && !keep_open)
+        {
             bar_state.open_menu = None;
         }
         Some(inner)
@@ -140,3 +162,213 @@ fn menu_impl<'c, R>(
     bar_state.save(ui.ctx(), bar_id);
     inner
 }
+
+// ----------------------------------------------------------------------------
+
+/// What to show to the left of the text of a [`MenuItem`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MenuItemCheck {
+    None,
+    Checkbox(bool),
+    Radio(bool),
+}
+
+/// An item in a [`menu`], with an optional check mark and keyboard shortcut.
+///
+/// All items in a menu leave room for a check mark, so that their texts line up.
+///
+/// See also [`checkbox_item`] and [`radio_item`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::menu::MenuItem;
+/// if ui.add(MenuItem::new("Copy").shortcut("Ctrl+C")).clicked() {
+///     // …
+/// }
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Debug)]
+pub struct MenuItem {
+    text: String,
+    shortcut: Option<String>,
+    check: MenuItemCheck,
+    enabled: bool,
+}
+
+impl MenuItem {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            shortcut: None,
+            check: MenuItemCheck::None,
+            enabled: true,
+        }
+    }
+
+    /// Show a keyboard shortcut (e.g. `"Ctrl+S"`) to the right of the text.
+    ///
+    /// This is only a label: you need to check for the key presses yourself.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn shortcut(mut self, shortcut: impl ToString) -> Self {
+        self.shortcut = Some(shortcut.to_string());
+        self
+    }
+
+    /// Show a check mark if `checked` is `true`.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.check = MenuItemCheck::Checkbox(checked);
+        self
+    }
+
+    /// Show a radio dot if `selected` is `true`.
+    pub fn radio(mut self, selected: bool) -> Self {
+        self.check = MenuItemCheck::Radio(selected);
+        self
+    }
+
+    /// If `false`, the item is grayed out and can't be clicked. Default: `true`.
+    ///
+    /// Clicking a disabled item does not close the menu.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl Widget for MenuItem {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let MenuItem {
+            text,
+            shortcut,
+            check,
+            enabled,
+        } = self;
+
+        let text_style = ui.style().override_text_style.unwrap_or(TextStyle::Button);
+        let icon_width = ui.spacing().icon_width;
+        let icon_spacing = ui.spacing().icon_spacing;
+        let button_padding = ui.spacing().button_padding;
+        let item_spacing = ui.spacing().item_spacing;
+
+        let galley = ui.fonts().layout_no_wrap(text_style, text);
+        let shortcut_galley =
+            shortcut.map(|shortcut| ui.fonts().layout_no_wrap(text_style, shortcut));
+
+        let mut desired_size = galley.size + 2.0 * button_padding;
+        desired_size.x += icon_width + icon_spacing;
+        if let Some(shortcut_galley) = &shortcut_galley {
+            desired_size.x += 2.0 * item_spacing.x + shortcut_galley.size.x;
+        }
+        desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+
+        let sense = if enabled && ui.enabled() {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let (rect, response) = ui.allocate_at_least(desired_size, sense);
+
+        if sense.click {
+            response.widget_info(|| match check {
+                MenuItemCheck::None => WidgetInfo::labeled(WidgetType::MenuItem, &galley.text),
+                MenuItemCheck::Checkbox(checked) | MenuItemCheck::Radio(checked) => {
+                    WidgetInfo::selected(WidgetType::MenuItem, checked, &galley.text)
+                }
+            });
+        } else if response.hovered() && ui.input().pointer.any_click() {
+            ui.memory().data_temp.insert(KeepMenuOpen(true));
+        }
+
+        let visuals = ui.style().interact(&response);
+        if sense.click && (response.hovered() || response.has_focus()) {
+            ui.painter().rect(
+                rect.expand(visuals.expansion),
+                visuals.corner_radius,
+                visuals.bg_fill,
+                visuals.bg_stroke,
+            );
+        }
+
+        let text_color = if sense.click {
+            ui.visuals()
+                .override_text_color
+                .unwrap_or_else(|| visuals.text_color())
+        } else {
+            ui.visuals().weak_text_color()
+        };
+
+        let (small_icon_rect, _) = ui.spacing().icon_rectangles(rect.shrink2(button_padding));
+        match check {
+            MenuItemCheck::None => {}
+            MenuItemCheck::Checkbox(checked) => {
+                if checked {
+                    ui.painter().add(Shape::line(
+                        vec![
+                            pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                            pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                            pos2(small_icon_rect.right(), small_icon_rect.top()),
+                        ],
+                        Stroke::new(visuals.fg_stroke.width, text_color),
+                    ));
+                }
+            }
+            MenuItemCheck::Radio(selected) => {
+                if selected {
+                    ui.painter().circle_filled(
+                        small_icon_rect.center(),
+                        small_icon_rect.width() / 3.0,
+                        text_color,
+                    );
+                }
+            }
+        }
+
+        let text_pos = pos2(
+            rect.left() + button_padding.x + icon_width + icon_spacing,
+            rect.center().y - 0.5 * galley.size.y,
+        );
+        ui.painter().galley(text_pos, galley, text_color);
+
+        if let Some(shortcut_galley) = shortcut_galley {
+            let shortcut_pos = pos2(
+                rect.right() - button_padding.x - shortcut_galley.size.x,
+                rect.center().y - 0.5 * shortcut_galley.size.y,
+            );
+            ui.painter().galley(
+                shortcut_pos,
+                shortcut_galley,
+                ui.visuals().weak_text_color(),
+            );
+        }
+
+        response
+    }
+}
+
+/// A menu item with a check mark, that toggles `checked` when clicked.
+#[allow(clippy::needless_pass_by_value)]
+pub fn checkbox_item(ui: &mut Ui, checked: &mut bool, text: impl ToString) -> Response {
+    let mut response = ui.add(MenuItem::new(text).checked(*checked));
+    if response.clicked() {
+        *checked = !*checked;
+        response.mark_changed();
+    }
+    response
+}
+
+/// One menu item in a radio group. Sets `*current_value = selected_value` when clicked.
+#[allow(clippy::needless_pass_by_value)]
+pub fn radio_item<Value: PartialEq>(
+    ui: &mut Ui,
+    current_value: &mut Value,
+    selected_value: Value,
+    text: impl ToString,
+) -> Response {
+    let mut response = ui.add(MenuItem::new(text).radio(*current_value == selected_value));
+    if response.clicked() && *current_value != selected_value {
+        *current_value = selected_value;
+        response.mark_changed();
+    }
+    response
+}
@@ -1,12 +1,12 @@
 use crate::{
-    emath::{Align2, Pos2, Rect, Vec2},
+    emath::{Align, Align2, Pos2, Rect, Rot2, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
     Color32, CtxRef,
 };
 use epaint::{
     mutex::Mutex,
     text::{Fonts, Galley, TextStyle},
-    Shape, Stroke,
+    Mesh, Shape, Stroke, Tessellator,
 };
 
 /// Helper to paint shapes and text to a specific region on a specific layer.
@@ -29,6 +29,13 @@ pub struct Painter {
     /// If set, all shapes will have their colors modified to be closer to this.
     /// This is used to implement grayed out interfaces.
     fade_to_color: Option<Color32>,
+
+    /// Shapes are painted above those with a lower z (on the same layer), see [`Self::with_z`].
+    z: i32,
+
+    /// If set, shapes are also clipped to this rectangle with rounded corners of the given radius,
+    /// see [`Self::with_rounded_clip`].
+    rounded_clip: Option<(Rect, f32)>,
 }
 
 impl Painter {
@@ -40,6 +47,8 @@ impl Painter {
             paint_list,
             clip_rect,
             fade_to_color: None,
+            z: 0,
+            rounded_clip: None,
         }
     }
 
@@ -52,9 +61,41 @@ impl Painter {
             layer_id,
             clip_rect: self.clip_rect,
             fade_to_color: None,
+            z: self.z,
+            rounded_clip: self.rounded_clip,
         }
     }
 
+    /// Paint on top of (or below) the other shapes on the same layer.
+    ///
+    /// Shapes with a higher `z` are painted above those with a lower `z`,
+    /// no matter in which order they were added.
+    /// Shapes with the same `z` are painted in the order they were added.
+    /// The default is `0`.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let rect = egui::Rect::from_min_size(Default::default(), egui::vec2(10.0, 10.0));
+    /// // Paint a highlight that stays on top of everything added later in the same window:
+    /// ui.painter().clone().with_z(1).rect_stroke(rect, 0.0, (2.0, egui::Color32::RED));
+    /// ```
+    #[must_use]
+    pub fn with_z(mut self, z: i32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// See [`Self::with_z`].
+    #[inline(always)]
+    pub fn z(&self) -> i32 {
+        self.z
+    }
+
+    /// See [`Self::with_z`].
+    pub fn set_z(&mut self, z: i32) {
+        self.z = z;
+    }
+
     /// redirect
     pub fn set_layer_id(&mut self, layer_id: LayerId) {
         self.layer_id = layer_id;
@@ -86,8 +127,47 @@ impl Painter {
             paint_list: self.paint_list.clone(),
             clip_rect: rect.intersect(self.clip_rect),
             fade_to_color: self.fade_to_color,
+            z: self.z,
+            rounded_clip: self.rounded_clip,
         }
     }
+
+    /// Only paint within `clip_rect` (and within the current [`Self::clip_rect`]).
+    ///
+    /// Unlike [`Self::sub_region`] this consumes the painter, so it can be chained with other `with_` calls.
+    #[must_use]
+    pub fn with_clip_rect(mut self, clip_rect: Rect) -> Self {
+        self.clip_rect = clip_rect.intersect(self.clip_rect);
+        self
+    }
+
+    /// Only paint within `rect` with its corners rounded by `corner_radius`
+    /// (and within the current [`Self::clip_rect`]).
+    ///
+    /// Use this to stop content from poking out of the corners of a rounded [`crate::Frame`].
+    ///
+    /// Shapes that reach into the rounded corners are tessellated when added,
+    /// and the vertices outside the rounded corners are moved onto their edge.
+    /// This is exact for content with many vertices along the corner (e.g. circles and rounded rectangles)
+    /// and an approximation for content with few vertices (a plain rectangle gets a beveled corner).
+    ///
+    /// Replaces any previous rounded clip of this painter.
+    #[must_use]
+    pub fn with_rounded_clip(mut self, rect: Rect, corner_radius: f32) -> Self {
+        self.clip_rect = rect.intersect(self.clip_rect);
+        self.set_rounded_clip(Some((rect, corner_radius)));
+        self
+    }
+
+    /// See [`Self::with_rounded_clip`].
+    pub fn rounded_clip(&self) -> Option<(Rect, f32)> {
+        self.rounded_clip
+    }
+
+    /// See [`Self::with_rounded_clip`].
+    pub(crate) fn set_rounded_clip(&mut self, rounded_clip: Option<(Rect, f32)>) {
+        self.rounded_clip = rounded_clip.filter(|&(_, corner_radius)| corner_radius > 0.0);
+    }
 }
 
 /// ## Accessors etc
@@ -149,6 +229,46 @@ impl Painter {
         if let Some(fade_to_color) = self.fade_to_color {
             tint_shape_towards(shape, fade_to_color);
         }
+        if let Some((rect, corner_radius)) = self.rounded_clip {
+            self.clip_to_rounded_rect(shape, rect, corner_radius);
+        }
+    }
+
+    fn clip_to_rounded_rect(&self, shape: &mut Shape, rect: Rect, corner_radius: f32) {
+        let corner_radius = corner_radius.min(0.5 * rect.width().min(rect.height()));
+        let bounds = shape.visual_bounding_rect();
+        let touches_a_corner = [
+            (rect.left_top(), Vec2::new(1.0, 1.0)),
+            (rect.right_top(), Vec2::new(-1.0, 1.0)),
+            (rect.left_bottom(), Vec2::new(1.0, -1.0)),
+            (rect.right_bottom(), Vec2::new(-1.0, -1.0)),
+        ]
+        .iter()
+        .any(|&(corner, inwards)| {
+            Rect::from_two_pos(corner, corner + corner_radius * inwards).intersects(bounds)
+        });
+        if !touches_a_corner {
+            return;
+        }
+
+        match shape {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.clip_to_rounded_rect(shape, rect, corner_radius);
+                }
+            }
+            Shape::Mesh(mesh) => clamp_to_rounded_rect(mesh, rect, corner_radius),
+            _ => {
+                let mut mesh = Mesh::default();
+                let tex_size = self.fonts().texture().size();
+                let unclipped = std::mem::replace(shape, Shape::Noop);
+                self.tessellator()
+                    .tessellate_shape(tex_size, unclipped, &mut mesh);
+                clamp_to_rounded_rect(&mut mesh, rect, corner_radius);
+                *shape = Shape::mesh(mesh);
+            }
+        }
     }
 
     /// It is up to the caller to make sure there is room for this.
@@ -156,10 +276,14 @@ impl Painter {
     /// NOTE: all coordinates are screen coordinates!
     pub fn add(&self, mut shape: Shape) -> ShapeIdx {
         if self.fade_to_color == Some(Color32::TRANSPARENT) {
-            self.paint_list.lock().add(self.clip_rect, Shape::Noop)
+            self.paint_list
+                .lock()
+                .add_with_z(self.z, self.clip_rect, Shape::Noop)
         } else {
             self.transform_shape(&mut shape);
-            self.paint_list.lock().add(self.clip_rect, shape)
+            self.paint_list
+                .lock()
+                .add_with_z(self.z, self.clip_rect, shape)
         }
     }
 
@@ -171,13 +295,15 @@ impl Painter {
             return;
         }
         if !shapes.is_empty() {
-            if self.fade_to_color.is_some() {
+            if self.fade_to_color.is_some() || self.rounded_clip.is_some() {
                 for shape in &mut shapes {
                     self.transform_shape(shape);
                 }
             }
 
-            self.paint_list.lock().extend(self.clip_rect, shapes);
+            self.paint_list
+                .lock()
+                .extend_with_z(self.z, self.clip_rect, shapes);
         }
     }
 
@@ -349,6 +475,119 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint some text, rotated `angle` radians clockwise around `pos`.
+    ///
+    /// The `anchor` is applied before the rotation, so `Align2::CENTER_CENTER`
+    /// will rotate the text around its center.
+    /// This is useful for e.g. labeling the y axis of a plot.
+    ///
+    /// Returns the bounding rectangle of the rotated text.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text_rotated(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        text: impl ToString,
+        text_style: TextStyle,
+        text_color: Color32,
+        angle: f32,
+    ) -> Rect {
+        let galley = self.layout_no_wrap(text_style, text.to_string());
+        let rect = anchor.anchor_rect(Rect::from_min_size(pos, galley.size));
+        if angle == 0.0 {
+            self.galley(rect.min, galley, text_color);
+            return rect;
+        }
+
+        let rot = Rot2::from_angle(angle);
+        let rotate = |p: Pos2| pos + rot * (p - pos);
+
+        if !galley.is_empty() {
+            let mut mesh = Mesh::default();
+            self.tessellator().tessellate_text(
+                self.fonts().texture().size(),
+                rect.min,
+                &galley,
+                text_color,
+                false,
+                &mut mesh,
+            );
+            for vertex in &mut mesh.vertices {
+                vertex.pos = rotate(vertex.pos);
+            }
+            self.add(Shape::mesh(mesh));
+        }
+
+        let mut bounds = Rect::NOTHING;
+        for &corner in &[
+            rect.left_top(),
+            rect.right_top(),
+            rect.left_bottom(),
+            rect.right_bottom(),
+        ] {
+            bounds.extend_with(rotate(corner));
+        }
+        bounds
+    }
+
+    /// Lay out some text wrapped to the width of `rect` and paint it aligned within `rect`.
+    ///
+    /// The horizontal part of `align` aligns each row of text,
+    /// and the vertical part places the whole block of text within `rect`.
+    /// The text is not clipped to `rect`, so it may overflow at the bottom.
+    ///
+    /// Returns the rectangle the text ended up covering.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn text_in_rect(
+        &self,
+        rect: Rect,
+        align: Align2,
+        text: impl ToString,
+        text_style: TextStyle,
+        text_color: Color32,
+    ) -> Rect {
+        let galley = self.layout_multiline(text_style, text.to_string(), rect.width());
+        let block_rect = align.align_size_within_rect(galley.size, rect);
+
+        let factor = match align.x() {
+            Align::Min => {
+                self.galley(block_rect.min, galley, text_color);
+                return block_rect;
+            }
+            Align::Center => 0.5,
+            Align::Max => 1.0,
+        };
+
+        let mut chars = galley.text.chars();
+        for row in &galley.rows {
+            let row_text: String = chars
+                .by_ref()
+                .take(row.char_count_including_newline())
+                .collect();
+            let row_galley = Galley {
+                text_style,
+                text: row_text,
+                rows: vec![row.clone()],
+                size: Vec2::new(row.max_x(), row.y_max),
+            };
+            let x_offset = (galley.size.x - row.max_x()) * factor;
+            self.galley(
+                block_rect.min + Vec2::new(x_offset, 0.0),
+                std::sync::Arc::new(row_galley),
+                text_color,
+            );
+        }
+        block_rect
+    }
+
+    fn tessellator(&self) -> Tessellator {
+        let pixels_per_point = self.ctx.pixels_per_point();
+        let mut options = self.ctx.memory().options.tessellation_options;
+        options.pixels_per_point = pixels_per_point;
+        options.aa_size = 1.0 / pixels_per_point;
+        Tessellator::from_options(options)
+    }
+
     /// Will line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -399,8 +638,96 @@ impl Painter {
     }
 }
 
+/// Move all vertices outside the rounded corners of `rect` onto the edge of the corners.
+fn clamp_to_rounded_rect(mesh: &mut Mesh, rect: Rect, corner_radius: f32) {
+    let centers = rect.shrink(corner_radius);
+    for vertex in &mut mesh.vertices {
+        let pos = vertex.pos;
+        let center = Pos2::new(
+            pos.x.clamp(centers.left(), centers.right()),
+            pos.y.clamp(centers.top(), centers.bottom()),
+        );
+        let offset = pos - center;
+        if offset.length() > corner_radius {
+            vertex.pos = center + corner_radius * offset.normalized();
+        }
+    }
+}
+
 fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     epaint::shape_transform::adjust_colors(shape, &|color| {
         *color = crate::color::tint_color_towards(*color, target);
     });
 }
+
+#[test]
+fn test_painter_z_order() {
+    use crate::harness::Harness;
+
+    let colors = [Color32::RED, Color32::GREEN, Color32::BLUE, Color32::YELLOW];
+    let mut harness = Harness::new(crate::vec2(100.0, 100.0));
+    let frame = harness.run_ctx(|ctx| {
+        let painter = ctx.layer_painter(LayerId::background());
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(10.0));
+        for (&color, &z) in colors.iter().zip(&[1, 0, -1, 0]) {
+            painter.clone().with_z(z).rect_filled(rect, 0.0, color);
+        }
+    });
+
+    let painted: Vec<Color32> = frame
+        .shapes
+        .iter()
+        .filter_map(|epaint::ClippedShape(_, shape)| match shape {
+            Shape::Rect { fill, .. } => Some(*fill),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        painted,
+        vec![Color32::BLUE, Color32::GREEN, Color32::YELLOW, Color32::RED]
+    );
+}
+
+#[test]
+fn test_rounded_clip() {
+    use crate::harness::Harness;
+
+    let clip = Rect::from_min_size(Pos2::new(10.0, 10.0), Vec2::splat(50.0));
+    let corner_radius = 10.0;
+    let mut harness = Harness::new(crate::vec2(100.0, 100.0));
+    let frame = harness.run_ctx(|ctx| {
+        let painter = ctx
+            .layer_painter(LayerId::background())
+            .with_rounded_clip(clip, corner_radius);
+        painter.rect_filled(clip.expand(5.0), 0.0, Color32::RED);
+        painter.circle_filled(clip.center(), 5.0, Color32::GREEN);
+    });
+
+    let shapes: Vec<&Shape> = frame
+        .shapes
+        .iter()
+        .filter(|clipped| clipped.0 == clip)
+        .map(|clipped| &clipped.1)
+        .collect();
+    assert_eq!(shapes.len(), 2);
+
+    // The big rectangle pokes into the corners, so it is tessellated and clamped:
+    let mesh = match shapes[0] {
+        Shape::Mesh(mesh) => mesh,
+        shape => panic!("Expected a mesh, got {:?}", shape),
+    };
+    let corners = [
+        clip.left_top(),
+        clip.right_top(),
+        clip.left_bottom(),
+        clip.right_bottom(),
+    ];
+    for vertex in &mesh.vertices {
+        for &corner in &corners {
+            assert!(vertex.pos.distance(corner) > 0.4 * corner_radius);
+        }
+    }
+
+    // The circle in the middle is left alone:
+    assert!(matches!(shapes[1], Shape::Circle { .. }));
+}
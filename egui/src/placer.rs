@@ -28,11 +28,22 @@ impl Placer {
         }
     }
 
+    pub(crate) fn set_grid_span(&mut self, columns: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_span(columns);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn grid(&self) -> Option<&grid::GridLayout> {
         self.grid.as_ref()
     }
 
+    #[inline(always)]
+    pub(crate) fn grid_mut(&mut self) -> Option<&mut grid::GridLayout> {
+        self.grid.as_mut()
+    }
+
     #[inline(always)]
     pub(crate) fn is_grid(&self) -> bool {
         self.grid.is_some()
@@ -1,5 +1,6 @@
 use crate::{
     emath::{lerp, Align, Pos2, Rect, Vec2},
+    style::WidgetState,
     CursorIcon, PointerButton, NUM_POINTER_BUTTONS,
 };
 use crate::{CtxRef, Id, LayerId, Sense, Ui};
@@ -50,6 +51,9 @@ pub struct Response {
     /// The widgets is being dragged
     pub(crate) dragged: bool,
 
+    /// A drag of this widget began this frame.
+    pub(crate) drag_started: bool,
+
     /// The widget was being dragged, but now it has been released.
     pub(crate) drag_released: bool,
 
@@ -65,6 +69,9 @@ pub struct Response {
     /// e.g. the slider was dragged, text was entered in a `TextEdit` etc.
     /// Always `false` for something like a `Button`.
     pub(crate) changed: bool,
+
+    /// Set by [`Ui::force_widget_state`]: look like this, whatever the actual input.
+    pub(crate) forced_state: Option<WidgetState>,
 }
 
 impl std::fmt::Debug for Response {
@@ -80,10 +87,12 @@ impl std::fmt::Debug for Response {
             clicked,
             double_clicked,
             dragged,
+            drag_started,
             drag_released,
             is_pointer_button_down_on,
             interact_pointer_pos,
             changed,
+            forced_state,
         } = self;
         f.debug_struct("Response")
             .field("layer_id", layer_id)
@@ -95,10 +104,12 @@ impl std::fmt::Debug for Response {
             .field("clicked", clicked)
             .field("double_clicked", double_clicked)
             .field("dragged", dragged)
+            .field("drag_started", drag_started)
             .field("drag_released", drag_released)
             .field("is_pointer_button_down_on", is_pointer_button_down_on)
             .field("interact_pointer_pos", interact_pointer_pos)
             .field("changed", changed)
+            .field("forced_state", forced_state)
             .finish()
     }
 }
@@ -165,14 +176,20 @@ impl Response {
     #[inline(always)]
     pub fn hovered(&self) -> bool {
         self.hovered
+            || matches!(
+                self.forced_state,
+                Some(state) if state == WidgetState::Hovered || state == WidgetState::Active
+            )
     }
 
     /// This widget has the keyboard focus (i.e. is receiving key presses).
     pub fn has_focus(&self) -> bool {
-        self.ctx.memory().has_focus(self.id)
+        self.forced_state == Some(WidgetState::Focused) || self.ctx.memory().has_focus(self.id)
     }
 
     /// True if this widget has keyboard focus this frame, but didn't last frame.
+    ///
+    /// Use [`Self::request_focus`] to give a widget focus programmatically.
     pub fn gained_focus(&self) -> bool {
         self.ctx.memory().gained_focus(self.id)
     }
@@ -228,8 +245,16 @@ impl Response {
     }
 
     /// Did a drag on this widgets begin this frame?
+    ///
+    /// This is only `true` for the frame the pointer button went down,
+    /// even if another button is pressed later in the same drag.
     pub fn drag_started(&self) -> bool {
-        self.dragged && self.ctx.input().pointer.any_pressed()
+        self.drag_started
+    }
+
+    /// Did a drag on this widget, with the given button, begin this frame?
+    pub fn drag_started_by(&self, button: PointerButton) -> bool {
+        self.drag_started && self.ctx.input().pointer.button_pressed(button)
     }
 
     /// The widget was being dragged, but now it has been released.
@@ -237,6 +262,11 @@ impl Response {
         self.drag_released
     }
 
+    /// The widget was being dragged, and now the given button has been released.
+    pub fn drag_released_by(&self, button: PointerButton) -> bool {
+        self.drag_released && self.ctx.input().pointer.button_released(button)
+    }
+
     /// If dragged, how many points were we dragged and in what direction?
     pub fn drag_delta(&self) -> Vec2 {
         if self.dragged() {
@@ -266,7 +296,7 @@ impl Response {
     /// This is true if the pointer is pressing down or dragging a widget
     #[inline(always)]
     pub fn is_pointer_button_down_on(&self) -> bool {
-        self.is_pointer_button_down_on
+        self.is_pointer_button_down_on || self.forced_state == Some(WidgetState::Active)
     }
 
     /// What the underlying data changed?
@@ -337,7 +367,44 @@ impl Response {
         self
     }
 
+    /// Show this text next to the cursor if the widget was hovered (i.e. a tooltip).
+    pub fn on_hover_text_at_pointer(self, text: impl ToString) -> Self {
+        self.on_hover_ui_at_pointer(|ui| {
+            ui.add(crate::widgets::Label::new(text));
+        })
+    }
+
     fn should_show_hover_ui(&self) -> bool {
+        self.should_show_hover_ui_after(self.ctx.style().interaction.tooltip_delay)
+    }
+
+    /// Should we show a tooltip, given that the widget must be hovered for `delay` seconds first?
+    pub(crate) fn should_show_hover_ui_after(&self, delay: f32) -> bool {
+        if delay <= 0.0 || self.ctx.memory().everything_is_visible() {
+            return self.should_show_hover_ui_now();
+        }
+
+        let hover_start_id = self.id.with("__tooltip_hover_start");
+        if !self.hovered {
+            self.ctx.memory().id_data_temp.remove(&hover_start_id);
+            return false;
+        }
+
+        let now = self.ctx.input().time;
+        let hover_start = *self
+            .ctx
+            .memory()
+            .id_data_temp
+            .get_or_insert_with(hover_start_id, || now);
+        if now - hover_start < delay as f64 {
+            self.ctx.request_repaint(); // wait for the delay to pass
+            false
+        } else {
+            self.should_show_hover_ui_now()
+        }
+    }
+
+    fn should_show_hover_ui_now(&self) -> bool {
         if self.ctx.memory().everything_is_visible() {
             true
         } else if self.hovered && self.ctx.input().pointer.has_pointer() {
@@ -402,14 +469,17 @@ impl Response {
     /// if response.clicked() { /* … */ }
     /// ```
     pub fn interact(&self, sense: Sense) -> Self {
-        self.ctx.interact_with_hovered(
+        let mut response = self.ctx.interact_with_hovered(
             self.layer_id,
             self.id,
             self.rect,
             sense,
-            self.enabled,
+            self.enabled && self.forced_state.is_none(),
             self.hovered,
-        )
+        );
+        response.enabled = self.enabled;
+        response.forced_state = self.forced_state;
+        response
     }
 
     /// Move the scroll to this UI with the specified alignment.
@@ -433,23 +503,26 @@ impl Response {
 
     /// For accessibility.
     ///
+    /// Generates the [`crate::output::OutputEvent`]:s for this widget (clicks, focus and value changes),
+    /// which can be read out loud using [`crate::Output::events_description`].
+    ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
-        let event = if self.clicked() {
-            Some(OutputEvent::Clicked(make_info()))
+        let mut events = vec![];
+        if self.clicked() {
+            events.push(OutputEvent::Clicked(make_info()));
         } else if self.double_clicked() {
-            Some(OutputEvent::DoubleClicked(make_info()))
-        } else if self.gained_focus() {
-            Some(OutputEvent::FocusGained(make_info()))
-        } else if self.changed {
-            Some(OutputEvent::ValueChanged(make_info()))
-        } else {
-            None
-        };
-        if let Some(event) = event {
-            self.ctx.output().events.push(event);
+            events.push(OutputEvent::DoubleClicked(make_info()));
         }
+        if self.gained_focus() {
+            events.push(OutputEvent::FocusGained(make_info()));
+        }
+        if self.changed {
+            // Last, so that the new value is what gets described:
+            events.push(OutputEvent::ValueChanged(make_info()));
+        }
+        self.ctx.output().events.extend(events);
     }
 }
 
@@ -458,6 +531,14 @@ impl Response {
     /// For instance `a.union(b).hovered` means "was either a or b hovered?".
     pub fn union(&self, other: Self) -> Self {
         assert!(self.ctx == other.ctx);
+        let mut clicked = self.clicked;
+        let mut double_clicked = self.double_clicked;
+        for (clicked, other) in clicked.iter_mut().zip(&other.clicked) {
+            *clicked |= other;
+        }
+        for (double_clicked, other) in double_clicked.iter_mut().zip(&other.double_clicked) {
+            *double_clicked |= other;
+        }
         crate::egui_assert!(
             self.layer_id == other.layer_id,
             "It makes no sense to combine Responses from two different layers"
@@ -470,22 +551,16 @@ impl Response {
             sense: self.sense.union(other.sense),
             enabled: self.enabled || other.enabled,
             hovered: self.hovered || other.hovered,
-            clicked: [
-                self.clicked[0] || other.clicked[0],
-                self.clicked[1] || other.clicked[1],
-                self.clicked[2] || other.clicked[2],
-            ],
-            double_clicked: [
-                self.double_clicked[0] || other.double_clicked[0],
-                self.double_clicked[1] || other.double_clicked[1],
-                self.double_clicked[2] || other.double_clicked[2],
-            ],
+            clicked,
+            double_clicked,
             dragged: self.dragged || other.dragged,
+            drag_started: self.drag_started || other.drag_started,
             drag_released: self.drag_released || other.drag_released,
             is_pointer_button_down_on: self.is_pointer_button_down_on
                 || other.is_pointer_button_down_on,
             interact_pointer_pos: self.interact_pointer_pos.or(other.interact_pointer_pos),
             changed: self.changed || other.changed,
+            forced_state: self.forced_state.or(other.forced_state),
         }
     }
 }
@@ -548,3 +623,50 @@ impl<R> InnerResponse<R> {
         Self { inner, response }
     }
 }
+
+#[test]
+fn test_drag_started_and_released() {
+    use crate::{vec2, Event};
+
+    fn run(harness: &mut crate::harness::Harness) -> Response {
+        let mut response = None;
+        harness.run(|ui| {
+            response = Some(ui.allocate_response(vec2(100.0, 100.0), Sense::click_and_drag()));
+        });
+        response.unwrap()
+    }
+
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 200.0));
+    let rect = run(&mut harness).rect;
+
+    harness.pointer_move(rect.center());
+    harness.pointer_button(rect.center(), true);
+    let response = run(&mut harness);
+    assert!(response.dragged());
+    assert!(response.drag_started());
+    assert!(response.drag_started_by(PointerButton::Primary));
+    assert!(!response.drag_started_by(PointerButton::Secondary));
+
+    harness.pointer_move(rect.center() + vec2(10.0, 0.0));
+    let response = run(&mut harness);
+    assert!(response.dragged());
+    assert!(!response.drag_started());
+
+    harness.pointer_button(rect.center() + vec2(10.0, 0.0), false);
+    let response = run(&mut harness);
+    assert!(!response.dragged());
+    assert!(response.drag_released_by(PointerButton::Primary));
+    assert!(!response.clicked(), "moved too far to be a click");
+
+    for &pressed in &[true, false] {
+        harness.event(Event::PointerButton {
+            pos: rect.center(),
+            button: PointerButton::Secondary,
+            pressed,
+            modifiers: Default::default(),
+        });
+    }
+    let response = run(&mut harness);
+    assert!(response.clicked_by(PointerButton::Secondary));
+    assert!(!response.clicked());
+}
@@ -0,0 +1,46 @@
+/// A width or height, either in points or as a fraction of the screen.
+///
+/// Anything that takes an `impl Into<Size>` also takes a plain `f32`, which is in points.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// use egui::Size::Relative;
+///
+/// // A quarter of the screen, even when the screen is resized:
+/// egui::SidePanel::left("my_left_panel")
+///     .default_width(Relative(0.25))
+///     .show(ctx, |ui| {
+///         ui.label("Hello World!");
+///     });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum Size {
+    /// In points.
+    Absolute(f32),
+
+    /// A fraction of the width or height of the screen, e.g. `0.25` for a quarter of it.
+    Relative(f32),
+}
+
+impl Size {
+    /// The size in points, given the full width or height of the screen.
+    pub fn resolve(self, full: f32) -> f32 {
+        match self {
+            Self::Absolute(points) => points,
+            Self::Relative(fraction) => fraction * full,
+        }
+    }
+
+    pub fn is_relative(self) -> bool {
+        matches!(self, Self::Relative(_))
+    }
+}
+
+impl From<f32> for Size {
+    fn from(points: f32) -> Self {
+        Self::Absolute(points)
+    }
+}
@@ -93,6 +93,9 @@ pub struct Spacing {
     /// Button size is text size plus this on each side
     pub button_padding: Vec2,
 
+    /// Padding of small buttons (see [`crate::Button::small`]), e.g. in compact toolbars.
+    pub small_button_padding: Vec2,
+
     /// Indent collapsing regions etc by this much.
     pub indent: f32,
 
@@ -128,6 +131,57 @@ pub struct Spacing {
 }
 
 impl Spacing {
+    /// Tighter spacing for data-dense tools: less padding, smaller widgets.
+    pub fn compact() -> Self {
+        Self {
+            item_spacing: vec2(6.0, 1.0),
+            window_padding: Vec2::splat(4.0),
+            button_padding: vec2(2.0, 0.0),
+            small_button_padding: vec2(2.0, 0.0),
+            indent: 14.0,
+            interact_size: vec2(32.0, 14.0),
+            icon_width: 12.0,
+            icon_spacing: 0.0,
+            scroll_bar_width: 6.0,
+            ..Self::default()
+        }
+    }
+
+    /// Roomier spacing with bigger widgets, e.g. for touch screens.
+    pub fn comfortable() -> Self {
+        Self {
+            item_spacing: vec2(10.0, 6.0),
+            window_padding: Vec2::splat(10.0),
+            button_padding: vec2(8.0, 4.0),
+            small_button_padding: vec2(6.0, 2.0),
+            indent: 28.0,
+            interact_size: vec2(48.0, 24.0),
+            icon_width: 16.0,
+            icon_spacing: 4.0,
+            scroll_bar_width: 12.0,
+            ..Self::default()
+        }
+    }
+
+    /// Scale all paddings, spacings, interact sizes and icon sizes by the same factor.
+    ///
+    /// Widths of sliders, text edits, tooltips and combo boxes are left as is.
+    #[must_use]
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            item_spacing: factor * self.item_spacing,
+            window_padding: factor * self.window_padding,
+            button_padding: factor * self.button_padding,
+            small_button_padding: factor * self.small_button_padding,
+            indent: factor * self.indent,
+            interact_size: factor * self.interact_size,
+            icon_width: factor * self.icon_width,
+            icon_spacing: factor * self.icon_spacing,
+            scroll_bar_width: factor * self.scroll_bar_width,
+            ..self.clone()
+        }
+    }
+
     /// Returns small icon rectangle and big icon rectangle
     pub fn icon_rectangles(&self, rect: Rect) -> (Rect, Rect) {
         let box_side = self.icon_width;
@@ -157,6 +211,45 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// How long a widget must be hovered before its tooltip shows, in seconds.
+    pub tooltip_delay: f32,
+
+    /// Smooth out jittery pointer movement while dragging (e.g. a finger on a touch screen).
+    ///
+    /// This is the time constant of the smoothing in seconds, so larger values are smoother but lag more.
+    /// `0.0` turns it off.
+    pub pointer_smoothing: f32,
+
+    /// While dragging, report the pointer position this many seconds ahead,
+    /// extrapolated from the pointer velocity.
+    ///
+    /// This hides some of the latency of touch screens, but overshoots a bit when the pointer stops.
+    /// `0.0` turns it off.
+    pub pointer_prediction: f32,
+
+    /// Keep a [`crate::ScrollArea`] scrolling after it is flung (dragged and released while moving).
+    pub kinetic_scrolling: bool,
+
+    /// Kinetic scrolling stops below this speed, in points per second.
+    pub scroll_stop_speed: f32,
+
+    /// How quickly kinetic scrolling slows down, in points per second squared.
+    pub scroll_friction: f32,
+
+    /// How many points to scroll for each line of [`crate::RawInput::scroll_delta_lines`],
+    /// e.g. for each notch of a mouse wheel.
+    pub scroll_line_height: f32,
+
+    /// Multiplies all scrolling, e.g. `2.0` to scroll twice as fast.
+    pub scroll_speed: f32,
+
+    /// Scroll horizontally when scrolling vertically with shift held down,
+    /// for mice without a horizontal wheel.
+    ///
+    /// This only applies to things that can scroll horizontally, e.g. a [`crate::containers::Canvas`]:
+    /// a vertical [`crate::ScrollArea`] still scrolls vertically with shift held down.
+    pub shift_scrolls_horizontally: bool,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -211,7 +304,26 @@ pub struct Visuals {
     /// Background color behind code-styled monospaced labels.
     pub code_bg_color: Color32,
 
+    /// A good color for warnings, e.g. a value getting close to a limit.
+    pub warn_fg_color: Color32,
+
+    /// A good color for errors, e.g. a value past a limit.
+    pub error_fg_color: Color32,
+
     pub window_corner_radius: f32,
+
+    /// Corner rounding of buttons.
+    /// `None` means the [`WidgetVisuals::corner_radius`] of the current interaction state.
+    pub button_corner_radius: Option<f32>,
+
+    /// Corner rounding of frames, such as [`crate::Frame::group`], menus and popups.
+    /// `None` means the [`WidgetVisuals::corner_radius`] of [`Widgets::noninteractive`].
+    pub frame_corner_radius: Option<f32>,
+
+    /// Corner rounding of scroll bars and their handles.
+    /// `None` means the [`WidgetVisuals::corner_radius`] of the current interaction state.
+    pub scroll_handle_corner_radius: Option<f32>,
+
     pub window_shadow: Shadow,
 
     pub popup_shadow: Shadow,
@@ -257,6 +369,114 @@ impl Visuals {
     pub fn window_stroke(&self) -> Stroke {
         self.widgets.noninteractive.bg_stroke
     }
+
+    /// Use square corners everywhere, e.g. to follow brand guidelines.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// ctx.set_visuals(egui::Visuals::dark().square_corners());
+    /// ```
+    pub fn square_corners(mut self) -> Self {
+        self.set_corner_radius(0.0);
+        self
+    }
+
+    /// Use the same corner rounding for all widgets, buttons, frames, windows and scroll bars.
+    pub fn set_corner_radius(&mut self, corner_radius: f32) {
+        let widgets = &mut self.widgets;
+        for widget_visuals in [
+            &mut widgets.noninteractive,
+            &mut widgets.inactive,
+            &mut widgets.hovered,
+            &mut widgets.active,
+            &mut widgets.open,
+        ]
+        .iter_mut()
+        {
+            widget_visuals.corner_radius = corner_radius;
+        }
+        self.window_corner_radius = corner_radius;
+        self.button_corner_radius = None;
+        self.frame_corner_radius = None;
+        self.scroll_handle_corner_radius = None;
+    }
+
+    /// Blend towards `other`: `t = 0` gives `self`, `t = 1` gives `other`.
+    ///
+    /// Colors and sizes are interpolated, while flags such as [`Self::dark_mode`] are taken from `other`.
+    /// This is what [`crate::Context::set_visuals_animated`] uses for smooth theme changes.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let override_text_color = match (self.override_text_color, other.override_text_color) {
+            (Some(a), Some(b)) => Some(lerp_color(a, b, t)),
+            (None, Some(b)) => Some(lerp_color(self.text_color(), b, t)),
+            (Some(a), None) if t < 1.0 => Some(lerp_color(a, other.text_color(), t)),
+            _ => None,
+        };
+
+        Self {
+            dark_mode: other.dark_mode,
+            override_text_color,
+            widgets: self.widgets.lerp(&other.widgets, t),
+            selection: Selection {
+                bg_fill: lerp_color(self.selection.bg_fill, other.selection.bg_fill, t),
+                stroke: lerp_stroke(self.selection.stroke, other.selection.stroke, t),
+            },
+            hyperlink_color: lerp_color(self.hyperlink_color, other.hyperlink_color, t),
+            faint_bg_color: lerp_color(self.faint_bg_color, other.faint_bg_color, t),
+            extreme_bg_color: lerp_color(self.extreme_bg_color, other.extreme_bg_color, t),
+            code_bg_color: lerp_color(self.code_bg_color, other.code_bg_color, t),
+            warn_fg_color: lerp_color(self.warn_fg_color, other.warn_fg_color, t),
+            error_fg_color: lerp_color(self.error_fg_color, other.error_fg_color, t),
+            window_corner_radius: lerp(self.window_corner_radius..=other.window_corner_radius, t),
+            button_corner_radius: lerp_opt(
+                self.button_corner_radius,
+                other.button_corner_radius,
+                t,
+            ),
+            frame_corner_radius: lerp_opt(self.frame_corner_radius, other.frame_corner_radius, t),
+            scroll_handle_corner_radius: lerp_opt(
+                self.scroll_handle_corner_radius,
+                other.scroll_handle_corner_radius,
+                t,
+            ),
+            window_shadow: lerp_shadow(self.window_shadow, other.window_shadow, t),
+            popup_shadow: lerp_shadow(self.popup_shadow, other.popup_shadow, t),
+            resize_corner_size: lerp(self.resize_corner_size..=other.resize_corner_size, t),
+            text_cursor_width: lerp(self.text_cursor_width..=other.text_cursor_width, t),
+            text_cursor_preview: other.text_cursor_preview,
+            clip_rect_margin: lerp(self.clip_rect_margin..=other.clip_rect_margin, t),
+            button_frame: other.button_frame,
+            collapsing_header_frame: other.collapsing_header_frame,
+        }
+    }
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
+    if t <= 0.0 {
+        a
+    } else if t >= 1.0 {
+        b
+    } else {
+        lerp_oklab(a, b, t)
+    }
+}
+
+fn lerp_opt(a: Option<f32>, b: Option<f32>, t: f32) -> Option<f32> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(lerp(a..=b, t)),
+        _ => b,
+    }
+}
+
+fn lerp_stroke(a: Stroke, b: Stroke, t: f32) -> Stroke {
+    Stroke::new(lerp(a.width..=b.width, t), lerp_color(a.color, b.color, t))
+}
+
+fn lerp_shadow(a: Shadow, b: Shadow, t: f32) -> Shadow {
+    Shadow {
+        extrusion: lerp(a.extrusion..=b.extrusion, t),
+        color: lerp_color(a.color, b.color, t),
+    }
 }
 
 /// Selected text, selected elements etc
@@ -300,6 +520,45 @@ impl Widgets {
             &self.inactive
         }
     }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            noninteractive: self.noninteractive.lerp(&other.noninteractive, t),
+            inactive: self.inactive.lerp(&other.inactive, t),
+            hovered: self.hovered.lerp(&other.hovered, t),
+            active: self.active.lerp(&other.active, t),
+            open: self.open.lerp(&other.open, t),
+        }
+    }
+}
+
+/// The visual states of an interactive widget.
+///
+/// Use with [`crate::Ui::force_widget_state`] to show how widgets look in every state,
+/// e.g. in a style editor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum WidgetState {
+    /// At rest. See [`Widgets::inactive`].
+    Inactive,
+    /// The pointer is above it. See [`Widgets::hovered`].
+    Hovered,
+    /// Being clicked or dragged. See [`Widgets::active`].
+    Active,
+    /// Has keyboard focus.
+    Focused,
+    /// Can't be interacted with, see [`crate::Ui::set_enabled`].
+    Disabled,
+}
+
+impl WidgetState {
+    pub const ALL: [WidgetState; 5] = [
+        WidgetState::Inactive,
+        WidgetState::Hovered,
+        WidgetState::Active,
+        WidgetState::Focused,
+        WidgetState::Disabled,
+    ];
 }
 
 /// bg = background, fg = foreground.
@@ -328,6 +587,16 @@ impl WidgetVisuals {
     pub fn text_color(&self) -> Color32 {
         self.fg_stroke.color
     }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            bg_fill: lerp_color(self.bg_fill, other.bg_fill, t),
+            bg_stroke: lerp_stroke(self.bg_stroke, other.bg_stroke, t),
+            corner_radius: lerp(self.corner_radius..=other.corner_radius, t),
+            fg_stroke: lerp_stroke(self.fg_stroke, other.fg_stroke, t),
+            expansion: lerp(self.expansion..=other.expansion, t),
+        }
+    }
 }
 
 /// Options for help debug egui by adding extra visualization
@@ -341,6 +610,9 @@ pub struct DebugOptions {
     /// Show which widgets make their parent higher
     pub show_expand_height: bool,
     pub show_resize: bool,
+    /// List the shapes under the pointer, with their layer, clip rectangle and the widget they belong to.
+    /// Alt-click to freeze the list at the current position.
+    pub inspect_shapes: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -366,6 +638,7 @@ impl Default for Spacing {
             item_spacing: vec2(8.0, 3.0),
             window_padding: Vec2::splat(6.0),
             button_padding: vec2(4.0, 1.0),
+            small_button_padding: vec2(4.0, 0.0),
             indent: 18.0, // match checkbox/radio-button with `button_padding.x + icon_width + icon_spacing`
             interact_size: vec2(40.0, 18.0),
             slider_width: 100.0,
@@ -386,6 +659,15 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            tooltip_delay: 0.0,
+            pointer_smoothing: 0.0,
+            pointer_prediction: 0.0,
+            kinetic_scrolling: true,
+            scroll_stop_speed: 20.0,
+            scroll_friction: 1000.0,
+            scroll_line_height: 20.0,
+            scroll_speed: 1.0,
+            shift_scrolls_horizontally: true,
         }
     }
 }
@@ -402,7 +684,12 @@ impl Visuals {
             faint_bg_color: Color32::from_gray(24),
             extreme_bg_color: Color32::from_gray(10),
             code_bg_color: Color32::from_gray(64),
+            warn_fg_color: Color32::from_rgb(255, 143, 0),
+            error_fg_color: Color32::from_rgb(255, 0, 0),
             window_corner_radius: 6.0,
+            button_corner_radius: None,
+            frame_corner_radius: None,
+            scroll_handle_corner_radius: None,
             window_shadow: Shadow::big_dark(),
             popup_shadow: Shadow::small_dark(),
             resize_corner_size: 12.0,
@@ -424,11 +711,36 @@ impl Visuals {
             faint_bg_color: Color32::from_gray(240),
             extreme_bg_color: Color32::from_gray(250),
             code_bg_color: Color32::from_gray(200),
+            warn_fg_color: Color32::from_rgb(255, 100, 0),
+            error_fg_color: Color32::from_rgb(255, 0, 0),
             window_shadow: Shadow::big_light(),
             popup_shadow: Shadow::small_light(),
             ..Self::dark()
         }
     }
+
+    /// A dark theme with maximum contrast, for users with low vision.
+    ///
+    /// Text is white on black, all widgets have a visible outline,
+    /// and hovered/focused widgets are highlighted in yellow.
+    pub fn high_contrast() -> Self {
+        Self {
+            dark_mode: true,
+            widgets: Widgets::high_contrast(),
+            selection: Selection {
+                bg_fill: Color32::from_rgb(0, 60, 160),
+                stroke: Stroke::new(2.0, Color32::YELLOW),
+            },
+            hyperlink_color: Color32::from_rgb(0, 255, 255),
+            faint_bg_color: Color32::from_gray(20),
+            extreme_bg_color: Color32::BLACK,
+            code_bg_color: Color32::from_gray(40),
+            warn_fg_color: Color32::from_rgb(255, 170, 0),
+            error_fg_color: Color32::from_rgb(255, 80, 80),
+            text_cursor_width: 3.0,
+            ..Self::dark()
+        }
+    }
 }
 
 impl Default for Visuals {
@@ -538,6 +850,46 @@ impl Widgets {
             },
         }
     }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            noninteractive: WidgetVisuals {
+                bg_fill: Color32::BLACK,                     // window background
+                bg_stroke: Stroke::new(1.0, Color32::WHITE), // separators, indentation lines, windows outlines
+                fg_stroke: Stroke::new(1.0, Color32::WHITE), // normal text color
+                corner_radius: 2.0,
+                expansion: 0.0,
+            },
+            inactive: WidgetVisuals {
+                bg_fill: Color32::from_gray(30), // button background
+                bg_stroke: Stroke::new(1.0, Color32::WHITE),
+                fg_stroke: Stroke::new(1.0, Color32::WHITE), // button text
+                corner_radius: 2.0,
+                expansion: 0.0,
+            },
+            hovered: WidgetVisuals {
+                bg_fill: Color32::from_gray(45),
+                bg_stroke: Stroke::new(2.0, Color32::YELLOW),
+                fg_stroke: Stroke::new(1.5, Color32::YELLOW),
+                corner_radius: 3.0,
+                expansion: 1.0,
+            },
+            active: WidgetVisuals {
+                bg_fill: Color32::from_gray(60),
+                bg_stroke: Stroke::new(2.0, Color32::YELLOW),
+                fg_stroke: Stroke::new(2.0, Color32::YELLOW),
+                corner_radius: 2.0,
+                expansion: 1.0,
+            },
+            open: WidgetVisuals {
+                bg_fill: Color32::BLACK,
+                bg_stroke: Stroke::new(1.0, Color32::WHITE),
+                fg_stroke: Stroke::new(1.0, Color32::WHITE),
+                corner_radius: 2.0,
+                expansion: 0.0,
+            },
+        }
+    }
 }
 
 impl Default for Widgets {
@@ -564,6 +916,7 @@ impl Style {
         } = self;
 
         visuals.light_dark_radio_buttons(ui);
+        spacing.density_radio_buttons(ui);
 
         crate::Grid::new("_options").show(ui, |ui| {
             ui.label("Default body text style:");
@@ -630,6 +983,7 @@ impl Spacing {
             item_spacing,
             window_padding,
             button_padding,
+            small_button_padding,
             indent,
             interact_size,
             slider_width,
@@ -645,6 +999,11 @@ impl Spacing {
         ui.add(slider_vec2(item_spacing, 0.0..=20.0, "Item spacing"));
         ui.add(slider_vec2(window_padding, 0.0..=20.0, "Window padding"));
         ui.add(slider_vec2(button_padding, 0.0..=20.0, "Button padding"));
+        ui.add(slider_vec2(
+            small_button_padding,
+            0.0..=20.0,
+            "Small button padding",
+        ));
         ui.add(slider_vec2(interact_size, 4.0..=60.0, "Interact size"))
             .on_hover_text("Minimum size of an interactive widget");
         ui.horizontal(|ui| {
@@ -703,6 +1062,15 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            tooltip_delay,
+            pointer_smoothing,
+            pointer_prediction,
+            kinetic_scrolling,
+            scroll_stop_speed,
+            scroll_friction,
+            scroll_line_height,
+            scroll_speed,
+            shift_scrolls_horizontally,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -712,6 +1080,51 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(
+            Slider::new(tooltip_delay, 0.0..=2.0)
+                .text("tooltip_delay")
+                .suffix(" s"),
+        );
+        ui.add(
+            Slider::new(pointer_smoothing, 0.0..=0.1)
+                .text("pointer_smoothing")
+                .suffix(" s"),
+        )
+        .on_hover_text("Smooth out jittery pointer movement while dragging");
+        ui.add(
+            Slider::new(pointer_prediction, 0.0..=0.1)
+                .text("pointer_prediction")
+                .suffix(" s"),
+        )
+        .on_hover_text("Predict where the pointer is going while dragging, to hide latency");
+        ui.checkbox(kinetic_scrolling, "Kinetic scrolling")
+            .on_hover_text("Keep scrolling after flinging a scroll area");
+        ui.add(
+            Slider::new(scroll_stop_speed, 0.0..=200.0)
+                .text("scroll_stop_speed")
+                .suffix(" points/s"),
+        );
+        ui.add(
+            Slider::new(scroll_friction, 0.0..=5000.0)
+                .text("scroll_friction")
+                .suffix(" points/s²"),
+        );
+        ui.add(
+            Slider::new(scroll_line_height, 1.0..=100.0)
+                .text("scroll_line_height")
+                .suffix(" points"),
+        )
+        .on_hover_text("How far to scroll for each notch of a mouse wheel");
+        ui.add(
+            Slider::new(scroll_speed, 0.1..=10.0)
+                .logarithmic(true)
+                .text("scroll_speed")
+                .suffix("x"),
+        );
+        ui.checkbox(shift_scrolls_horizontally, "Shift scrolls horizontally")
+            .on_hover_text(
+                "Turn the vertical mouse wheel into horizontal scrolling while shift is held",
+            );
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -749,6 +1162,20 @@ impl Widgets {
             ui.label("The style of an open combo-box or menu button");
             open.ui(ui)
         });
+        ui.collapsing("Preview", |ui| {
+            crate::Grid::new("widget_state_preview").show(ui, |ui| {
+                for state in WidgetState::ALL.iter().copied() {
+                    ui.label(format!("{:?}", state));
+                    ui.horizontal(|ui| {
+                        ui.force_widget_state(Some(state));
+                        let _ = ui.button("Button");
+                        ui.checkbox(&mut true, "Checkbox");
+                        ui.add(crate::TextEdit::singleline(&mut "Text").desired_width(60.0));
+                    });
+                    ui.end_row();
+                }
+            });
+        });
 
         // ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -781,6 +1208,19 @@ impl WidgetVisuals {
     }
 }
 
+impl Spacing {
+    /// Show radio-buttons to switch between compact, default and comfortable spacing.
+    pub fn density_radio_buttons(&mut self, ui: &mut crate::Ui) {
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.radio_value(self, Self::compact(), "Compact");
+                ui.radio_value(self, Self::default(), "Default");
+                ui.radio_value(self, Self::comfortable(), "Comfortable");
+            });
+        });
+    }
+}
+
 impl Visuals {
     /// Show radio-buttons to switch between light and dark mode.
     pub fn light_dark_radio_buttons(&mut self, ui: &mut crate::Ui) {
@@ -788,6 +1228,7 @@ impl Visuals {
             ui.horizontal(|ui| {
                 ui.radio_value(self, Self::light(), "☀ Light");
                 ui.radio_value(self, Self::dark(), "🌙 Dark");
+                ui.radio_value(self, Self::high_contrast(), "High contrast");
             });
         });
     }
@@ -826,7 +1267,12 @@ impl Visuals {
             faint_bg_color,
             extreme_bg_color,
             code_bg_color,
+            warn_fg_color,
+            error_fg_color,
             window_corner_radius,
+            button_corner_radius,
+            frame_corner_radius,
+            scroll_handle_corner_radius,
             window_shadow,
             popup_shadow,
             resize_corner_size,
@@ -851,11 +1297,18 @@ impl Visuals {
             // Common shortcuts
             ui_color(ui, &mut widgets.noninteractive.bg_fill, "Fill");
             stroke_ui(ui, &mut widgets.noninteractive.bg_stroke, "Outline");
-            ui.add(Slider::new(window_corner_radius, 0.0..=20.0).text("Rounding"));
             shadow_ui(ui, window_shadow, "Shadow");
             shadow_ui(ui, popup_shadow, "Shadow (small menus and popups)");
         });
 
+        ui.collapsing("Corner rounding", |ui| {
+            ui.add(Slider::new(window_corner_radius, 0.0..=20.0).text("Windows"));
+            corner_radius_override_ui(ui, button_corner_radius, "Buttons");
+            corner_radius_override_ui(ui, frame_corner_radius, "Frames, menus and popups");
+            corner_radius_override_ui(ui, scroll_handle_corner_radius, "Scroll bars");
+            ui.label("Without an override, the rounding of each widget state is used.");
+        });
+
         ui.collapsing("Widgets", |ui| widgets.ui(ui));
         ui.collapsing("Selection", |ui| selection.ui(ui));
 
@@ -874,6 +1327,8 @@ impl Visuals {
         });
 
         ui_color(ui, hyperlink_color, "hyperlink_color");
+        ui_color(ui, warn_fg_color, "Warnings");
+        ui_color(ui, error_fg_color, "Errors");
         ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
         ui.add(Slider::new(text_cursor_width, 0.0..=4.0).text("text_cursor_width"));
         ui.checkbox(text_cursor_preview, "Preview text cursor on hover");
@@ -882,10 +1337,29 @@ impl Visuals {
         ui.checkbox(button_frame, "Button has a frame");
         ui.checkbox(collapsing_header_frame, "Collapsing header has a frame");
 
-        ui.vertical_centered(|ui| reset_button(ui, self));
+        ui.vertical_centered(|ui| {
+            if ui.button("Square corners").clicked() {
+                self.set_corner_radius(0.0);
+            }
+            reset_button(ui, self);
+        });
     }
 }
 
+fn corner_radius_override_ui(ui: &mut Ui, corner_radius: &mut Option<f32>, text: &str) {
+    ui.horizontal(|ui| {
+        let mut is_overridden = corner_radius.is_some();
+        ui.checkbox(&mut is_overridden, text);
+        match (is_overridden, corner_radius.as_mut()) {
+            (true, Some(value)) => {
+                ui.add(Slider::new(value, 0.0..=20.0));
+            }
+            (true, None) => *corner_radius = Some(2.0),
+            (false, _) => *corner_radius = None,
+        }
+    });
+}
+
 impl DebugOptions {
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
@@ -893,6 +1367,7 @@ impl DebugOptions {
             show_expand_width: debug_expand_width,
             show_expand_height: debug_expand_height,
             show_resize: debug_resize,
+            inspect_shapes,
         } = self;
 
         ui.checkbox(debug_on_hover, "Show debug info on hover");
@@ -905,6 +1380,8 @@ impl DebugOptions {
             "Show which widgets make their parent higher",
         );
         ui.checkbox(debug_resize, "Debug Resize");
+        ui.checkbox(inspect_shapes, "List the shapes under the pointer")
+            .on_hover_text("Alt-click to freeze the list at the current position");
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
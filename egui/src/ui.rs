@@ -54,6 +54,26 @@ pub struct Ui {
     /// If false we are unresponsive to input,
     /// and all widgets will assume a gray style.
     enabled: bool,
+
+    /// See [`Self::force_widget_state`].
+    forced_widget_state: Option<WidgetState>,
+
+    /// Set inside of a [`Self::dialog_scope`].
+    dialog: Option<DialogScope>,
+}
+
+/// See [`Ui::dialog_scope`].
+#[derive(Clone, Copy, Debug)]
+struct DialogScope {
+    /// Does the dialog have keyboard focus (or does nothing)?
+    /// Only then do Enter and Escape click its buttons.
+    active: bool,
+}
+
+/// Remembered between frames, see [`Ui::dialog_scope`].
+#[derive(Clone, Copy, Debug, Default)]
+struct DialogState {
+    had_focus: bool,
 }
 
 impl Ui {
@@ -73,6 +93,8 @@ impl Ui {
             style,
             placer: Placer::new(max_rect, Layout::default()),
             enabled: true,
+            forced_widget_state: None,
+            dialog: None,
         }
     }
 
@@ -89,6 +111,8 @@ impl Ui {
             style: self.style.clone(),
             placer: Placer::new(max_rect, layout),
             enabled: self.enabled,
+            forced_widget_state: self.forced_widget_state,
+            dialog: self.dialog,
         }
     }
 
@@ -232,6 +256,44 @@ impl Ui {
         self.painter.visible()
     }
 
+    /// Make all widgets added to this `Ui` look like they are in the given state,
+    /// e.g. hovered or focused, whatever the actual input.
+    ///
+    /// This is for previewing styles, e.g. in a style editor.
+    /// The widgets will not react to any input.
+    /// [`WidgetState::Disabled`] is the same as calling `set_enabled(false)`.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// for state in egui::WidgetState::ALL.iter().copied() {
+    ///     ui.scope(|ui| {
+    ///         ui.force_widget_state(Some(state));
+    ///         ui.button(format!("{:?}", state));
+    ///     });
+    /// }
+    /// ```
+    pub fn force_widget_state(&mut self, state: Option<WidgetState>) {
+        self.forced_widget_state = state;
+        if state == Some(WidgetState::Disabled) {
+            self.set_enabled(false);
+        }
+    }
+
+    /// See [`Self::force_widget_state`].
+    pub fn forced_widget_state(&self) -> Option<WidgetState> {
+        self.forced_widget_state
+    }
+
+    /// Would anything painted in this rectangle be seen?
+    ///
+    /// `false` if the `Ui` is invisible or if `rect` is fully outside the clip rectangle,
+    /// e.g. for widgets that are scrolled out of view in a [`ScrollArea`].
+    /// Widgets use this to skip painting, so that long lists stay cheap.
+    #[inline(always)]
+    pub fn is_rect_visible(&self, rect: Rect) -> bool {
+        self.visible() && rect.intersects(self.clip_rect())
+    }
+
     /// Calling `set_visible(false)` will cause all further widgets to be invisible,
     /// yet still allocate space.
     ///
@@ -325,6 +387,52 @@ impl Ui {
     pub fn set_clip_rect(&mut self, clip_rect: Rect) {
         self.painter.set_clip_rect(clip_rect);
     }
+
+    /// Add contents that are clipped to `clip_rect` (and to the current [`Self::clip_rect`]).
+    ///
+    /// The clip rectangle is restored afterwards.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let rect = ui.available_rect_before_wrap().shrink(4.0);
+    /// ui.with_clip_rect(rect, |ui| {
+    ///     ui.label("Only the parts of this label inside `rect` are visible");
+    /// });
+    /// ```
+    pub fn with_clip_rect<R>(
+        &mut self,
+        clip_rect: Rect,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let old_clip_rect = self.clip_rect();
+        self.set_clip_rect(clip_rect.intersect(old_clip_rect));
+        let ret = add_contents(self);
+        self.set_clip_rect(old_clip_rect);
+        ret
+    }
+
+    /// Add contents that are clipped to `rect` with its corners rounded by `corner_radius`
+    /// (and to the current [`Self::clip_rect`]).
+    ///
+    /// Use this to stop e.g. images from poking out of the corners of a rounded [`Frame`].
+    /// See [`Painter::with_rounded_clip`] for how the corners are clipped.
+    ///
+    /// The clip is restored afterwards.
+    pub fn with_rounded_clip<R>(
+        &mut self,
+        rect: Rect,
+        corner_radius: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let old_clip_rect = self.clip_rect();
+        let old_rounded_clip = self.painter.rounded_clip();
+        self.set_clip_rect(rect.intersect(old_clip_rect));
+        self.painter.set_rounded_clip(Some((rect, corner_radius)));
+        let ret = add_contents(self);
+        self.set_clip_rect(old_clip_rect);
+        self.painter.set_rounded_clip(old_rounded_clip);
+        ret
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -524,15 +632,51 @@ impl Ui {
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this `Ui`.
     pub fn interact(&self, rect: Rect, id: Id, sense: Sense) -> Response {
-        self.ctx().interact(
+        let response = self.ctx().interact(
             self.clip_rect(),
             self.spacing().item_spacing,
             self.layer_id(),
             id,
             rect,
             sense,
-            self.enabled,
-        )
+            self.enabled && self.forced_widget_state.is_none(),
+        );
+        self.apply_forced_widget_state(response)
+    }
+
+    /// Check for clicks, drags and/or hover on a non-rectangular region of this `Ui`.
+    ///
+    /// Unlike [`Self::interact`], the region is not expanded to make it easier to click.
+    /// The [`Response::rect`] will be the bounding rectangle of the shape.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let (id, rect) = ui.allocate_space(egui::vec2(32.0, 32.0));
+    /// let shape = egui::HitShape::Circle {
+    ///     center: rect.center(),
+    ///     radius: 16.0,
+    /// };
+    /// let response = ui.interact_with_shape(id, &shape, egui::Sense::click());
+    /// ```
+    pub fn interact_with_shape(&self, id: Id, shape: &HitShape, sense: Sense) -> Response {
+        let response = self.ctx().interact_with_shape(
+            self.clip_rect(),
+            self.layer_id(),
+            id,
+            shape,
+            sense,
+            self.enabled && self.forced_widget_state.is_none(),
+        );
+        self.apply_forced_widget_state(response)
+    }
+
+    fn apply_forced_widget_state(&self, mut response: Response) -> Response {
+        if self.forced_widget_state.is_some() {
+            // We didn't let the widget interact with the input, but it should still look enabled:
+            response.enabled = self.enabled;
+            response.forced_state = self.forced_widget_state;
+        }
+        response
     }
 
     /// Is the pointer (mouse/touch) above this rectangle in this `Ui`?
@@ -1045,6 +1189,43 @@ impl Ui {
         Button::new(text).small().ui(self)
     }
 
+    /// A button that is also clicked by pressing Enter in a [`Self::dialog_scope`], e.g. "OK" or "Save".
+    ///
+    /// Enter is ignored while the focused widget uses it itself, e.g. a multiline [`TextEdit`] or another button.
+    /// In a dialog the button is highlighted, to show that it is the default choice.
+    /// Outside of a dialog this is just a normal button.
+    #[must_use = "You should check if the user clicked this with `if ui.default_button(…).clicked() { … } "]
+    pub fn default_button(&mut self, text: impl ToString) -> Response {
+        let mut button = Button::new(text);
+        if self.dialog.is_some() {
+            button = button.stroke(self.visuals().selection.stroke);
+        }
+        let pressed = self.dialog_key_pressed(Key::Enter) && !self.memory().enter_key_used();
+        self.add_dialog_button(button, pressed)
+    }
+
+    /// A button that is also clicked by pressing Escape in a [`Self::dialog_scope`], e.g. "Cancel".
+    ///
+    /// Escape is ignored while a popup (e.g. of a [`ComboBox`]) is open.
+    /// Outside of a dialog this is just a normal button.
+    #[must_use = "You should check if the user clicked this with `if ui.cancel_button(…).clicked() { … } "]
+    pub fn cancel_button(&mut self, text: impl ToString) -> Response {
+        let pressed = self.dialog_key_pressed(Key::Escape) && !self.memory().any_popup_open();
+        self.add_dialog_button(Button::new(text), pressed)
+    }
+
+    fn dialog_key_pressed(&self, key: Key) -> bool {
+        self.dialog.map_or(false, |dialog| dialog.active) && self.input().key_pressed(key)
+    }
+
+    fn add_dialog_button(&mut self, button: Button, pressed: bool) -> Response {
+        let mut response = self.add(button);
+        if pressed && self.enabled() {
+            response.clicked[PointerButton::Primary as usize] = true;
+        }
+        response
+    }
+
     /// Show a checkbox.
     pub fn checkbox(&mut self, checked: &mut bool, text: impl ToString) -> Response {
         Checkbox::new(checked, text).ui(self)
@@ -1164,6 +1345,37 @@ impl Ui {
     pub fn image(&mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Response {
         Image::new(texture_id, size).ui(self)
     }
+
+    /// Add a widget that edits `value`, and record its changes so they can be undone
+    /// with [`Context::undo`] and redone with [`Context::redo`].
+    ///
+    /// All changes during one interaction (e.g. one drag of a slider) become one undo step.
+    /// Undoing writes the old value back the next time this is called (i.e. next frame),
+    /// and marks the [`Response`] as changed.
+    ///
+    /// The undo history is keyed on the position of the widget in the `Ui`,
+    /// so the widget must be added in the same place each frame.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut volume = 0.5;
+    /// ui.undoable(&mut volume, |ui, volume| {
+    ///     ui.add(egui::Slider::new(volume, 0.0..=1.0))
+    /// });
+    /// if ui.input().modifiers.command && ui.input().key_pressed(egui::Key::Z) {
+    ///     ui.ctx().undo();
+    /// }
+    /// ```
+    pub fn undoable<T>(
+        &mut self,
+        value: &mut T,
+        add_widget: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> Response
+    where
+        T: Clone + PartialEq + Send + Sync + 'static,
+    {
+        crate::undo::undoable(self, value, add_widget)
+    }
 }
 
 /// # Colors
@@ -1288,6 +1500,55 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Treat the contents as a dialog, e.g. asking the user to confirm something:
+    ///
+    /// * Tab and shift-tab move the keyboard focus between the widgets of the dialog, without leaving it.
+    /// * Enter clicks the [`Self::default_button`].
+    /// * Escape clicks the [`Self::cancel_button`].
+    ///
+    /// The keys only work while the keyboard focus is in the dialog, or nowhere,
+    /// so that they don't affect other dialogs.
+    /// See also [`Window::dialog`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut name = String::new();
+    /// ui.dialog_scope(|ui| {
+    ///     ui.text_edit_singleline(&mut name);
+    ///     ui.horizontal(|ui| {
+    ///         if ui.default_button("Save").clicked() {
+    ///             // Clicked, or Enter pressed
+    ///         }
+    ///         if ui.cancel_button("Cancel").clicked() {
+    ///             // Clicked, or Escape pressed
+    ///         }
+    ///     });
+    /// });
+    /// ```
+    pub fn dialog_scope<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let id = self.id.with("dialog");
+        let active = {
+            let mut memory = self.memory();
+            memory.focus_at_begin_frame().is_none()
+                || memory
+                    .id_data_temp
+                    .get::<DialogState>(&id)
+                    .map_or(false, |state| state.had_focus)
+        };
+
+        // Only confine the focus to the dialog the user is in:
+        let trapped = self.memory().begin_focus_trap(active);
+        let inner = self.scope(|ui| {
+            ui.dialog = Some(DialogScope { active });
+            add_contents(ui)
+        });
+        let had_focus = trapped && self.memory().end_focus_trap();
+        self.memory()
+            .id_data_temp
+            .insert(id, DialogState { had_focus });
+        inner
+    }
+
     #[deprecated = "Renamed scope()"]
     pub fn wrap<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
         self.scope(add_contents)
@@ -1612,6 +1873,51 @@ impl Ui {
         self.placer.grid()
     }
 
+    pub(crate) fn grid_mut(&mut self) -> Option<&mut grid::GridLayout> {
+        self.placer.grid_mut()
+    }
+
+    /// Let the next cell of a [`Grid`] span this many columns, e.g. for a header or a [`Separator`]
+    /// across the whole grid. Spans are not remembered between rows.
+    ///
+    /// Outside of a grid this does nothing.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::Grid::new("table").num_columns(2).show(ui, |ui| {
+    ///     ui.grid_span(2);
+    ///     ui.heading("Settings");
+    ///     ui.end_row();
+    ///
+    ///     ui.label("Name");
+    ///     ui.label("Value");
+    ///     ui.end_row();
+    /// });
+    /// ```
+    pub fn grid_span(&mut self, columns: usize) {
+        self.placer.set_grid_span(columns);
+    }
+
+    /// Align the contents of the next cell of a [`Grid`] with this,
+    /// instead of with the alignment of its column (see [`Grid::column_alignment`]).
+    ///
+    /// Outside of a grid this does nothing.
+    pub fn grid_cell_alignment(&mut self, align: Align2) {
+        if let Some(grid) = self.placer.grid_mut() {
+            grid.set_next_cell_align(align);
+        }
+    }
+
+    /// Set the plain text of the next cell of a [`Grid`], for copying it with [`Grid::copy_as`].
+    /// [`Label`]s set this themselves, unless you set it first.
+    ///
+    /// Outside of a grid this does nothing.
+    pub fn grid_cell_text(&mut self, text: &str) {
+        if let Some(grid) = self.placer.grid_mut() {
+            grid.set_cell_text(text, true);
+        }
+    }
+
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
     pub fn end_row(&mut self) {
@@ -1694,3 +2000,126 @@ impl Ui {
         }
     }
 }
+
+#[test]
+fn test_widgets_outside_clip_rect_are_not_painted() {
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 100.0));
+    let mut num_shapes = |num_widgets: usize| {
+        let mut last_button_rect = Rect::NOTHING;
+        let frame = harness.run(|ui| {
+            ScrollArea::auto_sized().show(ui, |ui| {
+                for i in 0..num_widgets {
+                    ui.label(format!("Label {}", i));
+                    last_button_rect = ui.button("Button").rect;
+                }
+            });
+        });
+        (frame.shapes.len(), last_button_rect)
+    };
+
+    let (few, _) = num_shapes(20);
+    let (many, last_button_rect) = num_shapes(1000);
+    assert_eq!(few, many, "Only the visible widgets should be painted");
+    // The invisible ones still take up space:
+    assert!(last_button_rect.top() > 10_000.0, "{:?}", last_button_rect);
+}
+
+#[test]
+fn test_force_widget_state() {
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 100.0));
+    let run = |harness: &mut crate::harness::Harness| {
+        let mut responses = vec![];
+        harness.run(|ui| {
+            for state in WidgetState::ALL.iter().copied() {
+                ui.scope(|ui| {
+                    ui.force_widget_state(Some(state));
+                    responses.push((state, ui.button(format!("{:?}", state))));
+                });
+            }
+        });
+        responses
+    };
+
+    let responses = run(&mut harness);
+    for (state, response) in &responses {
+        assert_eq!(
+            response.hovered(),
+            matches!(state, WidgetState::Hovered | WidgetState::Active)
+        );
+        assert_eq!(
+            response.is_pointer_button_down_on(),
+            *state == WidgetState::Active
+        );
+        assert_eq!(response.has_focus(), *state == WidgetState::Focused);
+        assert_eq!(response.enabled(), *state != WidgetState::Disabled);
+    }
+
+    // The widgets don't react to input:
+    harness.click(responses[0].1.rect.center());
+    assert!(run(&mut harness)
+        .iter()
+        .all(|(_, response)| !response.clicked()));
+}
+
+#[test]
+fn test_dialog_scope() {
+    use crate::harness::Harness;
+
+    // Returns whether the default and cancel buttons were clicked, and the ids of all buttons.
+    fn run(harness: &mut Harness) -> ((bool, bool), Vec<Id>) {
+        let mut clicked = (false, false);
+        let mut ids = vec![];
+        harness.run(|ui| {
+            ids.push(ui.button("Before").id);
+            ui.dialog_scope(|ui| {
+                ids.push(ui.button("Other").id);
+                let ok = ui.default_button("OK");
+                let cancel = ui.cancel_button("Cancel");
+                ids.push(ok.id);
+                ids.push(cancel.id);
+                clicked = (ok.clicked(), cancel.clicked());
+            });
+            ids.push(ui.button("After").id);
+        });
+        (clicked, ids)
+    }
+    let focus = |harness: &Harness| harness.ctx().memory().focus();
+
+    let mut harness = Harness::new(vec2(400.0, 400.0));
+    let (clicked, ids) = run(&mut harness);
+    assert_eq!(clicked, (false, false));
+
+    harness.key_press(Key::Enter);
+    assert_eq!(run(&mut harness).0, (true, false));
+    harness.key_press(Key::Escape);
+    assert_eq!(run(&mut harness).0, (false, true));
+
+    // Tab moves the focus into the dialog, and then stays there:
+    harness.key_press(Key::Tab);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[1]));
+    harness.key_press(Key::Tab);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[2]));
+    harness.key_press(Key::Tab);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[3]));
+    harness.key_press(Key::Tab);
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[1]));
+
+    // Shift-tab wraps around to the last widget of the dialog:
+    harness.set_modifiers(Modifiers {
+        shift: true,
+        ..Default::default()
+    });
+    harness.key_press(Key::Tab);
+    run(&mut harness);
+    harness.set_modifiers(Default::default());
+    run(&mut harness);
+    assert_eq!(focus(&harness), Some(ids[3]));
+
+    // Enter clicks the focused button instead of the default one:
+    harness.key_press(Key::Enter);
+    assert_eq!(run(&mut harness).0, (false, true));
+}
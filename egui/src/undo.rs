@@ -0,0 +1,189 @@
+//! App-wide undo and redo of values edited with [`crate::Ui::undoable`].
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::*;
+
+type Value = Arc<dyn Any + Send + Sync>;
+
+/// One undoable change of one value.
+#[derive(Clone)]
+struct Entry {
+    /// The key given to the value by [`Ui::undoable`].
+    id: Id,
+    old: Value,
+    new: Value,
+}
+
+/// Stored in [`Memory::data_temp`].
+#[derive(Clone, Default)]
+pub(crate) struct UndoStack {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+
+    /// Changes to this value are merged into the last entry,
+    /// e.g. so that dragging a slider results in only one entry.
+    open_group: Option<Id>,
+
+    /// Values to write back the next time [`Ui::undoable`] is called with this id.
+    pending: Vec<(Id, Value)>,
+}
+
+impl UndoStack {
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.open_group = None;
+        if let Some(entry) = self.undo.pop() {
+            self.pending.push((entry.id, entry.old.clone()));
+            self.redo.push(entry);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.open_group = None;
+        if let Some(entry) = self.redo.pop() {
+            self.pending.push((entry.id, entry.new.clone()));
+            self.undo.push(entry);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_pending(&mut self, id: Id) -> Option<Value> {
+        let index = self
+            .pending
+            .iter()
+            .rposition(|(pending_id, _)| *pending_id == id)?;
+        let value = self.pending.remove(index).1;
+        self.pending.retain(|(pending_id, _)| *pending_id != id);
+        Some(value)
+    }
+
+    fn record(&mut self, id: Id, old: Value, new: Value, keep_open: bool) {
+        self.redo.clear();
+        match self.undo.last_mut() {
+            Some(last) if self.open_group == Some(id) && last.id == id => last.new = new,
+            _ => self.undo.push(Entry { id, old, new }),
+        }
+        self.open_group = if keep_open { Some(id) } else { None };
+    }
+}
+
+/// See [`Ui::undoable`].
+pub(crate) fn undoable<T>(
+    ui: &mut Ui,
+    value: &mut T,
+    add_widget: impl FnOnce(&mut Ui, &mut T) -> Response,
+) -> Response
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let id = ui.next_auto_id();
+
+    let restored = ui
+        .memory()
+        .data_temp
+        .get_mut_or_default::<UndoStack>()
+        .take_pending(id);
+    let restored = restored.and_then(|restored| restored.downcast_ref::<T>().cloned());
+    let was_restored = restored.is_some();
+    if let Some(restored) = restored {
+        *value = restored;
+    }
+
+    let old = value.clone();
+    let mut response = add_widget(ui, value);
+    let interacting = response.dragged() || response.has_focus();
+
+    {
+        let mut memory = ui.memory();
+        let stack = memory.data_temp.get_mut_or_default::<UndoStack>();
+        if *value != old {
+            stack.record(id, Arc::new(old), Arc::new(value.clone()), interacting);
+        } else if !interacting && stack.open_group == Some(id) {
+            stack.open_group = None;
+        }
+    }
+
+    if was_restored {
+        response.mark_changed();
+    }
+    response
+}
+
+#[test]
+fn test_undo_groups_drags() {
+    let mut stack = UndoStack::default();
+    let id = Id::new("value");
+    let value = |v: i32| -> Value { Arc::new(v) };
+
+    // One drag, three changes:
+    stack.record(id, value(0), value(1), true);
+    stack.record(id, value(1), value(2), true);
+    stack.record(id, value(2), value(3), false);
+    // Another, separate change:
+    stack.record(id, value(3), value(4), false);
+
+    assert!(stack.undo());
+    assert_eq!(
+        stack.take_pending(id).unwrap().downcast_ref::<i32>(),
+        Some(&3)
+    );
+    assert!(stack.undo());
+    assert_eq!(
+        stack.take_pending(id).unwrap().downcast_ref::<i32>(),
+        Some(&0)
+    );
+    assert!(!stack.undo());
+
+    assert!(stack.redo());
+    assert_eq!(
+        stack.take_pending(id).unwrap().downcast_ref::<i32>(),
+        Some(&3)
+    );
+    assert!(stack.can_redo());
+
+    stack.record(id, value(3), value(5), false);
+    assert!(!stack.can_redo());
+}
+
+#[test]
+fn test_undoable_checkbox() {
+    use crate::harness::{Harness, HarnessFrame};
+
+    let mut harness = Harness::new(vec2(200.0, 100.0));
+    let mut checked = false;
+    let run = |harness: &mut Harness, checked: &mut bool| -> HarnessFrame {
+        harness.run(|ui| {
+            ui.undoable(checked, |ui, checked| ui.checkbox(checked, "Check me"));
+        })
+    };
+
+    let frame = run(&mut harness, &mut checked);
+    harness.click(frame.contents_rect.unwrap().center());
+    run(&mut harness, &mut checked);
+    assert!(checked);
+
+    assert!(harness.ctx().undo());
+    run(&mut harness, &mut checked);
+    assert!(!checked);
+    assert!(!harness.ctx().can_undo());
+
+    assert!(harness.ctx().redo());
+    run(&mut harness, &mut checked);
+    assert!(checked);
+}
@@ -0,0 +1,302 @@
+//! Formatting of numbers and dates for display, shared by all widgets.
+//!
+//! Set a formatter for the whole app with [`crate::Context::set_value_formatter`].
+
+use std::ops::RangeInclusive;
+
+/// Turns values into human-readable text.
+///
+/// Used by [`crate::DragValue`], [`crate::Slider`] and the axis and hover labels of [`crate::plot::Plot`],
+/// so that the whole app formats numbers the same way.
+///
+/// Implement this to e.g. show dates in your own format.
+pub trait ValueFormatter: std::fmt::Debug + Send + Sync {
+    /// Format a number.
+    ///
+    /// `decimals` is the range of decimals the widget thinks is reasonable,
+    /// based on e.g. the precision of the mouse.
+    fn format_number(&self, value: f64, decimals: RangeInclusive<usize>) -> String;
+
+    /// Format a point in time, given as seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+    ///
+    /// `resolution` is how many seconds apart the points in time are that need telling apart,
+    /// e.g. the step between the ticks of a plot axis.
+    /// `utc_offset` is how many seconds the local time is ahead of UTC.
+    ///
+    /// The default implementation shows dates like `"2021-06-01"` and times of day like `"14:05:30"`.
+    fn format_time(
+        &self,
+        seconds_since_epoch: f64,
+        resolution: f64,
+        utc_offset: f64,
+        label: TimeLabel,
+    ) -> String {
+        format_time(seconds_since_epoch, resolution, utc_offset, label)
+    }
+}
+
+/// What a point in time formatted with [`ValueFormatter::format_time`] is shown as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeLabel {
+    /// The label of a tick on an axis, next to the labels of the other ticks.
+    ///
+    /// The default shows only the time of day, or the date for ticks at midnight.
+    Tick,
+
+    /// A point in time on its own, e.g. the one under the mouse pointer.
+    ///
+    /// The default shows both the date and the time of day.
+    Full,
+}
+
+/// A configurable [`ValueFormatter`] for numbers.
+///
+/// The default formats numbers exactly as egui always has:
+/// with as few decimals as needed, without thousands separators.
+///
+/// ```
+/// use egui::util::format::{NumberFormatter, ValueFormatter};
+/// let formatter = NumberFormatter {
+///     thousands_separator: Some(','),
+///     ..Default::default()
+/// };
+/// assert_eq!(formatter.format_number(12345.25, 0..=2), "12,345.25");
+///
+/// let formatter = NumberFormatter {
+///     si_prefixes: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(formatter.format_number(0.0025, 0..=2), "2.5 m");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberFormatter {
+    /// Put this between each group of three digits, e.g. `Some(',')` for `1,000,000`.
+    pub thousands_separator: Option<char>,
+
+    /// The character between the integer and the fraction. Default: `'.'`.
+    pub decimal_separator: char,
+
+    /// Always show exactly this many decimals,
+    /// ignoring what the widget asks for.
+    pub fixed_decimals: Option<usize>,
+
+    /// Use SI prefixes, e.g. `1.5 k` instead of `1500`.
+    pub si_prefixes: bool,
+}
+
+impl Default for NumberFormatter {
+    fn default() -> Self {
+        Self {
+            thousands_separator: None,
+            decimal_separator: '.',
+            fixed_decimals: None,
+            si_prefixes: false,
+        }
+    }
+}
+
+impl NumberFormatter {
+    fn format_plain(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
+        let decimals = match self.fixed_decimals {
+            Some(fixed) => fixed..=fixed,
+            None => decimals,
+        };
+
+        let text = if value == 0.0 {
+            match self.fixed_decimals {
+                // Not `value`, which could be `-0.0`:
+                Some(fixed) => format!("{:.*}", fixed, 0.0),
+                None => "0".to_owned(),
+            }
+        } else {
+            crate::emath::format_with_decimals_in_range(value, decimals)
+        };
+
+        if self.thousands_separator.is_none() && self.decimal_separator == '.' {
+            return text;
+        }
+
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = match text.find('.') {
+            Some(dot) => (&text[..dot], Some(&text[dot + 1..])),
+            None => (text, None),
+        };
+
+        let mut result = sign.to_owned();
+        for (i, digit) in integer.chars().enumerate() {
+            if let Some(separator) = self.thousands_separator {
+                if i > 0 && (integer.len() - i) % 3 == 0 {
+                    result.push(separator);
+                }
+            }
+            result.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            result.push(self.decimal_separator);
+            result += fraction;
+        }
+        result
+    }
+}
+
+impl ValueFormatter for NumberFormatter {
+    fn format_number(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
+        if self.si_prefixes && value != 0.0 && value.is_finite() {
+            const PREFIXES: [&str; 9] = ["n", "µ", "m", "", "k", "M", "G", "T", "P"];
+            let exponent = (value.abs().log10() / 3.0).floor() as i32;
+            let exponent = exponent.clamp(-3, 5);
+            let prefix = PREFIXES[(exponent + 3) as usize];
+            let mantissa = value / 1000_f64.powi(exponent);
+            let text = self.format_plain(mantissa, decimals);
+            if prefix.is_empty() {
+                text
+            } else {
+                format!("{} {}", text, prefix)
+            }
+        } else {
+            self.format_plain(value, decimals)
+        }
+    }
+}
+
+pub(crate) const MINUTE: f64 = 60.0;
+pub(crate) const HOUR: f64 = 60.0 * MINUTE;
+pub(crate) const DAY: f64 = 24.0 * HOUR;
+
+const MS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// How much of a point in time to show.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Precision {
+    Days,
+    Minutes,
+    Seconds { decimals: usize },
+}
+
+impl Precision {
+    /// Enough to tell apart two points in time `seconds` apart.
+    fn from_resolution(seconds: f64) -> Self {
+        if seconds >= DAY {
+            Self::Days
+        } else if seconds >= MINUTE {
+            Self::Minutes
+        } else if seconds >= 1.0 {
+            Self::Seconds { decimals: 0 }
+        } else {
+            // The small epsilon is so that e.g. `0.1` gets one decimal, not two.
+            let decimals = (-seconds.log10() - 1e-6).ceil().max(1.0) as usize;
+            Self::Seconds {
+                decimals: decimals.min(3),
+            }
+        }
+    }
+
+    /// The time in whole milliseconds since the epoch, in local time,
+    /// rounded down to this precision.
+    fn local_ms(self, time: f64, utc_offset: f64) -> i64 {
+        // Round to milliseconds first, so that e.g. `0.29999999` doesn't become `0.2`.
+        let ms = ((time + utc_offset) * 1000.0).round() as i64;
+        let unit = match self {
+            Self::Days => MS_PER_DAY,
+            Self::Minutes => 60_000,
+            Self::Seconds { decimals } => 10_i64.pow(3 - decimals as u32),
+        };
+        ms - ms.rem_euclid(unit)
+    }
+
+    /// `"14:05"`, `"14:05:30"` or `"14:05:30.25"`.
+    fn format_time_of_day(self, ms_of_day: i64) -> String {
+        let hours = ms_of_day / 3_600_000;
+        let minutes = ms_of_day / 60_000 % 60;
+        let seconds = ms_of_day / 1000 % 60;
+        match self {
+            Self::Days | Self::Minutes => format!("{:02}:{:02}", hours, minutes),
+            Self::Seconds { decimals: 0 } => {
+                format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+            }
+            Self::Seconds { decimals } => {
+                let fraction = format!("{:03}", ms_of_day % 1000);
+                format!(
+                    "{:02}:{:02}:{:02}.{}",
+                    hours,
+                    minutes,
+                    seconds,
+                    &fraction[..decimals]
+                )
+            }
+        }
+    }
+}
+
+/// The default [`ValueFormatter::format_time`].
+fn format_time(time: f64, resolution: f64, utc_offset: f64, label: TimeLabel) -> String {
+    let precision = Precision::from_resolution(resolution);
+    let ms = precision.local_ms(time, utc_offset);
+    let date = format_date(ms.div_euclid(MS_PER_DAY));
+    let ms_of_day = ms.rem_euclid(MS_PER_DAY);
+    if precision == Precision::Days || (label == TimeLabel::Tick && ms_of_day == 0) {
+        date
+    } else {
+        let time_of_day = precision.format_time_of_day(ms_of_day);
+        match label {
+            TimeLabel::Tick => time_of_day,
+            TimeLabel::Full => format!("{} {}", date, time_of_day),
+        }
+    }
+}
+
+/// The date `days_since_epoch` days after 1970-01-01, e.g. `"2021-06-01"`.
+pub(crate) fn format_date(days_since_epoch: i64) -> String {
+    // From Howard Hinnant's `civil_from_days`:
+    let z = days_since_epoch + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[test]
+fn test_fixed_decimals() {
+    let formatter = NumberFormatter {
+        fixed_decimals: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(formatter.format_number(1.5, 0..=6), "1.50");
+    assert_eq!(formatter.format_number(0.0, 0..=6), "0.00");
+    assert_eq!(formatter.format_number(-0.0, 0..=6), "0.00");
+    assert_eq!(NumberFormatter::default().format_number(0.0, 2..=6), "0");
+}
+
+#[test]
+fn test_format_time() {
+    let format = |time: f64, resolution: f64, utc_offset: f64, label: TimeLabel| {
+        NumberFormatter::default().format_time(time, resolution, utc_offset, label)
+    };
+    let tick =
+        |time: f64, step: f64, utc_offset: f64| format(time, step, utc_offset, TimeLabel::Tick);
+    let full = |time: f64, resolution: f64, utc_offset: f64| {
+        format(time, resolution, utc_offset, TimeLabel::Full)
+    };
+
+    let time = 1_600_000_000.0; // 2020-09-13 12:26:40 UTC
+    assert_eq!(tick(time, 10.0, 0.0), "12:26:40");
+    assert_eq!(tick(time, 20.0 * MINUTE, 0.0), "12:26");
+    assert_eq!(tick(time, 20.0 * MINUTE, 2.0 * HOUR), "14:26");
+    assert_eq!(tick(time + 0.3, 0.1, 0.0), "12:26:40.3");
+    assert_eq!(tick(time + 0.025, 0.005, 0.0), "12:26:40.025");
+    assert_eq!(tick(time, 2.0 * DAY, 0.0), "2020-09-13");
+    assert_eq!(tick(1_599_955_200.0, HOUR, 0.0), "2020-09-13"); // midnight
+
+    assert_eq!(full(time + 0.123, 0.5, 0.0), "2020-09-13 12:26:40.1");
+    assert_eq!(full(time, 120.0, 0.0), "2020-09-13 12:26");
+    assert_eq!(full(time, 2.0 * DAY, 12.0 * HOUR), "2020-09-14");
+}
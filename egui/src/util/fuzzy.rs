@@ -0,0 +1,141 @@
+//! Fuzzy matching of search queries against e.g. command names.
+//!
+//! Used by [`crate::CommandPalette`]. Use it for your own searchable lists too,
+//! and show the matched characters with [`crate::Label::highlight_chars`]:
+//!
+//! ```
+//! # let ui = &mut egui::Ui::__test();
+//! use egui::util::fuzzy_match;
+//! for name in &["Open file", "Save", "Save as"] {
+//!     if let Some(m) = fuzzy_match("sa", name) {
+//!         ui.add(egui::Label::new(name).highlight_chars(m.indices));
+//!     }
+//! }
+//! ```
+
+use crate::*;
+
+/// The result of a successful [`fuzzy_match`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FuzzyMatch {
+    /// Higher is better. Only meaningful when comparing matches of the same pattern.
+    pub score: i32,
+
+    /// The indices of the matched characters in the text (counted in `char`s, not bytes).
+    pub indices: Vec<usize>,
+}
+
+/// Does `text` contain all the characters of `pattern`, in order (ignoring case)?
+///
+/// If so, returns how good of a match it is.
+/// Consecutive characters and characters at the start of words score higher,
+/// so `"op f"` matches `"Open file"` better than it matches `"Copy from"`.
+/// Whitespace in the pattern is ignored.
+///
+/// An empty pattern matches everything with a score of zero.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    const MATCH: i32 = 16;
+    const CONSECUTIVE_BONUS: i32 = 24;
+    const WORD_START_BONUS: i32 = 32;
+    const GAP_PENALTY: i32 = 1;
+
+    let text: Vec<char> = text.chars().collect();
+    let mut result = FuzzyMatch::default();
+    let mut next = 0; // index into `text` to continue searching from
+
+    for p in pattern.chars().filter(|c| !c.is_whitespace()) {
+        let is_consecutive =
+            !result.indices.is_empty() && next < text.len() && chars_eq(text[next], p);
+        let index = if is_consecutive {
+            next
+        } else {
+            // Prefer the start of a word, but settle for any occurrence:
+            let mut candidates = (next..text.len()).filter(|&i| chars_eq(text[i], p));
+            let first = candidates.next()?;
+            std::iter::once(first)
+                .chain(candidates)
+                .find(|&i| is_word_start(&text, i))
+                .unwrap_or(first)
+        };
+
+        result.score += MATCH;
+        if result
+            .indices
+            .last()
+            .map_or(false, |&last| last + 1 == index)
+        {
+            result.score += CONSECUTIVE_BONUS;
+        }
+        if is_word_start(&text, index) {
+            result.score += WORD_START_BONUS;
+        }
+        result.score -= GAP_PENALTY * (index - next) as i32;
+
+        result.indices.push(index);
+        next = index + 1;
+    }
+
+    Some(result)
+}
+
+fn chars_eq(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_word_start(text: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let (prev, c) = (text[index - 1], text[index]);
+    (!prev.is_alphanumeric() && c.is_alphanumeric()) || (prev.is_lowercase() && c.is_uppercase())
+}
+
+/// Paint a background behind the characters at the given `char` indices of a galley painted at `pos`,
+/// e.g. the [`FuzzyMatch::indices`].
+pub(crate) fn paint_char_highlights(
+    painter: &Painter,
+    pos: Pos2,
+    galley: &epaint::Galley,
+    indices: &[usize],
+    color: Color32,
+) {
+    use epaint::text::cursor::CCursor;
+
+    let mut indices = indices.iter().copied().peekable();
+    while let Some(start) = indices.next() {
+        // Highlight runs of consecutive characters in one go:
+        let mut end = start + 1;
+        while indices.peek() == Some(&end) {
+            indices.next();
+            end += 1;
+        }
+        let start = galley.from_ccursor(CCursor::new(start));
+        let end = galley.from_ccursor(CCursor::new(end));
+        for rect in galley.selection_rects(&start, &end) {
+            painter.rect_filled(rect.translate(pos.to_vec2()), 2.0, color);
+        }
+    }
+}
+
+#[test]
+fn test_fuzzy_match() {
+    assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+    assert!(fuzzy_match("xyz", "Open file").is_none());
+    assert!(fuzzy_match("fo", "Open file").is_none());
+
+    let open_file = fuzzy_match("of", "Open file").unwrap();
+    assert_eq!(open_file.indices, vec![0, 5]);
+
+    // Word starts and consecutive characters beat scattered matches:
+    let good = fuzzy_match("op f", "Open file").unwrap();
+    let bad = fuzzy_match("op f", "Copy from").unwrap();
+    assert!(good.score > bad.score);
+
+    assert_eq!(fuzzy_match("sa", "Save all").unwrap().indices, vec![0, 1]);
+    assert_eq!(fuzzy_match("sa", "saveAll").unwrap().indices, vec![0, 1]);
+    assert_eq!(fuzzy_match("sA", "save All").unwrap().indices, vec![0, 1]);
+    assert_eq!(
+        fuzzy_match("tw", "toggle word wrap").unwrap().indices,
+        vec![0, 7]
+    );
+}
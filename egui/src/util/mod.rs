@@ -1,8 +1,11 @@
 //! Miscellaneous tools used by the rest of egui.
 
 pub(crate) mod cache;
+pub mod format;
+pub mod fuzzy;
 mod history;
 pub mod undoer;
 
 pub(crate) use cache::Cache;
+pub use fuzzy::{fuzzy_match, FuzzyMatch};
 pub use history::History;
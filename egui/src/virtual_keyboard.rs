@@ -0,0 +1,146 @@
+//! An on-screen keyboard for touch devices without a hardware keyboard.
+//!
+//! Shown when [`crate::RawInput::has_hardware_keyboard`] is `false` and a text field has focus.
+//! Pressing a key produces the same [`Event`]s as a hardware keyboard would, on the next frame.
+
+use crate::*;
+
+/// Kept in [`crate::Memory`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct VirtualKeyboard {
+    /// The text field we are typing into.
+    target: Option<Id>,
+
+    /// A key was pressed this frame.
+    used: bool,
+
+    /// Give the focus back to `target` next frame
+    /// (it lost it when the user tapped the keyboard).
+    refocus: bool,
+
+    shift: bool,
+
+    /// Events to inject at the start of the next frame.
+    pending_events: Vec<Event>,
+}
+
+impl VirtualKeyboard {
+    /// Inject the pending events into the new input.
+    ///
+    /// Returns the text field that should get its focus back.
+    pub(crate) fn begin_frame(&mut self, new_input: &mut RawInput) -> Option<Id> {
+        new_input.events.append(&mut self.pending_events);
+        if std::mem::take(&mut self.refocus) {
+            self.target
+        } else {
+            None
+        }
+    }
+
+    /// `text_input_focus`: the widget that received text input this frame, if any.
+    pub(crate) fn end_frame(&mut self, text_input_focus: Option<Id>) {
+        if self.used {
+            self.refocus = true;
+        } else {
+            self.target = text_input_focus;
+        }
+        self.used = false;
+    }
+
+    fn press_key(&mut self, key: Key) {
+        self.used = true;
+        for &pressed in &[true, false] {
+            self.pending_events.push(Event::Key {
+                key,
+                pressed,
+                modifiers: Default::default(),
+            });
+        }
+    }
+
+    fn type_char(&mut self, chr: char) {
+        self.used = true;
+        let text = if self.shift {
+            self.shift = false;
+            chr.to_uppercase().collect()
+        } else {
+            chr.to_string()
+        };
+        self.pending_events.push(Event::Text(text));
+    }
+}
+
+const ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm,."];
+
+/// Show the keyboard, if needed.
+pub(crate) fn show(ctx: &CtxRef) {
+    if ctx.input().raw.has_hardware_keyboard || ctx.memory().virtual_keyboard.target.is_none() {
+        return;
+    }
+
+    let mut keyboard = ctx.memory().virtual_keyboard.clone();
+
+    Area::new("__virtual_keyboard")
+        .order(Order::Foreground)
+        .anchor(Align2::CENTER_BOTTOM, Vec2::ZERO)
+        .show(ctx, |ui| {
+            Frame::popup(ui.style()).show(ui, |ui| {
+                keyboard_ui(ui, &mut keyboard);
+            });
+        });
+
+    if keyboard.used {
+        ctx.request_repaint();
+    }
+    ctx.memory().virtual_keyboard = keyboard;
+}
+
+fn keyboard_ui(ui: &mut Ui, keyboard: &mut VirtualKeyboard) {
+    let key_size = 2.0 * ui.spacing().interact_size.y;
+
+    ui.vertical_centered(|ui| {
+        for (i, row) in ROWS.iter().enumerate() {
+            let is_last_row = i + 1 == ROWS.len();
+            ui.horizontal(|ui| {
+                if is_last_row && key_button(ui, "⬆", key_size) {
+                    keyboard.shift = !keyboard.shift;
+                    keyboard.used = true;
+                }
+                for chr in row.chars() {
+                    let text: String = if keyboard.shift {
+                        chr.to_uppercase().collect()
+                    } else {
+                        chr.to_string()
+                    };
+                    if key_button(ui, &text, key_size) {
+                        keyboard.type_char(chr);
+                    }
+                }
+                if is_last_row && key_button(ui, "⬅", key_size) {
+                    keyboard.press_key(Key::Backspace);
+                }
+            });
+        }
+
+        ui.horizontal(|ui| {
+            if key_button(ui, "⏷", key_size) {
+                // Hide the keyboard:
+                if let Some(target) = keyboard.target.take() {
+                    ui.memory().surrender_focus(target);
+                }
+            }
+            if key_button(ui, "space", 6.0 * key_size) {
+                keyboard.type_char(' ');
+            }
+            if key_button(ui, "⮨", 2.0 * key_size) {
+                keyboard.press_key(Key::Enter);
+            }
+        });
+    });
+}
+
+fn key_button(ui: &mut Ui, text: &str, width: f32) -> bool {
+    let height = 2.0 * ui.spacing().interact_size.y;
+    ui.add_sized(vec2(width, height), Button::new(text))
+        .clicked()
+}
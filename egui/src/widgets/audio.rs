@@ -0,0 +1,583 @@
+//! Widgets for audio tools: a [`LevelMeter`] and a [`Waveform`] display.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
+
+use crate::*;
+use epaint::Mesh;
+
+/// Convert a linear amplitude to decibels. Silence is `-∞`.
+pub fn db_from_amplitude(amplitude: f32) -> f32 {
+    if amplitude > 0.0 {
+        20.0 * amplitude.log10()
+    } else {
+        f32::NEG_INFINITY
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What a [`LevelMeter`] remembers between frames.
+#[derive(Clone, Copy, Debug)]
+struct MeterState {
+    peak_db: f32,
+    rms_db: f32,
+    /// The highest recent peak, shown as a line.
+    hold_db: f32,
+    /// When `hold_db` was set.
+    hold_time: f64,
+}
+
+/// A meter showing the level of an audio signal in decibels, like on a mixing desk.
+///
+/// The RMS level is shown as a solid bar, with the peak level as a fainter bar on top of it
+/// and a line at the highest recent peak.
+/// When the level drops, the meter falls back smoothly.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let samples = [0.0_f32; 512];
+/// ui.add(egui::LevelMeter::from_samples(&samples));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct LevelMeter {
+    peak_db: f32,
+    rms_db: f32,
+    range_db: RangeInclusive<f32>,
+    warning_db: f32,
+    danger_db: f32,
+    decay_db_per_second: f32,
+    hold_seconds: f32,
+    vertical: bool,
+    desired_length: Option<f32>,
+}
+
+impl LevelMeter {
+    /// The peak and RMS levels as linear amplitudes, where `1.0` is full scale (0 dB).
+    pub fn new(peak: f32, rms: f32) -> Self {
+        Self {
+            peak_db: db_from_amplitude(peak.abs()),
+            rms_db: db_from_amplitude(rms.abs()),
+            range_db: -60.0..=0.0,
+            warning_db: -18.0,
+            danger_db: -6.0,
+            decay_db_per_second: 20.0,
+            hold_seconds: 1.5,
+            vertical: false,
+            desired_length: None,
+        }
+    }
+
+    /// Measure the peak and RMS levels of the samples played since the last frame.
+    pub fn from_samples(samples: &[f32]) -> Self {
+        let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+        let rms = if samples.is_empty() {
+            0.0
+        } else {
+            (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+        };
+        Self::new(peak, rms)
+    }
+
+    /// The levels shown, from empty to full meter. Default: `-60.0..=0.0`.
+    pub fn range_db(mut self, range_db: RangeInclusive<f32>) -> Self {
+        self.range_db = range_db;
+        self
+    }
+
+    /// The meter is yellow above `warning_db` and red above `danger_db`.
+    /// Default: `-18.0` and `-6.0`.
+    pub fn zones_db(mut self, warning_db: f32, danger_db: f32) -> Self {
+        self.warning_db = warning_db;
+        self.danger_db = danger_db;
+        self
+    }
+
+    /// How fast the meter falls back when the level drops. Default: `20.0`.
+    pub fn decay_db_per_second(mut self, decay_db_per_second: f32) -> Self {
+        self.decay_db_per_second = decay_db_per_second;
+        self
+    }
+
+    /// How long the highest peak is shown before it starts falling back. Default: `1.5`.
+    pub fn hold_seconds(mut self, hold_seconds: f32) -> Self {
+        self.hold_seconds = hold_seconds;
+        self
+    }
+
+    /// Fill the meter from the bottom up instead of from left to right.
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// The length of the meter. Default: all available space.
+    pub fn desired_length(mut self, desired_length: f32) -> Self {
+        self.desired_length = Some(desired_length);
+        self
+    }
+}
+
+impl Widget for LevelMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            peak_db,
+            rms_db,
+            range_db,
+            warning_db,
+            danger_db,
+            decay_db_per_second,
+            hold_seconds,
+            vertical,
+            desired_length,
+        } = self;
+
+        let thickness = ui.spacing().interact_size.y * 0.5;
+        let desired_size = if vertical {
+            vec2(
+                thickness,
+                desired_length.unwrap_or_else(|| ui.available_size_before_wrap().y),
+            )
+        } else {
+            vec2(
+                desired_length.unwrap_or_else(|| ui.available_size_before_wrap().x),
+                thickness,
+            )
+        };
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let (time, dt) = {
+            let input = ui.input();
+            (input.time, input.unstable_dt)
+        };
+        let min_db = *range_db.start();
+        let previous = ui
+            .memory()
+            .id_data_temp
+            .get::<MeterState>(&response.id)
+            .copied();
+        let state = match previous {
+            Some(previous) => decay(
+                previous,
+                peak_db,
+                rms_db,
+                time,
+                dt * decay_db_per_second,
+                hold_seconds,
+            ),
+            None => MeterState {
+                peak_db,
+                rms_db,
+                hold_db: peak_db,
+                hold_time: time,
+            },
+        };
+        ui.memory().id_data_temp.insert(response.id, state);
+        if state.peak_db > peak_db.max(min_db) || state.hold_db > peak_db.max(min_db) {
+            ui.ctx().request_repaint();
+        }
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let visuals = ui.visuals();
+        let painter = ui.painter();
+        painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+        // A part of the meter, from `t0` to `t1` in the `[0, 1]` range:
+        let segment = |t0: f32, t1: f32| {
+            if vertical {
+                Rect::from_x_y_ranges(
+                    rect.x_range(),
+                    lerp(rect.bottom()..=rect.top(), t1)..=lerp(rect.bottom()..=rect.top(), t0),
+                )
+            } else {
+                Rect::from_x_y_ranges(
+                    lerp(rect.left()..=rect.right(), t0)..=lerp(rect.left()..=rect.right(), t1),
+                    rect.y_range(),
+                )
+            }
+        };
+        let t_from_db = |db: f32| remap_clamp(db, range_db.clone(), 0.0..=1.0);
+
+        let zones = [
+            (f32::NEG_INFINITY, warning_db, Color32::from_rgb(0, 190, 80)),
+            (warning_db, danger_db, Color32::from_rgb(230, 200, 0)),
+            (danger_db, f32::INFINITY, Color32::from_rgb(230, 40, 40)),
+        ];
+        let peak_t = t_from_db(state.peak_db);
+        let rms_t = t_from_db(state.rms_db);
+        for &(zone_start, zone_end, color) in &zones {
+            let (zone_start, zone_end) = (t_from_db(zone_start), t_from_db(zone_end));
+            if rms_t > zone_start {
+                painter.rect_filled(segment(zone_start, rms_t.min(zone_end)), 0.0, color);
+            }
+            if peak_t > rms_t.max(zone_start) {
+                let faint = color.linear_multiply(0.4);
+                painter.rect_filled(
+                    segment(rms_t.max(zone_start), peak_t.min(zone_end)),
+                    0.0,
+                    faint,
+                );
+            }
+        }
+
+        if state.hold_db.is_finite() && state.hold_db > min_db {
+            let hold_t = t_from_db(state.hold_db);
+            let color = zones
+                .iter()
+                .find(|(_, zone_end, _)| state.hold_db < *zone_end)
+                .map_or(Color32::WHITE, |(_, _, color)| *color);
+            let width = 2.0 / rect.size()[vertical as usize].max(1.0);
+            painter.rect_filled(segment(hold_t - width, hold_t), 0.0, color);
+        }
+
+        response
+    }
+}
+
+/// Let the meter fall back towards the new levels, by at most `max_fall_db`.
+fn decay(
+    previous: MeterState,
+    peak_db: f32,
+    rms_db: f32,
+    time: f64,
+    max_fall_db: f32,
+    hold_seconds: f32,
+) -> MeterState {
+    let fall = |previous_db: f32, db: f32| {
+        if previous_db.is_finite() {
+            db.max(previous_db - max_fall_db)
+        } else {
+            db
+        }
+    };
+    let (hold_db, hold_time) = if peak_db >= previous.hold_db {
+        (peak_db, time)
+    } else if time - previous.hold_time < hold_seconds as f64 {
+        (previous.hold_db, previous.hold_time)
+    } else {
+        (fall(previous.hold_db, peak_db), previous.hold_time)
+    };
+    MeterState {
+        peak_db: fall(previous.peak_db, peak_db),
+        rms_db: fall(previous.rms_db, rms_db),
+        hold_db,
+        hold_time,
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+enum WaveformKind {
+    Samples,
+    Spectrum { log_frequency: bool },
+}
+
+/// Meshes of recently shown [`Waveform`]s, so they are only rebuilt when their data changes.
+///
+/// Stored in [`Memory::data_temp`].
+#[derive(Clone, Default)]
+struct WaveformCache {
+    meshes: HashMap<Id, CachedMesh>,
+}
+
+#[derive(Clone)]
+struct CachedMesh {
+    key: u64,
+    /// Relative to the top left corner of the widget.
+    mesh: Mesh,
+    last_used: f64,
+}
+
+/// Shows a buffer of audio samples as a waveform, or the magnitudes of a spectrum.
+///
+/// The data is drawn as one column per point of width,
+/// so large buffers (e.g. several seconds of audio) are shown at the same cost as small ones.
+/// The resulting mesh is cached until the data or the size of the widget changes.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let samples = [0.0_f32; 4096];
+/// # let magnitudes = [0.0_f32; 2048];
+/// ui.add(egui::Waveform::samples(&samples));
+/// ui.add(egui::Waveform::spectrum(&magnitudes));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Waveform<'a> {
+    data: &'a [f32],
+    kind: WaveformKind,
+    range_db: RangeInclusive<f32>,
+    desired_size: Option<Vec2>,
+    color: Option<Color32>,
+}
+
+impl<'a> Waveform<'a> {
+    /// Audio samples in the `[-1, 1]` range.
+    pub fn samples(samples: &'a [f32]) -> Self {
+        Self {
+            data: samples,
+            kind: WaveformKind::Samples,
+            range_db: -80.0..=0.0,
+            desired_size: None,
+            color: None,
+        }
+    }
+
+    /// Linear magnitudes of a spectrum (e.g. from an FFT), from 0 Hz up to the Nyquist frequency.
+    ///
+    /// They are shown in decibels (see [`Self::range_db`]) on a logarithmic frequency axis
+    /// (see [`Self::log_frequency`]).
+    pub fn spectrum(magnitudes: &'a [f32]) -> Self {
+        Self {
+            kind: WaveformKind::Spectrum {
+                log_frequency: true,
+            },
+            ..Self::samples(magnitudes)
+        }
+    }
+
+    /// For [`Self::spectrum`]: the range of levels shown. Default: `-80.0..=0.0`.
+    pub fn range_db(mut self, range_db: RangeInclusive<f32>) -> Self {
+        self.range_db = range_db;
+        self
+    }
+
+    /// For [`Self::spectrum`]: use a logarithmic frequency axis. Default: `true`.
+    pub fn log_frequency(mut self, log: bool) -> Self {
+        if let WaveformKind::Spectrum { log_frequency } = &mut self.kind {
+            *log_frequency = log;
+        }
+        self
+    }
+
+    /// Default: all available width, and four times [`crate::style::Spacing::interact_size`]`.y`.
+    pub fn desired_size(mut self, desired_size: Vec2) -> Self {
+        self.desired_size = Some(desired_size);
+        self
+    }
+
+    /// Default: the color of selected text.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl<'a> Widget for Waveform<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            data,
+            kind,
+            range_db,
+            desired_size,
+            color,
+        } = self;
+
+        let desired_size = desired_size.unwrap_or_else(|| {
+            vec2(
+                ui.available_size_before_wrap().x,
+                4.0 * ui.spacing().interact_size.y,
+            )
+        });
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let visuals = ui.visuals();
+        let color = color.unwrap_or(visuals.selection.stroke.color);
+        ui.painter().rect_filled(
+            rect,
+            visuals.widgets.noninteractive.corner_radius,
+            visuals.extreme_bg_color,
+        );
+        if data.is_empty() {
+            return response;
+        }
+
+        let key = {
+            let mut hasher = std::collections::hash_map::DefaultHasher::default();
+            for value in data {
+                value.to_bits().hash(&mut hasher);
+            }
+            kind.hash(&mut hasher);
+            range_db.start().to_bits().hash(&mut hasher);
+            range_db.end().to_bits().hash(&mut hasher);
+            rect.width().to_bits().hash(&mut hasher);
+            rect.height().to_bits().hash(&mut hasher);
+            color.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let time = ui.input().time;
+        let mut mesh = {
+            let mut memory = ui.memory();
+            let cache = memory.data_temp.get_mut_or_default::<WaveformCache>();
+            let cached = cache
+                .meshes
+                .get_mut(&response.id)
+                .filter(|cached| cached.key == key)
+                .map(|cached| {
+                    cached.last_used = time;
+                    cached.mesh.clone()
+                });
+            match cached {
+                Some(mesh) => mesh,
+                None => {
+                    let mesh = match kind {
+                        WaveformKind::Samples => {
+                            tessellate_columns(rect.size(), color, |f0, f1| {
+                                let (min, max) = envelope(data, f0, f1);
+                                let half_height = 0.5 * rect.height();
+                                let top = half_height * (1.0 - max.clamp(-1.0, 1.0));
+                                let bottom = half_height * (1.0 - min.clamp(-1.0, 1.0));
+                                (top, bottom)
+                            })
+                        }
+                        WaveformKind::Spectrum { log_frequency } => {
+                            // Skip the 0 Hz bin on a logarithmic axis:
+                            let last_bin = (data.len() - 1).max(1) as f32;
+                            let to_fraction = |f: f32| {
+                                if log_frequency {
+                                    last_bin.powf(f) / last_bin
+                                } else {
+                                    f
+                                }
+                            };
+                            tessellate_columns(rect.size(), color, |f0, f1| {
+                                let (f0, f1) = (to_fraction(f0), to_fraction(f1));
+                                let (_, max) = envelope(data, f0, f1);
+                                let t = remap_clamp(
+                                    db_from_amplitude(max).max(*range_db.start()),
+                                    range_db.clone(),
+                                    0.0..=1.0,
+                                );
+                                (rect.height() * (1.0 - t), rect.height())
+                            })
+                        }
+                    };
+                    cache
+                        .meshes
+                        .retain(|_, cached| time - cached.last_used < 10.0);
+                    cache.meshes.insert(
+                        response.id,
+                        CachedMesh {
+                            key,
+                            mesh: mesh.clone(),
+                            last_used: time,
+                        },
+                    );
+                    mesh
+                }
+            }
+        };
+
+        mesh.translate(rect.min.to_vec2());
+        ui.painter().add(Shape::mesh(mesh));
+
+        response
+    }
+}
+
+/// One column per point of `size.x`.
+///
+/// `extent` is given the range of the column as fractions of the full width,
+/// and returns the top and bottom of the column in points.
+fn tessellate_columns(
+    size: Vec2,
+    color: Color32,
+    mut extent: impl FnMut(f32, f32) -> (f32, f32),
+) -> Mesh {
+    let num_columns = size.x.ceil().at_least(1.0) as usize;
+    let column_width = size.x / num_columns as f32;
+    let mut mesh = Mesh::default();
+    mesh.reserve_triangles(2 * num_columns);
+    mesh.reserve_vertices(4 * num_columns);
+    for i in 0..num_columns {
+        let f0 = i as f32 / num_columns as f32;
+        let f1 = (i + 1) as f32 / num_columns as f32;
+        let (top, bottom) = extent(f0, f1);
+        // Silence should still show as a thin line:
+        let (top, bottom) = if bottom - top < 1.0 {
+            let middle = 0.5 * (top + bottom);
+            (middle - 0.5, middle + 0.5)
+        } else {
+            (top, bottom)
+        };
+        let x = i as f32 * column_width;
+        mesh.add_colored_rect(
+            Rect::from_min_max(pos2(x, top), pos2(x + column_width, bottom)),
+            color,
+        );
+    }
+    mesh
+}
+
+/// The smallest and largest value of `data` between the fractions `f0` and `f1` of its length,
+/// interpolating between values.
+fn envelope(data: &[f32], f0: f32, f1: f32) -> (f32, f32) {
+    let last = (data.len() - 1) as f32;
+    let sample = |position: f32| {
+        let i = position.floor() as usize;
+        match (data.get(i), data.get(i + 1)) {
+            (Some(&a), Some(&b)) => lerp(a..=b, position - i as f32),
+            (Some(&a), None) => a,
+            _ => 0.0,
+        }
+    };
+    let (p0, p1) = (f0.clamp(0.0, 1.0) * last, f1.clamp(0.0, 1.0) * last);
+    let (a, b) = (sample(p0), sample(p1));
+    let mut min = a.min(b);
+    let mut max = a.max(b);
+    let (first, last) = (p0.ceil() as usize, p1.floor() as usize);
+    if first <= last {
+        for &value in &data[first..=last] {
+            min = min.min(value);
+            max = max.max(value);
+        }
+    }
+    (min, max)
+}
+
+#[test]
+fn test_envelope() {
+    let data = [0.0, 1.0, -1.0, 0.0, 0.5];
+    assert_eq!(envelope(&data, 0.0, 1.0), (-1.0, 1.0));
+    assert_eq!(envelope(&data, 0.75, 1.0), (0.0, 0.5));
+    // Between two samples:
+    assert_eq!(envelope(&data, 0.0, 0.125), (0.0, 0.5));
+}
+
+#[test]
+fn test_level_meter_decays() {
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 100.0)).dt(0.1);
+    let mut id = None;
+    let mut run = |harness: &mut crate::harness::Harness, peak: f32| {
+        harness.run(|ui| {
+            id = Some(ui.add(LevelMeter::new(peak, peak)).id);
+        });
+        harness
+            .ctx()
+            .memory()
+            .id_data_temp
+            .get::<MeterState>(&id.unwrap())
+            .copied()
+            .unwrap()
+    };
+
+    let state = run(&mut harness, 1.0);
+    assert_eq!(state.peak_db as i32, 0);
+
+    // The default decay is 20 dB per second:
+    let state = run(&mut harness, 0.0);
+    assert_eq!(state.peak_db.round() as i32, -2);
+    assert_eq!(state.rms_db.round() as i32, -2);
+    assert_eq!(state.hold_db as i32, 0);
+
+    let state = run(&mut harness, 0.0);
+    assert_eq!(state.peak_db.round() as i32, -4);
+}
@@ -1,6 +1,23 @@
 use crate::*;
 
-/// Clickable button with text.
+/// Where to put the image of a [`Button`], relative to its text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImagePosition {
+    /// The image goes to the left of the text.
+    Left,
+    /// The image goes to the right of the text.
+    Right,
+    /// The image goes above the text, e.g. for big toolbar buttons.
+    Above,
+}
+
+impl Default for ImagePosition {
+    fn default() -> Self {
+        Self::Left
+    }
+}
+
+/// Clickable button with text and an optional image.
 ///
 /// See also [`Ui::button`].
 ///
@@ -9,6 +26,15 @@ use crate::*;
 /// if ui.add(egui::Button::new("Click mew")).clicked() {
 ///     do_stuff();
 /// }
+///
+/// // A toolbar button with an icon above the text:
+/// # let texture_id = egui::TextureId::Egui;
+/// ui.add(
+///     egui::Button::new("Save")
+///         .image(texture_id, [16.0, 16.0])
+///         .image_position(egui::widgets::ImagePosition::Above)
+///         .min_size(egui::vec2(48.0, 48.0)),
+/// );
 /// # fn do_stuff() {}
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
@@ -19,11 +45,14 @@ pub struct Button {
     /// None means default for interact
     fill: Option<Color32>,
     stroke: Option<Stroke>,
+    corner_radius: Option<f32>,
     sense: Sense,
     small: bool,
     frame: Option<bool>,
     wrap: Option<bool>,
     min_size: Vec2,
+    image: Option<widgets::Image>,
+    image_position: ImagePosition,
 }
 
 impl Button {
@@ -35,11 +64,14 @@ impl Button {
             text_style: None,
             fill: None,
             stroke: None,
+            corner_radius: None,
             sense: Sense::click(),
             small: false,
             frame: None,
             wrap: None,
             min_size: Vec2::ZERO,
+            image: None,
+            image_position: ImagePosition::Left,
         }
     }
 
@@ -74,7 +106,16 @@ impl Button {
         self
     }
 
-    /// Make this a small button, suitable for embedding into text.
+    /// Override the corner rounding of this button.
+    /// See also [`crate::style::Visuals::button_corner_radius`].
+    pub fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Make this a small button, suitable for embedding into text or compact toolbars.
+    ///
+    /// The padding is taken from [`crate::style::Spacing::small_button_padding`].
     pub fn small(mut self) -> Self {
         self.text_style = Some(TextStyle::Body);
         self.small = true;
@@ -116,10 +157,26 @@ impl Button {
         self
     }
 
-    pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
+    /// The button will be at least this large, e.g. to give all buttons in a toolbar the same size.
+    pub fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
     }
+
+    /// Show an image (e.g. an icon) next to the text.
+    ///
+    /// Use [`Self::image_position`] to choose where it goes.
+    /// Use an empty text for a button with only an image.
+    pub fn image(mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Self {
+        self.image = Some(widgets::Image::new(texture_id, size));
+        self
+    }
+
+    /// Where to put the image relative to the text. Default: [`ImagePosition::Left`].
+    pub fn image_position(mut self, image_position: ImagePosition) -> Self {
+        self.image_position = image_position;
+        self
+    }
 }
 
 impl Button {
@@ -130,11 +187,14 @@ impl Button {
             text_style,
             fill,
             stroke,
+            corner_radius,
             sense,
             small,
             frame,
             wrap,
             min_size,
+            image,
+            image_position,
         } = self;
 
         let frame = frame.unwrap_or_else(|| ui.visuals().button_frame);
@@ -143,11 +203,22 @@ impl Button {
             .or(ui.style().override_text_style)
             .unwrap_or(TextStyle::Button);
 
-        let mut button_padding = ui.spacing().button_padding;
-        if small {
-            button_padding.y = 0.0;
+        let button_padding = if small {
+            ui.spacing().small_button_padding
+        } else {
+            ui.spacing().button_padding
+        };
+        let mut total_extra = button_padding + button_padding;
+
+        let image_size = image.as_ref().map_or(Vec2::ZERO, |image| image.size());
+        let image_spacing = if image.is_some() && !text.is_empty() {
+            ui.spacing().icon_spacing.max(button_padding.x)
+        } else {
+            0.0
+        };
+        if image_position != ImagePosition::Above {
+            total_extra.x += image_size.x + image_spacing;
         }
-        let total_extra = button_padding + button_padding;
 
         let wrap = wrap.unwrap_or_else(|| ui.wrap_text());
         let galley = if wrap {
@@ -157,7 +228,18 @@ impl Button {
             ui.fonts().layout_no_wrap(text_style, text)
         };
 
-        let mut desired_size = galley.size + 2.0 * button_padding;
+        let content_size = match image_position {
+            ImagePosition::Left | ImagePosition::Right => vec2(
+                image_size.x + image_spacing + galley.size.x,
+                image_size.y.max(galley.size.y),
+            ),
+            ImagePosition::Above => vec2(
+                image_size.x.max(galley.size.x),
+                image_size.y + image_spacing + galley.size.y,
+            ),
+        };
+
+        let mut desired_size = content_size + 2.0 * button_padding;
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
@@ -166,22 +248,56 @@ impl Button {
         let (rect, response) = ui.allocate_at_least(desired_size, sense);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, &galley.text));
 
-        if ui.clip_rect().intersects(rect) {
-            let visuals = ui.style().interact(&response);
-            let text_pos = ui
+        if ui.is_rect_visible(rect) {
+            let visuals = *ui.style().interact(&response);
+            let content_rect = ui
                 .layout()
-                .align_size_within_rect(galley.size, rect.shrink2(button_padding))
-                .min;
+                .align_size_within_rect(content_size, rect.shrink2(button_padding));
+            let (image_pos, text_pos) = match image_position {
+                ImagePosition::Left => (
+                    pos2(
+                        content_rect.left(),
+                        content_rect.center().y - 0.5 * image_size.y,
+                    ),
+                    pos2(
+                        content_rect.right() - galley.size.x,
+                        content_rect.center().y - 0.5 * galley.size.y,
+                    ),
+                ),
+                ImagePosition::Right => (
+                    pos2(
+                        content_rect.right() - image_size.x,
+                        content_rect.center().y - 0.5 * image_size.y,
+                    ),
+                    pos2(
+                        content_rect.left(),
+                        content_rect.center().y - 0.5 * galley.size.y,
+                    ),
+                ),
+                ImagePosition::Above => (
+                    pos2(
+                        content_rect.center().x - 0.5 * image_size.x,
+                        content_rect.top(),
+                    ),
+                    pos2(
+                        content_rect.center().x - 0.5 * galley.size.x,
+                        content_rect.bottom() - galley.size.y,
+                    ),
+                ),
+            };
 
             if frame {
                 let fill = fill.unwrap_or(visuals.bg_fill);
                 let stroke = stroke.unwrap_or(visuals.bg_stroke);
-                ui.painter().rect(
-                    rect.expand(visuals.expansion),
-                    visuals.corner_radius,
-                    fill,
-                    stroke,
-                );
+                let corner_radius = corner_radius
+                    .or(ui.visuals().button_corner_radius)
+                    .unwrap_or(visuals.corner_radius);
+                ui.painter()
+                    .rect(rect.expand(visuals.expansion), corner_radius, fill, stroke);
+            }
+
+            if let Some(image) = image {
+                image.paint_at(ui, Rect::from_min_size(image_pos, image_size));
             }
 
             let text_color = text_color
@@ -292,36 +408,38 @@ impl<'a> Widget for Checkbox<'a> {
         }
         response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, *checked, &galley.text));
 
-        // let visuals = ui.style().interact_selectable(&response, *checked); // too colorful
-        let visuals = ui.style().interact(&response);
-        let text_pos = pos2(
-            rect.min.x + button_padding.x + icon_width + icon_spacing,
-            rect.center().y - 0.5 * galley.size.y,
-        );
-        let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
-        ui.painter().add(Shape::Rect {
-            rect: big_icon_rect.expand(visuals.expansion),
-            corner_radius: visuals.corner_radius,
-            fill: visuals.bg_fill,
-            stroke: visuals.bg_stroke,
-        });
-
-        if *checked {
-            // Check mark:
-            ui.painter().add(Shape::line(
-                vec![
-                    pos2(small_icon_rect.left(), small_icon_rect.center().y),
-                    pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
-                    pos2(small_icon_rect.right(), small_icon_rect.top()),
-                ],
-                visuals.fg_stroke,
-            ));
-        }
+        if ui.is_rect_visible(rect) {
+            // let visuals = ui.style().interact_selectable(&response, *checked); // too colorful
+            let visuals = ui.style().interact(&response);
+            let text_pos = pos2(
+                rect.min.x + button_padding.x + icon_width + icon_spacing,
+                rect.center().y - 0.5 * galley.size.y,
+            );
+            let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
+            ui.painter().add(Shape::Rect {
+                rect: big_icon_rect.expand(visuals.expansion),
+                corner_radius: visuals.corner_radius,
+                fill: visuals.bg_fill,
+                stroke: visuals.bg_stroke,
+            });
 
-        let text_color = text_color
-            .or(ui.visuals().override_text_color)
-            .unwrap_or_else(|| visuals.text_color());
-        ui.painter().galley(text_pos, galley, text_color);
+            if *checked {
+                // Check mark:
+                ui.painter().add(Shape::line(
+                    vec![
+                        pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                        pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                        pos2(small_icon_rect.right(), small_icon_rect.top()),
+                    ],
+                    visuals.fg_stroke,
+                ));
+            }
+
+            let text_color = text_color
+                .or(ui.visuals().override_text_color)
+                .unwrap_or_else(|| visuals.text_color());
+            ui.painter().galley(text_pos, galley, text_color);
+        }
         response
     }
 }
@@ -414,34 +532,36 @@ impl Widget for RadioButton {
             rect.center().y - 0.5 * galley.size.y,
         );
 
-        // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
-        let visuals = ui.style().interact(&response);
-
-        let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
+        if ui.is_rect_visible(rect) {
+            // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
+            let visuals = ui.style().interact(&response);
 
-        let painter = ui.painter();
+            let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
 
-        painter.add(Shape::Circle {
-            center: big_icon_rect.center(),
-            radius: big_icon_rect.width() / 2.0 + visuals.expansion,
-            fill: visuals.bg_fill,
-            stroke: visuals.bg_stroke,
-        });
+            let painter = ui.painter();
 
-        if checked {
             painter.add(Shape::Circle {
-                center: small_icon_rect.center(),
-                radius: small_icon_rect.width() / 3.0,
-                fill: visuals.fg_stroke.color, // Intentional to use stroke and not fill
-                // fill: ui.visuals().selection.stroke.color, // too much color
-                stroke: Default::default(),
+                center: big_icon_rect.center(),
+                radius: big_icon_rect.width() / 2.0 + visuals.expansion,
+                fill: visuals.bg_fill,
+                stroke: visuals.bg_stroke,
             });
-        }
 
-        let text_color = text_color
-            .or(ui.visuals().override_text_color)
-            .unwrap_or_else(|| visuals.text_color());
-        painter.galley(text_pos, galley, text_color);
+            if checked {
+                painter.add(Shape::Circle {
+                    center: small_icon_rect.center(),
+                    radius: small_icon_rect.width() / 3.0,
+                    fill: visuals.fg_stroke.color, // Intentional to use stroke and not fill
+                    // fill: ui.visuals().selection.stroke.color, // too much color
+                    stroke: Default::default(),
+                });
+            }
+
+            let text_color = text_color
+                .or(ui.visuals().override_text_color)
+                .unwrap_or_else(|| visuals.text_color());
+            painter.galley(text_pos, galley, text_color);
+        }
         response
     }
 }
@@ -514,7 +634,7 @@ impl Widget for ImageButton {
         let (rect, response) = ui.allocate_exact_size(size, sense);
         response.widget_info(|| WidgetInfo::new(WidgetType::ImageButton));
 
-        if ui.clip_rect().intersects(rect) {
+        if ui.is_rect_visible(rect) {
             let visuals = ui.style().interact(&response);
 
             if selected {
@@ -522,9 +642,13 @@ impl Widget for ImageButton {
                 ui.painter()
                     .rect(rect, 0.0, selection.bg_fill, selection.stroke);
             } else if frame {
+                let corner_radius = ui
+                    .visuals()
+                    .button_corner_radius
+                    .unwrap_or(visuals.corner_radius);
                 ui.painter().rect(
                     rect.expand(visuals.expansion),
-                    visuals.corner_radius,
+                    corner_radius,
                     visuals.bg_fill,
                     visuals.bg_stroke,
                 );
@@ -1,8 +1,11 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
-use crate::*;
+use crate::{
+    widgets::slider::{normalized_from_value, value_from_normalized},
+    *,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -55,6 +58,7 @@ pub struct DragValue<'a> {
     clamp_range: RangeInclusive<f64>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    mapping: Option<(RangeInclusive<f64>, Arc<dyn SliderMapping>)>,
 }
 
 macro_rules! impl_integer_constructor {
@@ -132,10 +136,13 @@ impl<'a> DragValue<'a> {
             clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
             min_decimals: 0,
             max_decimals: None,
+            mapping: None,
         }
     }
 
     /// How much the value changes when dragged one point (logical pixel).
+    ///
+    /// With a [`Self::mapping`], this is instead how much the normalized position (0-1) changes.
     pub fn speed(mut self, speed: impl Into<f64>) -> Self {
         self.speed = speed.into();
         self
@@ -147,6 +154,31 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Scale the drag speed with a [`SliderMapping`] over the given range,
+    /// so that the value changes as it would on a [`Slider`] with the same mapping.
+    ///
+    /// For instance, with a [`LogarithmicMapping`] each order of magnitude takes equally long to drag through.
+    /// The [`Self::speed`] is then in normalized units (the whole range being 1) per point.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut frequency = 440.0;
+    /// ui.add(
+    ///     egui::DragValue::new(&mut frequency)
+    ///         .mapping(20.0..=20_000.0, egui::LogarithmicMapping::default())
+    ///         .speed(0.002)
+    ///         .suffix(" Hz"),
+    /// );
+    /// ```
+    pub fn mapping(
+        mut self,
+        range: RangeInclusive<f64>,
+        mapping: impl SliderMapping + 'static,
+    ) -> Self {
+        self.mapping = Some((range, Arc::new(mapping)));
+        self
+    }
+
     #[deprecated = "Use clamp_range"]
     pub fn clamp_range_f64(mut self, clamp_range: RangeInclusive<f64>) -> Self {
         self.clamp_range = clamp_range;
@@ -215,6 +247,7 @@ impl<'a> Widget for DragValue<'a> {
             suffix,
             min_decimals,
             max_decimals,
+            mapping,
         } = self;
 
         let is_slow_speed =
@@ -227,16 +260,30 @@ impl<'a> Widget for DragValue<'a> {
         }
         let aim_rad = ui.input().aim_radius() as f64;
 
+        let speed = if let Some((range, mapping)) = &mapping {
+            // Scale the speed by how fast the value changes with the normalized position here:
+            let normalized = normalized_from_value(value, range.clone(), &**mapping);
+            let epsilon = 1e-4;
+            let (n0, n1) = (
+                (normalized - epsilon).max(0.0),
+                (normalized + epsilon).min(1.0),
+            );
+            let v0 = value_from_normalized(n0, range.clone(), &**mapping);
+            let v1 = value_from_normalized(n1, range.clone(), &**mapping);
+            speed * (v1 - v0) / (n1 - n0)
+        } else {
+            speed
+        };
+
         let auto_decimals = (aim_rad / speed.abs()).log10().ceil().clamp(0.0, 15.0) as usize;
         let auto_decimals = auto_decimals + is_slow_speed as usize;
 
         let max_decimals = max_decimals.unwrap_or(auto_decimals + 2);
         let auto_decimals = auto_decimals.clamp(min_decimals, max_decimals);
-        let value_text = if value == 0.0 {
-            "0".to_owned()
-        } else {
-            emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
-        };
+        let value_text = ui
+            .ctx()
+            .value_formatter()
+            .format_number(value, auto_decimals..=max_decimals);
 
         let kb_edit_id = ui.auto_id_with("edit");
         let is_kb_editing = ui.memory().has_focus(kb_edit_id);
@@ -248,7 +295,14 @@ impl<'a> Widget for DragValue<'a> {
                 .drag_value
                 .edit_string
                 .take()
-                .unwrap_or(value_text);
+                .unwrap_or_else(|| {
+                    // Plain formatting so that the text can be parsed back:
+                    if value == 0.0 {
+                        "0".to_owned()
+                    } else {
+                        emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
+                    }
+                });
             let response = ui.add(
                 TextEdit::singleline(&mut value_text)
                     .id(kb_edit_id)
@@ -321,6 +375,7 @@ impl<'a> Widget for DragValue<'a> {
                     ui.memory().drag_value = drag_state;
                 }
             } else if response.has_focus() {
+                ui.memory().use_arrow_keys();
                 let change = ui.input().num_presses(Key::ArrowUp) as f64
                     + ui.input().num_presses(Key::ArrowRight) as f64
                     - ui.input().num_presses(Key::ArrowDown) as f64
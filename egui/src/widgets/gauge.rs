@@ -0,0 +1,460 @@
+//! Widgets for showing single values on dashboards: [`Gauge`] and [`Compass`].
+
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GaugeShape {
+    Circular,
+    Linear,
+}
+
+/// How close a value is to its limits, see [`Gauge::thresholds`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Level {
+    Normal,
+    Warn,
+    Error,
+}
+
+impl Level {
+    fn color(self, visuals: &Visuals) -> Color32 {
+        match self {
+            Level::Normal => visuals.selection.bg_fill,
+            Level::Warn => visuals.warn_fg_color,
+            Level::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+/// Shows a value within a range, like the speedometer of a car or a fuel gauge.
+///
+/// The value is colored with [`Visuals::warn_fg_color`] and [`Visuals::error_fg_color`]
+/// when it passes the [`Self::thresholds`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let temperature = 65.0;
+/// # let battery = 0.8;
+/// ui.add(
+///     egui::Gauge::circular(temperature, 0.0..=100.0)
+///         .thresholds(70.0, 90.0)
+///         .suffix(" °C")
+///         .text("CPU"),
+/// );
+/// ui.add(egui::Gauge::linear(100.0 * battery, 0.0..=100.0).thresholds(20.0, 10.0).suffix("%"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Gauge {
+    value: f32,
+    range: RangeInclusive<f32>,
+    shape: GaugeShape,
+    size: Option<f32>,
+    thresholds: Option<(f32, f32)>,
+    show_range: bool,
+    decimals: usize,
+    suffix: String,
+    text: String,
+}
+
+impl Gauge {
+    /// A dial with an arc from the bottom left, around the top, to the bottom right.
+    pub fn circular(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            value,
+            range,
+            shape: GaugeShape::Circular,
+            size: None,
+            thresholds: None,
+            show_range: true,
+            decimals: 0,
+            suffix: Default::default(),
+            text: Default::default(),
+        }
+    }
+
+    /// A horizontal bar, followed by the value.
+    pub fn linear(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            shape: GaugeShape::Linear,
+            ..Self::circular(value, range)
+        }
+    }
+
+    /// The diameter of a circular gauge, or the length of a linear one.
+    ///
+    /// Default: four times [`crate::style::Spacing::interact_size`]`.y` for circular gauges,
+    /// and [`crate::style::Spacing::slider_width`] for linear ones.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Show a warning color past `warn`, and an error color past `error`.
+    ///
+    /// If `error` is larger than `warn`, high values are bad (e.g. a temperature).
+    /// Otherwise low values are bad (e.g. a battery level).
+    pub fn thresholds(mut self, warn: f32, error: f32) -> Self {
+        self.thresholds = Some((warn, error));
+        self
+    }
+
+    /// Show the ends of the range. Default: `true`.
+    pub fn show_range(mut self, show_range: bool) -> Self {
+        self.show_range = show_range;
+        self
+    }
+
+    /// How many decimals to show of the value. Default: `0`.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Shown after the value, e.g. a unit.
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// A label describing the value.
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    fn level(&self, value: f32) -> Level {
+        match self.thresholds {
+            Some((warn, error)) if error >= warn => {
+                if value >= error {
+                    Level::Error
+                } else if value >= warn {
+                    Level::Warn
+                } else {
+                    Level::Normal
+                }
+            }
+            Some((warn, error)) => {
+                if value <= error {
+                    Level::Error
+                } else if value <= warn {
+                    Level::Warn
+                } else {
+                    Level::Normal
+                }
+            }
+            None => Level::Normal,
+        }
+    }
+
+    /// The parts of the range in the warning and error zones, from `0` to `1`.
+    fn zones(&self) -> Vec<(RangeInclusive<f32>, Level)> {
+        let t = |value: f32| remap_clamp(value, self.range.clone(), 0.0..=1.0);
+        match self.thresholds {
+            Some((warn, error)) if error >= warn => vec![
+                (t(warn)..=t(error), Level::Warn),
+                (t(error)..=1.0, Level::Error),
+            ],
+            Some((warn, error)) => vec![
+                (0.0..=t(error), Level::Error),
+                (t(error)..=t(warn), Level::Warn),
+            ],
+            None => vec![],
+        }
+    }
+
+    fn value_text(&self) -> String {
+        format!(
+            "{}{}",
+            emath::format_with_decimals_in_range(self.value as f64, self.decimals..=self.decimals),
+            self.suffix
+        )
+    }
+
+    fn circular_ui(&self, ui: &mut Ui) -> Response {
+        let diameter = self
+            .size
+            .unwrap_or_else(|| 4.0 * ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let visuals = ui.visuals();
+        let painter = ui.painter();
+        let center = rect.center();
+        let width = 0.1 * diameter;
+        let radius = 0.5 * (diameter - width);
+        let (start_angle, sweep) = (0.375 * TAU, 0.75 * TAU);
+        let angle = |t: f32| start_angle + t * sweep;
+
+        let track = Stroke::new(width, visuals.widgets.inactive.bg_fill);
+        painter.add(Shape::line(
+            arc(center, radius, angle(0.0), angle(1.0)),
+            track,
+        ));
+        for (zone, level) in self.zones() {
+            let zone_stroke = Stroke::new(0.25 * width, level.color(visuals));
+            let zone_radius = radius + 0.375 * width;
+            painter.add(Shape::line(
+                arc(
+                    center,
+                    zone_radius,
+                    angle(*zone.start()),
+                    angle(*zone.end()),
+                ),
+                zone_stroke,
+            ));
+        }
+
+        let t = remap_clamp(self.value, self.range.clone(), 0.0..=1.0);
+        if t > 0.0 {
+            let color = self.level(self.value).color(visuals);
+            painter.add(Shape::line(
+                arc(center, radius, angle(0.0), angle(t)),
+                Stroke::new(width, color),
+            ));
+        }
+
+        let text_color = visuals.text_color();
+        painter.text(
+            center,
+            Align2::CENTER_CENTER,
+            self.value_text(),
+            TextStyle::Heading,
+            text_color,
+        );
+        if !self.text.is_empty() {
+            painter.text(
+                center + vec2(0.0, 0.5 * radius),
+                Align2::CENTER_CENTER,
+                &self.text,
+                TextStyle::Small,
+                visuals.weak_text_color(),
+            );
+        }
+        if self.show_range {
+            let format = |value: f32| {
+                emath::format_with_decimals_in_range(value as f64, self.decimals..=self.decimals)
+            };
+            let bottom = rect.bottom() - 0.5 * width;
+            painter.text(
+                pos2(center.x - 0.5 * radius, bottom),
+                Align2::CENTER_BOTTOM,
+                format(*self.range.start()),
+                TextStyle::Small,
+                visuals.weak_text_color(),
+            );
+            painter.text(
+                pos2(center.x + 0.5 * radius, bottom),
+                Align2::CENTER_BOTTOM,
+                format(*self.range.end()),
+                TextStyle::Small,
+                visuals.weak_text_color(),
+            );
+        }
+
+        response
+    }
+
+    fn linear_ui(&self, ui: &mut Ui) -> Response {
+        let length = self.size.unwrap_or_else(|| ui.spacing().slider_width);
+        let height = 0.5 * ui.spacing().interact_size.y;
+        let (rect, response) = ui.allocate_exact_size(vec2(length, height), Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let visuals = ui.visuals();
+        let painter = ui.painter();
+        let corner_radius = 0.5 * height;
+        painter.rect_filled(rect, corner_radius, visuals.widgets.inactive.bg_fill);
+
+        let t = remap_clamp(self.value, self.range.clone(), 0.0..=1.0);
+        if t > 0.0 {
+            let filled = Rect::from_min_size(rect.min, vec2((t * length).at_least(height), height));
+            painter.rect_filled(filled, corner_radius, self.level(self.value).color(visuals));
+        }
+
+        // Mark where the zones start:
+        for (zone, level) in self.zones() {
+            let x = lerp(rect.x_range(), *zone.start());
+            if 0.0 < *zone.start() && *zone.start() < 1.0 {
+                painter.line_segment(
+                    [pos2(x, rect.top() - 2.0), pos2(x, rect.bottom() + 2.0)],
+                    Stroke::new(2.0, level.color(visuals)),
+                );
+            }
+        }
+
+        response
+    }
+}
+
+impl Widget for Gauge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let inner_response = match self.shape {
+            GaugeShape::Circular => ui.vertical_centered(|ui| self.circular_ui(ui)),
+            GaugeShape::Linear => ui.horizontal(|ui| {
+                let response = self.linear_ui(ui);
+                ui.label(self.value_text());
+                if !self.text.is_empty() {
+                    ui.add(Label::new(&self.text).wrap(false));
+                }
+                response
+            }),
+        };
+        let response = inner_response.inner | inner_response.response;
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, self.value_text()));
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows a heading, like a compass with the needle pointing up for north.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let wind_direction = 225.0;
+/// ui.add(egui::Compass::new(wind_direction).text("Wind"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Compass {
+    heading: f32,
+    diameter: Option<f32>,
+    text: String,
+}
+
+impl Compass {
+    /// The heading in degrees, clockwise from north.
+    pub fn new(heading_degrees: f32) -> Self {
+        Self {
+            heading: heading_degrees,
+            diameter: None,
+            text: Default::default(),
+        }
+    }
+
+    /// Default: four times [`crate::style::Spacing::interact_size`]`.y`.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// A label shown beneath the compass.
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+}
+
+impl Widget for Compass {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            heading,
+            diameter,
+            text,
+        } = self;
+        let heading = heading.rem_euclid(360.0);
+        let value_text = format!("{:.0}°", heading);
+
+        let inner_response = ui.vertical_centered(|ui| {
+            let diameter = diameter.unwrap_or_else(|| 4.0 * ui.spacing().interact_size.y);
+            let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+
+            if ui.is_rect_visible(rect) {
+                let visuals = ui.visuals();
+                let painter = ui.painter();
+                let center = rect.center();
+                let radius = 0.5 * diameter - 1.0;
+                painter.circle(
+                    center,
+                    radius,
+                    visuals.extreme_bg_color,
+                    visuals.widgets.noninteractive.bg_stroke,
+                );
+
+                // Screen angle of a compass direction, with north up:
+                let direction = |degrees: f32| Vec2::angled((degrees - 90.0).to_radians());
+
+                let tick_stroke = Stroke::new(1.0, visuals.weak_text_color());
+                for i in 0..12 {
+                    let dir = direction(i as f32 * 30.0);
+                    painter.line_segment(
+                        [center + 0.85 * radius * dir, center + radius * dir],
+                        tick_stroke,
+                    );
+                }
+                for (degrees, name) in &[(0.0, "N"), (90.0, "E"), (180.0, "S"), (270.0, "W")] {
+                    painter.text(
+                        center + 0.65 * radius * direction(*degrees),
+                        Align2::CENTER_CENTER,
+                        name,
+                        TextStyle::Small,
+                        visuals.text_color(),
+                    );
+                }
+
+                // The needle, with the pointy end in the error color like on a real compass:
+                let dir = direction(heading);
+                let side = 0.08 * radius * dir.rot90();
+                let tip = center + 0.8 * radius * dir;
+                let tail = center - 0.5 * radius * dir;
+                painter.add(Shape::convex_polygon(
+                    vec![center + side, tip, center - side],
+                    visuals.error_fg_color,
+                    Stroke::none(),
+                ));
+                painter.add(Shape::convex_polygon(
+                    vec![center - side, tail, center + side],
+                    visuals.widgets.inactive.fg_stroke.color,
+                    Stroke::none(),
+                ));
+            }
+            ui.label(&value_text);
+            if !text.is_empty() {
+                ui.add(Label::new(&text).small().weak());
+            }
+            response
+        });
+
+        let response = inner_response.inner | inner_response.response;
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &value_text));
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Points along an arc, clockwise on screen from `start_angle` to `end_angle`.
+fn arc(center: Pos2, radius: f32, start_angle: f32, end_angle: f32) -> Vec<Pos2> {
+    let num_points = ((end_angle - start_angle).abs() / TAU * 64.0)
+        .ceil()
+        .at_least(1.0) as usize;
+    (0..=num_points)
+        .map(|i| {
+            let angle = lerp(start_angle..=end_angle, i as f32 / num_points as f32);
+            center + radius * Vec2::angled(angle)
+        })
+        .collect()
+}
+
+#[test]
+fn test_gauge_levels() {
+    let temperature = Gauge::circular(0.0, 0.0..=100.0).thresholds(70.0, 90.0);
+    assert_eq!(temperature.level(50.0), Level::Normal);
+    assert_eq!(temperature.level(70.0), Level::Warn);
+    assert_eq!(temperature.level(95.0), Level::Error);
+
+    let battery = Gauge::linear(0.0, 0.0..=100.0).thresholds(20.0, 10.0);
+    assert_eq!(battery.level(50.0), Level::Normal);
+    assert_eq!(battery.level(15.0), Level::Warn);
+    assert_eq!(battery.level(5.0), Level::Error);
+
+    assert_eq!(Gauge::linear(0.0, 0.0..=1.0).level(100.0), Level::Normal);
+}
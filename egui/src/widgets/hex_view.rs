@@ -0,0 +1,482 @@
+//! A hex viewer and editor for binary data.
+
+use std::hash::Hash;
+use std::ops::Range;
+
+use crate::*;
+
+enum HexData<'a> {
+    ReadOnly(&'a [u8]),
+    Editable(&'a mut [u8]),
+}
+
+impl<'a> HexData<'a> {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            HexData::ReadOnly(data) => data,
+            HexData::Editable(data) => data,
+        }
+    }
+}
+
+/// Selection and cursor of a [`HexView`].
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// Where the selection started, and the byte at the cursor.
+    selection: Option<(usize, usize)>,
+
+    /// The cursor is in the ASCII column rather than the hex column.
+    ascii: bool,
+
+    /// The high nibble typed at the cursor, waiting for the low nibble.
+    pending_nibble: Option<u8>,
+}
+
+impl State {
+    fn range(&self) -> Option<Range<usize>> {
+        self.selection
+            .map(|(anchor, cursor)| anchor.min(cursor)..anchor.max(cursor) + 1)
+    }
+}
+
+/// Shows binary data as rows of hex bytes, with addresses to the left and ASCII to the right.
+///
+/// Only the visible rows are laid out, so it can show buffers of many megabytes.
+///
+/// Click or drag to select bytes, shift-click to extend the selection
+/// and press Cmd+C (Ctrl+C) to copy the selected bytes as hex
+/// (or as text, if the selection was made in the ASCII column).
+/// The arrow keys move the cursor.
+///
+/// If created with [`Self::editable`], type hex digits (or text in the ASCII column) to overwrite bytes.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut memory = vec![0_u8; 1024 * 1024];
+/// let selection = egui::HexView::editable(&mut memory).show(ui).inner;
+/// if let Some(selection) = selection {
+///     ui.label(format!("Selected {} bytes", selection.len()));
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct HexView<'a> {
+    data: HexData<'a>,
+    id_source: Option<Id>,
+    bytes_per_row: usize,
+    base_address: usize,
+    max_height: f32,
+}
+
+impl<'a> HexView<'a> {
+    /// Show the data without allowing changes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self::from_data(HexData::ReadOnly(data))
+    }
+
+    /// Show the data, and allow the user to overwrite bytes.
+    pub fn editable(data: &'a mut [u8]) -> Self {
+        Self::from_data(HexData::Editable(data))
+    }
+
+    fn from_data(data: HexData<'a>) -> Self {
+        Self {
+            data,
+            id_source: None,
+            bytes_per_row: 16,
+            base_address: 0,
+            max_height: 400.0,
+        }
+    }
+
+    /// Needed if you show several hex views in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Default: `16`.
+    pub fn bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.bytes_per_row = bytes_per_row.max(1);
+        self
+    }
+
+    /// The address shown for the first byte, e.g. where the data is in memory. Default: `0`.
+    pub fn base_address(mut self, base_address: usize) -> Self {
+        self.base_address = base_address;
+        self
+    }
+
+    /// The height of the scroll area. Default: `400.0`.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Returns the range of selected bytes, if any.
+    ///
+    /// [`Response::changed`] is set when a byte was edited.
+    pub fn show(self, ui: &mut Ui) -> InnerResponse<Option<Range<usize>>> {
+        let Self {
+            mut data,
+            id_source,
+            bytes_per_row,
+            base_address,
+            max_height,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("hex_view")));
+        let mut state = ui
+            .memory()
+            .id_data_temp
+            .get::<State>(&id)
+            .copied()
+            .unwrap_or_default();
+
+        let len = data.bytes().len();
+        let num_rows = (len + bytes_per_row - 1) / bytes_per_row;
+        let last_address = base_address + len.saturating_sub(1);
+        let address_digits = format!("{:x}", last_address).len().max(8);
+
+        let text_style = TextStyle::Monospace;
+        let (char_width, row_height) = {
+            let fonts = ui.fonts();
+            (
+                fonts.glyph_width(text_style, '0'),
+                fonts.row_height(text_style),
+            )
+        };
+        let columns = Columns {
+            hex_x: (address_digits + 2) as f32 * char_width,
+            ascii_x: (address_digits + 2 + 3 * bytes_per_row + 1) as f32 * char_width,
+            char_width,
+            row_height,
+            bytes_per_row,
+        };
+        let total_width = columns.ascii_x + bytes_per_row as f32 * char_width;
+
+        let mut changed = false;
+        let response = ScrollArea::from_max_height(max_height)
+            .id_source(id)
+            .show_viewport(ui, |ui, viewport| {
+                ui.set_height(row_height * num_rows as f32);
+                ui.set_min_width(total_width);
+
+                let origin = ui.max_rect().min;
+                let min_row = (viewport.min.y / row_height).floor().at_least(0.0) as usize;
+                let max_row = ((viewport.max.y / row_height).ceil() as usize + 1).at_most(num_rows);
+                let visible_rect = Rect::from_min_size(
+                    origin + vec2(0.0, min_row as f32 * row_height),
+                    vec2(total_width, (max_row - min_row) as f32 * row_height),
+                );
+
+                let response = ui.interact(visible_rect, id, Sense::click_and_drag());
+                if response.clicked() || response.drag_started() {
+                    ui.memory().request_focus(id);
+                }
+
+                // Pointer interaction:
+                if let Some(pos) = response.interact_pointer_pos() {
+                    if let Some((index, ascii)) = columns.byte_at(pos - origin, len) {
+                        let extend = (response.drag_started() && ui.input().modifiers.shift)
+                            || (response.dragged() && !response.drag_started());
+                        let anchor = match state.selection {
+                            Some((anchor, _)) if extend => anchor,
+                            _ => index,
+                        };
+                        if response.drag_started() || response.dragged() || response.clicked() {
+                            state.selection = Some((anchor, index));
+                            state.ascii = ascii;
+                            state.pending_nibble = None;
+                        }
+                    }
+                }
+
+                // Keyboard interaction:
+                if ui.memory().has_focus(id) && len > 0 {
+                    ui.memory().use_arrow_keys();
+                    let old_cursor = state.selection.map(|(_, cursor)| cursor);
+                    changed = keyboard_input(ui, &mut state, &mut data, bytes_per_row);
+                    let new_cursor = state.selection.map(|(_, cursor)| cursor);
+                    if let Some(cursor) = new_cursor.filter(|_| new_cursor != old_cursor) {
+                        let row = cursor / bytes_per_row;
+                        if row <= min_row || row + 1 >= max_row {
+                            let y = origin.y + (row as f32 + 0.5) * row_height;
+                            ui.ctx().frame_state().scroll_target = Some((y, Align::Center));
+                        }
+                    }
+                }
+
+                paint_rows(
+                    ui,
+                    &columns,
+                    origin,
+                    data.bytes(),
+                    base_address,
+                    address_digits,
+                    min_row..max_row,
+                    &state,
+                );
+
+                response
+            });
+
+        ui.memory().id_data_temp.insert(id, state);
+
+        let mut response = response;
+        if changed {
+            response.mark_changed();
+        }
+        InnerResponse::new(state.range(), response)
+    }
+}
+
+/// Where things are within each row, relative to the top left of the view.
+struct Columns {
+    hex_x: f32,
+    ascii_x: f32,
+    char_width: f32,
+    row_height: f32,
+    bytes_per_row: usize,
+}
+
+impl Columns {
+    /// The byte at the given position, and whether or not it is in the ASCII column.
+    fn byte_at(&self, pos: Vec2, len: usize) -> Option<(usize, bool)> {
+        if len == 0 || pos.y < 0.0 {
+            return None;
+        }
+        let row = (pos.y / self.row_height) as usize;
+        let (column, ascii) = if pos.x >= self.ascii_x - self.char_width {
+            ((pos.x - self.ascii_x) / self.char_width, true)
+        } else {
+            ((pos.x - self.hex_x) / (3.0 * self.char_width), false)
+        };
+        let column = (column.floor().at_least(0.0) as usize).at_most(self.bytes_per_row - 1);
+        Some(((row * self.bytes_per_row + column).at_most(len - 1), ascii))
+    }
+
+    /// The rectangles of the bytes `columns` of a row, in the hex and in the ASCII column.
+    fn byte_rects(&self, row_min: Pos2, columns: Range<usize>) -> (Rect, Rect) {
+        let (start, end) = (columns.start as f32, columns.end as f32);
+        let y_range = row_min.y..=row_min.y + self.row_height;
+        let hex = Rect::from_x_y_ranges(
+            row_min.x + self.hex_x + 3.0 * start * self.char_width
+                ..=row_min.x + self.hex_x + (3.0 * end - 1.0) * self.char_width,
+            y_range.clone(),
+        );
+        let ascii = Rect::from_x_y_ranges(
+            row_min.x + self.ascii_x + start * self.char_width
+                ..=row_min.x + self.ascii_x + end * self.char_width,
+            y_range,
+        );
+        (hex, ascii)
+    }
+}
+
+/// Returns `true` if a byte was changed.
+fn keyboard_input(
+    ui: &Ui,
+    state: &mut State,
+    data: &mut HexData<'_>,
+    bytes_per_row: usize,
+) -> bool {
+    let len = data.bytes().len();
+    let events = ui.input().events.clone();
+    let mut changed = false;
+
+    for event in &events {
+        match event {
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } => {
+                let cursor = state.selection.map_or(0, |(_, cursor)| cursor);
+                let new_cursor = match key {
+                    Key::ArrowLeft => cursor.saturating_sub(1),
+                    Key::ArrowRight => (cursor + 1).at_most(len - 1),
+                    Key::ArrowUp => cursor.saturating_sub(bytes_per_row),
+                    Key::ArrowDown => (cursor + bytes_per_row).at_most(len - 1),
+                    Key::Home => cursor - cursor % bytes_per_row,
+                    Key::End => {
+                        (cursor - cursor % bytes_per_row + bytes_per_row - 1).at_most(len - 1)
+                    }
+                    Key::Escape => {
+                        state.selection = None;
+                        continue;
+                    }
+                    _ => continue,
+                };
+                let anchor = match state.selection {
+                    Some((anchor, _)) if modifiers.shift => anchor,
+                    _ => new_cursor,
+                };
+                state.selection = Some((anchor, new_cursor));
+                state.pending_nibble = None;
+            }
+            Event::Copy => {
+                if let Some(range) = state.range() {
+                    let bytes = &data.bytes()[range];
+                    ui.output().copied_text = if state.ascii {
+                        bytes.iter().map(|&b| ascii_char(b)).collect()
+                    } else {
+                        let hex: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                        hex.join(" ")
+                    };
+                }
+            }
+            Event::Text(text) => {
+                let data = match data {
+                    HexData::Editable(data) => data,
+                    HexData::ReadOnly(_) => continue,
+                };
+                for c in text.chars() {
+                    let cursor = match state.selection {
+                        Some((_, cursor)) => cursor,
+                        None => break,
+                    };
+                    if state.ascii {
+                        if c.is_ascii() && !c.is_ascii_control() {
+                            data[cursor] = c as u8;
+                        } else {
+                            continue;
+                        }
+                    } else if let Some(nibble) = c.to_digit(16) {
+                        let nibble = nibble as u8;
+                        match state.pending_nibble.take() {
+                            None => {
+                                data[cursor] = (nibble << 4) | (data[cursor] & 0x0f);
+                                state.pending_nibble = Some(nibble);
+                                changed = true;
+                                continue;
+                            }
+                            Some(high) => data[cursor] = (high << 4) | nibble,
+                        }
+                    } else {
+                        continue;
+                    }
+                    changed = true;
+                    let next = (cursor + 1).at_most(len - 1);
+                    state.selection = Some((next, next));
+                }
+            }
+            _ => {}
+        }
+    }
+    changed
+}
+
+#[allow(clippy::too_many_arguments)]
+fn paint_rows(
+    ui: &Ui,
+    columns: &Columns,
+    origin: Pos2,
+    bytes: &[u8],
+    base_address: usize,
+    address_digits: usize,
+    rows: Range<usize>,
+    state: &State,
+) {
+    let painter = ui.painter();
+    let visuals = ui.visuals();
+    let text_style = TextStyle::Monospace;
+    let bytes_per_row = columns.bytes_per_row;
+    let selection = state.range();
+
+    for row in rows {
+        let row_min = origin + vec2(0.0, row as f32 * columns.row_height);
+        let start = row * bytes_per_row;
+        let row_bytes = &bytes[start..(start + bytes_per_row).at_most(bytes.len())];
+
+        if let Some(selection) = &selection {
+            let first = selection.start.max(start);
+            let end = selection.end.min(start + row_bytes.len());
+            if first < end {
+                let (hex, ascii) = columns.byte_rects(row_min, first - start..end - start);
+                painter.rect_filled(hex, 0.0, visuals.selection.bg_fill);
+                painter.rect_filled(ascii, 0.0, visuals.selection.bg_fill);
+            }
+        }
+        if let Some((_, cursor)) = state.selection {
+            if (start..start + row_bytes.len()).contains(&cursor) {
+                let (hex, ascii) = columns.byte_rects(row_min, cursor - start..cursor - start + 1);
+                let cursor_rect = if state.ascii { ascii } else { hex };
+                painter.rect_stroke(cursor_rect, 0.0, visuals.selection.stroke);
+            }
+        }
+
+        painter.text(
+            row_min,
+            Align2::LEFT_TOP,
+            format!("{:0width$x}", base_address + start, width = address_digits),
+            text_style,
+            visuals.weak_text_color(),
+        );
+        let hex: Vec<String> = row_bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        painter.text(
+            row_min + vec2(columns.hex_x, 0.0),
+            Align2::LEFT_TOP,
+            hex.join(" "),
+            text_style,
+            visuals.text_color(),
+        );
+        let ascii: String = row_bytes.iter().map(|&b| ascii_char(b)).collect();
+        painter.text(
+            row_min + vec2(columns.ascii_x, 0.0),
+            Align2::LEFT_TOP,
+            ascii,
+            text_style,
+            visuals.text_color(),
+        );
+    }
+}
+
+/// Printable ASCII characters as themselves, everything else as `.`.
+fn ascii_char(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+#[test]
+fn test_hex_view_editing() {
+    let mut harness = crate::harness::Harness::new(vec2(800.0, 400.0));
+    let mut data: Vec<u8> = (0..=255).collect();
+    let mut selection = None;
+    let mut run = |harness: &mut crate::harness::Harness, data: &mut Vec<u8>| {
+        harness.run(|ui| {
+            selection = HexView::editable(data).show(ui).inner;
+        });
+        selection.clone()
+    };
+
+    assert_eq!(run(&mut harness, &mut data), None);
+    let origin = harness.run(|_| {}).contents_rect.unwrap().min;
+
+    // Click the second byte of the first row:
+    let (char_width, row_height) = {
+        let fonts = harness.ctx().fonts();
+        (
+            fonts.glyph_width(TextStyle::Monospace, '0'),
+            fonts.row_height(TextStyle::Monospace),
+        )
+    };
+    let first_byte_x = 10.0 * char_width; // 8 address digits and 2 spaces.
+    harness.click(origin + vec2(first_byte_x + 3.5 * char_width, 0.5 * row_height));
+    run(&mut harness, &mut data);
+    assert_eq!(run(&mut harness, &mut data), Some(1..2));
+
+    harness.type_text("ab");
+    run(&mut harness, &mut data);
+    assert_eq!(data[1], 0xab);
+    assert_eq!(run(&mut harness, &mut data), Some(2..3));
+
+    harness.set_modifiers(Modifiers {
+        shift: true,
+        ..Default::default()
+    });
+    harness.key_press(Key::ArrowDown);
+    assert_eq!(run(&mut harness, &mut data), Some(2..19));
+}
@@ -20,6 +20,7 @@ pub struct Image {
     size: Vec2,
     bg_fill: Color32,
     tint: Color32,
+    texture_filter: Option<TextureFilter>,
     sense: Sense,
 }
 
@@ -31,6 +32,7 @@ impl Image {
             size: size.into(),
             bg_fill: Default::default(),
             tint: Color32::WHITE,
+            texture_filter: None,
             sense: Sense::hover(),
         }
     }
@@ -53,6 +55,15 @@ impl Image {
         self
     }
 
+    /// Sample the texture with this filter instead of the one the texture was allocated with.
+    ///
+    /// Use [`TextureFilter::Nearest`] to keep pixel art or zoomed-in data crisp.
+    /// Default: use the [`TextureOptions`] of the texture.
+    pub fn texture_filter(mut self, texture_filter: TextureFilter) -> Self {
+        self.texture_filter = Some(texture_filter);
+        self
+    }
+
     /// Make the image respond to clicks and/or drags.
     ///
     /// Consider using [`ImageButton`] instead, for an on-hover effect.
@@ -75,6 +86,7 @@ impl Image {
             size: _,
             bg_fill,
             tint,
+            texture_filter,
             sense: _,
         } = self;
 
@@ -87,6 +99,7 @@ impl Image {
         {
             // TODO: builder pattern for Mesh
             let mut mesh = Mesh::with_texture(*texture_id);
+            mesh.texture_filter = *texture_filter;
             mesh.add_rect_with_uv(rect, *uv, *tint);
             ui.painter().add(Shape::mesh(mesh));
         }
@@ -96,7 +109,9 @@ impl Image {
 impl Widget for Image {
     fn ui(self, ui: &mut Ui) -> Response {
         let (rect, response) = ui.allocate_exact_size(self.size, self.sense);
-        self.paint_at(ui, rect);
+        if ui.is_rect_visible(rect) {
+            self.paint_at(ui, rect);
+        }
         response
     }
 }
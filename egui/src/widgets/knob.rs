@@ -0,0 +1,399 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use crate::*;
+use std::f32::consts::TAU;
+use std::ops::RangeInclusive;
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
+    (get_set_value)(Some(value));
+}
+
+// ----------------------------------------------------------------------------
+
+/// How the user turns a [`Knob`] with the pointer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KnobDragMode {
+    /// Drag up (or right) to increase the value, down (or left) to decrease it.
+    Linear,
+
+    /// Drag around the center of the knob, like turning a real knob.
+    Circular,
+}
+
+/// A rotary knob for angles and other continuous values, as found in audio plugins.
+///
+/// Drag it to change the value (see [`KnobDragMode`]), holding down shift for finer control.
+/// When it has keyboard focus, the arrow keys change the value.
+///
+/// The value is shown next to the knob with the same formatting options as a [`DragValue`],
+/// and can be clicked to enter a value.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut gain: f32 = 0.0;
+/// # let mut angle: f32 = 0.0;
+/// ui.add(egui::Knob::new(&mut gain, -60.0..=12.0).suffix(" dB").text("Gain"));
+///
+/// // An angle in radians, shown in degrees and wrapping around:
+/// ui.add(egui::Knob::angle(&mut angle).text("Direction"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Knob<'a> {
+    get_set_value: GetSetValue<'a>,
+    range: RangeInclusive<f64>,
+    diameter: Option<f32>,
+    drag_mode: KnobDragMode,
+    speed: Option<f64>,
+    wrap: bool,
+    start_angle: f32,
+    sweep: f32,
+    show_value: bool,
+    prefix: String,
+    suffix: String,
+    text: String,
+    min_decimals: usize,
+    max_decimals: Option<usize>,
+}
+
+impl<'a> Knob<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, move |v: Option<f64>| {
+            if let Some(v) = v {
+                *value = Num::from_f64(v)
+            }
+            value.to_f64()
+        });
+
+        if Num::INTEGRAL {
+            slf.fixed_decimals(0)
+        } else {
+            slf
+        }
+    }
+
+    /// Turn an angle given in radians, shown in degrees.
+    ///
+    /// The knob points in the direction of the angle, and wraps around at a full turn.
+    pub fn angle(radians: &'a mut f32) -> Self {
+        Self::from_get_set(0.0..=360.0, move |v: Option<f64>| {
+            if let Some(v) = v {
+                *radians = (v as f32).to_radians();
+            }
+            radians.to_degrees() as f64
+        })
+        .wrap(true)
+        .sweep(0.0, TAU)
+        .drag_mode(KnobDragMode::Circular)
+        .suffix("°")
+    }
+
+    pub fn from_get_set(
+        range: RangeInclusive<f64>,
+        get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
+    ) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            range,
+            diameter: None,
+            drag_mode: KnobDragMode::Linear,
+            speed: None,
+            wrap: false,
+            // From bottom left, clockwise to bottom right:
+            start_angle: 0.375 * TAU,
+            sweep: 0.75 * TAU,
+            show_value: true,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            text: Default::default(),
+            min_decimals: 0,
+            max_decimals: None,
+        }
+    }
+
+    /// The size of the knob. Default: twice [`crate::style::Spacing::interact_size`]`.y`.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// How to turn the knob with the pointer. Default: [`KnobDragMode::Linear`].
+    pub fn drag_mode(mut self, drag_mode: KnobDragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// For [`KnobDragMode::Linear`]: how much the value changes when dragged one point.
+    /// Default: the whole range over 200 points.
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = Some(speed.into());
+        self
+    }
+
+    /// If `true`, turning the knob past one end of the range continues from the other end,
+    /// e.g. for angles. If `false` (default), the value stops at the ends.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Where the start of the range is on the knob, and how far around the knob the range goes (clockwise).
+    ///
+    /// Angles are in radians, with zero pointing to the right.
+    /// The default is from the bottom left (`0.375 * TAU`) three quarters of a turn around (`0.75 * TAU`).
+    pub fn sweep(mut self, start_angle: f32, sweep: f32) -> Self {
+        self.start_angle = start_angle;
+        self.sweep = sweep;
+        self
+    }
+
+    /// Control wether or not the knob shows the current value.
+    /// Default: `true`.
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the number, e.g. "x: "
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the number, this can be e.g. a unit ("°" or " dB")
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show a text next to the knob (e.g. explaining what the knob controls).
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// Set a minimum number of decimals to display. See [`DragValue::min_decimals`].
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// Set a maximum number of decimals to display. See [`DragValue::max_decimals`].
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Set an exact number of decimals to display. See [`DragValue::fixed_decimals`].
+    pub fn fixed_decimals(mut self, num_decimals: usize) -> Self {
+        self.min_decimals = num_decimals;
+        self.max_decimals = Some(num_decimals);
+        self
+    }
+
+    fn range_width(&self) -> f64 {
+        self.range.end() - self.range.start()
+    }
+
+    /// Wrap or clamp the value to the range.
+    fn constrain(&self, value: f64) -> f64 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (min, max) = (start.min(end), start.max(end));
+        if self.wrap && min < max {
+            min + (value - min).rem_euclid(max - min)
+        } else {
+            value.clamp(min, max)
+        }
+    }
+
+    fn set_value(&mut self, value: f64) {
+        let mut value = self.constrain(value);
+        if let Some(max_decimals) = self.max_decimals {
+            value = emath::round_to_decimals(value, max_decimals);
+        }
+        set(&mut self.get_set_value, value);
+    }
+
+    fn angle_from_value(&self, value: f64) -> f32 {
+        let normalized = if self.range_width() == 0.0 {
+            0.0
+        } else {
+            ((value - self.range.start()) / self.range_width()).clamp(0.0, 1.0) as f32
+        };
+        self.start_angle + normalized * self.sweep
+    }
+
+    /// Just the knob, no text
+    fn knob_ui(&mut self, ui: &mut Ui) -> Response {
+        let diameter = self
+            .diameter
+            .unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+        let (id, rect) = ui.allocate_space(Vec2::splat(diameter));
+        let center = rect.center();
+        let hit_shape = HitShape::Circle {
+            center,
+            radius: 0.5 * diameter,
+        };
+        let response = ui.interact_with_shape(id, &hit_shape, Sense::click_and_drag());
+
+        let value = get(&mut self.get_set_value);
+        let fine = if ui.input().modifiers.shift_only() {
+            0.1
+        } else {
+            1.0
+        };
+
+        if response.dragged() {
+            let delta = response.drag_delta();
+            let delta_value = match self.drag_mode {
+                KnobDragMode::Linear => {
+                    let speed = self.speed.unwrap_or_else(|| self.range_width() / 200.0);
+                    (delta.x - delta.y) as f64 * speed
+                }
+                KnobDragMode::Circular => {
+                    let pointer_pos = response.interact_pointer_pos().unwrap_or(center);
+                    let prev_angle = (pointer_pos - delta - center).angle();
+                    let angle = (pointer_pos - center).angle();
+                    // Take the short way around:
+                    let delta_angle = (angle - prev_angle + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+                    if self.sweep == 0.0 {
+                        0.0
+                    } else {
+                        (delta_angle / self.sweep) as f64 * self.range_width()
+                    }
+                }
+            };
+
+            // Since the value may be rounded, we need to store the full precision value in memory:
+            let stored_value = ui
+                .memory()
+                .id_data_temp
+                .get::<f64>(&response.id)
+                .copied()
+                .unwrap_or(value);
+            let new_value = self.constrain(stored_value + fine * delta_value);
+            ui.memory().id_data_temp.insert(response.id, new_value);
+            self.set_value(new_value);
+        } else {
+            ui.memory().id_data_temp.remove(&response.id);
+        }
+
+        if response.has_focus() {
+            ui.memory().use_arrow_keys();
+            let kb_step = ui.input().num_presses(Key::ArrowUp) as f64
+                + ui.input().num_presses(Key::ArrowRight) as f64
+                - ui.input().num_presses(Key::ArrowDown) as f64
+                - ui.input().num_presses(Key::ArrowLeft) as f64;
+            if kb_step != 0.0 {
+                let step = self.range_width() / 100.0;
+                let value = get(&mut self.get_set_value);
+                self.set_value(value + fine * kb_step * step);
+            }
+        }
+
+        let value = get(&mut self.get_set_value);
+        response.widget_info(|| WidgetInfo::slider(value, &self.text));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let radius = 0.5 * diameter - visuals.expansion.abs() - 1.0;
+
+            ui.painter().add(Shape::Circle {
+                center,
+                radius: radius + visuals.expansion,
+                fill: visuals.bg_fill,
+                stroke: visuals.bg_stroke,
+            });
+
+            // The arc from the start of the range to the current value:
+            if !self.wrap {
+                let arc_radius = radius - 2.0;
+                let start_angle = self.start_angle;
+                let end_angle = self.angle_from_value(value);
+                let num_points = ((end_angle - start_angle).abs() / TAU * 64.0).ceil() as usize;
+                if num_points > 0 {
+                    let points = (0..=num_points)
+                        .map(|i| {
+                            let angle = lerp(start_angle..=end_angle, i as f32 / num_points as f32);
+                            center + arc_radius * Vec2::angled(angle)
+                        })
+                        .collect();
+                    ui.painter().add(Shape::line(
+                        points,
+                        Stroke::new(2.0, ui.visuals().selection.bg_fill),
+                    ));
+                }
+            }
+
+            // The pointer of the knob:
+            let direction = Vec2::angled(self.angle_from_value(value));
+            ui.painter().line_segment(
+                [
+                    center + 0.3 * radius * direction,
+                    center + radius * direction,
+                ],
+                visuals.fg_stroke,
+            );
+        }
+
+        response
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui) {
+        let mut value = get(&mut self.get_set_value);
+        let speed = self
+            .speed
+            .unwrap_or_else(|| self.range_width().abs() / 200.0);
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let clamp_range = if self.wrap {
+            f64::NEG_INFINITY..=f64::INFINITY
+        } else {
+            start.min(end)..=start.max(end)
+        };
+        ui.add(
+            DragValue::new(&mut value)
+                .speed(speed)
+                .clamp_range(clamp_range)
+                .min_decimals(self.min_decimals)
+                .max_decimals_opt(self.max_decimals)
+                .suffix(self.suffix.clone())
+                .prefix(self.prefix.clone()),
+        );
+        if value != get(&mut self.get_set_value) {
+            self.set_value(value);
+        }
+    }
+}
+
+impl<'a> Widget for Knob<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_value = get(&mut self.get_set_value);
+
+        let inner_response = ui.horizontal(|ui| {
+            let knob_response = self.knob_ui(ui);
+
+            if self.show_value {
+                self.value_ui(ui);
+            }
+
+            if !self.text.is_empty() {
+                ui.add(Label::new(&self.text).wrap(false));
+            }
+            knob_response
+        });
+
+        let mut response = inner_response.inner | inner_response.response;
+        response.changed = get(&mut self.get_set_value) != old_value;
+        response
+    }
+}
@@ -15,6 +15,8 @@ pub struct Label {
     // TODO: not pub
     pub(crate) text: String,
     pub(crate) wrap: Option<bool>,
+    elide: bool,
+    highlighted_chars: Vec<usize>,
     pub(crate) text_style: Option<TextStyle>,
     pub(crate) background_color: Color32,
     pub(crate) text_color: Option<Color32>,
@@ -34,6 +36,8 @@ impl Label {
         Self {
             text: text.to_string(),
             wrap: None,
+            elide: false,
+            highlighted_chars: Vec::new(),
             text_style: None,
             background_color: Color32::TRANSPARENT,
             text_color: None,
@@ -63,6 +67,24 @@ impl Label {
         self
     }
 
+    /// If `true`, the text is shown on a single row,
+    /// and if it doesn't fit in the available width it is cut short with an ellipsis (`…`).
+    /// Hover the label to see the full text.
+    ///
+    /// This overrides [`Self::wrap`].
+    pub fn elide(mut self, elide: bool) -> Self {
+        self.elide = elide;
+        self
+    }
+
+    /// Highlight the characters at these `char` indices (not byte indices),
+    /// e.g. the characters that matched a search with [`crate::util::fuzzy_match`].
+    pub fn highlight_chars(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.highlighted_chars = indices.into_iter().collect();
+        self.highlighted_chars.sort_unstable();
+        self
+    }
+
     #[deprecated = "Use Label::wrap instead"]
     pub fn multiline(self, multiline: bool) -> Self {
         self.wrap(multiline)
@@ -172,6 +194,11 @@ impl Label {
 
     pub fn layout_width(&self, ui: &Ui, max_width: f32) -> Arc<Galley> {
         let text_style = self.text_style_or_default(ui.style());
+        if self.elide {
+            let galley = layout_elided(ui.fonts(), text_style, &self.text, max_width);
+            return self.valign_galley(ui, text_style, galley);
+        }
+
         let wrap_width = if self.should_wrap(ui) {
             max_width
         } else {
@@ -208,6 +235,10 @@ impl Label {
         has_focus: bool,
         response_color: Color32,
     ) {
+        if !ui.is_rect_visible(Rect::from_min_size(pos, galley.size)) {
+            return;
+        }
+
         let Self {
             mut background_color,
             code,
@@ -236,6 +267,16 @@ impl Label {
             background_color = ui.visuals().code_bg_color;
         }
 
+        if !self.highlighted_chars.is_empty() {
+            crate::util::fuzzy::paint_char_highlights(
+                ui.painter(),
+                pos,
+                &galley,
+                &self.highlighted_chars,
+                ui.visuals().selection.bg_fill,
+            );
+        }
+
         let mut lines = vec![];
 
         if strikethrough || underline || background_color != Color32::TRANSPARENT {
@@ -277,6 +318,9 @@ impl Label {
     }
 
     fn should_wrap(&self, ui: &Ui) -> bool {
+        if self.elide {
+            return false;
+        }
         self.wrap.or(ui.style().wrap).unwrap_or_else(|| {
             if let Some(grid) = ui.grid() {
                 grid.wrap_text()
@@ -319,6 +363,10 @@ impl Widget for Label {
     fn ui(self, ui: &mut Ui) -> Response {
         let sense = self.sense;
 
+        if let Some(grid) = ui.grid_mut() {
+            grid.set_cell_text(&self.text, false);
+        }
+
         if self.should_wrap(ui)
             && ui.layout().main_dir() == Direction::LeftToRight
             && ui.layout().main_wrap()
@@ -374,15 +422,46 @@ impl Widget for Label {
             response
         } else {
             let galley = self.layout(ui);
-            let (rect, response) = ui.allocate_exact_size(galley.size, sense);
-            response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &galley.text));
+            let (rect, mut response) = ui.allocate_exact_size(galley.size, sense);
+            response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &self.text));
             let response_color = ui.style().interact(&response).text_color();
+            let elided = galley.text != self.text;
             self.paint_galley_impl(ui, rect.min, galley, response.has_focus(), response_color);
+            if elided {
+                response = response.on_hover_text(self.text);
+            }
             response
         }
     }
 }
 
+/// Lay out `text` on a single row, cutting it short with an ellipsis if it is wider than `max_width`.
+fn layout_elided(
+    fonts: &epaint::text::Fonts,
+    text_style: TextStyle,
+    text: &str,
+    max_width: f32,
+) -> Arc<Galley> {
+    let galley = fonts.layout_single_line(text_style, text.to_owned());
+    if galley.size.x <= max_width || text.is_empty() {
+        return galley;
+    }
+
+    // Find the longest prefix that fits together with the ellipsis:
+    let char_starts: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    let elided = |num_chars: usize| format!("{}…", text[..char_starts[num_chars]].trim_end());
+    let (mut min, mut max) = (0, char_starts.len() - 1);
+    while min < max {
+        let mid = (min + max + 1) / 2;
+        if fonts.layout_single_line(text_style, elided(mid)).size.x <= max_width {
+            min = mid;
+        } else {
+            max = mid - 1;
+        }
+    }
+    fonts.layout_single_line(text_style, elided(min))
+}
+
 impl From<&str> for Label {
     fn from(s: &str) -> Label {
         Label::new(s)
@@ -0,0 +1,249 @@
+use crate::*;
+use std::collections::HashSet;
+
+/// Keeps track of which items in a list are selected,
+/// with the usual click, ctrl+click and shift+click semantics.
+///
+/// * Click: select only that item.
+/// * Ctrl+click (⌘ on Mac): toggle that item.
+/// * Shift+click: select the range from the last clicked item.
+/// * Up/down arrow keys: move the selection (add shift to extend it, or ctrl to only move the focus).
+/// * Space: toggle the focused item.
+///
+/// Store the `ListSelection` in your app state next to the list it belongs to.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let files = ["a.txt", "b.txt", "c.txt"];
+/// let mut selection = egui::ListSelection::default();
+///
+/// let change = selection.show(ui, files.len(), |ui, index, selected| {
+///     ui.selectable_label(selected, files[index])
+/// });
+/// if change.changed() {
+///     println!("Selected: {:?}", selection.selected());
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct ListSelection {
+    selected: HashSet<usize>,
+
+    /// Where shift+click ranges start.
+    anchor: Option<usize>,
+
+    /// The item last clicked or moved to with the arrow keys.
+    cursor: Option<usize>,
+
+    /// Give keyboard focus to the item at `cursor` next frame.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    focus_cursor: bool,
+}
+
+/// How the selection changed when showing a [`ListSelection`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SelectionChange {
+    /// Indices that were selected this frame, in ascending order.
+    pub added: Vec<usize>,
+
+    /// Indices that were deselected this frame, in ascending order.
+    pub removed: Vec<usize>,
+}
+
+impl SelectionChange {
+    /// Did the selection change this frame?
+    pub fn changed(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty()
+    }
+}
+
+impl ListSelection {
+    /// The indices of all selected items.
+    pub fn selected(&self) -> &HashSet<usize> {
+        &self.selected
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// The item last clicked or moved to with the arrow keys.
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Deselect everything.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+        self.cursor = None;
+    }
+
+    /// Select only this item.
+    pub fn select_only(&mut self, index: usize) {
+        self.selected.clear();
+        self.selected.insert(index);
+        self.anchor = Some(index);
+        self.cursor = Some(index);
+    }
+
+    /// Select or deselect this item, leaving the rest of the selection alone.
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+        self.anchor = Some(index);
+        self.cursor = Some(index);
+    }
+
+    /// Select all items in `0..num_items`.
+    pub fn select_all(&mut self, num_items: usize) {
+        self.selected = (0..num_items).collect();
+    }
+
+    /// Select the range from the anchor (the last clicked item) to `index`.
+    ///
+    /// If `extend` is `false`, everything outside that range is deselected.
+    pub fn select_range(&mut self, index: usize, extend: bool) {
+        let anchor = self.anchor.unwrap_or(index);
+        if !extend {
+            self.selected.clear();
+        }
+        self.selected.extend(anchor.min(index)..=anchor.max(index));
+        self.anchor = Some(anchor);
+        self.cursor = Some(index);
+    }
+
+    /// Show `num_items` items, updating the selection from clicks and keyboard input.
+    ///
+    /// `add_item` is called with the index of each item and whether or not it is selected,
+    /// and should add a clickable widget for it, e.g. a [`SelectableLabel`].
+    ///
+    /// Indices at or above `num_items` are removed from the selection.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        num_items: usize,
+        mut add_item: impl FnMut(&mut Ui, usize, bool) -> Response,
+    ) -> SelectionChange {
+        let before = self.selected.clone();
+        self.selected.retain(|&index| index < num_items);
+
+        let modifiers = ui.input().modifiers;
+        let space_pressed = ui.input().key_pressed(Key::Space);
+        let mut focused = None;
+
+        for index in 0..num_items {
+            let response = add_item(ui, index, self.is_selected(index));
+
+            if self.focus_cursor && self.cursor == Some(index) {
+                response.request_focus();
+                self.focus_cursor = false;
+            }
+
+            if response.has_focus() {
+                focused = Some(index);
+            }
+
+            if response.clicked() {
+                if response.has_focus() && space_pressed {
+                    self.toggle(index);
+                } else if modifiers.shift {
+                    self.select_range(index, modifiers.command);
+                } else if modifiers.command {
+                    self.toggle(index);
+                } else {
+                    self.select_only(index);
+                }
+                response.request_focus();
+            }
+        }
+
+        if let Some(focused) = focused {
+            ui.memory().use_arrow_keys();
+            let mut next = focused;
+            if ui.input().key_pressed(Key::ArrowUp) {
+                next = next.saturating_sub(1);
+            }
+            if ui.input().key_pressed(Key::ArrowDown) {
+                next = (next + 1).min(num_items - 1);
+            }
+
+            if next != focused {
+                if modifiers.shift {
+                    self.select_range(next, modifiers.command);
+                } else if modifiers.command {
+                    self.cursor = Some(next);
+                } else {
+                    self.select_only(next);
+                }
+                self.focus_cursor = true;
+                ui.ctx().request_repaint();
+            }
+        }
+
+        let mut added: Vec<usize> = self.selected.difference(&before).copied().collect();
+        let mut removed: Vec<usize> = before.difference(&self.selected).copied().collect();
+        added.sort_unstable();
+        removed.sort_unstable();
+        SelectionChange { added, removed }
+    }
+}
+
+#[cfg(test)]
+fn run_test_list(
+    harness: &mut crate::harness::Harness,
+    selection: &mut ListSelection,
+) -> (SelectionChange, Vec<Rect>) {
+    let mut change = SelectionChange::default();
+    let mut rects = vec![];
+    harness.run(|ui| {
+        change = selection.show(ui, 5, |ui, index, selected| {
+            let response = ui.selectable_label(selected, format!("Item {}", index));
+            rects.push(response.rect);
+            response
+        });
+    });
+    (change, rects)
+}
+
+#[test]
+fn test_list_selection() {
+    let mut harness = crate::harness::Harness::new(vec2(200.0, 200.0));
+    let mut selection = ListSelection::default();
+    let (_, rects) = run_test_list(&mut harness, &mut selection);
+
+    harness.click(rects[1].center());
+    let (change, _) = run_test_list(&mut harness, &mut selection);
+    assert_eq!(change.added, vec![1]);
+    assert!(change.removed.is_empty());
+
+    harness.set_modifiers(Modifiers {
+        shift: true,
+        ..Default::default()
+    });
+    harness.click(rects[3].center());
+    let (change, _) = run_test_list(&mut harness, &mut selection);
+    assert_eq!(change.added, vec![2, 3]);
+
+    harness.set_modifiers(Modifiers {
+        ctrl: true,
+        command: true,
+        ..Default::default()
+    });
+    harness.click(rects[2].center());
+    let (change, _) = run_test_list(&mut harness, &mut selection);
+    assert_eq!(change.removed, vec![2]);
+
+    harness.set_modifiers(Modifiers::default());
+    harness.click(rects[0].center());
+    let (change, _) = run_test_list(&mut harness, &mut selection);
+    assert_eq!(change.added, vec![0]);
+    assert_eq!(change.removed, vec![1, 3]);
+
+    harness.key_press(Key::ArrowDown);
+    let (change, _) = run_test_list(&mut harness, &mut selection);
+    assert_eq!(change.added, vec![1]);
+    assert_eq!(change.removed, vec![0]);
+}
@@ -0,0 +1,337 @@
+//! A scrolling list of chat messages.
+
+use std::hash::Hash;
+
+use crate::{util::format::format_date, *};
+
+/// One message in a [`MessageList`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    /// Who wrote the message.
+    pub author: String,
+
+    /// The message itself. Long lines are wrapped.
+    pub text: String,
+
+    /// When the message was sent, in seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+    pub time: f64,
+
+    /// Sent by the user (shown to the right), rather than received (shown to the left).
+    pub outgoing: bool,
+}
+
+impl Message {
+    /// A received message.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(author: impl ToString, text: impl ToString, time: f64) -> Self {
+        Self {
+            author: author.to_string(),
+            text: text.to_string(),
+            time,
+            outgoing: false,
+        }
+    }
+
+    /// Sent by the user, rather than received. Outgoing messages are shown to the right.
+    pub fn outgoing(mut self, outgoing: bool) -> Self {
+        self.outgoing = outgoing;
+        self
+    }
+}
+
+/// Measured row heights, so we can skip laying out the rows that are not visible.
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The width the heights were measured at.
+    width: f32,
+
+    /// Height of each message (including its day separator, if any), or `0.0` if not yet measured.
+    heights: Vec<f32>,
+}
+
+/// Shows a chat history as text bubbles in a [`ScrollArea`].
+///
+/// Received messages are shown to the left and sent messages to the right,
+/// each with its author and time. A separator with the date is shown whenever the day changes.
+/// The text in the bubbles can be selected and copied.
+///
+/// The list stays scrolled to the bottom as new messages arrive, unless the user has scrolled up.
+/// Only the visible messages are laid out, so it can show very long histories.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::Message;
+/// let messages = vec![
+///     Message::new("Alice", "Hi Bob!", 1_600_000_000.0),
+///     Message::new("Bob", "Hi Alice, how are you?", 1_600_000_060.0).outgoing(true),
+/// ];
+/// egui::MessageList::new(&messages).max_height(300.0).show(ui);
+/// ```
+#[must_use = "You should call .show()"]
+pub struct MessageList<'a> {
+    messages: &'a [Message],
+    id_source: Option<Id>,
+    max_height: f32,
+    max_bubble_width: f32,
+    utc_offset: f64,
+    stick_to_bottom: bool,
+}
+
+impl<'a> MessageList<'a> {
+    pub fn new(messages: &'a [Message]) -> Self {
+        Self {
+            messages,
+            id_source: None,
+            max_height: f32::INFINITY,
+            max_bubble_width: 0.75,
+            utc_offset: 0.0,
+            stick_to_bottom: true,
+        }
+    }
+
+    /// Needed if you show several message lists in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// The height of the scroll area. Default: fill the available space.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// The widest a bubble may be, as a fraction of the width of the list. Default: `0.75`.
+    pub fn max_bubble_width(mut self, fraction: f32) -> Self {
+        self.max_bubble_width = fraction.clamp(0.1, 1.0);
+        self
+    }
+
+    /// Show times and dates in this time zone, given as seconds ahead of UTC,
+    /// e.g. `3600.0` for UTC+1. Default: `0.0` (UTC).
+    pub fn utc_offset(mut self, seconds: f64) -> Self {
+        self.utc_offset = seconds;
+        self
+    }
+
+    /// Stay scrolled to the bottom when new messages arrive, unless the user has scrolled up.
+    /// Default: `true`.
+    pub fn stick_to_bottom(mut self, stick_to_bottom: bool) -> Self {
+        self.stick_to_bottom = stick_to_bottom;
+        self
+    }
+
+    /// Returns the range of messages that were visible.
+    pub fn show(self, ui: &mut Ui) -> std::ops::Range<usize> {
+        let Self {
+            messages,
+            id_source,
+            max_height,
+            max_bubble_width,
+            utc_offset,
+            stick_to_bottom,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("message_list")));
+
+        ScrollArea::from_max_height(max_height)
+            .id_source(id)
+            .stick_to_bottom(stick_to_bottom)
+            .show_viewport(ui, |ui, viewport| {
+                let width = ui.available_width();
+                let mut state =
+                    std::mem::take(ui.memory().id_data_temp.get_mut_or_default::<State>(id));
+                if state.width != width {
+                    // Everything wraps differently now:
+                    state.width = width;
+                    state.heights.clear();
+                }
+                state.heights.resize(messages.len(), 0.0);
+
+                let spacing = ui.spacing().item_spacing.y;
+                let estimated_height = estimate_height(ui);
+                let height_of = |heights: &[f32], i: usize| {
+                    if heights[i] > 0.0 {
+                        heights[i]
+                    } else {
+                        estimated_height
+                    }
+                };
+
+                // Find the first visible message:
+                let mut first = 0;
+                let mut first_top = 0.0;
+                while first < messages.len() {
+                    let bottom = first_top + height_of(&state.heights, first);
+                    if bottom > viewport.min.y {
+                        break;
+                    }
+                    first_top = bottom + spacing;
+                    first += 1;
+                }
+
+                let top = ui.max_rect().top();
+                let mut rows_ui = ui.child_ui(
+                    Rect::from_x_y_ranges(ui.max_rect().x_range(), top + first_top..=f32::INFINITY),
+                    Layout::top_down(Align::Min),
+                );
+                rows_ui.skip_ahead_auto_ids(first); // Make sure we get consistent IDs.
+
+                let mut last = first;
+                let mut changed = false;
+                while last < messages.len() && rows_ui.min_rect().bottom() - top < viewport.max.y {
+                    let previous_day = last
+                        .checked_sub(1)
+                        .map(|prev| day(messages[prev].time, utc_offset));
+                    let row = rows_ui.scope(|ui| {
+                        message_ui(
+                            ui,
+                            &messages[last],
+                            previous_day,
+                            width * max_bubble_width,
+                            utc_offset,
+                        );
+                    });
+                    let height = row.response.rect.height();
+                    if state.heights[last] != height {
+                        state.heights[last] = height;
+                        changed = true;
+                    }
+                    last += 1;
+                }
+
+                let total_height: f32 = (0..messages.len())
+                    .map(|i| height_of(&state.heights, i) + spacing)
+                    .sum::<f32>()
+                    - spacing;
+                ui.set_height(total_height.at_least(0.0));
+
+                if changed {
+                    // The estimated heights were wrong, so the scroll position may be too:
+                    ui.ctx().request_repaint();
+                }
+                ui.memory().id_data_temp.insert(id, state);
+
+                first..last
+            })
+    }
+}
+
+fn estimate_height(ui: &Ui) -> f32 {
+    let header = ui.fonts().row_height(TextStyle::Small);
+    let body = ui.fonts().row_height(ui.style().body_text_style);
+    header + body + ui.spacing().item_spacing.y + 2.0 * bubble_margin(ui).y
+}
+
+fn bubble_margin(ui: &Ui) -> Vec2 {
+    ui.spacing().button_padding + vec2(2.0, 2.0)
+}
+
+fn message_ui(
+    ui: &mut Ui,
+    message: &Message,
+    previous_day: Option<i64>,
+    max_bubble_width: f32,
+    utc_offset: f64,
+) {
+    let this_day = day(message.time, utc_offset);
+    if previous_day != Some(this_day) {
+        ui.vertical_centered(|ui| {
+            ui.add(Label::new(format_date(this_day)).small().weak());
+        });
+    }
+
+    let (align, fill) = if message.outgoing {
+        (Align::Max, ui.visuals().selection.bg_fill)
+    } else {
+        (Align::Min, ui.visuals().widgets.inactive.bg_fill)
+    };
+
+    let margin = bubble_margin(ui);
+    let text_style = ui.style().body_text_style;
+    let text_width = ui
+        .fonts()
+        .layout_multiline(
+            text_style,
+            message.text.clone(),
+            max_bubble_width - 2.0 * margin.x,
+        )
+        .size
+        .x;
+
+    ui.with_layout(Layout::top_down(align), |ui| {
+        Frame {
+            margin,
+            corner_radius: 2.0 * ui.visuals().widgets.noninteractive.corner_radius,
+            fill,
+            ..Default::default()
+        }
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.add(Label::new(&message.author).small().strong());
+                ui.add(
+                    Label::new(format_time_of_day(message.time, utc_offset))
+                        .small()
+                        .weak(),
+                );
+            });
+
+            let mut text = message.text.as_str();
+            ui.add(
+                TextEdit::multiline(&mut text)
+                    .frame(false)
+                    .desired_rows(1)
+                    .desired_width(text_width.ceil() + 1.0),
+            );
+        });
+    });
+}
+
+const SECONDS_PER_DAY: f64 = 24.0 * 60.0 * 60.0;
+
+/// Days since 1970-01-01 in the given time zone.
+fn day(time: f64, utc_offset: f64) -> i64 {
+    ((time + utc_offset) / SECONDS_PER_DAY).floor() as i64
+}
+
+/// `"14:05"`
+fn format_time_of_day(time: f64, utc_offset: f64) -> String {
+    let seconds = (time + utc_offset).rem_euclid(SECONDS_PER_DAY) as i64;
+    format!("{:02}:{:02}", seconds / 3600, seconds / 60 % 60)
+}
+
+#[test]
+fn test_format_date_and_time() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(day(1_600_000_000.0, 0.0)), "2020-09-13");
+    assert_eq!(format_date(day(951_782_400.0, 0.0)), "2000-02-29");
+    assert_eq!(format_date(-1), "1969-12-31");
+    assert_eq!(format_time_of_day(1_600_000_000.0, 0.0), "12:26");
+    assert_eq!(format_time_of_day(1_600_000_000.0, 2.0 * 3600.0), "14:26");
+}
+
+#[test]
+fn test_message_list_virtualization_and_stick_to_bottom() {
+    use crate::harness::Harness;
+
+    let mut messages: Vec<Message> = (0..1000)
+        .map(|i| Message::new("Alice", format!("Message {}", i), 60.0 * i as f64))
+        .collect();
+
+    let mut harness = Harness::new(vec2(300.0, 200.0));
+    let mut visible = 0..0;
+    for _ in 0..5 {
+        harness.run(|ui| visible = MessageList::new(&messages).show(ui));
+    }
+
+    // Only a few messages are laid out, and we start at the bottom:
+    assert!(visible.len() < 20, "{:?}", visible);
+    assert_eq!(visible.end, messages.len());
+
+    // New messages keep us at the bottom:
+    messages.push(Message::new("Bob", "Hello", 60_000.0).outgoing(true));
+    for _ in 0..3 {
+        harness.run(|ui| visible = MessageList::new(&messages).show(ui));
+    }
+    assert_eq!(visible.end, messages.len());
+}
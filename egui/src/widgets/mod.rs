@@ -6,24 +6,38 @@
 
 use crate::*;
 
+pub mod audio;
 mod button;
 pub mod color_picker;
 pub(crate) mod drag_value;
+mod gauge;
+mod hex_view;
 mod hyperlink;
 mod image;
+mod knob;
 mod label;
+mod list_selection;
+mod message_list;
 pub mod plot;
 mod progress_bar;
 mod selected_label;
 mod separator;
-mod slider;
+pub(crate) mod slider;
 pub(crate) mod text_edit;
+mod vector_edit;
 
+pub use audio::{LevelMeter, Waveform};
+pub use gauge::{Compass, Gauge};
+pub use hex_view::HexView;
 pub use hyperlink::*;
+pub use knob::{Knob, KnobDragMode};
 pub use label::*;
+pub use list_selection::{ListSelection, SelectionChange};
+pub use message_list::{Message, MessageList};
 pub use progress_bar::ProgressBar;
 pub use selected_label::*;
 pub use separator::*;
+pub use vector_edit::{MatrixEdit, VectorEdit};
 pub use {button::*, drag_value::DragValue, image::Image, slider::*, text_edit::*};
 
 // ----------------------------------------------------------------------------
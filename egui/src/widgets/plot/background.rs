@@ -0,0 +1,147 @@
+//! An image under the data of a plot, e.g. a map.
+
+use super::{
+    transform::{Bounds, ScreenTransform},
+    Value,
+};
+use crate::*;
+use epaint::Mesh;
+
+/// An image behind the grid and items of a [`super::Plot`], covering a rectangle in plot coordinates,
+/// e.g. a map under GPS traces. It pans and zooms together with the data.
+///
+/// A large image looks grainy when zoomed out, and is slow to draw.
+/// Add smaller versions of it with [`Self::downsampled`], and the plot picks one that fits the zoom level.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let map_texture = egui::TextureId::User(0);
+/// # let map_texture_small = egui::TextureId::User(1);
+/// use egui::plot::{Plot, PlotBackground, Value};
+/// // A 4096x2048 map covering longitudes -180..180 and latitudes -90..90:
+/// let map = PlotBackground::new(
+///     map_texture,
+///     [4096.0, 2048.0],
+///     Value::new(-180.0, -90.0),
+///     Value::new(180.0, 90.0),
+/// )
+/// .downsampled(map_texture_small, [1024.0, 512.0]);
+/// ui.add(Plot::new("map").background(map).data_aspect(1.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotBackground {
+    /// `(texture, size in texels)` of each version of the image, largest first.
+    levels: Vec<(TextureId, Vec2)>,
+    min: Value,
+    max: Value,
+    tint: Color32,
+    include_in_bounds: bool,
+}
+
+impl PlotBackground {
+    /// `size` is the size of the texture in texels.
+    /// The image is stretched from `min` (its bottom left corner) to `max` (its top right corner).
+    pub fn new(texture_id: TextureId, size: impl Into<Vec2>, min: Value, max: Value) -> Self {
+        Self {
+            levels: vec![(texture_id, size.into())],
+            min,
+            max,
+            tint: Color32::WHITE,
+            include_in_bounds: true,
+        }
+    }
+
+    /// Add a downsampled version of the image, e.g. at half or a quarter of the size.
+    ///
+    /// The plot draws the smallest version that still has at least one texel per pixel on screen,
+    /// or the largest one when zoomed in further than that.
+    pub fn downsampled(mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Self {
+        self.levels.push((texture_id, size.into()));
+        self.levels
+            .sort_by(|(_, a), (_, b)| b.x.partial_cmp(&a.x).unwrap_or(std::cmp::Ordering::Equal));
+        self
+    }
+
+    /// Multiply the colors of the image with this, e.g. to fade it. Default: [`Color32::WHITE`].
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Whether to fit the whole image in view when the plot bounds are set automatically.
+    /// Default: `true`.
+    pub fn include_in_bounds(mut self, include: bool) -> Self {
+        self.include_in_bounds = include;
+        self
+    }
+
+    pub(super) fn get_bounds(&self) -> Bounds {
+        let mut bounds = Bounds::NOTHING;
+        if self.include_in_bounds {
+            bounds.extend_with(&self.min);
+            bounds.extend_with(&self.max);
+        }
+        bounds
+    }
+
+    /// The version of the image to draw when it is `width_in_pixels` wide on screen.
+    fn pick_level(&self, width_in_pixels: f32) -> TextureId {
+        self.levels
+            .iter()
+            .rev()
+            .find(|(_, size)| size.x >= width_in_pixels)
+            .unwrap_or(&self.levels[0])
+            .0
+    }
+
+    pub(super) fn get_shape(&self, ui: &Ui, transform: &ScreenTransform) -> Option<Shape> {
+        let rect = Rect::from_two_pos(
+            transform.position_from_value(&self.min),
+            transform.position_from_value(&self.max),
+        );
+        let frame = *transform.frame();
+        if !rect.is_finite() || !rect.intersects(frame) {
+            return None;
+        }
+
+        // Only draw the visible part, so that the mesh stays small when zoomed in.
+        let visible = rect.intersect(frame);
+        let uv = Rect::from_min_max(
+            pos2(
+                remap(visible.min.x, rect.x_range(), 0.0..=1.0),
+                remap(visible.min.y, rect.y_range(), 0.0..=1.0),
+            ),
+            pos2(
+                remap(visible.max.x, rect.x_range(), 0.0..=1.0),
+                remap(visible.max.y, rect.y_range(), 0.0..=1.0),
+            ),
+        );
+
+        let texture_id = self.pick_level(rect.width() * ui.ctx().pixels_per_point());
+        let mut mesh = Mesh::with_texture(texture_id);
+        mesh.add_rect_with_uv(visible, uv, self.tint);
+        Some(Shape::mesh(mesh))
+    }
+}
+
+#[test]
+fn test_plot_background_level() {
+    let background = PlotBackground::new(
+        TextureId::User(0),
+        [1000.0, 500.0],
+        Value::new(0.0, 0.0),
+        Value::new(10.0, 5.0),
+    )
+    .downsampled(TextureId::User(2), [250.0, 125.0])
+    .downsampled(TextureId::User(1), [500.0, 250.0]);
+
+    assert_eq!(background.pick_level(100.0), TextureId::User(2));
+    assert_eq!(background.pick_level(250.0), TextureId::User(2));
+    assert_eq!(background.pick_level(300.0), TextureId::User(1));
+    assert_eq!(background.pick_level(800.0), TextureId::User(0));
+    assert_eq!(background.pick_level(5000.0), TextureId::User(0));
+
+    let bounds = background.get_bounds();
+    assert_eq!((bounds.min, bounds.max), ([0.0, 0.0], [10.0, 5.0]));
+    assert!(!background.include_in_bounds(false).get_bounds().is_valid());
+}
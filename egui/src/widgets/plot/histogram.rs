@@ -0,0 +1,184 @@
+//! Binning raw samples into a [`BarChart`].
+
+use super::items::{Bar, BarChart};
+
+/// How to divide the range of the samples of a [`Histogram`] into bins.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bins {
+    /// This many bins of equal width, from the smallest to the largest sample.
+    Count(usize),
+
+    /// Bins of this width, starting at multiples of it,
+    /// e.g. `Width(0.5)` gives the bins `[1.0, 1.5)`, `[1.5, 2.0)`, …
+    Width(f64),
+
+    /// Pick the number of bins from the number of samples, using Sturges' rule.
+    Auto,
+}
+
+impl Default for Bins {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+/// Counts how many samples fall in each bin, to show them as a [`BarChart`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Bins, Histogram, Plot};
+/// let samples = [1.0, 2.5, 2.7, 3.1, 3.3, 3.4, 4.8];
+/// let chart = Histogram::new(samples.iter().copied())
+///     .bins(Bins::Width(1.0))
+///     .bar_chart()
+///     .name("samples");
+/// ui.add(Plot::new("histogram").bar_chart(chart));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    samples: Vec<f64>,
+    bins: Bins,
+    cumulative: bool,
+    normalized: bool,
+}
+
+impl Histogram {
+    /// Samples that are not finite (NaN or infinite) are ignored.
+    pub fn new(samples: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            samples: samples.into_iter().filter(|x| x.is_finite()).collect(),
+            bins: Bins::Auto,
+            cumulative: false,
+            normalized: false,
+        }
+    }
+
+    /// How to pick the bins. Default: [`Bins::Auto`].
+    pub fn bins(mut self, bins: Bins) -> Self {
+        self.bins = bins;
+        self
+    }
+
+    /// Let each bar also count the samples of the bars to its left. Default: `false`.
+    pub fn cumulative(mut self, cumulative: bool) -> Self {
+        self.cumulative = cumulative;
+        self
+    }
+
+    /// Divide the counts by the number of samples, so that the bars show fractions of the samples.
+    /// The bars then sum up to one, or end at one if [`Self::cumulative`]. Default: `false`.
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+
+    /// One bar per bin, from left to right, with the (possibly cumulative and normalized) count
+    /// as the height. Empty bins between the smallest and largest sample are included.
+    pub fn bars(&self) -> Vec<Bar> {
+        let Self {
+            samples,
+            bins,
+            cumulative,
+            normalized,
+        } = self;
+
+        if samples.is_empty() {
+            return Vec::new();
+        }
+        let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let (start, width, count) = match *bins {
+            Bins::Width(width) if width > 0.0 && width.is_finite() => {
+                let start = (min / width).floor() * width;
+                let count = ((max - start) / width).floor() as usize + 1;
+                (start, width, count)
+            }
+            Bins::Count(count) if count > 0 => even_bins(min, max, count),
+            _ => {
+                // Sturges' rule:
+                let count = (samples.len() as f64).log2().ceil() as usize + 1;
+                even_bins(min, max, count)
+            }
+        };
+
+        let mut counts = vec![0.0; count];
+        for sample in samples {
+            let index = ((sample - start) / width).floor() as usize;
+            // The largest sample is at the very end of the last bin:
+            counts[index.min(count - 1)] += 1.0;
+        }
+        if *cumulative {
+            let mut sum = 0.0;
+            for height in &mut counts {
+                sum += *height;
+                *height = sum;
+            }
+        }
+        if *normalized {
+            let total = samples.len() as f64;
+            counts.iter_mut().for_each(|height| *height /= total);
+        }
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, height)| {
+                let x_min = start + i as f64 * width;
+                Bar::new(x_min, x_min + width, height)
+            })
+            .collect()
+    }
+
+    /// The histogram as bars to add to a [`super::Plot`].
+    pub fn bar_chart(&self) -> BarChart {
+        BarChart::new(self.bars())
+    }
+}
+
+/// `(start, width, count)` of `count` bins from `min` to `max`.
+fn even_bins(min: f64, max: f64, count: usize) -> (f64, f64, usize) {
+    if max > min {
+        (min, (max - min) / count as f64, count)
+    } else {
+        // All samples are the same, so put them in a single bin around them.
+        (min - 0.5, 1.0, 1)
+    }
+}
+
+#[test]
+fn test_histogram() {
+    let samples = vec![1.0, 2.5, 2.7, 3.1, 3.3, 3.4, 4.8, f64::NAN];
+    let heights = |histogram: Histogram| {
+        histogram
+            .bars()
+            .iter()
+            .map(|bar| bar.height)
+            .collect::<Vec<_>>()
+    };
+
+    let histogram = Histogram::new(samples.clone()).bins(Bins::Width(1.0));
+    let bars = histogram.bars();
+    assert_eq!(bars[0], Bar::new(1.0, 2.0, 1.0));
+    assert_eq!(bars.last().unwrap().x_max, 5.0);
+    assert_eq!(heights(histogram), vec![1.0, 2.0, 3.0, 1.0]);
+
+    let histogram = Histogram::new(samples.clone()).bins(Bins::Count(2));
+    assert_eq!(heights(histogram), vec![3.0, 4.0]);
+
+    let histogram = Histogram::new(samples.clone())
+        .bins(Bins::Width(1.0))
+        .cumulative(true)
+        .normalized(true);
+    let cumulative = heights(histogram);
+    assert_eq!(cumulative.len(), 4);
+    assert!((cumulative[3] - 1.0).abs() < 1e-9);
+
+    // Sturges' rule: 7 samples give 4 bins.
+    assert_eq!(Histogram::new(samples).bars().len(), 4);
+    assert_eq!(
+        Histogram::new(vec![2.0, 2.0]).bars(),
+        vec![Bar::new(1.5, 2.5, 2.0)]
+    );
+    assert!(Histogram::new(vec![]).bars().is_empty());
+}
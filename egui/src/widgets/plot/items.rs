@@ -14,6 +14,7 @@ const DEFAULT_FILL_ALPHA: f32 = 0.05;
 /// Uses f64 for improved accuracy to enable plotting
 /// large values (e.g. unix time on x axis).
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Value {
     /// This is often something monotonically increasing, such as time, but doesn't have to be.
     /// Goes from left to right.
@@ -351,6 +352,11 @@ pub(super) trait PlotItem {
     fn highlighted(&self) -> bool;
     fn values(&self) -> Option<&Values>;
     fn get_bounds(&self) -> Bounds;
+
+    /// How much this item adds at `x`, if it is a layer of a [`StackedArea`].
+    fn stacked_contribution(&self, _x: f64) -> Option<f64> {
+        None
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -840,6 +846,346 @@ impl PlotItem for Polygon {
     }
 }
 
+/// Several series stacked on top of each other, e.g. the memory use of a few processes over time.
+///
+/// Each series is filled between the sum of the series below it, and that sum plus itself.
+/// Hovering the plot shows how much each series contributes at the hovered x.
+///
+/// The series don't need to share x-values: each series is linearly interpolated
+/// at the x-values of the others, and counts as zero outside its own range.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Line, Plot, StackedArea, Value, Values};
+/// let cpu = Values::from_values(vec![Value::new(0.0, 1.0), Value::new(1.0, 2.0)]);
+/// let gpu = Values::from_values(vec![Value::new(0.0, 0.5), Value::new(1.0, 1.5)]);
+/// let stack = StackedArea::new()
+///     .series(Line::new(cpu).name("CPU"))
+///     .series(Line::new(gpu).name("GPU"));
+/// ui.add(Plot::new("usage").stacked_area(stack));
+/// ```
+pub struct StackedArea {
+    pub(super) series: Vec<Line>,
+    pub(super) fill_alpha: f32,
+}
+
+impl Default for StackedArea {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StackedArea {
+    pub fn new() -> Self {
+        Self {
+            series: Vec::new(),
+            fill_alpha: 0.5,
+        }
+    }
+
+    /// Add a series on top of the previous ones.
+    ///
+    /// The name, color and style of the line are used for the series,
+    /// but [`Line::fill`] is ignored.
+    /// The values must be given up front: callbacks such as [`Values::from_explicit_callback`]
+    /// are not supported.
+    pub fn series(mut self, line: Line) -> Self {
+        self.series.push(line);
+        self
+    }
+
+    /// Alpha of the filled areas. Default: `0.5`.
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// One plot item per series, from the bottom up.
+    pub(super) fn into_layers(self) -> Vec<StackedAreaLayer> {
+        let Self { series, fill_alpha } = self;
+
+        let mut xs: Vec<f64> = series
+            .iter()
+            .flat_map(|line| line.series.values.iter().map(|value| value.x))
+            .filter(|x| x.is_finite())
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs.dedup();
+
+        let mut below = vec![0.0; xs.len()];
+        series
+            .into_iter()
+            .map(|mut line| {
+                let mut own = std::mem::take(&mut line.series.values);
+                own.retain(|value| value.x.is_finite() && value.y.is_finite());
+                own.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+
+                let lower: Vec<Value> = xs
+                    .iter()
+                    .zip(&below)
+                    .map(|(&x, &y)| Value::new(x, y))
+                    .collect();
+                for (&x, y) in xs.iter().zip(&mut below) {
+                    *y += interpolate_y(&own, x);
+                }
+                line.series = Values::from_values(
+                    xs.iter()
+                        .zip(&below)
+                        .map(|(&x, &y)| Value::new(x, y))
+                        .collect(),
+                );
+
+                StackedAreaLayer {
+                    line,
+                    lower,
+                    own,
+                    fill_alpha,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The y-value of the line through `values` (sorted by x) at `x`, or zero outside of it.
+fn interpolate_y(values: &[Value], x: f64) -> f64 {
+    match (values.first(), values.last()) {
+        (Some(first), Some(last)) if first.x <= x && x <= last.x => {
+            match values.binary_search_by(|value| value.x.partial_cmp(&x).unwrap()) {
+                Ok(i) => values[i].y,
+                Err(i) => {
+                    let (left, right) = (values[i - 1], values[i]);
+                    remap(x, left.x..=right.x, left.y..=right.y)
+                }
+            }
+        }
+        _ => 0.0,
+    }
+}
+
+/// One series of a [`StackedArea`].
+pub(super) struct StackedAreaLayer {
+    /// The top edge of the layer, i.e. the sum of this series and the ones below it.
+    pub(super) line: Line,
+    /// The bottom edge of the layer, at the same x-values as the top edge.
+    lower: Vec<Value>,
+    /// The values of this series on its own, sorted by x.
+    own: Vec<Value>,
+    fill_alpha: f32,
+}
+
+impl PlotItem for StackedAreaLayer {
+    fn get_shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            line,
+            lower,
+            fill_alpha,
+            ..
+        } = self;
+
+        let upper: Vec<Pos2> = line
+            .series
+            .values
+            .iter()
+            .map(|v| transform.position_from_value(v))
+            .collect();
+
+        if upper.len() >= 2 {
+            let mut fill_alpha = *fill_alpha;
+            if line.highlight {
+                fill_alpha = (1.5 * fill_alpha).at_most(1.0);
+            }
+            let fill_color = Rgba::from(line.stroke.color)
+                .to_opaque()
+                .multiply(fill_alpha)
+                .into();
+
+            let mut mesh = Mesh::default();
+            mesh.reserve_triangles((upper.len() - 1) * 2);
+            mesh.reserve_vertices(upper.len() * 2);
+            for (top, bottom) in upper.iter().zip(lower) {
+                mesh.colored_vertex(*top, fill_color);
+                mesh.colored_vertex(transform.position_from_value(bottom), fill_color);
+            }
+            for i in 0..(upper.len() as u32 - 1) {
+                mesh.add_triangle(2 * i, 2 * i + 1, 2 * i + 2);
+                mesh.add_triangle(2 * i + 1, 2 * i + 2, 2 * i + 3);
+            }
+            shapes.push(Shape::Mesh(mesh));
+        }
+
+        line.style
+            .style_line(upper, line.stroke, line.highlight, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.line.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.line.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.line.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.line.highlight
+    }
+
+    fn values(&self) -> Option<&Values> {
+        Some(&self.line.series)
+    }
+
+    fn get_bounds(&self) -> Bounds {
+        let mut bounds = self.line.series.get_bounds();
+        self.lower
+            .iter()
+            .for_each(|value| bounds.extend_with(value));
+        bounds
+    }
+
+    fn stacked_contribution(&self, x: f64) -> Option<f64> {
+        Some(interpolate_y(&self.own, x))
+    }
+}
+
+/// A bar of a [`BarChart`], from `x_min` to `x_max`, and from zero up to `height`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bar {
+    pub x_min: f64,
+    pub x_max: f64,
+    pub height: f64,
+}
+
+impl Bar {
+    pub fn new(x_min: f64, x_max: f64, height: f64) -> Self {
+        Self {
+            x_min,
+            x_max,
+            height,
+        }
+    }
+}
+
+/// Bars standing on the x-axis, e.g. a histogram made with [`super::Histogram`].
+pub struct BarChart {
+    pub(super) bars: Vec<Bar>,
+    /// The middle of the top of each bar, for hovering.
+    tops: Values,
+    pub(super) color: Color32,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) fill_alpha: f32,
+}
+
+impl BarChart {
+    pub fn new(bars: Vec<Bar>) -> Self {
+        let tops = Values::from_values_iter(
+            bars.iter()
+                .map(|bar| Value::new(0.5 * (bar.x_min + bar.x_max), bar.height)),
+        );
+        Self {
+            bars,
+            tops,
+            color: Color32::TRANSPARENT,
+            name: Default::default(),
+            highlight: false,
+            fill_alpha: 0.5,
+        }
+    }
+
+    /// Highlight the bars in the plot by thickening their outlines.
+    pub fn highlight(mut self) -> Self {
+        self.highlight = true;
+        self
+    }
+
+    /// Color of the bars. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Alpha of the filled bars. Default: `0.5`.
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Name of this bar chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+impl PlotItem for BarChart {
+    fn get_shapes(&self, _ui: &mut Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            bars,
+            color,
+            highlight,
+            fill_alpha,
+            ..
+        } = self;
+
+        let fill = Rgba::from(*color).to_opaque().multiply(*fill_alpha).into();
+        let stroke = Stroke::new(if *highlight { 2.0 } else { 1.0 }, *color);
+        for bar in bars {
+            let rect = Rect::from_two_pos(
+                transform.position_from_value(&Value::new(bar.x_min, 0.0)),
+                transform.position_from_value(&Value::new(bar.x_max, bar.height)),
+            );
+            shapes.push(Shape::Rect {
+                rect,
+                corner_radius: 0.0,
+                fill,
+                stroke,
+            });
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn values(&self) -> Option<&Values> {
+        Some(&self.tops)
+    }
+
+    fn get_bounds(&self) -> Bounds {
+        let mut bounds = Bounds::NOTHING;
+        for bar in &self.bars {
+            bounds.extend_with(&Value::new(bar.x_min, 0.0));
+            bounds.extend_with(&Value::new(bar.x_max, bar.height));
+        }
+        bounds
+    }
+}
+
 /// Text inside the plot.
 pub struct Text {
     pub(super) text: String,
@@ -1476,3 +1822,26 @@ impl PlotItem for PlotImage {
         bounds
     }
 }
+
+#[test]
+fn test_stacked_area() {
+    let cpu = Values::from_values(vec![Value::new(0.0, 1.0), Value::new(2.0, 3.0)]);
+    let gpu = Values::from_values(vec![Value::new(1.0, 1.0), Value::new(3.0, 1.0)]);
+    let layers = StackedArea::new()
+        .series(Line::new(cpu).name("CPU"))
+        .series(Line::new(gpu).name("GPU"))
+        .into_layers();
+    assert_eq!(layers.len(), 2);
+
+    let ys = |values: &[Value]| values.iter().map(|value| value.y).collect::<Vec<_>>();
+
+    // Both layers use the x-values of all series:
+    assert_eq!(ys(&layers[0].lower), vec![0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(ys(&layers[0].line.series.values), vec![1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(ys(&layers[1].lower), vec![1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(ys(&layers[1].line.series.values), vec![1.0, 3.0, 4.0, 1.0]);
+
+    assert_eq!(layers[0].stacked_contribution(0.5), Some(1.5));
+    assert_eq!(layers[1].stacked_contribution(0.5), Some(0.0));
+    assert_eq!(layers[1].stacked_contribution(2.5), Some(1.0));
+}
@@ -1,9 +1,11 @@
 use std::{
     collections::{BTreeMap, HashSet},
     string::String,
+    sync::Arc,
 };
 
 use crate::*;
+use epaint::text::Galley;
 
 use super::items::PlotItem;
 
@@ -29,12 +31,52 @@ impl Corner {
     }
 }
 
+/// Whether to show the plot legend on top of the plot, beside it, or not at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LegendPlacement {
+    /// On top of the plot, in the corner given by [`Legend::position`].
+    Inside,
+
+    /// Beside the plot, on the side of [`Legend::position`].
+    /// The plot is made narrower to make room for it.
+    Outside,
+
+    /// Don't show the legend.
+    ///
+    /// All items are shown, but the plot remembers which ones were unchecked in the legend
+    /// until it is shown again.
+    Hidden,
+}
+
+impl LegendPlacement {
+    pub fn all() -> impl Iterator<Item = LegendPlacement> {
+        [
+            LegendPlacement::Inside,
+            LegendPlacement::Outside,
+            LegendPlacement::Hidden,
+        ]
+        .iter()
+        .copied()
+    }
+}
+
 /// The configuration for a plot legend.
 #[derive(Clone, Copy, PartialEq)]
 pub struct Legend {
     pub text_style: TextStyle,
     pub background_alpha: f32,
     pub position: Corner,
+    pub placement: LegendPlacement,
+
+    /// The fill of the legend background. `None` means [`crate::style::Visuals::extreme_bg_color`].
+    pub background_color: Option<Color32>,
+
+    /// The color of the item names. `None` means the usual text color of widgets.
+    pub text_color: Option<Color32>,
+
+    /// Start a new column after this many entries.
+    /// The legend also starts a new column when the next entry wouldn't fit in the plot.
+    pub max_rows: Option<usize>,
 }
 
 impl Default for Legend {
@@ -43,6 +85,10 @@ impl Default for Legend {
             text_style: TextStyle::Body,
             background_alpha: 0.75,
             position: Corner::RightTop,
+            placement: LegendPlacement::Inside,
+            background_color: None,
+            text_color: None,
+            max_rows: None,
         }
     }
 }
@@ -65,6 +111,33 @@ impl Legend {
         self.position = corner;
         self
     }
+
+    /// Whether to show the legend inside the plot, beside it, or not at all.
+    /// Default: `LegendPlacement::Inside`.
+    pub fn placement(mut self, placement: LegendPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// The fill of the legend background, before applying [`Self::background_alpha`].
+    /// Default: [`crate::style::Visuals::extreme_bg_color`].
+    pub fn background_color(mut self, color: impl Into<Color32>) -> Self {
+        self.background_color = Some(color.into());
+        self
+    }
+
+    /// The color of the item names. Default: the usual text color of widgets.
+    pub fn text_color(mut self, color: impl Into<Color32>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    /// Lay out the entries in columns of at most this many rows.
+    /// Default: as many rows as fit in the plot.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows.max(1));
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -83,26 +156,36 @@ impl LegendEntry {
         }
     }
 
-    fn ui(&mut self, ui: &mut Ui, text: String) -> Response {
+    /// The size of the icon and the name.
+    fn size(galley: &Galley) -> Vec2 {
+        let icon_size = galley.size.y;
+        let icon_spacing = icon_size / 5.0;
+        vec2(icon_size + icon_spacing, 0.0) + galley.size
+    }
+
+    fn ui(
+        &mut self,
+        ui: &Ui,
+        id: Id,
+        rect: Rect,
+        galley: Arc<Galley>,
+        text_color: Option<Color32>,
+        label_on_the_left: bool,
+    ) -> Response {
         let Self {
             color,
             checked,
             hovered,
         } = self;
 
-        let galley = ui.fonts().layout_no_wrap(ui.style().body_text_style, text);
-
         let icon_size = galley.size.y;
         let icon_spacing = icon_size / 5.0;
-        let total_extra = vec2(icon_size + icon_spacing, 0.0);
 
-        let desired_size = total_extra + galley.size;
-        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let response = ui.interact(rect, id, Sense::click());
 
         response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, *checked, &galley.text));
 
         let visuals = ui.style().interact(&response);
-        let label_on_the_left = ui.layout().horizontal_align() == Align::RIGHT;
 
         let icon_position_x = if label_on_the_left {
             rect.right() - icon_size / 2.0
@@ -142,7 +225,11 @@ impl LegendEntry {
         };
 
         let text_position = pos2(text_position_x, rect.center().y - 0.5 * galley.size.y);
-        painter.galley(text_position, galley, visuals.text_color());
+        painter.galley(
+            text_position,
+            galley,
+            text_color.unwrap_or_else(|| visuals.text_color()),
+        );
 
         *checked ^= response.clicked_by(PointerButton::Primary);
         *hovered = response.hovered();
@@ -153,20 +240,40 @@ impl LegendEntry {
 
 #[derive(Clone)]
 pub(super) struct LegendWidget {
+    id: Id,
+    /// Where to paint the legend background.
     rect: Rect,
+    /// What is left of the plot area.
+    plot_rect: Rect,
     entries: BTreeMap<String, LegendEntry>,
+    /// The laid out names, in the same order as `entries`.
+    galleys: Vec<Arc<Galley>>,
+    rows: usize,
+    column_widths: Vec<f32>,
     config: Legend,
 }
 
+/// Space between the legend background and the edge of the plot.
+const LEGEND_PAD: f32 = 4.0;
+const LEGEND_MARGIN: Vec2 = vec2(8.0, 4.0);
+
 impl LegendWidget {
     /// Create a new legend from items, the names of items that are hidden and the style of the
-    /// text. Returns `None` if the legend has no entries.
+    /// text. Returns `None` if the legend has no entries, or is hidden.
+    ///
+    /// `rect` is the space for the plot, including the legend if it is outside the plot.
     pub(super) fn try_new(
+        ui: &Ui,
+        id: Id,
         rect: Rect,
         config: Legend,
         items: &[Box<dyn PlotItem>],
         hidden_items: &HashSet<String>,
     ) -> Option<Self> {
+        if config.placement == LegendPlacement::Hidden {
+            return None;
+        }
+
         // Collect the legend entries. If multiple items have the same name, they share a
         // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
         let mut entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
@@ -188,13 +295,90 @@ impl LegendWidget {
                         LegendEntry::new(color, checked)
                     });
             });
-        (!entries.is_empty()).then(|| Self {
-            rect,
+        if entries.is_empty() {
+            return None;
+        }
+
+        let galleys: Vec<_> = entries
+            .keys()
+            .map(|name| ui.fonts().layout_no_wrap(config.text_style, name.clone()))
+            .collect();
+
+        // Fill columns from the top (or bottom), and start a new column when full:
+        let spacing = ui.spacing().item_spacing;
+        let row_height = ui.fonts().row_height(config.text_style);
+        let available_height = rect.height() - 2.0 * (LEGEND_PAD + LEGEND_MARGIN.y);
+        let rows_that_fit = ((available_height + spacing.y) / (row_height + spacing.y)).floor();
+        let rows = config
+            .max_rows
+            .unwrap_or(usize::MAX)
+            .min(rows_that_fit.max(1.0) as usize)
+            .min(galleys.len());
+        let column_widths: Vec<f32> = galleys
+            .chunks(rows)
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|galley| LegendEntry::size(galley).x)
+                    .fold(0.0, f32::max)
+            })
+            .collect();
+
+        let content_size = vec2(
+            column_widths.iter().sum::<f32>() + spacing.x * (column_widths.len() - 1) as f32,
+            row_height * rows as f32 + spacing.y * (rows - 1) as f32,
+        );
+        let size = content_size + 2.0 * LEGEND_MARGIN;
+
+        let (on_the_left, at_the_top) = match config.position {
+            Corner::LeftTop => (true, true),
+            Corner::RightTop => (false, true),
+            Corner::LeftBottom => (true, false),
+            Corner::RightBottom => (false, false),
+        };
+
+        let mut plot_rect = rect;
+        if config.placement == LegendPlacement::Outside {
+            // Make room beside the plot, but leave at least a sliver of it:
+            let width = (size.x + LEGEND_PAD)
+                .at_most(rect.width() - 16.0)
+                .at_least(0.0);
+            if on_the_left {
+                plot_rect.min.x += width;
+            } else {
+                plot_rect.max.x -= width;
+            }
+        }
+
+        let x = match (config.placement, on_the_left) {
+            (LegendPlacement::Outside, true) => rect.left(),
+            (LegendPlacement::Outside, false) => rect.right() - size.x,
+            (_, true) => rect.left() + LEGEND_PAD,
+            (_, false) => rect.right() - LEGEND_PAD - size.x,
+        };
+        let y = if at_the_top {
+            rect.top() + LEGEND_PAD
+        } else {
+            rect.bottom() - LEGEND_PAD - size.y
+        };
+
+        Some(Self {
+            id,
+            rect: Rect::from_min_size(pos2(x, y), size),
+            plot_rect,
             entries,
+            galleys,
+            rows,
+            column_widths,
             config,
         })
     }
 
+    /// The area left for the plot itself.
+    pub fn plot_rect(&self) -> Rect {
+        self.plot_rect
+    }
+
     // Get the names of the hidden items.
     pub fn get_hidden_items(&self) -> HashSet<String> {
         self.entries
@@ -216,44 +400,100 @@ impl LegendWidget {
 impl Widget for &mut LegendWidget {
     fn ui(self, ui: &mut Ui) -> Response {
         let LegendWidget {
+            id,
             rect,
             entries,
+            galleys,
+            rows,
+            column_widths,
             config,
+            ..
         } = self;
 
-        let main_dir = match config.position {
-            Corner::LeftTop | Corner::RightTop => Direction::TopDown,
-            Corner::LeftBottom | Corner::RightBottom => Direction::BottomUp,
-        };
-        let cross_align = match config.position {
-            Corner::LeftTop | Corner::LeftBottom => Align::LEFT,
-            Corner::RightTop | Corner::RightBottom => Align::RIGHT,
-        };
-        let layout = Layout::from_main_dir_and_cross_align(main_dir, cross_align);
-        let legend_pad = 4.0;
-        let legend_rect = rect.shrink(legend_pad);
-        let mut legend_ui = ui.child_ui(legend_rect, layout);
-        legend_ui
-            .scope(|ui| {
-                ui.style_mut().body_text_style = config.text_style;
-                let background_frame = Frame {
-                    margin: vec2(8.0, 4.0),
-                    corner_radius: ui.style().visuals.window_corner_radius,
-                    shadow: epaint::Shadow::default(),
-                    fill: ui.style().visuals.extreme_bg_color,
-                    stroke: ui.style().visuals.window_stroke(),
-                }
-                .multiply_with_opacity(config.background_alpha);
-                background_frame
-                    .show(ui, |ui| {
-                        entries
-                            .iter_mut()
-                            .map(|(name, entry)| entry.ui(ui, name.clone()))
-                            .reduce(|r1, r2| r1.union(r2))
-                            .unwrap()
-                    })
-                    .inner
-            })
-            .inner
+        let visuals = ui.visuals();
+        let background_frame = Frame {
+            corner_radius: visuals.window_corner_radius,
+            shadow: epaint::Shadow::default(),
+            fill: config.background_color.unwrap_or(visuals.extreme_bg_color),
+            stroke: visuals.window_stroke(),
+            ..Default::default()
+        }
+        .multiply_with_opacity(config.background_alpha);
+        ui.painter().add(background_frame.paint(*rect));
+
+        let label_on_the_left = matches!(config.position, Corner::RightTop | Corner::RightBottom);
+        let at_the_top = matches!(config.position, Corner::LeftTop | Corner::RightTop);
+        let spacing = ui.spacing().item_spacing;
+        let row_height = ui.fonts().row_height(config.text_style);
+        let content_rect = rect.shrink2(LEGEND_MARGIN);
+
+        let mut response = ui.interact(*rect, *id, Sense::hover());
+        let mut column_left = content_rect.left();
+        for (i, ((name, entry), galley)) in entries.iter_mut().zip(galleys.iter()).enumerate() {
+            let (column, row) = (i / *rows, i % *rows);
+            if column > 0 && row == 0 {
+                column_left += column_widths[column - 1] + spacing.x;
+            }
+            let y = if at_the_top {
+                content_rect.top() + row as f32 * (row_height + spacing.y)
+            } else {
+                content_rect.bottom() - row_height - row as f32 * (row_height + spacing.y)
+            };
+            let size = LegendEntry::size(galley);
+            // Line up the icons along the edge of the plot:
+            let x = if label_on_the_left {
+                column_left + column_widths[column] - size.x
+            } else {
+                column_left
+            };
+            let entry_rect = Rect::from_min_size(pos2(x, y), vec2(size.x, row_height));
+            response |= entry.ui(
+                ui,
+                id.with(name),
+                entry_rect,
+                galley.clone(),
+                config.text_color,
+                label_on_the_left,
+            );
+        }
+        response
     }
 }
+
+#[test]
+fn test_legend_placement() {
+    use super::{Line, Plot, Values};
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(400.0, 300.0));
+    let mut plot_rect_with = |legend: Legend| {
+        let mut plot_rect = Rect::NOTHING;
+        harness.run(|ui| {
+            let line = Line::new(Values::from_explicit_callback(|x| x, .., 10)).name("line");
+            let plot = Plot::new("plot")
+                .line(line)
+                .width(300.0)
+                .height(200.0)
+                .legend(legend);
+            plot_rect = ui.add(plot).rect;
+        });
+        plot_rect
+    };
+
+    let inside = plot_rect_with(Legend::default());
+    assert_eq!(inside.width(), 300.0);
+
+    let outside = plot_rect_with(Legend::default().placement(LegendPlacement::Outside));
+    assert!(outside.width() < 280.0, "{:?}", outside);
+    assert_eq!(outside.left(), inside.left());
+
+    let outside_left = plot_rect_with(
+        Legend::default()
+            .placement(LegendPlacement::Outside)
+            .position(Corner::LeftBottom),
+    );
+    assert_eq!(outside_left.right(), inside.right());
+
+    let hidden = plot_rect_with(Legend::default().placement(LegendPlacement::Hidden));
+    assert_eq!(hidden, inside);
+}
@@ -1,22 +1,26 @@
 //! Simple plotting library.
 
+mod background;
+mod histogram;
 mod items;
 mod legend;
+mod time_axis;
 mod transform;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
+pub use background::PlotBackground;
+pub use histogram::{Bins, Histogram};
 use items::PlotItem;
 pub use items::{
-    Arrows, HLine, Line, LineStyle, MarkerShape, PlotImage, Points, Polygon, Text, VLine, Value,
-    Values,
+    Arrows, Bar, BarChart, HLine, Line, LineStyle, MarkerShape, PlotImage, Points, Polygon,
+    StackedArea, Text, VLine, Value, Values,
 };
 use legend::LegendWidget;
-pub use legend::{Corner, Legend};
+pub use legend::{Corner, Legend, LegendPlacement};
 use transform::{Bounds, ScreenTransform};
 
-use crate::*;
-use color::Hsva;
+use crate::{util::format::TimeLabel, *};
 
 // ----------------------------------------------------------------------------
 
@@ -28,6 +32,46 @@ struct PlotMemory {
     auto_bounds: bool,
     hovered_entry: Option<String>,
     hidden_items: HashSet<String>,
+    /// The measurement cursors placed so far, see [`Plot::measure`].
+    cursors: Vec<Value>,
+    /// The measurement cursor being dragged, if any.
+    dragged_cursor: Option<usize>,
+    /// Two opposite corners of the box selection, see [`Plot::box_select`].
+    selection: Option<[Value; 2]>,
+}
+
+/// The points inside the box selection of a [`Plot`], see [`Plot::box_select`] and [`Plot::selection`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlotSelection {
+    /// The indices of the selected points of each item, by item name.
+    /// Items with the same name share an entry.
+    pub points: BTreeMap<String, Vec<usize>>,
+}
+
+// ----------------------------------------------------------------------------
+
+/// What the values along an axis of a [`Plot`] are, which decides how the axis is labeled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisMode {
+    /// Plain numbers, with grid lines at powers of ten.
+    Numbers,
+
+    /// Points in time, as seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+    ///
+    /// Depending on the zoom level, the grid lines fall on whole milliseconds, seconds,
+    /// minutes, hours or days, and are labeled with e.g. `12:30:15.5`, `12:30` or `2021-06-01`.
+    /// The value shown when hovering the plot is formatted the same way.
+    Time {
+        /// Show times and dates in this time zone, given as seconds ahead of UTC,
+        /// e.g. `3600.0` for UTC+1.
+        utc_offset: f64,
+    },
+}
+
+impl Default for AxisMode {
+    fn default() -> Self {
+        Self::Numbers
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -53,11 +97,14 @@ pub struct Plot {
     next_auto_color_idx: usize,
 
     items: Vec<Box<dyn PlotItem>>,
+    background: Option<PlotBackground>,
 
     center_x_axis: bool,
     center_y_axis: bool,
     allow_zoom: bool,
     allow_drag: bool,
+    measure: bool,
+    box_select: bool,
     min_auto_bounds: Bounds,
     margin_fraction: Vec2,
 
@@ -69,6 +116,7 @@ pub struct Plot {
 
     show_x: bool,
     show_y: bool,
+    axis_modes: [AxisMode; 2],
     legend_config: Option<Legend>,
 }
 
@@ -80,11 +128,14 @@ impl Plot {
             next_auto_color_idx: 0,
 
             items: Default::default(),
+            background: None,
 
             center_x_axis: false,
             center_y_axis: false,
             allow_zoom: true,
             allow_drag: true,
+            measure: false,
+            box_select: false,
             min_auto_bounds: Bounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
 
@@ -96,6 +147,7 @@ impl Plot {
 
             show_x: true,
             show_y: true,
+            axis_modes: Default::default(),
             legend_config: None,
         }
     }
@@ -103,9 +155,7 @@ impl Plot {
     fn auto_color(&mut self) -> Color32 {
         let i = self.next_auto_color_idx;
         self.next_auto_color_idx += 1;
-        let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
-        let h = i as f32 * golden_ratio;
-        Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO: OkLab or some other perspective color space
+        crate::color::categorical_color(i)
     }
 
     /// Add a data lines.
@@ -136,6 +186,24 @@ impl Plot {
         self
     }
 
+    /// Add a stacked area chart, with one legend entry per series.
+    pub fn stacked_area(mut self, stacked_area: StackedArea) -> Self {
+        for mut layer in stacked_area.into_layers() {
+            // Give the series an automatic color if no color has been assigned.
+            if layer.line.stroke.color == Color32::TRANSPARENT {
+                layer.line.stroke.color = self.auto_color();
+            }
+            self.items.push(Box::new(layer));
+        }
+        self
+    }
+
+    /// Show an image behind the grid and all items, e.g. a map under GPS traces.
+    pub fn background(mut self, background: PlotBackground) -> Self {
+        self.background = Some(background);
+        self
+    }
+
     /// Add a text.
     pub fn text(mut self, text: Text) -> Self {
         if text.text.is_empty() {
@@ -160,6 +228,20 @@ impl Plot {
         self
     }
 
+    /// Add bars, e.g. a [`Histogram`].
+    pub fn bar_chart(mut self, mut bar_chart: BarChart) -> Self {
+        if bar_chart.bars.is_empty() {
+            return self;
+        };
+
+        // Give the bars an automatic color if no color has been assigned.
+        if bar_chart.color == Color32::TRANSPARENT {
+            bar_chart.color = self.auto_color();
+        }
+        self.items.push(Box::new(bar_chart));
+        self
+    }
+
     /// Add arrows.
     pub fn arrows(mut self, mut arrows: Arrows) -> Self {
         if arrows.origins.is_empty() || arrows.tips.is_empty() {
@@ -252,6 +334,33 @@ impl Plot {
         self
     }
 
+    /// How to label the x-axis. Default: [`AxisMode::Numbers`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::{AxisMode, Line, Plot, Value, Values};
+    /// // One value per minute, starting 2021-06-01 00:00:00 UTC:
+    /// let temperatures = (0..1000).map(|i| {
+    ///     let time = 1_622_505_600.0 + 60.0 * i as f64;
+    ///     Value::new(time, 20.0 + (i as f64 / 100.0).sin())
+    /// });
+    /// ui.add(
+    ///     Plot::new("temperatures")
+    ///         .line(Line::new(Values::from_values_iter(temperatures)))
+    ///         .x_axis_mode(AxisMode::Time { utc_offset: 0.0 }),
+    /// );
+    /// ```
+    pub fn x_axis_mode(mut self, mode: AxisMode) -> Self {
+        self.axis_modes[0] = mode;
+        self
+    }
+
+    /// How to label the y-axis. Default: [`AxisMode::Numbers`].
+    pub fn y_axis_mode(mut self, mode: AxisMode) -> Self {
+        self.axis_modes[1] = mode;
+        self
+    }
+
     #[deprecated = "Renamed center_x_axis"]
     pub fn symmetrical_x_axis(mut self, on: bool) -> Self {
         self.center_x_axis = on;
@@ -288,6 +397,51 @@ impl Plot {
         self
     }
 
+    /// Measure the distance between two points, like the cursors of an oscilloscope.
+    /// Default: `false`.
+    ///
+    /// Click to place cursor A, click again to place cursor B,
+    /// and the plot shows the differences in x and y between them, and the slope.
+    /// The cursors can then be dragged around. Another click starts over with a new cursor A.
+    /// The cursors are remembered between frames.
+    pub fn measure(mut self, on: bool) -> Self {
+        self.measure = on;
+        self
+    }
+
+    /// Select points by dragging a rectangle around them. Default: `false`.
+    ///
+    /// While this is on, dragging selects instead of moving the plot.
+    /// A click clears the selection.
+    /// The selected points are highlighted, and you get their indices with [`Self::selection`].
+    pub fn box_select(mut self, on: bool) -> Self {
+        self.box_select = on;
+        self
+    }
+
+    /// The points inside the box selection of the plot with this `id_source`, see [`Self::box_select`].
+    ///
+    /// Call this after adding the plot to the same `ui` to get the selection of the current frame.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::{Plot, Points, Value, Values};
+    /// let points = Points::new(Values::from_values(vec![Value::new(1.0, 2.0)])).name("samples");
+    /// ui.add(Plot::new("my_plot").points(points).box_select(true));
+    /// let selection = Plot::selection(ui, "my_plot");
+    /// if let Some(indices) = selection.points.get("samples") {
+    ///     ui.label(format!("{} points selected", indices.len()));
+    /// }
+    /// ```
+    pub fn selection(ui: &Ui, id_source: impl std::hash::Hash) -> PlotSelection {
+        let plot_id = ui.make_persistent_id(Id::new(id_source));
+        ui.memory()
+            .id_data_temp
+            .get::<PlotSelection>(&plot_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// Expand bounds to include the given x value.
     /// For instance, to always show the y axis, call `plot.include_x(0.0)`.
     pub fn include_x(mut self, x: impl Into<f64>) -> Self {
@@ -322,10 +476,13 @@ impl Widget for Plot {
             id_source,
             next_auto_color_idx: _,
             mut items,
+            background,
             center_x_axis,
             center_y_axis,
             allow_zoom,
             allow_drag,
+            measure,
+            box_select,
             min_auto_bounds,
             margin_fraction,
             width,
@@ -335,6 +492,7 @@ impl Widget for Plot {
             view_aspect,
             mut show_x,
             mut show_y,
+            axis_modes,
             legend_config,
         } = self;
 
@@ -347,6 +505,9 @@ impl Widget for Plot {
                 auto_bounds: !min_auto_bounds.is_valid(),
                 hovered_entry: None,
                 hidden_items: HashSet::new(),
+                cursors: Vec::new(),
+                dragged_cursor: None,
+                selection: None,
             })
             .clone();
 
@@ -355,6 +516,9 @@ impl Widget for Plot {
             mut auto_bounds,
             mut hovered_entry,
             mut hidden_items,
+            mut cursors,
+            mut dragged_cursor,
+            mut selection,
         } = memory;
 
         // Determine the size of the plot in the UI
@@ -381,7 +545,22 @@ impl Widget for Plot {
             vec2(width, height)
         };
 
-        let (rect, response) = ui.allocate_exact_size(size, Sense::drag());
+        let (full_rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+        // Legend
+        let legend = legend_config.and_then(|config| {
+            LegendWidget::try_new(
+                ui,
+                plot_id.with("legend"),
+                full_rect,
+                config,
+                &items,
+                &hidden_items,
+            )
+        });
+        // A legend outside the plot takes some of the space.
+        let rect = legend.as_ref().map_or(full_rect, LegendWidget::plot_rect);
+        let response = ui.interact(rect, response.id, Sense::drag());
         let plot_painter = ui.painter().sub_region(rect);
 
         // Background
@@ -392,16 +571,18 @@ impl Widget for Plot {
             stroke: ui.visuals().widgets.noninteractive.bg_stroke,
         });
 
-        // Legend
-        let legend = legend_config
-            .and_then(|config| LegendWidget::try_new(rect, config, &items, &hidden_items));
+        if legend.is_none() {
+            hovered_entry = None;
+        }
         // Don't show hover cursor when hovering over legend.
         if hovered_entry.is_some() {
             show_x = false;
             show_y = false;
         }
-        // Remove the deselected items.
-        items.retain(|item| !hidden_items.contains(item.name()));
+        // Remove the deselected items, unless the legend they were deselected in is hidden.
+        if legend.is_some() {
+            items.retain(|item| !hidden_items.contains(item.name()));
+        }
         // Highlight the hovered items.
         if let Some(hovered_name) = &hovered_entry {
             items
@@ -417,6 +598,9 @@ impl Widget for Plot {
         // Set bounds automatically based on content.
         if auto_bounds || !bounds.is_valid() {
             bounds = min_auto_bounds;
+            if let Some(background) = &background {
+                bounds.merge(&background.get_bounds());
+            }
             items
                 .iter()
                 .for_each(|item| bounds.merge(&item.get_bounds()));
@@ -442,22 +626,73 @@ impl Widget for Plot {
             transform.set_aspect(data_aspect as f64);
         }
 
+        // Measurement cursors
+        if measure {
+            let grab_radius = 8.0;
+            if response.drag_started() {
+                dragged_cursor = response.interact_pointer_pos().and_then(|pointer| {
+                    cursors.iter().position(|cursor| {
+                        transform.position_from_value(cursor).distance(pointer) < grab_radius
+                    })
+                });
+            }
+            if let Some(index) = dragged_cursor {
+                let pos = transform.position_from_value(&cursors[index]) + response.drag_delta();
+                cursors[index] = transform.value_from_position(pos);
+            }
+            // Pressing on a cursor without moving it is still a click:
+            if response.clicked_by(PointerButton::Primary)
+                && !response.double_clicked_by(PointerButton::Primary)
+            {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    if cursors.len() == 2 {
+                        cursors.clear();
+                    }
+                    cursors.push(transform.value_from_position(pointer));
+                }
+            }
+            if response.drag_released() {
+                dragged_cursor = None;
+            }
+        }
+
+        // Box selection
+        if box_select && dragged_cursor.is_none() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let value = transform.value_from_position(pointer);
+                if response.drag_started() {
+                    selection = Some([value, value]);
+                } else if let Some(corners) = &mut selection {
+                    if response.dragged_by(PointerButton::Primary) {
+                        corners[1] = value;
+                    }
+                }
+            }
+            if response.clicked_by(PointerButton::Primary) {
+                selection = None;
+            }
+        }
+
         // Dragging
-        if allow_drag && response.dragged_by(PointerButton::Primary) {
+        if allow_drag
+            && response.dragged_by(PointerButton::Primary)
+            && dragged_cursor.is_none()
+            && !box_select
+        {
             transform.translate_bounds(-response.drag_delta());
             auto_bounds = false;
         }
 
         // Zooming
-        if allow_zoom {
-            if let Some(hover_pos) = response.hover_pos() {
+        if allow_zoom && response.hovered() {
+            if let Some(zoom_anchor) = ui.input().zoom_anchor() {
                 let zoom_factor = if data_aspect.is_some() {
                     Vec2::splat(ui.input().zoom_delta())
                 } else {
                     ui.input().zoom_delta_2d()
                 };
                 if zoom_factor != Vec2::splat(1.0) {
-                    transform.zoom(zoom_factor, hover_pos);
+                    transform.zoom(zoom_factor, zoom_anchor);
                     auto_bounds = false;
                 }
 
@@ -476,10 +711,43 @@ impl Widget for Plot {
 
         let bounds = *transform.bounds();
 
+        let selection_bounds = selection.filter(|_| box_select).map(|[a, b]| {
+            let mut bounds = Bounds::NOTHING;
+            bounds.extend_with(&a);
+            bounds.extend_with(&b);
+            bounds
+        });
+        let mut selected = PlotSelection::default();
+        if let Some(selection_bounds) = &selection_bounds {
+            for item in &items {
+                let indices = item.values().into_iter().flat_map(|values| {
+                    values
+                        .values
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, value)| selection_bounds.contains(value))
+                        .map(|(index, _)| index)
+                });
+                let indices: Vec<usize> = indices.collect();
+                if !indices.is_empty() {
+                    selected
+                        .points
+                        .entry(item.name().to_owned())
+                        .or_default()
+                        .extend(indices);
+                }
+            }
+        }
+        ui.memory().id_data_temp.insert(plot_id, selected);
+
         let prepared = Prepared {
             items,
+            background,
             show_x,
             show_y,
+            axis_modes,
+            cursors: if measure { cursors.clone() } else { Vec::new() },
+            selection: selection_bounds,
             transform,
         };
         prepared.ui(ui, &response);
@@ -497,6 +765,9 @@ impl Widget for Plot {
                 auto_bounds,
                 hovered_entry,
                 hidden_items,
+                cursors,
+                dragged_cursor,
+                selection,
             },
         );
 
@@ -510,8 +781,13 @@ impl Widget for Plot {
 
 struct Prepared {
     items: Vec<Box<dyn PlotItem>>,
+    background: Option<PlotBackground>,
     show_x: bool,
     show_y: bool,
+    axis_modes: [AxisMode; 2],
+    cursors: Vec<Value>,
+    /// The box selection, see [`Plot::box_select`].
+    selection: Option<Bounds>,
     transform: ScreenTransform,
 }
 
@@ -519,6 +795,10 @@ impl Prepared {
     fn ui(self, ui: &mut Ui, response: &Response) {
         let mut shapes = Vec::new();
 
+        if let Some(background) = &self.background {
+            shapes.extend(background.get_shape(ui, &self.transform));
+        }
+
         for d in 0..2 {
             self.paint_axis(ui, d, &mut shapes);
         }
@@ -531,6 +811,9 @@ impl Prepared {
             item.get_shapes(&mut plot_ui, transform, &mut shapes);
         }
 
+        self.paint_selection(ui, &mut shapes);
+        self.paint_cursors(ui, &mut shapes);
+
         if let Some(pointer) = response.hover_pos() {
             self.hover(ui, pointer, &mut shapes);
         }
@@ -539,10 +822,16 @@ impl Prepared {
     }
 
     fn paint_axis(&self, ui: &Ui, axis: usize, shapes: &mut Vec<Shape>) {
+        match self.axis_modes[axis] {
+            AxisMode::Numbers => self.paint_number_axis(ui, axis, shapes),
+            AxisMode::Time { utc_offset } => self.paint_time_axis(ui, axis, utc_offset, shapes),
+        }
+    }
+
+    fn paint_number_axis(&self, ui: &Ui, axis: usize, shapes: &mut Vec<Shape>) {
         let Self { transform, .. } = self;
 
         let bounds = transform.bounds();
-        let text_style = TextStyle::Body;
 
         let base: i64 = 10;
         let basef = base as f64;
@@ -553,22 +842,12 @@ impl Prepared {
 
         let step_size_in_points = (transform.dpos_dvalue()[axis] * step_size).abs() as f32;
 
-        // Where on the cross-dimension to show the label values
-        let value_cross = 0.0_f64.clamp(bounds.min[1 - axis], bounds.max[1 - axis]);
-
         for i in 0.. {
             let value_main = step_size * (bounds.min[axis] / step_size + i as f64).floor();
             if value_main > bounds.max[axis] {
                 break;
             }
 
-            let value = if axis == 0 {
-                Value::new(value_main, value_cross)
-            } else {
-                Value::new(value_cross, value_main)
-            };
-            let pos_in_gui = transform.position_from_value(&value);
-
             let n = (value_main / step_size).round() as i64;
             let spacing_in_points = if n % (base * base) == 0 {
                 step_size_in_points * (basef * basef) as f32 // think line (multiple of 100)
@@ -584,45 +863,213 @@ impl Prepared {
                 0.0..=0.15,
             );
 
-            if line_alpha > 0.0 {
-                let line_color = color_from_alpha(ui, line_alpha);
+            let text_alpha = remap_clamp(spacing_in_points, 40.0..=150.0, 0.0..=0.4);
+            let label = (text_alpha > 0.0).then(|| {
+                let text = ui
+                    .ctx()
+                    .value_formatter()
+                    .format_number(emath::round_to_decimals(value_main, 5), 0..=5);
+                (text, text_alpha)
+            });
+
+            self.paint_grid_line(ui, axis, value_main, line_alpha, label, shapes);
+        }
+    }
 
-                let mut p0 = pos_in_gui;
-                let mut p1 = pos_in_gui;
-                p0[1 - axis] = transform.frame().min[1 - axis];
-                p1[1 - axis] = transform.frame().max[1 - axis];
-                shapes.push(Shape::line_segment([p0, p1], Stroke::new(1.0, line_color)));
-            }
+    fn paint_time_axis(&self, ui: &Ui, axis: usize, utc_offset: f64, shapes: &mut Vec<Shape>) {
+        let Self { transform, .. } = self;
 
-            let text_alpha = remap_clamp(spacing_in_points, 40.0..=150.0, 0.0..=0.4);
+        let bounds = transform.bounds();
+
+        // Labels are wider than numbers, so we keep them further apart.
+        let min_label_spacing_in_points = if axis == 0 { 120.0 } else { 40.0 };
+        let (step, minor_step) =
+            time_axis::steps(transform.dvalue_dpos()[axis].abs() * min_label_spacing_in_points);
+        let minor_steps_per_step = (step / minor_step).round() as i64;
+
+        let points_per_second = transform.dpos_dvalue()[axis].abs() as f32;
+        let formatter = ui.ctx().value_formatter();
+
+        // Count whole minor steps in local time, so that e.g. days start at local midnight:
+        let first = ((bounds.min[axis] + utc_offset) / minor_step).ceil() as i64;
+        let last = ((bounds.max[axis] + utc_offset) / minor_step).floor() as i64;
+        for n in first..=last {
+            let value_main = n as f64 * minor_step - utc_offset;
+            let is_major = n % minor_steps_per_step == 0;
+
+            let spacing_in_points =
+                if is_major { step } else { minor_step } as f32 * points_per_second;
+            let line_alpha = remap_clamp(spacing_in_points, 6.0..=300.0, 0.0..=0.15);
+            let label = is_major.then(|| {
+                let text = formatter.format_time(value_main, step, utc_offset, TimeLabel::Tick);
+                (text, 0.4)
+            });
+
+            self.paint_grid_line(ui, axis, value_main, line_alpha, label, shapes);
+        }
+    }
 
-            if text_alpha > 0.0 {
-                let color = color_from_alpha(ui, text_alpha);
-                let text = emath::round_to_decimals(value_main, 5).to_string(); // hack
+    /// A grid line across the plot at `value_main` along `axis`, with an optional label.
+    fn paint_grid_line(
+        &self,
+        ui: &Ui,
+        axis: usize,
+        value_main: f64,
+        line_alpha: f32,
+        label: Option<(String, f32)>,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let Self { transform, .. } = self;
 
-                let galley = ui.fonts().layout_single_line(text_style, text);
+        let bounds = transform.bounds();
+
+        // Where on the cross-dimension to show the label values
+        let value_cross = 0.0_f64.clamp(bounds.min[1 - axis], bounds.max[1 - axis]);
 
-                let mut text_pos = pos_in_gui + vec2(1.0, -galley.size.y);
+        let value = if axis == 0 {
+            Value::new(value_main, value_cross)
+        } else {
+            Value::new(value_cross, value_main)
+        };
+        let pos_in_gui = transform.position_from_value(&value);
 
-                // Make sure we see the labels, even if the axis is off-screen:
-                text_pos[1 - axis] = text_pos[1 - axis]
-                    .at_most(transform.frame().max[1 - axis] - galley.size[1 - axis] - 2.0)
-                    .at_least(transform.frame().min[1 - axis] + 1.0);
+        if line_alpha > 0.0 {
+            let line_color = color_from_alpha(ui, line_alpha);
 
-                shapes.push(Shape::Text {
-                    pos: text_pos,
-                    galley,
-                    color,
-                    fake_italics: false,
-                });
+            let mut p0 = pos_in_gui;
+            let mut p1 = pos_in_gui;
+            p0[1 - axis] = transform.frame().min[1 - axis];
+            p1[1 - axis] = transform.frame().max[1 - axis];
+            shapes.push(Shape::line_segment([p0, p1], Stroke::new(1.0, line_color)));
+        }
+
+        if let Some((text, text_alpha)) = label {
+            let color = color_from_alpha(ui, text_alpha);
+            let galley = ui.fonts().layout_single_line(TextStyle::Body, text);
+
+            let mut text_pos = pos_in_gui + vec2(1.0, -galley.size.y);
+
+            // Make sure we see the labels, even if the axis is off-screen:
+            text_pos[1 - axis] = text_pos[1 - axis]
+                .at_most(transform.frame().max[1 - axis] - galley.size[1 - axis] - 2.0)
+                .at_least(transform.frame().min[1 - axis] + 1.0);
+
+            shapes.push(Shape::Text {
+                pos: text_pos,
+                galley,
+                color,
+                fake_italics: false,
+            });
+        }
+    }
+
+    /// The measurement cursors, and the differences between them.
+    fn paint_cursors(&self, ui: &Ui, shapes: &mut Vec<Shape>) {
+        let Self {
+            transform,
+            axis_modes,
+            cursors,
+            ..
+        } = self;
+
+        let frame = transform.frame();
+        let stroke = ui.visuals().selection.stroke;
+
+        for (cursor, name) in cursors.iter().zip(["A", "B"].iter()) {
+            let pos = transform.position_from_value(cursor);
+            shapes.extend(Shape::dashed_line(
+                &[pos2(pos.x, frame.top()), pos2(pos.x, frame.bottom())],
+                stroke,
+                4.0,
+                4.0,
+            ));
+            shapes.extend(Shape::dashed_line(
+                &[pos2(frame.left(), pos.y), pos2(frame.right(), pos.y)],
+                stroke,
+                4.0,
+                4.0,
+            ));
+            shapes.push(Shape::circle_stroke(pos, 4.0, stroke));
+            shapes.push(Shape::text(
+                ui.fonts(),
+                pos + vec2(5.0, 3.0),
+                Align2::LEFT_TOP,
+                name,
+                TextStyle::Body,
+                stroke.color,
+            ));
+        }
+
+        if let [a, b] = cursors.as_slice() {
+            let scale = transform.dvalue_dpos();
+            let formatter = ui.ctx().value_formatter();
+            let format_delta = |axis: usize, delta: f64| {
+                let decimals = decimals_for_scale(scale[axis]);
+                let text = formatter.format_number(delta, decimals..=decimals);
+                match axis_modes[axis] {
+                    AxisMode::Numbers => text,
+                    AxisMode::Time { .. } => format!("{} s", text),
+                }
+            };
+
+            let (dx, dy) = (b.x - a.x, b.y - a.y);
+            let mut text = format!("Δx = {}\nΔy = {}", format_delta(0, dx), format_delta(1, dy));
+            if dx != 0.0 {
+                text += &format!("\nslope = {}", formatter.format_number(dy / dx, 0..=6));
             }
+
+            let galley = ui
+                .fonts()
+                .layout_multiline(TextStyle::Body, text, f32::INFINITY);
+            let text_pos = frame.left_top() + vec2(6.0, 6.0);
+            shapes.push(Shape::Rect {
+                rect: Rect::from_min_size(text_pos, galley.size).expand(3.0),
+                corner_radius: 2.0,
+                fill: ui.visuals().extreme_bg_color,
+                stroke: ui.visuals().widgets.noninteractive.bg_stroke,
+            });
+            shapes.push(Shape::Text {
+                pos: text_pos,
+                galley,
+                color: ui.visuals().text_color(),
+                fake_italics: false,
+            });
         }
+    }
 
-        fn color_from_alpha(ui: &Ui, alpha: f32) -> Color32 {
-            if ui.visuals().dark_mode {
-                Rgba::from_white_alpha(alpha).into()
-            } else {
-                Rgba::from_black_alpha((4.0 * alpha).at_most(1.0)).into()
+    /// The box selection, and a ring around each selected point.
+    fn paint_selection(&self, ui: &Ui, shapes: &mut Vec<Shape>) {
+        let Self {
+            transform,
+            items,
+            selection,
+            ..
+        } = self;
+
+        let selection = match selection {
+            Some(selection) => selection,
+            None => return,
+        };
+
+        let visuals = &ui.visuals().selection;
+        let rect = Rect::from_two_pos(
+            transform.position_from_value(&Value::new(selection.min[0], selection.min[1])),
+            transform.position_from_value(&Value::new(selection.max[0], selection.max[1])),
+        );
+        shapes.push(Shape::Rect {
+            rect,
+            corner_radius: 0.0,
+            fill: visuals.bg_fill.linear_multiply(0.25),
+            stroke: visuals.stroke,
+        });
+
+        for item in items {
+            let stroke = Stroke::new(visuals.stroke.width.at_least(1.5), item.color());
+            for value in item.values().into_iter().flat_map(|values| &values.values) {
+                if selection.contains(value) {
+                    let pos = transform.position_from_value(value);
+                    shapes.push(Shape::circle_stroke(pos, 4.0, stroke));
+                }
             }
         }
     }
@@ -632,6 +1079,7 @@ impl Prepared {
             transform,
             show_x,
             show_y,
+            axis_modes,
             items,
             ..
         } = self;
@@ -699,20 +1147,36 @@ impl Prepared {
 
         let text = {
             let scale = transform.dvalue_dpos();
-            let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-            let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-            if *show_x && *show_y {
-                format!(
-                    "{}x = {:.*}\ny = {:.*}",
-                    prefix, x_decimals, value.x, y_decimals, value.y
-                )
+            let formatter = ui.ctx().value_formatter();
+            let format = |axis: usize, value: f64| match axis_modes[axis] {
+                AxisMode::Numbers => {
+                    let decimals = decimals_for_scale(scale[axis]);
+                    formatter.format_number(value, decimals..=decimals)
+                }
+                AxisMode::Time { utc_offset } => {
+                    formatter.format_time(value, scale[axis].abs(), utc_offset, TimeLabel::Full)
+                }
+            };
+            let x = format(0, value.x);
+            let y = format(1, value.y);
+            let mut text = if *show_x && *show_y {
+                format!("{}x = {}\ny = {}", prefix, x, y)
             } else if *show_x {
-                format!("{}x = {:.*}", prefix, x_decimals, value.x)
+                format!("{}x = {}", prefix, x)
             } else if *show_y {
-                format!("{}y = {:.*}", prefix, y_decimals, value.y)
+                format!("{}y = {}", prefix, y)
             } else {
                 unreachable!()
+            };
+            if *show_y {
+                // What each layer of a stacked area adds at this x, from the top down:
+                for item in items.iter().rev() {
+                    if let Some(contribution) = item.stacked_contribution(value.x) {
+                        text += &format!("\n{}: {}", item.name(), format(1, contribution));
+                    }
+                }
             }
+            text
         };
 
         shapes.push(Shape::text(
@@ -725,3 +1189,115 @@ impl Prepared {
         ));
     }
 }
+
+/// How many decimals to show of a value, given how much it changes per point on screen.
+fn decimals_for_scale(value_per_point: f64) -> usize {
+    ((-value_per_point.abs().log10()).ceil().at_least(0.0) as usize).at_most(6)
+}
+
+fn color_from_alpha(ui: &Ui, alpha: f32) -> Color32 {
+    if ui.visuals().dark_mode {
+        Rgba::from_white_alpha(alpha).into()
+    } else {
+        Rgba::from_black_alpha((4.0 * alpha).at_most(1.0)).into()
+    }
+}
+
+#[test]
+fn test_plot_measure() {
+    use crate::harness::Harness;
+
+    // Long frames, so that two clicks are never a double-click:
+    let mut harness = Harness::new(vec2(400.0, 300.0)).dt(0.5);
+    let run = |harness: &mut Harness| {
+        let mut plot_id = Id::new("");
+        let mut plot_rect = Rect::NOTHING;
+        harness.run(|ui| {
+            plot_id = ui.make_persistent_id(Id::new("plot"));
+            let plot = Plot::new("plot")
+                .include_x(0.0)
+                .include_x(10.0)
+                .include_y(0.0)
+                .include_y(10.0)
+                .width(200.0)
+                .height(200.0)
+                .measure(true);
+            plot_rect = ui.add(plot).rect;
+        });
+        let mut memory = harness.ctx().memory();
+        let plot_memory = memory.id_data.get::<PlotMemory>(&plot_id).unwrap();
+        (plot_rect, plot_memory.cursors.clone())
+    };
+
+    let (plot_rect, _) = run(&mut harness);
+    let a = plot_rect.left_bottom() + vec2(50.0, -50.0);
+    let b = plot_rect.left_bottom() + vec2(150.0, -150.0);
+    harness.click(a);
+    assert_eq!(run(&mut harness).1.len(), 1);
+    harness.click(b);
+    let (_, cursors) = run(&mut harness);
+    assert_eq!(cursors.len(), 2);
+    assert!(cursors[0].x < cursors[1].x && cursors[0].y < cursors[1].y);
+
+    // Drag cursor B without panning the plot:
+    harness.pointer_move(b);
+    harness.pointer_button(b, true);
+    run(&mut harness);
+    harness.pointer_move(b - vec2(50.0, 0.0));
+    run(&mut harness);
+    harness.pointer_button(b - vec2(50.0, 0.0), false);
+    let (_, dragged) = run(&mut harness);
+    assert_eq!(dragged[0], cursors[0]);
+    assert!(dragged[1].x < cursors[1].x);
+    assert!((dragged[1].y - cursors[1].y).abs() < 1e-3);
+
+    // A third click starts over:
+    harness.click(a);
+    assert_eq!(run(&mut harness).1.len(), 1);
+}
+
+#[test]
+fn test_plot_box_select() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(400.0, 300.0)).dt(0.5);
+    let run = |harness: &mut Harness| {
+        let mut plot_rect = Rect::NOTHING;
+        let mut selection = PlotSelection::default();
+        harness.run(|ui| {
+            let values = (0..=10).map(|i| Value::new(i as f64, i as f64)).collect();
+            let plot = Plot::new("plot")
+                .points(Points::new(Values::from_values(values)).name("diagonal"))
+                .include_x(0.0)
+                .include_x(10.0)
+                .include_y(0.0)
+                .include_y(10.0)
+                .width(200.0)
+                .height(200.0)
+                .box_select(true);
+            plot_rect = ui.add(plot).rect;
+            selection = Plot::selection(ui, "plot");
+        });
+        (plot_rect, selection)
+    };
+
+    let (plot_rect, selection) = run(&mut harness);
+    assert!(selection.points.is_empty());
+
+    // Drag a box around the middle of the diagonal:
+    let start = plot_rect.center() - vec2(30.0, 30.0);
+    let end = plot_rect.center() + vec2(30.0, 30.0);
+    harness.pointer_move(start);
+    harness.pointer_button(start, true);
+    run(&mut harness);
+    harness.pointer_move(end);
+    run(&mut harness);
+    harness.pointer_button(end, false);
+    let (_, selection) = run(&mut harness);
+    assert_eq!(selection.points["diagonal"], vec![4, 5, 6]);
+
+    // A click clears the selection:
+    harness.click(plot_rect.center());
+    run(&mut harness);
+    assert!(run(&mut harness).1.points.is_empty());
+}
@@ -0,0 +1,81 @@
+//! Ticks for plot axes that show points in time.
+//!
+//! The labels are formatted with [`crate::util::format::ValueFormatter::format_time`].
+
+use crate::util::format::{DAY, HOUR, MINUTE};
+
+const YEAR: f64 = 365.0 * DAY;
+
+/// `(step, minor_step)` between ticks, in seconds. Each step is a multiple of its minor step.
+const STEPS: [(f64, f64); 36] = [
+    (0.001, 0.001),
+    (0.002, 0.001),
+    (0.005, 0.001),
+    (0.01, 0.002),
+    (0.02, 0.005),
+    (0.05, 0.01),
+    (0.1, 0.02),
+    (0.2, 0.05),
+    (0.5, 0.1),
+    (1.0, 0.2),
+    (2.0, 0.5),
+    (5.0, 1.0),
+    (10.0, 2.0),
+    (15.0, 5.0),
+    (30.0, 5.0),
+    (MINUTE, 10.0),
+    (2.0 * MINUTE, 30.0),
+    (5.0 * MINUTE, MINUTE),
+    (10.0 * MINUTE, 2.0 * MINUTE),
+    (15.0 * MINUTE, 5.0 * MINUTE),
+    (30.0 * MINUTE, 5.0 * MINUTE),
+    (HOUR, 10.0 * MINUTE),
+    (2.0 * HOUR, 30.0 * MINUTE),
+    (3.0 * HOUR, HOUR),
+    (6.0 * HOUR, HOUR),
+    (12.0 * HOUR, 3.0 * HOUR),
+    (DAY, 6.0 * HOUR),
+    (2.0 * DAY, 12.0 * HOUR),
+    (7.0 * DAY, DAY),
+    (14.0 * DAY, DAY),
+    (30.0 * DAY, 10.0 * DAY),
+    (90.0 * DAY, 30.0 * DAY),
+    (180.0 * DAY, 30.0 * DAY),
+    (YEAR, YEAR),
+    (2.0 * YEAR, YEAR),
+    (5.0 * YEAR, YEAR),
+];
+
+/// The smallest `(step, minor_step)` with a step of at least `min_step` seconds.
+pub(crate) fn steps(min_step: f64) -> (f64, f64) {
+    if let Some(&steps) = STEPS.iter().find(|(step, _)| *step >= min_step) {
+        steps
+    } else {
+        // Many years: 10, 20, 50, 100, 200, 500, … years.
+        let mut step = 10.0 * YEAR;
+        let mut factors = [2.0, 2.5, 2.0].iter().cycle();
+        while step < min_step {
+            step *= factors.next().unwrap();
+        }
+        (step, step / 5.0)
+    }
+}
+
+#[test]
+fn test_time_axis_steps() {
+    assert_eq!(steps(0.0), (0.001, 0.001));
+    assert_eq!(steps(0.003), (0.005, 0.001));
+    assert_eq!(steps(40.0), (MINUTE, 10.0));
+    assert_eq!(steps(4000.0), (2.0 * HOUR, 30.0 * MINUTE));
+    assert_eq!(steps(3.0 * YEAR), (5.0 * YEAR, YEAR));
+    assert_eq!(steps(15.0 * YEAR).0, 20.0 * YEAR);
+    for &(step, minor_step) in &STEPS {
+        let ratio = step / minor_step;
+        assert!(
+            (ratio - ratio.round()).abs() < 1e-9,
+            "{} / {}",
+            step,
+            minor_step
+        );
+    }
+}
@@ -71,6 +71,11 @@ impl Bounds {
         self.max[1] += pad;
     }
 
+    pub fn contains(&self, value: &Value) -> bool {
+        (self.min[0]..=self.max[0]).contains(&value.x)
+            && (self.min[1]..=self.max[1]).contains(&value.y)
+    }
+
     pub fn merge(&mut self, other: &Bounds) {
         self.min[0] = self.min[0].min(other.min[0]);
         self.min[1] = self.min[1].min(other.min[1]);
@@ -73,27 +73,29 @@ impl Widget for SelectableLabel {
             WidgetInfo::selected(WidgetType::SelectableLabel, selected, &galley.text)
         });
 
-        let text_pos = ui
-            .layout()
-            .align_size_within_rect(galley.size, rect.shrink2(button_padding))
-            .min;
+        if ui.is_rect_visible(rect) {
+            let text_pos = ui
+                .layout()
+                .align_size_within_rect(galley.size, rect.shrink2(button_padding))
+                .min;
 
-        let visuals = ui.style().interact_selectable(&response, selected);
+            let visuals = ui.style().interact_selectable(&response, selected);
 
-        if selected || response.hovered() || response.has_focus() {
-            let rect = rect.expand(visuals.expansion);
+            if selected || response.hovered() || response.has_focus() {
+                let rect = rect.expand(visuals.expansion);
 
-            let corner_radius = 2.0;
-            ui.painter()
-                .rect(rect, corner_radius, visuals.bg_fill, visuals.bg_stroke);
-        }
+                let corner_radius = 2.0;
+                ui.painter()
+                    .rect(rect, corner_radius, visuals.bg_fill, visuals.bg_stroke);
+            }
 
-        let text_color = ui
-            .style()
-            .visuals
-            .override_text_color
-            .unwrap_or_else(|| visuals.text_color());
-        ui.painter().galley(text_pos, galley, text_color);
+            let text_color = ui
+                .style()
+                .visuals
+                .override_text_color
+                .unwrap_or_else(|| visuals.text_color());
+            ui.painter().galley(text_pos, galley, text_color);
+        }
         response
     }
 }
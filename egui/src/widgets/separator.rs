@@ -84,8 +84,10 @@ impl Widget for Separator {
                 pos2(rect.center().x, rect.bottom()),
             ]
         };
-        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
-        ui.painter().line_segment(points, stroke);
+        if ui.is_rect_visible(rect) {
+            let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+            ui.painter().line_segment(points, stroke);
+        }
         response
     }
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
 use crate::{widgets::Label, *};
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 // ----------------------------------------------------------------------------
 
@@ -29,6 +29,23 @@ struct SliderSpec {
     /// before the slider switches to `INFINITY`, if that is the higher end.
     /// Default: INFINITY.
     largest_finite: f64,
+    /// Overrides `logarithmic` if set.
+    custom_mapping: Option<Arc<dyn SliderMapping>>,
+}
+
+impl SliderSpec {
+    fn mapping(&self) -> Arc<dyn SliderMapping> {
+        if let Some(mapping) = &self.custom_mapping {
+            mapping.clone()
+        } else if self.logarithmic {
+            Arc::new(LogarithmicMapping {
+                smallest_positive: self.smallest_positive,
+                largest_finite: self.largest_finite,
+            })
+        } else {
+            Arc::new(LinearMapping)
+        }
+    }
 }
 
 /// Control a number by a horizontal slider.
@@ -50,6 +67,9 @@ struct SliderSpec {
 /// ```
 ///
 /// The default `Slider` size is set by [`crate::style::Spacing::slider_width`].
+///
+/// When the slider has keyboard focus, the arrow keys nudge the value.
+/// Hold down shift for bigger steps, or alt for smaller ones.
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Slider<'a> {
     get_set_value: GetSetValue<'a>,
@@ -64,6 +84,8 @@ pub struct Slider<'a> {
     text_color: Option<Color32>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    step: Option<f64>,
+    show_ticks: bool,
 }
 
 macro_rules! impl_integer_constructor {
@@ -142,6 +164,7 @@ impl<'a> Slider<'a> {
                 logarithmic: false,
                 smallest_positive: 1e-6,
                 largest_finite: f64::INFINITY,
+                custom_mapping: None,
             },
             clamp_to_range: false,
             smart_aim: true,
@@ -152,6 +175,8 @@ impl<'a> Slider<'a> {
             text_color: None,
             min_decimals: 0,
             max_decimals: None,
+            step: None,
+            show_ticks: false,
         }
     }
 
@@ -194,6 +219,15 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Use your own mapping between values and positions along the slider,
+    /// e.g. a [`CurveMapping`] for a volume control.
+    ///
+    /// This overrides [`Self::logarithmic`].
+    pub fn mapping(mut self, mapping: impl SliderMapping + 'static) -> Self {
+        self.spec.custom_mapping = Some(Arc::new(mapping));
+        self
+    }
+
     /// For logarithmic sliders that includes zero:
     /// what is the smallest positive value you want to be able to select?
     /// The default is `1` for integer sliders and `1e-6` for real sliders.
@@ -224,6 +258,25 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Only allow values that are a whole number of `step`s from the start of the range.
+    ///
+    /// The arrow keys will then change the value by one step (ten with shift held down).
+    /// A `step` of zero or less turns this off (the default).
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = if step > 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Paint tick marks along the slider.
+    ///
+    /// With [`Self::step_by`] there is a tick for every step (as long as they are not too dense),
+    /// otherwise the slider is divided into ten parts.
+    /// Default: `false`.
+    pub fn show_ticks(mut self, show_ticks: bool) -> Self {
+        self.show_ticks = show_ticks;
+        self
+    }
+
     #[deprecated = "Use fixed_decimals instead"]
     pub fn precision(self, precision: usize) -> Self {
         self.max_decimals(precision)
@@ -277,6 +330,10 @@ impl<'a> Slider<'a> {
     }
 
     fn set_value(&mut self, mut value: f64) {
+        if let Some(step) = self.step {
+            let start = *self.range.start();
+            value = start + ((value - start) / step).round() * step;
+        }
         if self.clamp_to_range {
             let start = *self.range.start();
             let end = *self.range.end();
@@ -303,11 +360,11 @@ impl<'a> Slider<'a> {
     /// For instance, `x` is the mouse position and `x_range` is the physical location of the slider on the screen.
     fn value_from_x(&self, x: f32, x_range: RangeInclusive<f32>) -> f64 {
         let normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
-        value_from_normalized(normalized, self.range(), &self.spec)
+        value_from_normalized(normalized, self.range(), &*self.spec.mapping())
     }
 
     fn x_from_value(&self, value: f64, x_range: RangeInclusive<f32>) -> f32 {
-        let normalized = normalized_from_value(value, self.range(), &self.spec);
+        let normalized = normalized_from_value(value, self.range(), &*self.spec.mapping());
         lerp(x_range, normalized as f32)
     }
 }
@@ -351,21 +408,39 @@ impl<'a> Slider<'a> {
         response.widget_info(|| WidgetInfo::slider(value, &self.text));
 
         if response.has_focus() {
-            let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
-                - ui.input().num_presses(Key::ArrowLeft) as f32;
+            ui.memory().use_arrow_keys();
+            let input = ui.input();
+            let kb_step = input.num_presses(Key::ArrowRight) as f32
+                + input.num_presses(Key::ArrowUp) as f32
+                - input.num_presses(Key::ArrowLeft) as f32
+                - input.num_presses(Key::ArrowDown) as f32;
+            let modifiers = input.modifiers;
+            let aim_radius = input.aim_radius();
 
             if kb_step != 0.0 {
                 let prev_value = self.get_value();
-                let prev_x = self.x_from_value(prev_value, x_range.clone());
-                let new_x = prev_x + kb_step;
-                let new_value = if self.smart_aim {
-                    let aim_radius = ui.input().aim_radius();
-                    emath::smart_aim::best_in_range_f64(
-                        self.value_from_x(new_x - aim_radius, x_range.clone()),
-                        self.value_from_x(new_x + aim_radius, x_range.clone()),
-                    )
+                let new_value = if let Some(step) = self.step {
+                    let steps = if modifiers.shift { 10.0 } else { 1.0 };
+                    prev_value + step * steps * kb_step as f64
                 } else {
-                    self.value_from_x(new_x, x_range.clone())
+                    // Move the handle one point at a time (ten with shift, a tenth with alt):
+                    let points = if modifiers.shift {
+                        10.0
+                    } else if modifiers.alt {
+                        0.1
+                    } else {
+                        1.0
+                    };
+                    let prev_x = self.x_from_value(prev_value, x_range.clone());
+                    let new_x = prev_x + points * kb_step;
+                    if self.smart_aim && !modifiers.alt {
+                        emath::smart_aim::best_in_range_f64(
+                            self.value_from_x(new_x - aim_radius, x_range.clone()),
+                            self.value_from_x(new_x + aim_radius, x_range.clone()),
+                        )
+                    } else {
+                        self.value_from_x(new_x, x_range.clone())
+                    }
                 };
                 self.set_value(new_value);
             }
@@ -383,7 +458,7 @@ impl<'a> Slider<'a> {
                 pos2(rect.left(), rect.center().y - rail_radius),
                 pos2(rect.right(), rect.center().y + rail_radius),
             );
-            let marker_center_x = self.x_from_value(value, x_range);
+            let marker_center_x = self.x_from_value(value, x_range.clone());
 
             let visuals = ui.style().interact(response);
             ui.painter().add(Shape::Rect {
@@ -397,6 +472,20 @@ impl<'a> Slider<'a> {
                 // stroke: ui.visuals().widgets.inactive.bg_stroke,
             });
 
+            if self.show_ticks {
+                let tick_stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                let tick_half_height = rail_radius + 2.0;
+                for x in self.tick_xs(x_range) {
+                    ui.painter().line_segment(
+                        [
+                            pos2(x, rail_rect.center().y - tick_half_height),
+                            pos2(x, rail_rect.center().y + tick_half_height),
+                        ],
+                        tick_stroke,
+                    );
+                }
+            }
+
             ui.painter().add(Shape::Circle {
                 center: pos2(marker_center_x, rail_rect.center().y),
                 radius: handle_radius(rect) + visuals.expansion,
@@ -406,6 +495,37 @@ impl<'a> Slider<'a> {
         }
     }
 
+    /// Where to paint the tick marks.
+    fn tick_xs(&self, x_range: RangeInclusive<f32>) -> Vec<f32> {
+        /// Don't paint ticks closer than this (in points).
+        const MIN_TICK_SPACING: f32 = 4.0;
+
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let width = x_range.end() - x_range.start();
+
+        if let Some(step) = self.step {
+            let num_steps = ((end - start).abs() / step).floor();
+            if !num_steps.is_finite() || num_steps < 1.0 {
+                return vec![];
+            }
+            // Skip ticks if they would be too dense:
+            let stride = (MIN_TICK_SPACING * num_steps as f32 / width)
+                .ceil()
+                .at_least(1.0) as usize;
+            let step = step * (end - start).signum();
+            (0..=num_steps as usize)
+                .step_by(stride)
+                .map(|i| self.x_from_value(start + i as f64 * step, x_range.clone()))
+                .collect()
+        } else if width >= 10.0 * MIN_TICK_SPACING {
+            (0..=10)
+                .map(|i| lerp(x_range.clone(), i as f32 / 10.0))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     fn label_ui(&mut self, ui: &mut Ui) {
         if !self.text.is_empty() {
             let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
@@ -415,9 +535,12 @@ impl<'a> Slider<'a> {
 
     fn value_ui(&mut self, ui: &mut Ui, x_range: RangeInclusive<f32>) {
         let mut value = self.get_value();
+        // Drag the value as fast as the slider handle:
+        let speed = 1.0 / (x_range.end() - x_range.start()).at_least(1.0) as f64;
         ui.add(
             DragValue::new(&mut value)
-                .speed(self.current_gradient(&x_range))
+                .mapping(self.range(), self.spec.mapping())
+                .speed(speed)
                 .clamp_range(self.clamp_range())
                 .min_decimals(self.min_decimals)
                 .max_decimals_opt(self.max_decimals)
@@ -428,17 +551,6 @@ impl<'a> Slider<'a> {
             self.set_value(value);
         }
     }
-
-    /// delta(value) / delta(points)
-    fn current_gradient(&mut self, x_range: &RangeInclusive<f32>) -> f64 {
-        // TODO: handle clamping
-        let value = self.get_value();
-        let value_from_x = |x: f32| self.value_from_x(x, x_range.clone());
-        let x_from_value = |value: f64| self.x_from_value(value, x_range.clone());
-        let left_value = value_from_x(x_from_value(value) - 0.5);
-        let right_value = value_from_x(x_from_value(value) + 0.5);
-        right_value - left_value
-    }
 }
 
 impl<'a> Widget for Slider<'a> {
@@ -473,36 +585,86 @@ impl<'a> Widget for Slider<'a> {
 }
 
 // ----------------------------------------------------------------------------
-// Helpers for converting slider range to/from normalized [0-1] range.
-// Always clamps.
-// Logarithmic sliders are allowed to include zero and infinity,
-// even though mathematically it doesn't make sense.
 
-use std::f64::INFINITY;
+/// Maps between the values of a [`Slider`] (or [`DragValue`]) and the normalized position along it,
+/// where `0` is the start of the range and `1` the end.
+///
+/// You only need to handle increasing ranges (`min < max`) with values strictly between `min` and `max`,
+/// and normalized positions strictly between `0` and `1`.
+/// Reversed ranges, empty ranges and values outside the range are handled for you.
+///
+/// See [`LinearMapping`], [`LogarithmicMapping`] and [`CurveMapping`].
+pub trait SliderMapping {
+    /// The value at `normalized` (0-1) along `range`.
+    fn value_from_normalized(&self, normalized: f64, range: RangeInclusive<f64>) -> f64;
 
-/// When the user asks for an infinitely large range (e.g. logarithmic from zero),
-/// give a scale that this many orders of magnitude in size.
-const INF_RANGE_MAGNITUDE: f64 = 10.0;
+    /// Where along `range` (0-1) `value` is.
+    fn normalized_from_value(&self, value: f64, range: RangeInclusive<f64>) -> f64;
+}
 
-fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
-    let (min, max) = (*range.start(), *range.end());
+impl<M: SliderMapping + ?Sized> SliderMapping for Arc<M> {
+    fn value_from_normalized(&self, normalized: f64, range: RangeInclusive<f64>) -> f64 {
+        (**self).value_from_normalized(normalized, range)
+    }
 
-    if min.is_nan() || max.is_nan() {
-        f64::NAN
-    } else if min == max {
-        min
-    } else if min > max {
-        value_from_normalized(1.0 - normalized, max..=min, spec)
-    } else if normalized <= 0.0 {
-        min
-    } else if normalized >= 1.0 {
-        max
-    } else if spec.logarithmic {
+    fn normalized_from_value(&self, value: f64, range: RangeInclusive<f64>) -> f64 {
+        (**self).normalized_from_value(value, range)
+    }
+}
+
+/// Values are evenly spread out. This is the default mapping.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinearMapping;
+
+impl SliderMapping for LinearMapping {
+    fn value_from_normalized(&self, normalized: f64, range: RangeInclusive<f64>) -> f64 {
+        let (min, max) = (*range.start(), *range.end());
+        crate::egui_assert!(
+            min.is_finite() && max.is_finite(),
+            "You should use a logarithmic range"
+        );
+        lerp(range, normalized)
+    }
+
+    fn normalized_from_value(&self, value: f64, range: RangeInclusive<f64>) -> f64 {
+        let (min, max) = (*range.start(), *range.end());
+        crate::egui_assert!(
+            min.is_finite() && max.is_finite(),
+            "You should use a logarithmic range"
+        );
+        remap_clamp(value, range, 0.0..=1.0)
+    }
+}
+
+/// Each order of magnitude takes up the same space, e.g. for frequencies.
+///
+/// The range may include zero and infinity, and may cross zero.
+#[derive(Clone, Copy, Debug)]
+pub struct LogarithmicMapping {
+    /// If the range includes zero: the smallest positive value we are interested in.
+    pub smallest_positive: f64,
+
+    /// If the range ends at `INFINITY`: the largest finite value we are interested in.
+    pub largest_finite: f64,
+}
+
+impl Default for LogarithmicMapping {
+    fn default() -> Self {
+        Self {
+            smallest_positive: 1e-6,
+            largest_finite: f64::INFINITY,
+        }
+    }
+}
+
+impl SliderMapping for LogarithmicMapping {
+    fn value_from_normalized(&self, normalized: f64, range: RangeInclusive<f64>) -> f64 {
+        let (min, max) = (*range.start(), *range.end());
         if max <= 0.0 {
             // non-positive range
-            -value_from_normalized(normalized, -min..=-max, spec)
+            -value_from_normalized(normalized, -min..=-max, self)
         } else if 0.0 <= min {
-            let (min_log, max_log) = range_log10(min, max, spec);
+            let (min_log, max_log) = self.range_log10(min, max);
             let log = lerp(min_log..=max_log, normalized);
             10.0_f64.powf(log)
         } else {
@@ -513,45 +675,26 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
                 value_from_normalized(
                     remap(normalized, 0.0..=zero_cutoff, 0.0..=1.0),
                     min..=0.0,
-                    spec,
+                    self,
                 )
             } else {
                 // positive
                 value_from_normalized(
                     remap(normalized, zero_cutoff..=1.0, 0.0..=1.0),
                     0.0..=max,
-                    spec,
+                    self,
                 )
             }
         }
-    } else {
-        crate::egui_assert!(
-            min.is_finite() && max.is_finite(),
-            "You should use a logarithmic range"
-        );
-        lerp(range, normalized.clamp(0.0, 1.0))
     }
-}
-
-fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
-    let (min, max) = (*range.start(), *range.end());
 
-    if min.is_nan() || max.is_nan() {
-        f64::NAN
-    } else if min == max {
-        0.5 // empty range, show center of slider
-    } else if min > max {
-        1.0 - normalized_from_value(value, max..=min, spec)
-    } else if value <= min {
-        0.0
-    } else if value >= max {
-        1.0
-    } else if spec.logarithmic {
+    fn normalized_from_value(&self, value: f64, range: RangeInclusive<f64>) -> f64 {
+        let (min, max) = (*range.start(), *range.end());
         if max <= 0.0 {
             // non-positive range
-            normalized_from_value(-value, -min..=-max, spec)
+            normalized_from_value(-value, -min..=-max, self)
         } else if 0.0 <= min {
-            let (min_log, max_log) = range_log10(min, max, spec);
+            let (min_log, max_log) = self.range_log10(min, max);
             let value_log = value.log10();
             remap_clamp(value_log, min_log..=max_log, 0.0..=1.0)
         } else {
@@ -560,48 +703,138 @@ fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSp
             if value < 0.0 {
                 // negative
                 remap(
-                    normalized_from_value(value, min..=0.0, spec),
+                    normalized_from_value(value, min..=0.0, self),
                     0.0..=1.0,
                     0.0..=zero_cutoff,
                 )
             } else {
                 // positive side
                 remap(
-                    normalized_from_value(value, 0.0..=max, spec),
+                    normalized_from_value(value, 0.0..=max, self),
                     0.0..=1.0,
                     zero_cutoff..=1.0,
                 )
             }
         }
-    } else {
-        crate::egui_assert!(
-            min.is_finite() && max.is_finite(),
-            "You should use a logarithmic range"
-        );
-        remap_clamp(value, range, 0.0..=1.0)
     }
 }
 
-fn range_log10(min: f64, max: f64, spec: &SliderSpec) -> (f64, f64) {
-    assert!(spec.logarithmic);
-    assert!(min <= max);
+impl LogarithmicMapping {
+    fn range_log10(&self, min: f64, max: f64) -> (f64, f64) {
+        assert!(min <= max);
 
-    if min == 0.0 && max == INFINITY {
-        (spec.smallest_positive.log10(), INF_RANGE_MAGNITUDE)
-    } else if min == 0.0 {
-        if spec.smallest_positive < max {
-            (spec.smallest_positive.log10(), max.log10())
+        if min == 0.0 && max == INFINITY {
+            (self.smallest_positive.log10(), INF_RANGE_MAGNITUDE)
+        } else if min == 0.0 {
+            if self.smallest_positive < max {
+                (self.smallest_positive.log10(), max.log10())
+            } else {
+                (max.log10() - INF_RANGE_MAGNITUDE, max.log10())
+            }
+        } else if max == INFINITY {
+            if min < self.largest_finite {
+                (min.log10(), self.largest_finite.log10())
+            } else {
+                (min.log10(), min.log10() + INF_RANGE_MAGNITUDE)
+            }
         } else {
-            (max.log10() - INF_RANGE_MAGNITUDE, max.log10())
+            (min.log10(), max.log10())
         }
-    } else if max == INFINITY {
-        if min < spec.largest_finite {
-            (min.log10(), spec.largest_finite.log10())
-        } else {
-            (min.log10(), min.log10() + INF_RANGE_MAGNITUDE)
+    }
+}
+
+/// Bends a linear range with a curve, e.g. to give a volume control more room at the quiet end.
+///
+/// The curve maps the normalized slider position (0-1) to a normalized position in the range (0-1),
+/// and must be increasing with `curve(0) = 0` and `curve(1) = 1`.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut volume = 0.5;
+/// let mapping = egui::CurveMapping::new(|t| t * t, |t| t.sqrt());
+/// ui.add(egui::Slider::new(&mut volume, 0.0..=1.0).mapping(mapping));
+/// ```
+pub struct CurveMapping {
+    curve: Box<dyn Fn(f64) -> f64>,
+    inverse: Box<dyn Fn(f64) -> f64>,
+}
+
+impl CurveMapping {
+    /// `inverse` must undo `curve`.
+    pub fn new(
+        curve: impl Fn(f64) -> f64 + 'static,
+        inverse: impl Fn(f64) -> f64 + 'static,
+    ) -> Self {
+        Self {
+            curve: Box::new(curve),
+            inverse: Box::new(inverse),
         }
+    }
+}
+
+impl SliderMapping for CurveMapping {
+    fn value_from_normalized(&self, normalized: f64, range: RangeInclusive<f64>) -> f64 {
+        LinearMapping.value_from_normalized((self.curve)(normalized).clamp(0.0, 1.0), range)
+    }
+
+    fn normalized_from_value(&self, value: f64, range: RangeInclusive<f64>) -> f64 {
+        (self.inverse)(LinearMapping.normalized_from_value(value, range)).clamp(0.0, 1.0)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Helpers for converting slider range to/from normalized [0-1] range.
+// Always clamps.
+// Logarithmic sliders are allowed to include zero and infinity,
+// even though mathematically it doesn't make sense.
+
+use std::f64::INFINITY;
+
+/// When the user asks for an infinitely large range (e.g. logarithmic from zero),
+/// give a scale that this many orders of magnitude in size.
+const INF_RANGE_MAGNITUDE: f64 = 10.0;
+
+pub(crate) fn value_from_normalized(
+    normalized: f64,
+    range: RangeInclusive<f64>,
+    mapping: &dyn SliderMapping,
+) -> f64 {
+    let (min, max) = (*range.start(), *range.end());
+
+    if min.is_nan() || max.is_nan() {
+        f64::NAN
+    } else if min == max {
+        min
+    } else if min > max {
+        value_from_normalized(1.0 - normalized, max..=min, mapping)
+    } else if normalized <= 0.0 {
+        min
+    } else if normalized >= 1.0 {
+        max
+    } else {
+        mapping.value_from_normalized(normalized, range)
+    }
+}
+
+pub(crate) fn normalized_from_value(
+    value: f64,
+    range: RangeInclusive<f64>,
+    mapping: &dyn SliderMapping,
+) -> f64 {
+    let (min, max) = (*range.start(), *range.end());
+
+    if min.is_nan() || max.is_nan() {
+        f64::NAN
+    } else if min == max {
+        0.5 // empty range, show center of slider
+    } else if min > max {
+        1.0 - normalized_from_value(value, max..=min, mapping)
+    } else if value <= min {
+        0.0
+    } else if value >= max {
+        1.0
     } else {
-        (min.log10(), max.log10())
+        mapping.normalized_from_value(value, range)
     }
 }
 
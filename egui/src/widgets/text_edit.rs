@@ -195,6 +195,20 @@ impl TextBuffer for String {
     }
 }
 
+/// Immutable text: it can be selected and copied, but not edited.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.add(egui::TextEdit::multiline(&mut "You can select me, but not change me"));
+/// ```
+impl TextBuffer for &str {
+    fn insert_text(&mut self, _text: &str, _ch_idx: usize) -> usize {
+        0
+    }
+
+    fn delete_char_range(&mut self, _ch_range: Range<usize>) {}
+}
+
 /// A text region that the user can edit the contents of.
 ///
 /// See also [`Ui::text_edit_singleline`] and  [`Ui::text_edit_multiline`].
@@ -237,6 +251,8 @@ pub struct TextEdit<'t, S: TextBuffer = String> {
     desired_width: Option<f32>,
     desired_height_rows: usize,
     lock_focus: bool,
+    read_only: bool,
+    highlights: Vec<(Range<usize>, Color32)>,
 }
 impl<'t, S: TextBuffer> TextEdit<'t, S> {
     pub fn cursor(ui: &Ui, id: Id) -> Option<CursorPair> {
@@ -269,6 +285,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             desired_width: None,
             desired_height_rows: 1,
             lock_focus: false,
+            read_only: false,
+            highlights: Vec::new(),
         }
     }
 
@@ -288,6 +306,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             desired_width: None,
             desired_height_rows: 4,
             lock_focus: false,
+            read_only: false,
+            highlights: Vec::new(),
         }
     }
 
@@ -374,6 +394,35 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         self.lock_focus = b;
         self
     }
+
+    /// If `true`, the text can be selected and copied, but not edited. Default: `false`.
+    ///
+    /// Unlike [`Self::enabled`], the text isn't grayed out, and you can still click,
+    /// drag and use the keyboard to select parts of it.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Paint a colored background behind the characters in `char_range`,
+    /// e.g. for search matches or the lines of a diff.
+    /// Can be called several times; later highlights are painted on top of earlier ones.
+    ///
+    /// The range is in characters (not bytes) of the text as it is passed in.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut log = "ok\nerror: disk full\nok".to_owned();
+    /// ui.add(
+    ///     egui::TextEdit::multiline(&mut log)
+    ///         .read_only(true)
+    ///         .highlight(3..19, egui::Color32::from_rgb(96, 32, 32)),
+    /// );
+    /// ```
+    pub fn highlight(mut self, char_range: Range<usize>, color: Color32) -> Self {
+        self.highlights.push((char_range, color));
+        self
+    }
 }
 
 impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
@@ -443,6 +492,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             desired_width,
             desired_height_rows,
             lock_focus,
+            read_only,
+            highlights,
         } = self;
 
         let mask_if_password = |text: &str| {
@@ -548,12 +599,19 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 
         if response.hovered() && enabled {
             ui.output().cursor_icon = CursorIcon::Text;
+            ui.output().mutable_text_under_cursor = !read_only;
         }
 
         let mut text_cursor = None;
         let prev_text_cursor = state.cursorp;
         if ui.memory().has_focus(id) && enabled {
             ui.memory().lock_focus(id, lock_focus);
+            ui.memory().use_arrow_keys();
+            if multiline {
+                ui.memory().use_enter_key();
+            } else {
+                ui.memory().release_enter_key();
+            }
 
             let mut cursorp = state
                 .cursorp
@@ -579,19 +637,46 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                 &(cursorp.as_ccursorp(), text.as_ref().to_owned()),
             );
 
+            let copy_selection = |ui: &Ui, text: &S, cursorp: &CursorPair| {
+                if cursorp.is_empty() {
+                    copy_if_not_password(ui, text.as_ref().to_owned());
+                } else {
+                    copy_if_not_password(ui, selected_str(text.as_ref(), cursorp).to_owned());
+                }
+            };
+
             for event in &ui.input().events {
                 let did_mutate_text = match event {
                     Event::Copy => {
-                        if cursorp.is_empty() {
-                            copy_if_not_password(ui, text.as_ref().to_owned());
+                        copy_selection(ui, text, &cursorp);
+                        None
+                    }
+                    Event::Cut if read_only => {
+                        copy_selection(ui, text, &cursorp);
+                        None
+                    }
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                    } if read_only => {
+                        // Moving the cursor and selecting is fine, editing is not:
+                        let moves_cursor = matches!(
+                            key,
+                            Key::ArrowLeft
+                                | Key::ArrowRight
+                                | Key::ArrowUp
+                                | Key::ArrowDown
+                                | Key::Home
+                                | Key::End
+                        ) || (*key == Key::A && modifiers.command);
+                        if moves_cursor {
+                            on_key_press(&mut cursorp, text, &galley, *key, modifiers)
                         } else {
-                            copy_if_not_password(
-                                ui,
-                                selected_str(text.as_ref(), &cursorp).to_owned(),
-                            );
+                            None
                         }
-                        None
                     }
+                    _ if read_only => None,
                     Event::Cut => {
                         if cursorp.is_empty() {
                             copy_if_not_password(ui, text.take());
@@ -760,12 +845,18 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             text_draw_pos -= vec2(offset_x, 0.0);
         }
 
+        for (char_range, color) in highlights {
+            paint_highlight(&painter, text_draw_pos, &galley, char_range, color);
+        }
+
         if ui.memory().has_focus(id) {
             if let Some(cursorp) = state.cursorp {
                 paint_cursor_selection(ui, &painter, text_draw_pos, &galley, &cursorp);
-                paint_cursor_end(ui, &painter, text_draw_pos, &galley, &cursorp.primary);
+                if !read_only {
+                    paint_cursor_end(ui, &painter, text_draw_pos, &galley, &cursorp.primary);
+                }
 
-                if enabled {
+                if enabled && !read_only {
                     ui.ctx().output().text_cursor_pos = Some(
                         galley
                             .pos_from_cursor(&cursorp.primary)
@@ -848,28 +939,25 @@ fn paint_cursor_selection(
         return;
     }
     let [min, max] = cursorp.sorted();
-    let min = min.rcursor;
-    let max = max.rcursor;
+    for rect in galley.selection_rects(&min, &max) {
+        painter.rect_filled(rect.translate(pos.to_vec2()), 0.0, color);
+    }
+}
 
-    for ri in min.row..=max.row {
-        let row = &galley.rows[ri];
-        let left = if ri == min.row {
-            row.x_offset(min.column)
-        } else {
-            row.min_x()
-        };
-        let right = if ri == max.row {
-            row.x_offset(max.column)
-        } else {
-            let newline_size = if row.ends_with_newline {
-                row.height() / 2.0 // visualize that we select the newline
-            } else {
-                0.0
-            };
-            row.max_x() + newline_size
-        };
-        let rect = Rect::from_min_max(pos + vec2(left, row.y_min), pos + vec2(right, row.y_max));
-        painter.rect_filled(rect, 0.0, color);
+fn paint_highlight(
+    painter: &Painter,
+    pos: Pos2,
+    galley: &Galley,
+    char_range: Range<usize>,
+    color: Color32,
+) {
+    if char_range.start >= char_range.end {
+        return;
+    }
+    let min = galley.from_ccursor(CCursor::new(char_range.start));
+    let max = galley.from_ccursor(CCursor::new(char_range.end));
+    for rect in galley.selection_rects(&min, &max) {
+        painter.rect_filled(rect.translate(pos.to_vec2()), 0.0, color);
     }
 }
 
@@ -1260,3 +1348,56 @@ fn decrease_identation<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S) {
         }
     }
 }
+
+#[test]
+fn test_text_edit_read_only() {
+    use crate::harness::Harness;
+
+    let mut harness = Harness::new(vec2(300.0, 100.0));
+    let highlight_color = Color32::from_rgb(1, 2, 3);
+    let mut text = "error: disk full".to_owned();
+    {
+        let mut run = |harness: &mut Harness| {
+            let mut response = None;
+            let frame = harness.run(|ui| {
+                let text_edit = TextEdit::singleline(&mut text)
+                    .read_only(true)
+                    .highlight(0..5, highlight_color);
+                response = Some(ui.add(text_edit));
+            });
+            (response.unwrap(), frame)
+        };
+
+        let (response, frame) = run(&mut harness);
+        let highlighted = frame.shapes.iter().any(|clipped| match &clipped.1 {
+            Shape::Rect { fill, .. } => *fill == highlight_color,
+            _ => false,
+        });
+        assert!(highlighted);
+
+        harness.click(response.rect.center());
+        run(&mut harness);
+        assert!(run(&mut harness).0.has_focus());
+
+        // Typing, deleting and cutting don't change the text:
+        harness.type_text("!");
+        harness.key_press(Key::Backspace);
+        harness.event(Event::Cut);
+        assert!(!run(&mut harness).0.changed());
+
+        // Select all and copy:
+        harness.set_modifiers(Modifiers {
+            command: true,
+            ctrl: true,
+            ..Default::default()
+        });
+        harness.key_press(Key::A);
+        run(&mut harness);
+        harness.set_modifiers(Modifiers::default());
+        harness.event(Event::Copy);
+        let (response, frame) = run(&mut harness);
+        assert!(!response.changed());
+        assert_eq!(frame.output.copied_text, "error: disk full");
+    }
+    assert_eq!(text, "error: disk full");
+}
@@ -0,0 +1,419 @@
+//! Compact editors for small vectors and matrices, e.g. positions, rotations and transforms.
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// Edit a small vector, e.g. a position, a direction or a quaternion,
+/// with one [`DragValue`] per component.
+///
+/// Hover the editor and press Cmd+C (Ctrl+C) to copy the whole vector as text,
+/// or paste a vector with Cmd+V (Ctrl+V).
+///
+/// All components use the same minimum width so that editors shown beneath each other line up.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut position = egui::Vec2::ZERO;
+/// # let mut scale = [1.0_f32; 3];
+/// # let mut rotation = [0.0, 0.0, 0.0, 1.0_f32];
+/// ui.add(egui::VectorEdit::vec2(&mut position));
+/// ui.add(egui::VectorEdit::new(&mut scale).lock_aspect(true));
+/// ui.add(egui::VectorEdit::quaternion(&mut rotation));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct VectorEdit<'a> {
+    components: Vec<&'a mut f32>,
+    labels: Vec<String>,
+    id_source: Option<Id>,
+    speed: f64,
+    component_width: Option<f32>,
+    lock_aspect: bool,
+    normalized: bool,
+}
+
+impl<'a> VectorEdit<'a> {
+    /// Edit the given components, labeled "x", "y", "z" and "w" (if there are at most four).
+    pub fn new(values: &'a mut [f32]) -> Self {
+        let labels = if values.len() <= 4 {
+            ["x", "y", "z", "w"][..values.len()]
+                .iter()
+                .map(|&label| label.to_owned())
+                .collect()
+        } else {
+            vec![]
+        };
+        Self {
+            components: values.iter_mut().collect(),
+            labels,
+            id_source: None,
+            speed: 0.1,
+            component_width: None,
+            lock_aspect: false,
+            normalized: false,
+        }
+    }
+
+    pub fn vec2(value: &'a mut Vec2) -> Self {
+        Self {
+            components: vec![&mut value.x, &mut value.y],
+            ..Self::new(&mut [])
+        }
+        .labels(&["x", "y"])
+    }
+
+    /// Edit a rotation quaternion given as `[x, y, z, w]`, keeping it normalized.
+    ///
+    /// See [`Self::normalized`].
+    pub fn quaternion(value: &'a mut [f32; 4]) -> Self {
+        Self::new(value).speed(0.01).normalized(true)
+    }
+
+    /// Labels shown in front of each component. Pass an empty slice for no labels.
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.labels = labels.iter().map(|&label| label.to_string()).collect();
+        self
+    }
+
+    /// Needed if you show several editors with the same labels in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// How much each component changes when dragged one point. Default: `0.1`.
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = speed.into();
+        self
+    }
+
+    /// The minimum width of each component. Default: [`crate::style::Spacing::interact_size`]`.x`.
+    pub fn component_width(mut self, component_width: f32) -> Self {
+        self.component_width = Some(component_width);
+        self
+    }
+
+    /// Show a toggle that, when on, scales all components together
+    /// when one of them is changed, e.g. to keep the aspect ratio of a size.
+    ///
+    /// The state of the toggle is remembered.
+    pub fn lock_aspect(mut self, lock_aspect: bool) -> Self {
+        self.lock_aspect = lock_aspect;
+        self
+    }
+
+    /// Keep the vector at unit length: when one component is changed,
+    /// the other components are scaled to compensate.
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+}
+
+impl<'a> Widget for VectorEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut components,
+            labels,
+            id_source,
+            speed,
+            component_width,
+            lock_aspect,
+            normalized,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new(&labels)));
+        let lock_id = id.with("lock_aspect");
+        let locked = lock_aspect && ui.memory().id_data.get::<bool>(&lock_id) == Some(&true);
+        let before: Vec<f32> = components.iter().map(|c| **c).collect();
+
+        let inner = ui.horizontal(|ui| {
+            if let Some(component_width) = component_width {
+                ui.spacing_mut().interact_size.x = component_width;
+            }
+
+            let mut response: Option<Response> = None;
+            let mut changed_index = None;
+            for (index, component) in components.iter_mut().enumerate() {
+                if let Some(label) = labels.get(index) {
+                    ui.add(Label::new(label).weak());
+                }
+                let mut drag_value = DragValue::new(&mut **component).speed(speed);
+                if normalized {
+                    drag_value = drag_value.clamp_range(-1.0..=1.0);
+                }
+                let component_response = ui.add(drag_value);
+                if component_response.changed() {
+                    changed_index = Some(index);
+                }
+                response = Some(match response {
+                    Some(response) => response | component_response,
+                    None => component_response,
+                });
+            }
+
+            if lock_aspect {
+                let toggle = ui
+                    .selectable_label(locked, if locked { "🔒" } else { "🔓" })
+                    .on_hover_text("Change all components together");
+                if toggle.clicked() {
+                    ui.memory().id_data.insert(lock_id, !locked);
+                }
+            }
+
+            (response, changed_index)
+        });
+
+        let ((response, changed_index), outer_response) = (inner.inner, inner.response);
+        let mut response = response.unwrap_or_else(|| outer_response.clone());
+
+        if let Some(index) = changed_index {
+            if locked {
+                scale_others(&mut components, &before, index);
+            }
+            if normalized {
+                normalize_others(&mut components, index);
+            }
+        }
+
+        if copy_paste(ui, outer_response.rect, &mut components, format_vector) {
+            response.mark_changed();
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Edit a small matrix, e.g. a transform, with one [`DragValue`] per element laid out in a [`Grid`].
+///
+/// Hover the editor and press Cmd+C (Ctrl+C) to copy the whole matrix as text,
+/// or paste a matrix with Cmd+V (Ctrl+V).
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut transform = [[0.0_f32; 4]; 4];
+/// ui.add(egui::MatrixEdit::mat4(&mut transform));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct MatrixEdit<'a> {
+    elements: Vec<&'a mut f32>,
+    num_columns: usize,
+    id_source: Option<Id>,
+    speed: f64,
+}
+
+impl<'a> MatrixEdit<'a> {
+    /// Edit the given elements, in row-major order.
+    pub fn new(elements: &'a mut [f32], num_columns: usize) -> Self {
+        Self {
+            elements: elements.iter_mut().collect(),
+            num_columns: num_columns.max(1),
+            id_source: None,
+            speed: 0.1,
+        }
+    }
+
+    pub fn mat2(rows: &'a mut [[f32; 2]; 2]) -> Self {
+        Self::from_elements(rows.iter_mut().flat_map(|row| row.iter_mut()), 2)
+    }
+
+    pub fn mat3(rows: &'a mut [[f32; 3]; 3]) -> Self {
+        Self::from_elements(rows.iter_mut().flat_map(|row| row.iter_mut()), 3)
+    }
+
+    pub fn mat4(rows: &'a mut [[f32; 4]; 4]) -> Self {
+        Self::from_elements(rows.iter_mut().flat_map(|row| row.iter_mut()), 4)
+    }
+
+    fn from_elements(elements: impl Iterator<Item = &'a mut f32>, num_columns: usize) -> Self {
+        Self {
+            elements: elements.collect(),
+            ..Self::new(&mut [], num_columns)
+        }
+    }
+
+    /// Needed if you show several matrix editors in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// How much each element changes when dragged one point. Default: `0.1`.
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = speed.into();
+        self
+    }
+}
+
+impl<'a> Widget for MatrixEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut elements,
+            num_columns,
+            id_source,
+            speed,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("matrix_edit")));
+
+        let inner = Grid::new(id)
+            .num_columns(num_columns)
+            .spacing(ui.spacing().item_spacing * vec2(1.0, 0.5))
+            .show(ui, |ui| {
+                let mut response: Option<Response> = None;
+                for row in elements.chunks_mut(num_columns) {
+                    for element in row {
+                        let element_response = ui.add(DragValue::new(&mut **element).speed(speed));
+                        response = Some(match response {
+                            Some(response) => response | element_response,
+                            None => element_response,
+                        });
+                    }
+                    ui.end_row();
+                }
+                response
+            });
+
+        let outer_rect = inner.response.rect;
+        let mut response = inner.inner.unwrap_or(inner.response);
+        let format = |values: &[f32]| format_matrix(values, num_columns);
+        if copy_paste(ui, outer_rect, &mut elements, format) {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Component `index` was changed: scale all other components by the same factor.
+fn scale_others(components: &mut [&mut f32], before: &[f32], index: usize) {
+    let old = before[index];
+    if old == 0.0 {
+        return;
+    }
+    let factor = *components[index] / old;
+    for (i, component) in components.iter_mut().enumerate() {
+        if i != index {
+            **component = before[i] * factor;
+        }
+    }
+}
+
+/// Component `index` was changed: scale all other components so that the vector has unit length.
+fn normalize_others(components: &mut [&mut f32], index: usize) {
+    let fixed = *components[index];
+    let others_length_sq: f32 = components
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != index)
+        .map(|(_, c)| **c * **c)
+        .sum();
+    if others_length_sq <= 0.0 {
+        return;
+    }
+    let factor = ((1.0 - fixed * fixed).max(0.0) / others_length_sq).sqrt();
+    for (i, component) in components.iter_mut().enumerate() {
+        if i != index {
+            **component *= factor;
+        }
+    }
+}
+
+/// Copy the values on [`Event::Copy`], or paste them from [`Event::Text`],
+/// if the pointer is over `rect` and nothing has keyboard focus.
+///
+/// Returns `true` if something was pasted.
+fn copy_paste(
+    ui: &Ui,
+    rect: Rect,
+    values: &mut [&mut f32],
+    format: impl Fn(&[f32]) -> String,
+) -> bool {
+    if !ui.rect_contains_pointer(rect) || ui.memory().focus().is_some() {
+        return false;
+    }
+
+    let mut pasted = false;
+    let events = ui.input().events.clone();
+    for event in &events {
+        match event {
+            Event::Copy => {
+                let copied: Vec<f32> = values.iter().map(|v| **v).collect();
+                ui.output().copied_text = format(&copied);
+            }
+            Event::Text(text) => {
+                if let Some(parsed) = parse_values(text, values.len()) {
+                    for (value, parsed) in values.iter_mut().zip(parsed) {
+                        **value = parsed;
+                    }
+                    pasted = true;
+                }
+            }
+            _ => {}
+        }
+    }
+    pasted
+}
+
+fn format_vector(values: &[f32]) -> String {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn format_matrix(values: &[f32], num_columns: usize) -> String {
+    let rows: Vec<String> = values.chunks(num_columns).map(format_vector).collect();
+    format!("[{}]", rows.join(", "))
+}
+
+/// Parse exactly `count` numbers, ignoring brackets, commas and other separators.
+fn parse_values(text: &str, count: usize) -> Option<Vec<f32>> {
+    let is_number_char = |c: char| c.is_ascii_digit() || "+-.eE".contains(c);
+    let values = text
+        .split(|c: char| !is_number_char(c))
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().ok())
+        .collect::<Option<Vec<f32>>>()?;
+    if values.len() == count {
+        Some(values)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_format_and_parse_values() {
+    let values = [1.0, -0.5, 2e10, 0.0];
+    assert_eq!(format_vector(&values[..2]), "[1, -0.5]");
+    assert_eq!(format_matrix(&values, 2), "[[1, -0.5], [20000000000, 0]]");
+    assert_eq!(
+        parse_values(&format_matrix(&values, 2), 4),
+        Some(values.to_vec())
+    );
+    assert_eq!(parse_values("1 2; 3", 3), Some(vec![1.0, 2.0, 3.0]));
+    assert_eq!(parse_values("1, 2", 3), None);
+    assert_eq!(parse_values("1, two, 3", 3), None);
+}
+
+#[test]
+fn test_vector_edit_paste_and_lock_aspect() {
+    let mut harness = crate::harness::Harness::new(vec2(400.0, 100.0));
+    let mut size = [2.0_f32, 1.0];
+
+    let frame = harness.run(|ui| {
+        ui.add(VectorEdit::new(&mut size));
+    });
+    harness.pointer_move(frame.contents_rect.unwrap().left_center() + vec2(4.0, 0.0));
+    harness.event(Event::Text("[4, 3]".to_owned()));
+    harness.run(|ui| {
+        ui.add(VectorEdit::new(&mut size));
+    });
+    assert_eq!(size, [4.0, 3.0]);
+
+    let mut components: Vec<&mut f32> = size.iter_mut().collect();
+    *components[0] = 8.0;
+    scale_others(&mut components, &[4.0, 3.0], 0);
+    assert_eq!(size, [8.0, 6.0]);
+}
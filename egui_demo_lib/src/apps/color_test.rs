@@ -68,6 +68,29 @@ impl ColorTest {
         ui.checkbox(&mut self.texture_gradients, "Texture gradients");
         ui.checkbox(&mut self.srgb, "Show naive sRGBA horror");
 
+        let interpolates_in_linear_space = ui.input().raw.interpolates_colors_in_linear_space;
+        ui.label(format!(
+            "The integration reports that it {} vertex colors in linear space.",
+            if interpolates_in_linear_space {
+                "interpolates"
+            } else {
+                "does NOT interpolate"
+            }
+        ));
+        let mut subdivide_gradients = ui
+            .ctx()
+            .memory()
+            .options
+            .tessellation_options
+            .subdivide_gradients;
+        ui.checkbox(&mut subdivide_gradients, "Subdivide gradients")
+            .on_hover_text("Emulate linear interpolation of vertex colors with more triangles.");
+        ui.ctx()
+            .memory()
+            .options
+            .tessellation_options
+            .subdivide_gradients = subdivide_gradients;
+
         ui.heading("sRGB color test");
         ui.label("Use a color picker to ensure this color is (255, 165, 0) / #ffa500");
         ui.scope(|ui| {
@@ -145,49 +145,34 @@ impl DemoWindows {
     /// Show the app ui (menu bar and windows).
     /// `sidebar_ui` can be used to optionally show some things in the sidebar
     pub fn ui(&mut self, ctx: &CtxRef) {
-        let Self { demos, tests } = self;
-
-        egui::SidePanel::right("egui_demo_panel")
-            .min_width(150.0)
-            .default_width(190.0)
-            .show(ctx, |ui| {
-                egui::trace!(ui);
-                ui.vertical_centered(|ui| {
-                    ui.heading("✒ egui demos");
-                });
-
-                ui.separator();
-
-                ScrollArea::auto_sized().show(ui, |ui| {
-                    use egui::special_emojis::{GITHUB, OS_APPLE, OS_LINUX, OS_WINDOWS};
-
-                    ui.label("egui is an immediate mode GUI library written in Rust.");
-
-                    ui.label(format!(
-                        "egui runs on the web, or natively on {}{}{}",
-                        OS_APPLE, OS_LINUX, OS_WINDOWS,
-                    ));
-
+        // On narrow screens (e.g. phones) the side panel would leave no room for the demos,
+        // so we show it as a collapsible window instead:
+        let wide = egui::Responsive::new("demo_layout", false)
+            .at_least(600.0, true)
+            .pick(ctx);
+
+        if wide {
+            egui::SidePanel::right("egui_demo_panel")
+                .min_width(150.0)
+                .default_width(190.0)
+                .show(ctx, |ui| {
+                    egui::trace!(ui);
                     ui.vertical_centered(|ui| {
-                        ui.hyperlink_to(
-                            format!("{} egui home page", GITHUB),
-                            "https://github.com/emilk/egui",
-                        );
+                        ui.heading("✒ egui demos");
                     });
 
-                    ui.separator();
-                    demos.checkboxes(ui);
-                    ui.separator();
-                    tests.checkboxes(ui);
                     ui.separator();
 
-                    ui.vertical_centered(|ui| {
-                        if ui.button("Organize windows").clicked() {
-                            ui.ctx().memory().reset_areas();
-                        }
-                    });
+                    self.side_panel_contents(ui);
                 });
-            });
+        } else {
+            egui::Window::new("✒ egui demos")
+                .anchor(egui::Align2::RIGHT_TOP, [-4.0, 28.0])
+                .resizable(false)
+                .show(ctx, |ui| {
+                    self.side_panel_contents(ui);
+                });
+        }
 
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             show_menu_bar(ui);
@@ -208,6 +193,40 @@ impl DemoWindows {
         self.windows(ctx);
     }
 
+    fn side_panel_contents(&mut self, ui: &mut Ui) {
+        let Self { demos, tests } = self;
+
+        ScrollArea::auto_sized().show(ui, |ui| {
+            use egui::special_emojis::{GITHUB, OS_APPLE, OS_LINUX, OS_WINDOWS};
+
+            ui.label("egui is an immediate mode GUI library written in Rust.");
+
+            ui.label(format!(
+                "egui runs on the web, or natively on {}{}{}",
+                OS_APPLE, OS_LINUX, OS_WINDOWS,
+            ));
+
+            ui.vertical_centered(|ui| {
+                ui.hyperlink_to(
+                    format!("{} egui home page", GITHUB),
+                    "https://github.com/emilk/egui",
+                );
+            });
+
+            ui.separator();
+            demos.checkboxes(ui);
+            ui.separator();
+            tests.checkboxes(ui);
+            ui.separator();
+
+            ui.vertical_centered(|ui| {
+                if ui.button("Organize windows").clicked() {
+                    ui.ctx().memory().reset_areas();
+                }
+            });
+        });
+    }
+
     /// Show the open windows.
     fn windows(&mut self, ctx: &CtxRef) {
         let Self { demos, tests } = self;
@@ -236,5 +255,15 @@ fn show_menu_bar(ui: &mut Ui) {
                 *ui.ctx().memory() = Default::default();
             }
         });
+        menu::menu(ui, "View", |ui| {
+            let mut reduce_motion = ui.ctx().memory().options.reduce_motion;
+            if menu::checkbox_item(ui, &mut reduce_motion, "Reduce motion").changed() {
+                ui.ctx().memory().options.reduce_motion = reduce_motion;
+            }
+            let mut screen_reader = ui.ctx().memory().options.screen_reader;
+            if menu::checkbox_item(ui, &mut screen_reader, "Screen reader").changed() {
+                ui.ctx().memory().options.screen_reader = screen_reader;
+            }
+        });
     });
 }
@@ -10,7 +10,15 @@ pub struct MiscDemoWindow {
     widgets: Widgets,
     colors: ColorWidgets,
     tree: Tree,
+    list_selection: ListSelection,
     box_painting: BoxPainting,
+    transform: Transform,
+    handles: HandlesDemo,
+    dashboard: Dashboard,
+    table: EditableTable,
+    form: FormDemo,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    chat: ChatDemo,
 }
 
 impl Default for MiscDemoWindow {
@@ -21,7 +29,14 @@ impl Default for MiscDemoWindow {
             widgets: Default::default(),
             colors: Default::default(),
             tree: Tree::demo(),
+            list_selection: Default::default(),
             box_painting: Default::default(),
+            transform: Default::default(),
+            handles: Default::default(),
+            dashboard: Default::default(),
+            table: Default::default(),
+            form: Default::default(),
+            chat: Default::default(),
         }
     }
 }
@@ -57,6 +72,19 @@ impl View for MiscDemoWindow {
             .default_open(false)
             .show(ui, |ui| self.tree.ui(ui));
 
+        CollapsingHeader::new("List selection")
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.label("Try click, ctrl+click, shift+click and the arrow keys.");
+                self.list_selection.show(ui, 8, |ui, index, selected| {
+                    ui.selectable_label(selected, format!("📄 File {}", index))
+                });
+                let mut selected: Vec<usize> =
+                    self.list_selection.selected().iter().copied().collect();
+                selected.sort_unstable();
+                ui.label(format!("Selected: {:?}", selected));
+            });
+
         ui.collapsing("Columns", |ui| {
             ui.add(Slider::new(&mut self.num_columns, 1..=10).text("Columns"));
             ui.columns(self.num_columns, |cols| {
@@ -73,6 +101,34 @@ impl View for MiscDemoWindow {
             .default_open(false)
             .show(ui, |ui| self.box_painting.ui(ui));
 
+        CollapsingHeader::new("Vectors and matrices")
+            .default_open(false)
+            .show(ui, |ui| self.transform.ui(ui));
+
+        CollapsingHeader::new("Audio")
+            .default_open(false)
+            .show(ui, audio_ui);
+
+        CollapsingHeader::new("Handles")
+            .default_open(false)
+            .show(ui, |ui| self.handles.ui(ui));
+
+        CollapsingHeader::new("Gauges")
+            .default_open(false)
+            .show(ui, |ui| self.dashboard.ui(ui));
+
+        CollapsingHeader::new("Editable table")
+            .default_open(false)
+            .show(ui, |ui| self.table.ui(ui));
+
+        CollapsingHeader::new("Form")
+            .default_open(false)
+            .show(ui, |ui| self.form.ui(ui));
+
+        CollapsingHeader::new("Chat")
+            .default_open(false)
+            .show(ui, |ui| self.chat.ui(ui));
+
         CollapsingHeader::new("Resize")
             .default_open(false)
             .show(ui, |ui| {
@@ -175,6 +231,15 @@ impl Widgets {
         ui.label("Tooltips can be more than just simple text.")
             .on_hover_ui(tooltip_ui);
 
+        let response = ui.label("Some tooltips wait a while, and you can interact with them.");
+        Tooltip::new()
+            .delay(0.5)
+            .interactable(true)
+            .show_for(&response, |ui| {
+                ui.label("This tooltip stays open while you hover it.");
+                ui.hyperlink_to("egui on GitHub", "https://github.com/emilk/egui");
+            });
+
         ui.separator();
 
         ui.horizontal(|ui| {
@@ -186,6 +251,8 @@ impl Widgets {
         .response
         .on_hover_text("The angle is stored in radians, but presented in degrees");
 
+        ui.add(Knob::angle(angle).text("The same angle as a knob"));
+
         ui.separator();
 
         ui.horizontal(|ui| {
@@ -211,9 +278,37 @@ impl Widgets {
                 .code_editor()
                 .lock_focus(*lock_focus),
         );
+
+        ui.separator();
+
+        ui.label("Log viewer (read-only, but you can select and copy):");
+        let mut log = LOG.to_owned();
+        let mut log_viewer = TextEdit::multiline(&mut log)
+            .text_style(TextStyle::Monospace)
+            .read_only(true);
+        let mut line_start = 0;
+        for line in LOG.lines() {
+            let line_end = line_start + line.chars().count();
+            if line.starts_with("WARN") {
+                log_viewer =
+                    log_viewer.highlight(line_start..line_end, Color32::from_rgb(80, 64, 0));
+            } else if line.starts_with("ERROR") {
+                log_viewer =
+                    log_viewer.highlight(line_start..line_end, Color32::from_rgb(96, 0, 0));
+            }
+            line_start = line_end + 1; // newline
+        }
+        ui.add(log_viewer);
     }
 }
 
+const LOG: &str = "\
+INFO  starting up
+INFO  loaded 3 fonts
+WARN  no config file found, using defaults
+INFO  listening on port 8080
+ERROR connection refused: 127.0.0.1:5432";
+
 // ----------------------------------------------------------------------------
 
 #[derive(PartialEq)]
@@ -331,6 +426,355 @@ impl BoxPainting {
 
 // ----------------------------------------------------------------------------
 
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct Transform {
+    position: Vec2,
+    scale: [f32; 3],
+    rotation: [f32; 4],
+    matrix: [[f32; 4]; 4],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            scale: [1.0; 3],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            matrix: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+}
+
+impl Transform {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.label("Hover an editor and press Cmd+C / Cmd+V to copy or paste the whole value.");
+        Grid::new("transform").num_columns(2).show(ui, |ui| {
+            ui.label("Position:");
+            ui.add(VectorEdit::vec2(&mut self.position));
+            ui.end_row();
+
+            ui.label("Scale:");
+            ui.add(VectorEdit::new(&mut self.scale).lock_aspect(true));
+            ui.end_row();
+
+            ui.label("Rotation:");
+            ui.add(VectorEdit::quaternion(&mut self.rotation));
+            ui.end_row();
+
+            ui.label("Matrix:");
+            ui.add(MatrixEdit::mat4(&mut self.matrix));
+            ui.end_row();
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct HandlesDemo {
+    point: Pos2,
+    rect: Rect,
+    angle: f32,
+    snap: bool,
+}
+
+impl Default for HandlesDemo {
+    fn default() -> Self {
+        Self {
+            point: pos2(-80.0, -40.0),
+            rect: Rect::from_min_size(pos2(-20.0, -20.0), vec2(100.0, 60.0)),
+            angle: 0.0,
+            snap: true,
+        }
+    }
+}
+
+impl HandlesDemo {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.label("Drag the handles. Hold shift to constrain, alt to resize around the center.");
+        ui.checkbox(&mut self.snap, "Snap to a grid of 10 units");
+
+        let Self {
+            point,
+            rect,
+            angle,
+            snap,
+        } = self;
+        Frame::dark_canvas(ui.style()).show(ui, |ui| {
+            Canvas::new("handles_demo")
+                .desired_size(vec2(ui.available_width(), 200.0))
+                .show(ui, |ui, to_screen| {
+                    let mut handles = Handles::new(to_screen);
+                    if *snap {
+                        handles = handles.snap(10.0);
+                    }
+                    handles.rect(ui, "rect", rect);
+                    handles.rotation(ui, "angle", rect.center(), 50.0, angle);
+                    handles.point(ui, "point", point);
+                });
+        });
+        ui.label(format!(
+            "Point: {:?}, rectangle: {:?}, angle: {:.0}°",
+            point,
+            rect,
+            angle.to_degrees()
+        ));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct Dashboard {
+    temperature: f32,
+    battery: f32,
+    heading: f32,
+}
+
+impl Default for Dashboard {
+    fn default() -> Self {
+        Self {
+            temperature: 65.0,
+            battery: 80.0,
+            heading: 225.0,
+        }
+    }
+}
+
+impl Dashboard {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.add(Slider::new(&mut self.temperature, 0.0..=100.0).text("temperature"));
+        ui.add(Slider::new(&mut self.battery, 0.0..=100.0).text("battery"));
+        ui.add(Slider::new(&mut self.heading, 0.0..=360.0).text("heading"));
+
+        ui.horizontal(|ui| {
+            ui.add(
+                Gauge::circular(self.temperature, 0.0..=100.0)
+                    .thresholds(70.0, 90.0)
+                    .suffix(" °C")
+                    .text("CPU"),
+            );
+            ui.add(Compass::new(self.heading).text("Heading"));
+        });
+        ui.add(
+            Gauge::linear(self.battery, 0.0..=100.0)
+                .thresholds(20.0, 10.0)
+                .suffix("%")
+                .text("Battery"),
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct EditableTable {
+    rows: Vec<(String, u32, f32)>,
+}
+
+impl Default for EditableTable {
+    fn default() -> Self {
+        Self {
+            rows: vec![
+                ("Apples".to_owned(), 12, 0.5),
+                ("Bananas".to_owned(), 6, 0.25),
+                ("Cherries".to_owned(), 250, 0.05),
+            ],
+        }
+    }
+}
+
+impl EditableTable {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.label("Double-click a cell to edit it. Enter commits, Escape cancels and Tab moves on.");
+        ui.label("Drag the gaps between the columns to resize them.");
+        ui.label("Paste a table from a spreadsheet into a focused cell.");
+        let copy = ui.button("Copy as TSV").clicked();
+        Grid::new("editable_table")
+            .striped(true)
+            .resizable(true)
+            .column_alignment(1, Align2::RIGHT_CENTER)
+            .column_alignment(2, Align2::RIGHT_CENTER)
+            .column_alignment(3, Align2::RIGHT_CENTER)
+            .copy_as(copy.then(|| TableFormat::Tsv))
+            .show_editable(ui, |ui, cells| {
+                ui.label(Label::new("Fruit").strong());
+                ui.label(Label::new("Count").strong());
+                ui.label(Label::new("Price").strong());
+                ui.label(Label::new("Total").strong());
+                ui.end_row();
+
+                for (fruit, count, price) in &mut self.rows {
+                    cells.text(ui, fruit);
+                    cells.value(ui, count);
+                    cells.value(ui, price);
+                    ui.label(format!("{:.2}", *count as f32 * *price));
+                    ui.end_row();
+                }
+
+                // The first row is the header:
+                let rows = &mut self.rows;
+                cells.paste(ui, |row, col, text| {
+                    if let Some((fruit, count, price)) = rows.get_mut(row.wrapping_sub(1)) {
+                        let text = text.trim();
+                        match col {
+                            0 => *fruit = text.to_owned(),
+                            1 => *count = text.parse().unwrap_or(*count),
+                            2 => *price = text.parse().unwrap_or(*price),
+                            _ => {}
+                        }
+                    }
+                });
+            });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct FormDemo {
+    name: String,
+    email: String,
+    age: u32,
+    accept_terms: bool,
+    submitted: Option<String>,
+}
+
+impl FormDemo {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let Self {
+            name,
+            email,
+            age,
+            accept_terms,
+            submitted,
+        } = self;
+
+        let response = Form::new("form_demo")
+            .submit_text("Sign up")
+            .show(ui, |form| {
+                form.field("Name", |ui| ui.text_edit_singleline(name));
+                form.validate(!name.trim().is_empty(), "Please enter your name");
+
+                form.field("Email", |ui| ui.text_edit_singleline(email));
+                form.validate(
+                    email.contains('@'),
+                    "That doesn't look like an email address",
+                );
+
+                form.field("Age", |ui| ui.add(DragValue::new(age).clamp_range(0..=150)));
+                form.validate(*age >= 13, "You must be at least 13 years old");
+
+                form.field("", |ui| ui.checkbox(accept_terms, "I accept the terms"));
+                form.validate(*accept_terms, "You must accept the terms");
+            });
+
+        if response.submitted {
+            *submitted = Some(name.clone());
+        }
+        if let Some(name) = submitted {
+            ui.label(format!("Welcome, {}!", name));
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+struct ChatDemo {
+    messages: Vec<Message>,
+    draft: String,
+}
+
+impl Default for ChatDemo {
+    fn default() -> Self {
+        // A long made-up history, spanning a few days:
+        let start = 1_600_000_000.0;
+        let lines = [
+            "Hi!",
+            "Did you see the new release?",
+            "Yes, the chat widget only lays out the messages you can see, \
+             so even a history of ten thousand messages scrolls smoothly.",
+            "Nice 👍",
+        ];
+        let messages = (0..10_000)
+            .map(|i| {
+                let outgoing = i % 3 == 1;
+                let author = if outgoing { "Me" } else { "Alice" };
+                Message::new(author, lines[i % lines.len()], start + 47.0 * i as f64)
+                    .outgoing(outgoing)
+            })
+            .collect();
+        Self {
+            messages,
+            draft: Default::default(),
+        }
+    }
+}
+
+impl ChatDemo {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let Self { messages, draft } = self;
+
+        MessageList::new(messages).max_height(300.0).show(ui);
+
+        ui.horizontal(|ui| {
+            let response = ui.text_edit_singleline(draft);
+            let send = ui.button("Send").clicked()
+                || (response.lost_focus() && ui.input().key_pressed(Key::Enter));
+            if send && !draft.trim().is_empty() {
+                let time = messages.last().map_or(0.0, |last| last.time) + 60.0;
+                messages.push(Message::new("Me", std::mem::take(draft), time).outgoing(true));
+                response.request_focus();
+            }
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn audio_ui(ui: &mut Ui) {
+    ui.ctx().request_repaint();
+    let time = ui.input().time;
+
+    // A made-up signal: a tone that fades in and out, with some overtones.
+    let sample_rate = 8000.0;
+    let amplitude = 0.5 + 0.5 * (0.5 * time).sin();
+    let samples: Vec<f32> = (0..1024)
+        .map(|i| {
+            let t = time + i as f64 / sample_rate;
+            let tone = (440.0 * std::f64::consts::TAU * t).sin()
+                + 0.3 * (1320.0 * std::f64::consts::TAU * t).sin();
+            (amplitude * tone / 1.3) as f32
+        })
+        .collect();
+
+    ui.add(LevelMeter::from_samples(&samples));
+    ui.add(Waveform::samples(&samples[..256]));
+
+    // A crude spectrum of the two tones, instead of a real FFT:
+    let magnitudes: Vec<f32> = (0..512)
+        .map(|bin| {
+            let frequency = bin as f64 * sample_rate / 1024.0;
+            let peak = |f: f64, gain: f64| gain / (1.0 + ((frequency - f) / 20.0).powi(2));
+            (amplitude * (peak(440.0, 1.0) + peak(1320.0, 0.3))) as f32
+        })
+        .collect();
+    ui.add(Waveform::spectrum(&magnitudes));
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone, Copy, PartialEq)]
 enum Action {
     Keep,
@@ -349,18 +793,33 @@ impl Tree {
         ])
     }
     pub fn ui(&mut self, ui: &mut Ui) -> Action {
-        self.ui_impl(ui, 0, "root")
+        let mut open = None;
+        ui.horizontal(|ui| {
+            if ui.button("Expand all").clicked() {
+                open = Some(true);
+            }
+            if ui.button("Collapse all").clicked() {
+                open = Some(false);
+            }
+        });
+
+        CollapsingHeader::new("root")
+            .default_open_depth(2)
+            .open(open)
+            .show(ui, |ui| self.children_ui(ui, 0, open))
+            .body_returned
+            .unwrap_or(Action::Keep)
     }
 
-    fn ui_impl(&mut self, ui: &mut Ui, depth: usize, name: &str) -> Action {
+    fn ui_impl(&mut self, ui: &mut Ui, depth: usize, name: &str, open: Option<bool>) -> Action {
         CollapsingHeader::new(name)
-            .default_open(depth < 1)
-            .show(ui, |ui| self.children_ui(ui, depth))
+            .open(open)
+            .show(ui, |ui| self.children_ui(ui, depth, open))
             .body_returned
             .unwrap_or(Action::Keep)
     }
 
-    fn children_ui(&mut self, ui: &mut Ui, depth: usize) -> Action {
+    fn children_ui(&mut self, ui: &mut Ui, depth: usize, open: Option<bool>) -> Action {
         if depth > 0
             && ui
                 .add(Button::new("delete").text_color(Color32::RED))
@@ -374,7 +833,7 @@ impl Tree {
             .into_iter()
             .enumerate()
             .filter_map(|(i, mut tree)| {
-                if tree.ui_impl(ui, depth + 1, &format!("child #{}", i)) == Action::Keep {
+                if tree.ui_impl(ui, depth + 1, &format!("child #{}", i), open) == Action::Keep {
                     Some(tree)
                 } else {
                     None
@@ -133,6 +133,11 @@ impl super::View for MultiTouch {
                 Color32::RED.linear_multiply(1. / (1. + (5. * speed).powi(2))),
             );
 
+            // Paints a ring around each finger:
+            for touch in ui.input().touches() {
+                painter.circle_stroke(touch.pos, 20. + 20. * touch.force, Stroke::new(2., color));
+            }
+
             // we want continuous UI updates, so the circle can smoothly follow the arrow's origin:
             ui.ctx().request_repaint();
         });
@@ -46,10 +46,14 @@ impl Painting {
         let current_line = self.lines.last_mut().unwrap();
 
         if let Some(pointer_pos) = response.interact_pointer_pos() {
-            let canvas_pos = from_screen * pointer_pos;
-            if current_line.last() != Some(&canvas_pos) {
-                current_line.push(canvas_pos);
-                response.mark_changed();
+            // Use every pointer position since last frame, for smooth lines:
+            let pointer_moves = ui.input().pointer_moves();
+            for pos in pointer_moves.chain(std::iter::once(pointer_pos)) {
+                let canvas_pos = from_screen * pos;
+                if current_line.last() != Some(&canvas_pos) {
+                    current_line.push(canvas_pos);
+                    response.mark_changed();
+                }
             }
         } else if !current_line.is_empty() {
             self.lines.push(vec![]);
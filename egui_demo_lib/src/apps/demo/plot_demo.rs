@@ -1,7 +1,8 @@
 use egui::*;
 use plot::{
-    Arrows, Corner, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, Points, Polygon,
-    Text, VLine, Value, Values,
+    Arrows, AxisMode, Bins, Corner, HLine, Histogram, Legend, LegendPlacement, Line, LineStyle,
+    MarkerShape, Plot, PlotBackground, PlotImage, Points, Polygon, StackedArea, Text, VLine, Value,
+    Values,
 };
 use std::f64::consts::TAU;
 
@@ -13,6 +14,7 @@ struct LineDemo {
     circle_center: Pos2,
     square: bool,
     proportional: bool,
+    measure: bool,
     line_style: LineStyle,
 }
 
@@ -25,6 +27,7 @@ impl Default for LineDemo {
             circle_center: Pos2::new(0.0, 0.0),
             square: false,
             proportional: true,
+            measure: false,
             line_style: LineStyle::Solid,
         }
     }
@@ -39,6 +42,7 @@ impl LineDemo {
             circle_center,
             square,
             proportional,
+            measure,
             line_style,
             ..
         } = self;
@@ -75,6 +79,8 @@ impl LineDemo {
                     .on_hover_text("Always keep the viewport square.");
                 ui.checkbox(proportional, "Proportional data axes")
                     .on_hover_text("Tick are the same size on both axes.");
+                ui.checkbox(measure, "Measure")
+                    .on_hover_text("Click twice to place two cursors, and drag them around.");
             });
             ui.vertical(|ui| {
                 ComboBox::from_label("Line style")
@@ -148,7 +154,8 @@ impl Widget for &mut LineDemo {
             .line(self.circle())
             .line(self.sin())
             .line(self.thingy())
-            .legend(Legend::default());
+            .legend(Legend::default())
+            .measure(self.measure);
         if self.square {
             plot = plot.view_aspect(1.0);
         }
@@ -165,6 +172,7 @@ struct MarkerDemo {
     marker_radius: f32,
     automatic_colors: bool,
     marker_color: Color32,
+    box_select: bool,
 }
 
 impl Default for MarkerDemo {
@@ -174,6 +182,7 @@ impl Default for MarkerDemo {
             marker_radius: 5.0,
             automatic_colors: true,
             marker_color: Color32::GREEN,
+            box_select: false,
         }
     }
 }
@@ -221,27 +230,39 @@ impl Widget for &mut MarkerDemo {
             if !self.automatic_colors {
                 ui.color_edit_button_srgba(&mut self.marker_color);
             }
+            ui.checkbox(&mut self.box_select, "Box select")
+                .on_hover_text("Drag a rectangle to select points, click to clear the selection.");
         });
 
         let mut markers_plot = Plot::new("markers_demo")
             .data_aspect(1.0)
-            .legend(Legend::default());
+            .legend(Legend::default())
+            .box_select(self.box_select);
         for marker in self.markers() {
             markers_plot = markers_plot.points(marker);
         }
-        ui.add(markers_plot)
+        let response = ui.add(markers_plot);
+
+        if self.box_select {
+            let selection = Plot::selection(ui, "markers_demo");
+            let count: usize = selection.points.values().map(Vec::len).sum();
+            ui.label(format!("{} points selected", count));
+        }
+        response
     }
 }
 
 #[derive(PartialEq)]
 struct LegendDemo {
     config: Legend,
+    many_lines: bool,
 }
 
 impl Default for LegendDemo {
     fn default() -> Self {
         Self {
             config: Legend::default(),
+            many_lines: false,
         }
     }
 }
@@ -260,7 +281,7 @@ impl LegendDemo {
 
 impl Widget for &mut LegendDemo {
     fn ui(self, ui: &mut Ui) -> Response {
-        let LegendDemo { config } = self;
+        let LegendDemo { config, many_lines } = self;
 
         egui::Grid::new("settings").show(ui, |ui| {
             ui.label("Text style:");
@@ -279,6 +300,33 @@ impl Widget for &mut LegendDemo {
             });
             ui.end_row();
 
+            ui.label("Placement:");
+            ui.horizontal(|ui| {
+                LegendPlacement::all().for_each(|placement| {
+                    ui.selectable_value(
+                        &mut config.placement,
+                        placement,
+                        format!("{:?}", placement),
+                    );
+                });
+            });
+            ui.end_row();
+
+            ui.label("Max rows:");
+            ui.horizontal(|ui| {
+                let mut limit_rows = config.max_rows.is_some();
+                ui.checkbox(&mut limit_rows, "");
+                if limit_rows {
+                    let mut max_rows = config.max_rows.unwrap_or(3);
+                    ui.add(egui::DragValue::new(&mut max_rows).clamp_range(1..=20));
+                    config.max_rows = Some(max_rows);
+                } else {
+                    config.max_rows = None;
+                }
+                ui.checkbox(many_lines, "Many lines");
+            });
+            ui.end_row();
+
             ui.label("Opacity:");
             ui.add(
                 egui::DragValue::new(&mut config.background_alpha)
@@ -288,7 +336,7 @@ impl Widget for &mut LegendDemo {
             ui.end_row();
         });
 
-        let legend_plot = Plot::new("legend_demo")
+        let mut legend_plot = Plot::new("legend_demo")
             .line(LegendDemo::line_with_slope(0.5).name("lines"))
             .line(LegendDemo::line_with_slope(1.0).name("lines"))
             .line(LegendDemo::line_with_slope(2.0).name("lines"))
@@ -296,6 +344,19 @@ impl Widget for &mut LegendDemo {
             .line(LegendDemo::cos().name("cos(x)"))
             .legend(*config)
             .data_aspect(1.0);
+        if *many_lines {
+            for i in 1..=20 {
+                let offset = i as f64 * 0.25;
+                legend_plot = legend_plot.line(
+                    Line::new(Values::from_explicit_callback(
+                        move |x| x.sin() + offset,
+                        ..,
+                        100,
+                    ))
+                    .name(format!("sin(x) + {}", offset)),
+                );
+            }
+        }
         ui.add(legend_plot)
     }
 }
@@ -346,8 +407,18 @@ impl Widget for &mut ItemsDemo {
                 ui.fonts().texture().height as f32 / 100.0,
             ],
         );
+        // A faded copy of the font atlas behind everything, panning and zooming with the data:
+        let texture_size = ui.fonts().texture().size();
+        let background = PlotBackground::new(
+            TextureId::Egui,
+            [texture_size[0] as f32, texture_size[1] as f32],
+            Value::new(-12.0, -12.0),
+            Value::new(12.0, 12.0),
+        )
+        .tint(Color32::from_white_alpha(24));
 
         let plot = Plot::new("items_demo")
+            .background(background)
             .hline(HLine::new(9.0).name("Lines horizontal"))
             .hline(HLine::new(-9.0).name("Lines horizontal"))
             .vline(VLine::new(9.0).name("Lines vertical"))
@@ -369,11 +440,126 @@ impl Widget for &mut ItemsDemo {
     }
 }
 
+#[derive(Default, PartialEq)]
+struct TimeDemo {
+    utc_offset_hours: f64,
+}
+
+impl Widget for &mut TimeDemo {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            ui.label("UTC offset:");
+            ui.add(
+                egui::DragValue::new(&mut self.utc_offset_hours)
+                    .speed(0.25)
+                    .clamp_range(-12.0..=14.0)
+                    .suffix(" h"),
+            );
+        });
+        ui.label("Zoom in and out to see the time labels adapt.");
+
+        // A week of made-up temperatures, one every ten minutes, starting 2021-06-01 00:00 UTC:
+        let start = 1_622_505_600.0;
+        let temperatures = (0..7 * 24 * 6).map(|i| {
+            let hours = i as f64 / 6.0;
+            let daily = (TAU * (hours - 9.0) / 24.0).sin();
+            Value::new(
+                start + 3600.0 * hours,
+                18.0 + 6.0 * daily + (hours / 13.0).sin(),
+            )
+        });
+
+        let plot = Plot::new("time_demo")
+            .line(Line::new(Values::from_values_iter(temperatures)).name("Temperature"))
+            .x_axis_mode(AxisMode::Time {
+                utc_offset: 3600.0 * self.utc_offset_hours,
+            });
+        ui.add(plot)
+    }
+}
+
+#[derive(Default, PartialEq)]
+struct StackedDemo {}
+
+impl Widget for &mut StackedDemo {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.label("Hover to see what each process adds.");
+
+        let usage = |phase: f64, scale: f64| {
+            Values::from_values_iter((0..=60).map(move |i| {
+                let t = i as f64;
+                Value::new(t, scale * (1.2 + (0.2 * t + phase).sin()))
+            }))
+        };
+        let stack = StackedArea::new()
+            .series(Line::new(usage(0.0, 1.0)).name("Browser"))
+            .series(Line::new(usage(2.0, 0.5)).name("Compiler"))
+            .series(Line::new(usage(4.0, 0.8)).name("Music"));
+
+        let plot = Plot::new("stacked_demo")
+            .stacked_area(stack)
+            .include_y(0.0)
+            .legend(Legend::default().position(Corner::LeftTop));
+        ui.add(plot)
+    }
+}
+
+#[derive(PartialEq)]
+struct HistogramDemo {
+    bins: usize,
+    cumulative: bool,
+    normalized: bool,
+}
+
+impl Default for HistogramDemo {
+    fn default() -> Self {
+        Self {
+            bins: 20,
+            cumulative: false,
+            normalized: false,
+        }
+    }
+}
+
+impl Widget for &mut HistogramDemo {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::DragValue::new(&mut self.bins)
+                    .clamp_range(1..=100)
+                    .prefix("bins: "),
+            );
+            ui.checkbox(&mut self.cumulative, "Cumulative");
+            ui.checkbox(&mut self.normalized, "Normalized");
+        });
+
+        // Roughly normally distributed samples, by summing a few evenly spread sequences:
+        let samples = (0..1000).map(|i| {
+            [0.618_034, 0.414_214, 0.732_051, 0.236_068]
+                .iter()
+                .map(|step| (i as f64 * step).fract())
+                .sum::<f64>()
+        });
+        let histogram = Histogram::new(samples)
+            .bins(Bins::Count(self.bins))
+            .cumulative(self.cumulative)
+            .normalized(self.normalized);
+
+        let plot = Plot::new("histogram_demo")
+            .bar_chart(histogram.bar_chart().name("samples"))
+            .legend(Legend::default());
+        ui.add(plot)
+    }
+}
+
 #[derive(PartialEq, Eq)]
 enum Panel {
     Lines,
     Markers,
     Legend,
+    Time,
+    Stacked,
+    Histogram,
     Items,
 }
 
@@ -388,6 +574,9 @@ pub struct PlotDemo {
     line_demo: LineDemo,
     marker_demo: MarkerDemo,
     legend_demo: LegendDemo,
+    time_demo: TimeDemo,
+    stacked_demo: StackedDemo,
+    histogram_demo: HistogramDemo,
     items_demo: ItemsDemo,
     open_panel: Panel,
 }
@@ -429,6 +618,9 @@ impl super::View for PlotDemo {
             ui.selectable_value(&mut self.open_panel, Panel::Lines, "Lines");
             ui.selectable_value(&mut self.open_panel, Panel::Markers, "Markers");
             ui.selectable_value(&mut self.open_panel, Panel::Legend, "Legend");
+            ui.selectable_value(&mut self.open_panel, Panel::Time, "Time");
+            ui.selectable_value(&mut self.open_panel, Panel::Stacked, "Stacked");
+            ui.selectable_value(&mut self.open_panel, Panel::Histogram, "Histogram");
             ui.selectable_value(&mut self.open_panel, Panel::Items, "Items");
         });
         ui.separator();
@@ -443,6 +635,15 @@ impl super::View for PlotDemo {
             Panel::Legend => {
                 ui.add(&mut self.legend_demo);
             }
+            Panel::Time => {
+                ui.add(&mut self.time_demo);
+            }
+            Panel::Stacked => {
+                ui.add(&mut self.stacked_demo);
+            }
+            Panel::Histogram => {
+                ui.add(&mut self.histogram_demo);
+            }
             Panel::Items => {
                 ui.add(&mut self.items_demo);
             }
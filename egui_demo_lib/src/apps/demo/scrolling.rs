@@ -131,6 +131,7 @@ struct ScrollTo {
     track_item: usize,
     tack_item_align: Align,
     offset: f32,
+    minimap: bool,
 }
 
 impl Default for ScrollTo {
@@ -139,6 +140,7 @@ impl Default for ScrollTo {
             track_item: 25,
             tack_item_align: Align::Center,
             offset: 0.0,
+            minimap: false,
         }
     }
 }
@@ -184,7 +186,15 @@ impl super::View for ScrollTo {
             scroll_bottom |= ui.button("Scroll to bottom").clicked();
         });
 
-        let mut scroll_area = ScrollArea::from_max_height(200.0);
+        ui.checkbox(&mut self.minimap, "Show minimap");
+
+        // Mark the tracked item on the scroll bar:
+        let item_height = ui.fonts()[TextStyle::Body].row_height() + ui.spacing().item_spacing.y;
+        let item_offset = (self.track_item - 1) as f32 * item_height;
+
+        let mut scroll_area = ScrollArea::from_max_height(200.0)
+            .minimap(self.minimap)
+            .marker(item_offset, Color32::YELLOW);
         if go_to_scroll_offset {
             scroll_area = scroll_area.scroll_offset(self.offset);
         }
@@ -11,6 +11,8 @@ pub struct Sliders {
     pub logarithmic: bool,
     pub clamp_to_range: bool,
     pub smart_aim: bool,
+    pub step: f64,
+    pub show_ticks: bool,
     pub integer: bool,
     pub value: f64,
 }
@@ -23,6 +25,8 @@ impl Default for Sliders {
             logarithmic: true,
             clamp_to_range: false,
             smart_aim: true,
+            step: 0.0,
+            show_ticks: false,
             integer: false,
             value: 10.0,
         }
@@ -53,6 +57,8 @@ impl super::View for Sliders {
             logarithmic,
             clamp_to_range,
             smart_aim,
+            step,
+            show_ticks,
             integer,
             value,
         } = self;
@@ -77,6 +83,8 @@ impl super::View for Sliders {
                     .logarithmic(*logarithmic)
                     .clamp_to_range(*clamp_to_range)
                     .smart_aim(*smart_aim)
+                    .step_by(*step)
+                    .show_ticks(*show_ticks)
                     .text("i32 demo slider"),
             );
             *value = value_i32 as f64;
@@ -86,6 +94,8 @@ impl super::View for Sliders {
                     .logarithmic(*logarithmic)
                     .clamp_to_range(*clamp_to_range)
                     .smart_aim(*smart_aim)
+                    .step_by(*step)
+                    .show_ticks(*show_ticks)
                     .text("f64 demo slider"),
             );
 
@@ -138,6 +148,15 @@ impl super::View for Sliders {
         ui.label("Smart Aim will guide you towards round values when you drag the slider so you you are more likely to hit 250 than 247.23");
         ui.add_space(8.0);
 
+        ui.horizontal(|ui| {
+            ui.label("Step:");
+            ui.add(DragValue::new(step).speed(0.1).clamp_range(0.0..=INFINITY));
+            ui.checkbox(show_ticks, "Show ticks");
+        });
+        ui.label("With a step, the slider only allows whole steps from the start of the range. Zero means no step.");
+        ui.label("When a slider has keyboard focus, the arrow keys change its value. Hold shift for bigger steps and alt for smaller.");
+        ui.add_space(8.0);
+
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
             ui.add(crate::__egui_github_link_file!());
@@ -334,11 +334,7 @@ impl super::View for InputTest {
         );
 
         let mut new_info = String::new();
-        for &button in &[
-            egui::PointerButton::Primary,
-            egui::PointerButton::Secondary,
-            egui::PointerButton::Middle,
-        ] {
+        for &button in &egui::PointerButton::ALL {
             if response.clicked_by(button) {
                 new_info += &format!("Clicked by {:?} button\n", button);
             }
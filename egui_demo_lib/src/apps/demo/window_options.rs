@@ -5,6 +5,9 @@ pub struct WindowOptions {
     title_bar: bool,
     closable: bool,
     collapsible: bool,
+    collapsed_preview: bool,
+    pinnable: bool,
+    help: bool,
     resizable: bool,
     scroll: bool,
     disabled_time: f64,
@@ -21,6 +24,9 @@ impl Default for WindowOptions {
             title_bar: true,
             closable: true,
             collapsible: true,
+            collapsed_preview: false,
+            pinnable: false,
+            help: false,
             resizable: true,
             scroll: false,
             disabled_time: f64::NEG_INFINITY,
@@ -42,6 +48,9 @@ impl super::Demo for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            collapsed_preview,
+            pinnable,
+            help,
             resizable,
             scroll,
             disabled_time,
@@ -60,12 +69,17 @@ impl super::Demo for WindowOptions {
             .id(egui::Id::new("demo_window_options")) // required since we change the title
             .resizable(resizable)
             .collapsible(collapsible)
+            .collapsed_preview(collapsed_preview)
+            .pinnable(pinnable)
             .title_bar(title_bar)
             .scroll(scroll)
             .enabled(enabled);
         if closable {
             window = window.open(open);
         }
+        if help {
+            window = window.help_text("Change the options of this window below.");
+        }
         if anchored {
             window = window.anchor(anchor, anchor_offset);
         }
@@ -80,6 +94,9 @@ impl super::View for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            collapsed_preview,
+            pinnable,
+            help,
             resizable,
             scroll,
             disabled_time,
@@ -95,6 +112,10 @@ impl super::View for WindowOptions {
         ui.checkbox(title_bar, "title_bar");
         ui.checkbox(closable, "closable");
         ui.checkbox(collapsible, "collapsible");
+        ui.checkbox(collapsed_preview, "collapsed_preview")
+            .on_hover_text("Collapse the window, then hover its title");
+        ui.checkbox(pinnable, "pinnable");
+        ui.checkbox(help, "help_text");
         ui.checkbox(resizable, "resizable");
         ui.checkbox(scroll, "scroll");
 
@@ -1,54 +1,10 @@
-/// How often we repaint the demo app by default
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum RunMode {
-    /// This is the default for the demo.
-    ///
-    /// If this is selected, egui is only updated if are input events
-    /// (like mouse movements) or there are some animations in the GUI.
-    ///
-    /// Reactive mode saves CPU.
-    ///
-    /// The downside is that the UI can become out-of-date if something it is supposed to monitor changes.
-    /// For instance, a GUI for a thermostat need to repaint each time the temperature changes.
-    /// To ensure the UI is up to date you need to call `egui::Context::request_repaint()` each
-    /// time such an event happens. You can also chose to call `request_repaint()` once every second
-    /// or after every single frame - this is called `Continuous` mode,
-    /// and for games and interactive tools that need repainting every frame anyway, this should be the default.
-    Reactive,
-
-    /// This will call `egui::Context::request_repaint()` at the end of each frame
-    /// to request the backend to repaint as soon as possible.
-    ///
-    /// On most platforms this will mean that egui will run at the display refresh rate of e.g. 60 Hz.
-    ///
-    /// For this demo it is not any reason to do so except to
-    /// demonstrate how quickly egui runs.
-    ///
-    /// For games or other interactive apps, this is probably what you want to do.
-    /// It will guarantee that egui is always up-to-date.
-    Continuous,
-}
-
-/// Default for demo is Reactive since
-/// 1) We want to use minimal CPU
-/// 2) There are no external events that could invalidate the UI
-///    so there are no events to miss.
-impl Default for RunMode {
-    fn default() -> Self {
-        RunMode::Reactive
-    }
-}
-
-// ----------------------------------------------------------------------------
-
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
 pub struct BackendPanel {
     pub open: bool,
 
-    #[cfg_attr(feature = "persistence", serde(skip))]
-    // go back to `Reactive` mode each time we start
-    run_mode: RunMode,
+    /// The fps limit to use when "Limit FPS" is checked.
+    max_fps: f32,
 
     /// current slider value for current gui scale
     pixels_per_point: Option<f32>,
@@ -70,7 +26,7 @@ impl Default for BackendPanel {
     fn default() -> Self {
         Self {
             open: false,
-            run_mode: Default::default(),
+            max_fps: 30.0,
             pixels_per_point: Default::default(),
             max_size_points_ui: egui::Vec2::new(1024.0, 2048.0),
             max_size_points_active: egui::Vec2::new(1024.0, 2048.0),
@@ -85,11 +41,6 @@ impl BackendPanel {
     pub fn update(&mut self, ctx: &egui::CtxRef, frame: &mut epi::Frame<'_>) {
         self.frame_history
             .on_new_frame(ctx.input().time, frame.info().cpu_usage);
-
-        if self.run_mode == RunMode::Continuous {
-            // Tell the backend to repaint as soon as possible
-            ctx.request_repaint();
-        }
     }
 
     pub fn end_of_frame(&mut self, ctx: &egui::CtxRef) {
@@ -110,7 +61,7 @@ impl BackendPanel {
         });
         ui.separator();
 
-        self.run_mode_ui(ui);
+        self.run_mode_ui(ui, frame);
 
         if ui
             .button("Clear egui memory")
@@ -143,6 +94,17 @@ impl BackendPanel {
             frame.set_window_size(egui::Vec2::new(375.0, 812.0)); // iPhone 12 mini
         }
 
+        if let Some(window_info) = frame.info().window_info.clone() {
+            let mut fullscreen = window_info.fullscreen;
+            if ui
+                .checkbox(&mut fullscreen, "🗖 Fullscreen")
+                .on_hover_text("Toggle fullscreen mode of the native window.")
+                .changed()
+            {
+                frame.set_fullscreen(fullscreen);
+            }
+        }
+
         ui.separator();
 
         ui.label("egui windows");
@@ -190,6 +152,13 @@ impl BackendPanel {
             ui.ctx().memory().options.screen_reader = screen_reader;
         }
 
+        {
+            let mut reduce_motion = ui.ctx().memory().options.reduce_motion;
+            ui.checkbox(&mut reduce_motion, "Reduce motion")
+                .on_hover_text("Turn off all animations");
+            ui.ctx().memory().options.reduce_motion = reduce_motion;
+        }
+
         ui.collapsing("Output events", |ui| {
             ui.set_max_width(450.0);
             ui.label(
@@ -256,17 +225,35 @@ impl BackendPanel {
         }
     }
 
-    fn run_mode_ui(&mut self, ui: &mut egui::Ui) {
+    fn run_mode_ui(&mut self, ui: &mut egui::Ui, frame: &mut epi::Frame<'_>) {
+        use epi::RunMode;
+
+        let mut run_mode = frame.info().run_mode;
         ui.horizontal(|ui| {
-            let run_mode = &mut self.run_mode;
             ui.label("Mode:");
-            ui.radio_value(run_mode, RunMode::Continuous, "Continuous")
+            ui.radio_value(&mut run_mode, RunMode::Continuous, "Continuous")
                 .on_hover_text("Repaint everything each frame");
-            ui.radio_value(run_mode, RunMode::Reactive, "Reactive")
+            ui.radio_value(&mut run_mode, RunMode::Reactive, "Reactive")
                 .on_hover_text("Repaint when there are animations or input (e.g. mouse movement)");
         });
+        if run_mode != frame.info().run_mode {
+            frame.set_run_mode(run_mode);
+        }
+
+        let mut limit_fps = frame.info().max_fps.is_some();
+        ui.horizontal(|ui| {
+            let response = ui
+                .checkbox(&mut limit_fps, "Limit FPS")
+                .on_hover_text("Never repaint more often than this, to save battery");
+            ui.set_enabled(limit_fps);
+            let slider_response =
+                ui.add(egui::Slider::new(&mut self.max_fps, 1.0..=120.0).integer());
+            if response.changed() || slider_response.changed() {
+                frame.set_max_fps(if limit_fps { Some(self.max_fps) } else { None });
+            }
+        });
 
-        if self.run_mode == RunMode::Continuous {
+        if run_mode == RunMode::Continuous {
             ui.label(format!(
                 "Repainting the UI each frame. FPS: {:.1}",
                 self.frame_history.fps()
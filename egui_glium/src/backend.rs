@@ -1,4 +1,4 @@
-use crate::{window_settings::WindowSettings, *};
+use crate::{window_resize::WindowResize, window_settings::WindowSettings, *};
 use egui::Color32;
 #[cfg(target_os = "windows")]
 use glium::glutin::platform::windows::WindowBuilderExtWindows;
@@ -30,16 +30,22 @@ fn deserialize_memory(_: &Option<Box<dyn epi::Storage>>) -> Option<egui::Memory>
 }
 
 impl epi::TextureAllocator for Painter {
-    fn alloc_srgba_premultiplied(
+    fn alloc(
         &mut self,
         size: (usize, usize),
         srgba_pixels: &[Color32],
+        options: egui::TextureOptions,
     ) -> egui::TextureId {
         let id = self.alloc_user_texture();
+        self.set_user_texture_options(id, options);
         self.set_user_texture(id, size, srgba_pixels);
         id
     }
 
+    fn update(&mut self, id: egui::TextureId, size: (usize, usize), srgba_pixels: &[Color32]) {
+        self.set_user_texture(id, size, srgba_pixels);
+    }
+
     fn free(&mut self, id: egui::TextureId) {
         self.free_user_texture(id)
     }
@@ -148,6 +154,7 @@ fn create_storage(app_name: &str) -> Option<Box<dyn epi::Storage>> {
 fn integration_info(
     display: &glium::Display,
     previous_frame_time: Option<f32>,
+    repaint_settings: epi::backend::RepaintSettings,
 ) -> epi::IntegrationInfo {
     epi::IntegrationInfo {
         web_info: None,
@@ -155,6 +162,24 @@ fn integration_info(
         cpu_usage: previous_frame_time,
         seconds_since_midnight: seconds_since_midnight(),
         native_pixels_per_point: Some(native_pixels_per_point(display)),
+        window_info: Some(window_info(display)),
+        run_mode: repaint_settings.run_mode,
+        max_fps: repaint_settings.max_fps,
+    }
+}
+
+fn window_info(display: &glium::Display) -> epi::WindowInfo {
+    let gl_window = display.gl_window();
+    let window = gl_window.window();
+    let pixels_per_point = native_pixels_per_point(display);
+    let size = window.inner_size();
+    epi::WindowInfo {
+        position: window
+            .outer_position()
+            .ok()
+            .map(|pos| egui::pos2(pos.x as f32, pos.y as f32) / pixels_per_point),
+        size: egui::vec2(size.width as f32, size.height as f32) / pixels_per_point,
+        fullscreen: window.fullscreen().is_some(),
     }
 }
 
@@ -180,28 +205,42 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
         event_loop.create_proxy(),
     )));
 
+    #[cfg(feature = "dialogs")]
+    let dialogs = std::sync::Arc::new(crate::dialogs::GliumDialogs {
+        repaint_signal: repaint_signal.clone(),
+    });
+
     let mut egui = EguiGlium::new(&display);
     *egui.ctx().memory() = deserialize_memory(&storage).unwrap_or_default();
 
+    let mut repaint_settings = epi::backend::RepaintSettings::default();
+
     {
         let (ctx, painter) = egui.ctx_and_painter_mut();
         let mut app_output = epi::backend::AppOutput::default();
         let mut frame = epi::backend::FrameBuilder {
-            info: integration_info(&display, None),
+            info: integration_info(&display, None, repaint_settings),
             tex_allocator: painter,
             #[cfg(feature = "http")]
             http: http.clone(),
+            #[cfg(feature = "dialogs")]
+            dialogs: dialogs.clone(),
             output: &mut app_output,
             repaint_signal: repaint_signal.clone(),
         }
         .build();
         app.setup(ctx, &mut frame, storage.as_deref());
+        repaint_settings.apply(&app_output);
     }
 
     let mut previous_frame_time = None;
 
     let mut is_focused = true;
 
+    // Latest cursor position relative to the window, in physical pixels.
+    let mut cursor_pos = None;
+    let mut window_resize: Option<WindowResize> = None;
+
     #[cfg(feature = "persistence")]
     let mut last_auto_save = Instant::now();
 
@@ -213,10 +252,12 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
         let (ctx, painter) = egui.ctx_and_painter_mut();
         let mut app_output = epi::backend::AppOutput::default();
         let mut frame = epi::backend::FrameBuilder {
-            info: integration_info(&display, None),
+            info: integration_info(&display, None, repaint_settings),
             tex_allocator: painter,
             #[cfg(feature = "http")]
             http: http.clone(),
+            #[cfg(feature = "dialogs")]
+            dialogs: dialogs.clone(),
             output: &mut app_output,
             repaint_signal: repaint_signal.clone(),
         }
@@ -250,15 +291,21 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
             let (ctx, painter) = egui.ctx_and_painter_mut();
             let mut app_output = epi::backend::AppOutput::default();
             let mut frame = epi::backend::FrameBuilder {
-                info: integration_info(&display, previous_frame_time),
+                info: integration_info(&display, previous_frame_time, repaint_settings),
                 tex_allocator: painter,
                 #[cfg(feature = "http")]
                 http: http.clone(),
+                #[cfg(feature = "dialogs")]
+                dialogs: dialogs.clone(),
                 output: &mut app_output,
                 repaint_signal: repaint_signal.clone(),
             }
             .build();
             app.update(ctx, &mut frame);
+            repaint_settings.apply(&app_output);
+            if repaint_settings.run_mode == epi::RunMode::Continuous {
+                egui.ctx().request_repaint();
+            }
             let (needs_repaint, shapes) = egui.end_frame(&display);
 
             let frame_time = (Instant::now() - frame_start).as_secs_f64() as f32;
@@ -279,10 +326,26 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
             }
 
             {
-                let epi::backend::AppOutput { quit, window_size } = app_output;
+                let epi::backend::AppOutput {
+                    quit,
+                    window_size,
+                    window_title,
+                    window_pos,
+                    fullscreen,
+                    maximized,
+                    minimized,
+                    window_icon,
+                    drag_window,
+                    drag_resize_window,
+                    run_mode: _, // handled by `repaint_settings`
+                    max_fps: _,  // handled by `repaint_settings`
+                } = app_output;
+
+                let gl_window = display.gl_window();
+                let window = gl_window.window();
 
                 if let Some(window_size) = window_size {
-                    display.gl_window().window().set_inner_size(
+                    window.set_inner_size(
                         glutin::dpi::PhysicalSize {
                             width: (egui.ctx().pixels_per_point() * window_size.x).round(),
                             height: (egui.ctx().pixels_per_point() * window_size.y).round(),
@@ -291,11 +354,60 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
                     );
                 }
 
+                if let Some(window_title) = window_title {
+                    window.set_title(&window_title);
+                }
+
+                if let Some(window_pos) = window_pos {
+                    window.set_outer_position(glutin::dpi::LogicalPosition {
+                        x: window_pos.x as f64,
+                        y: window_pos.y as f64,
+                    });
+                }
+
+                if let Some(fullscreen) = fullscreen {
+                    window.set_fullscreen(
+                        fullscreen.then(|| glutin::window::Fullscreen::Borderless(None)),
+                    );
+                }
+
+                if let Some(maximized) = maximized {
+                    window.set_maximized(maximized);
+                }
+
+                if let Some(minimized) = minimized {
+                    window.set_minimized(minimized);
+                }
+
+                if let Some(window_icon) = window_icon {
+                    window.set_window_icon(load_icon(window_icon));
+                }
+
+                if drag_window {
+                    let _ = window.drag_window();
+                }
+
+                if let (Some(direction), Some(cursor_pos)) = (drag_resize_window, cursor_pos) {
+                    window_resize = WindowResize::begin(window, cursor_pos, direction);
+                }
+
+                let next_frame_start = repaint_settings
+                    .min_frame_time()
+                    .map(|min_frame_time| {
+                        frame_start + std::time::Duration::from_secs_f32(min_frame_time)
+                    })
+                    .filter(|next_frame_start| *next_frame_start > Instant::now());
+
                 *control_flow = if quit {
                     glutin::event_loop::ControlFlow::Exit
                 } else if needs_repaint {
-                    display.gl_window().window().request_redraw();
-                    glutin::event_loop::ControlFlow::Poll
+                    if let Some(next_frame_start) = next_frame_start {
+                        // Wait before repainting, to stay below the fps limit:
+                        glutin::event_loop::ControlFlow::WaitUntil(next_frame_start)
+                    } else {
+                        display.gl_window().window().request_redraw();
+                        glutin::event_loop::ControlFlow::Poll
+                    }
                 } else {
                     glutin::event_loop::ControlFlow::Wait
                 };
@@ -325,13 +437,63 @@ pub fn run(mut app: Box<dyn epi::App>, nativve_options: epi::NativeOptions) -> !
             glutin::event::Event::RedrawEventsCleared if cfg!(windows) => redraw(),
             glutin::event::Event::RedrawRequested(_) if !cfg!(windows) => redraw(),
 
+            glutin::event::Event::NewEvents(glutin::event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                // We waited to stay below the fps limit, now it is time to repaint:
+                display.gl_window().window().request_redraw();
+            }
+
             glutin::event::Event::WindowEvent { event, .. } => {
                 if egui.is_quit_event(&event) {
                     *control_flow = glium::glutin::event_loop::ControlFlow::Exit;
                 }
 
-                if let glutin::event::WindowEvent::Focused(new_focused) = event {
-                    is_focused = new_focused;
+                match &event {
+                    glutin::event::WindowEvent::Focused(new_focused) => {
+                        is_focused = *new_focused;
+                    }
+                    glutin::event::WindowEvent::CursorMoved { position, .. } => {
+                        cursor_pos = Some(*position);
+                        if let Some(window_resize) = &window_resize {
+                            window_resize.on_cursor_moved(display.gl_window().window(), *position);
+                        }
+                    }
+                    glutin::event::WindowEvent::MouseInput {
+                        state: glutin::event::ElementState::Pressed,
+                        button: glutin::event::MouseButton::Left,
+                        ..
+                    } => {
+                        // Let the OS move or resize the window, see `egui::Output::window_regions`:
+                        if let Some(cursor_pos) = cursor_pos {
+                            let pixels_per_point = egui.ctx().pixels_per_point();
+                            let pos = egui::pos2(
+                                cursor_pos.x as f32 / pixels_per_point,
+                                cursor_pos.y as f32 / pixels_per_point,
+                            );
+                            let gl_window = display.gl_window();
+                            match egui.window_region_at(pos) {
+                                Some(egui::WindowRegion::Drag) => {
+                                    let _ = gl_window.window().drag_window();
+                                }
+                                Some(egui::WindowRegion::Resize(direction)) => {
+                                    window_resize = WindowResize::begin(
+                                        gl_window.window(),
+                                        cursor_pos,
+                                        direction,
+                                    );
+                                }
+                                Some(egui::WindowRegion::Client) | None => {}
+                            }
+                        }
+                    }
+                    glutin::event::WindowEvent::MouseInput {
+                        state: glutin::event::ElementState::Released,
+                        ..
+                    } => {
+                        window_resize = None;
+                    }
+                    _ => {}
                 }
 
                 egui.on_event(&event);
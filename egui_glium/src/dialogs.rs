@@ -0,0 +1,134 @@
+//! Native file dialogs and message boxes, using [`rfd`](https://docs.rs/rfd).
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub use epi::dialogs::{FileContents, FileDialog, MessageBox, MessageButtons, MessageLevel};
+
+fn rfd_file_dialog(dialog: FileDialog) -> rfd::AsyncFileDialog {
+    let FileDialog {
+        title,
+        directory,
+        file_name,
+        filters,
+    } = dialog;
+
+    let mut rfd_dialog = rfd::AsyncFileDialog::new();
+    if !title.is_empty() {
+        rfd_dialog = rfd_dialog.set_title(&title);
+    }
+    if let Some(directory) = &directory {
+        rfd_dialog = rfd_dialog.set_directory(directory);
+    }
+    if !file_name.is_empty() {
+        rfd_dialog = rfd_dialog.set_file_name(&file_name);
+    }
+    for filter in &filters {
+        let extensions: Vec<&str> = filter.extensions.iter().map(String::as_str).collect();
+        rfd_dialog = rfd_dialog.add_filter(&filter.name, &extensions);
+    }
+    rfd_dialog
+}
+
+fn rfd_message_dialog(message: MessageBox) -> rfd::AsyncMessageDialog {
+    let MessageBox {
+        title,
+        description,
+        level,
+        buttons,
+    } = message;
+
+    let level = match level {
+        MessageLevel::Info => rfd::MessageLevel::Info,
+        MessageLevel::Warning => rfd::MessageLevel::Warning,
+        MessageLevel::Error => rfd::MessageLevel::Error,
+    };
+    let buttons = match buttons {
+        MessageButtons::Ok => rfd::MessageButtons::Ok,
+        MessageButtons::OkCancel => rfd::MessageButtons::OkCancel,
+        MessageButtons::YesNo => rfd::MessageButtons::YesNo,
+    };
+
+    rfd::AsyncMessageDialog::new()
+        .set_title(&title)
+        .set_description(&description)
+        .set_level(level)
+        .set_buttons(buttons)
+}
+
+pub(crate) struct GliumDialogs {
+    pub repaint_signal: Arc<dyn epi::RepaintSignal>,
+}
+
+impl GliumDialogs {
+    /// Wait for the dialog on a background thread so we don't block the event loop.
+    fn spawn<T: 'static + Send>(
+        &self,
+        future: impl 'static + Send + std::future::Future<Output = T>,
+        on_done: Box<dyn FnOnce(T) + Send>,
+    ) {
+        let repaint_signal = self.repaint_signal.clone();
+        std::thread::spawn(move || {
+            let result = pollster::block_on(future);
+            on_done(result);
+            repaint_signal.request_repaint();
+        });
+    }
+}
+
+impl epi::backend::Dialogs for GliumDialogs {
+    fn open_file_dyn(&self, dialog: FileDialog, on_done: Box<dyn FnOnce(Option<PathBuf>) + Send>) {
+        let future = rfd_file_dialog(dialog).pick_file();
+        self.spawn(
+            async move { future.await.map(|handle| handle.path().to_owned()) },
+            on_done,
+        );
+    }
+
+    fn save_file_dyn(&self, dialog: FileDialog, on_done: Box<dyn FnOnce(Option<PathBuf>) + Send>) {
+        let future = rfd_file_dialog(dialog).save_file();
+        self.spawn(
+            async move { future.await.map(|handle| handle.path().to_owned()) },
+            on_done,
+        );
+    }
+
+    fn open_file_contents_dyn(
+        &self,
+        dialog: FileDialog,
+        on_done: Box<dyn FnOnce(Option<FileContents>) + Send>,
+    ) {
+        let future = rfd_file_dialog(dialog).pick_file();
+        self.spawn(
+            async move {
+                let path = future.await?.path().to_owned();
+                let bytes = std::fs::read(&path).ok()?;
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                Some(FileContents { name, bytes })
+            },
+            on_done,
+        );
+    }
+
+    fn save_file_contents_dyn(
+        &self,
+        dialog: FileDialog,
+        contents: Vec<u8>,
+        on_done: Box<dyn FnOnce(bool) + Send>,
+    ) {
+        let future = rfd_file_dialog(dialog).save_file();
+        self.spawn(
+            async move {
+                match future.await {
+                    Some(handle) => std::fs::write(handle.path(), contents).is_ok(),
+                    None => false,
+                }
+            },
+            on_done,
+        );
+    }
+
+    fn message_box_dyn(&self, message: MessageBox, on_done: Box<dyn FnOnce(bool) + Send>) {
+        self.spawn(rfd_message_dialog(message).show(), on_done);
+    }
+}
@@ -19,12 +19,15 @@
 #![allow(clippy::manual_range_contains, clippy::single_match)]
 
 mod backend;
+#[cfg(feature = "dialogs")]
+pub mod dialogs;
 #[cfg(feature = "http")]
 pub mod http;
 mod painter;
 #[cfg(feature = "persistence")]
 pub mod persistence;
 pub mod screen_reader;
+mod window_resize;
 pub mod window_settings;
 
 pub use backend::*;
@@ -55,6 +58,7 @@ impl GliumInputState {
             pointer_pos_in_points: Default::default(),
             raw: egui::RawInput {
                 pixels_per_point: Some(pixels_per_point),
+                interpolates_colors_in_linear_space: true,
                 ..Default::default()
             },
         }
@@ -97,6 +101,11 @@ pub fn input_to_egui(
     input_state: &mut GliumInputState,
 ) {
     use glutin::event::WindowEvent;
+
+    // If the window moved to a monitor with a different DPI earlier this frame,
+    // the new scale already applies to the events that follow:
+    let pixels_per_point = input_state.raw.pixels_per_point.unwrap_or(pixels_per_point);
+
     match event {
         WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
             input_state.raw.pixels_per_point = Some(*scale_factor as f32);
@@ -199,14 +208,12 @@ pub fn input_to_egui(
             input_state.raw.modifiers = Modifiers::default();
         }
         WindowEvent::MouseWheel { delta, .. } => {
-            let mut delta = match *delta {
-                glutin::event::MouseScrollDelta::LineDelta(x, y) => {
-                    let line_height = 8.0; // magic value!
-                    vec2(x, y) * line_height
-                }
-                glutin::event::MouseScrollDelta::PixelDelta(delta) => {
-                    vec2(delta.x as f32, delta.y as f32) / pixels_per_point
-                }
+            let (mut delta, is_lines) = match *delta {
+                glutin::event::MouseScrollDelta::LineDelta(x, y) => (vec2(x, y), true),
+                glutin::event::MouseScrollDelta::PixelDelta(delta) => (
+                    vec2(delta.x as f32, delta.y as f32) / pixels_per_point,
+                    false,
+                ),
             };
             if cfg!(target_os = "macos") {
                 // This is still buggy in winit despite
@@ -214,9 +221,9 @@ pub fn input_to_egui(
                 delta.x *= -1.0;
             }
 
-            if input_state.raw.modifiers.ctrl || input_state.raw.modifiers.command {
-                // Treat as zoom instead:
-                input_state.raw.zoom_delta *= (delta.y / 200.0).exp();
+            // egui turns this into zooming if ctrl/cmd is held down.
+            if is_lines {
+                input_state.raw.scroll_delta_lines += delta;
             } else {
                 input_state.raw.scroll_delta += delta;
             }
@@ -255,6 +262,22 @@ pub fn input_to_egui(
                 },
             });
         }
+        WindowEvent::HoveredFile(path) => {
+            input_state.raw.hovered_files.push(egui::HoveredFile {
+                path: Some(path.clone()),
+                ..Default::default()
+            });
+        }
+        WindowEvent::HoveredFileCancelled => {
+            input_state.raw.hovered_files.clear();
+        }
+        WindowEvent::DroppedFile(path) => {
+            input_state.raw.hovered_files.clear();
+            input_state.raw.dropped_files.push(egui::DroppedFile {
+                path: Some(path.clone()),
+                ..Default::default()
+            });
+        }
         _ => {
             // dbg!(event);
         }
@@ -293,7 +316,12 @@ pub fn translate_mouse_button(button: glutin::event::MouseButton) -> Option<egui
         glutin::event::MouseButton::Left => Some(egui::PointerButton::Primary),
         glutin::event::MouseButton::Right => Some(egui::PointerButton::Secondary),
         glutin::event::MouseButton::Middle => Some(egui::PointerButton::Middle),
-        _ => None,
+        glutin::event::MouseButton::Other(button) => match button {
+            // Windows reports the extra buttons as 1 and 2, Mac as 3 and 4, and X11 as 8 and 9:
+            1 | 3 | 8 => Some(egui::PointerButton::Extra1),
+            2 | 4 | 9 => Some(egui::PointerButton::Extra2),
+            _ => None,
+        },
     }
 }
 
@@ -476,6 +504,7 @@ pub struct EguiGlium {
     painter: crate::Painter,
     current_cursor_icon: egui::CursorIcon,
     screen_reader: crate::screen_reader::ScreenReader,
+    window_regions: Vec<(egui::Rect, egui::WindowRegion)>,
 }
 
 impl EguiGlium {
@@ -490,6 +519,7 @@ impl EguiGlium {
             painter: crate::Painter::new(display),
             current_cursor_icon: egui::CursorIcon::Default,
             screen_reader: crate::screen_reader::ScreenReader::default(),
+            window_regions: Default::default(),
         }
     }
 
@@ -558,12 +588,19 @@ impl EguiGlium {
         }
 
         let needs_repaint = egui_output.needs_repaint;
+        self.window_regions = egui_output.window_regions.clone();
 
         handle_output(egui_output, self.clipboard.as_mut(), display);
 
         (needs_repaint, shapes)
     }
 
+    /// What happens if the user presses the primary mouse button at `pos` (in points),
+    /// according to the [`egui::Output::window_regions`] of the last frame.
+    pub fn window_region_at(&self, pos: egui::Pos2) -> Option<egui::WindowRegion> {
+        egui::output::window_region_at(&self.window_regions, pos)
+    }
+
     pub fn paint(
         &mut self,
         display: &glium::Display,
@@ -4,6 +4,7 @@ use {
     egui::{
         emath::Rect,
         epaint::{Color32, Mesh},
+        TextureFilter, TextureOptions, TextureWrapMode,
     },
     glium::{
         implement_vertex,
@@ -11,7 +12,7 @@ use {
         program,
         texture::{self, srgb_texture2d::SrgbTexture2d},
         uniform,
-        uniforms::{MagnifySamplerFilter, SamplerWrapFunction},
+        uniforms::{MagnifySamplerFilter, MinifySamplerFilter, SamplerWrapFunction},
         Frame, Surface,
     },
 };
@@ -33,6 +34,9 @@ struct UserTexture {
 
     /// Lazily uploaded
     gl_texture: Option<SrgbTexture2d>,
+
+    /// How to sample the texture.
+    options: TextureOptions,
 }
 
 impl Painter {
@@ -155,12 +159,36 @@ impl Painter {
 
         if let Some(texture) = self.get_texture(mesh.texture_id) {
             // The texture coordinates for text are so that both nearest and linear should work with the egui font texture.
-            // For user textures linear sampling is more likely to be the right choice.
-            let filter = MagnifySamplerFilter::Linear;
+            // For user textures linear sampling is the default, but it can be changed with `TextureOptions`,
+            // or for a single mesh with `Mesh::texture_filter`.
+            let mut options = self.get_texture_options(mesh.texture_id);
+            if let Some(filter) = mesh.texture_filter {
+                options.magnification = filter;
+                options.minification = filter;
+            }
+            let magnify_filter = match options.magnification {
+                TextureFilter::Nearest => MagnifySamplerFilter::Nearest,
+                TextureFilter::Linear => MagnifySamplerFilter::Linear,
+            };
+            let minify_filter = match (options.minification, options.mipmaps) {
+                (TextureFilter::Nearest, false) => MinifySamplerFilter::Nearest,
+                (TextureFilter::Linear, false) => MinifySamplerFilter::Linear,
+                (TextureFilter::Nearest, true) => MinifySamplerFilter::NearestMipmapNearest,
+                (TextureFilter::Linear, true) => MinifySamplerFilter::LinearMipmapLinear,
+            };
+            let wrap_function = match options.wrap_mode {
+                TextureWrapMode::ClampToEdge => SamplerWrapFunction::Clamp,
+                TextureWrapMode::Repeat => SamplerWrapFunction::Repeat,
+                TextureWrapMode::MirroredRepeat => SamplerWrapFunction::Mirror,
+            };
 
             let uniforms = uniform! {
                 u_screen_size: [width_in_points, height_in_points],
-                u_sampler: texture.sampled().magnify_filter(filter).wrap_function(SamplerWrapFunction::Clamp),
+                u_sampler: texture
+                    .sampled()
+                    .magnify_filter(magnify_filter)
+                    .minify_filter(minify_filter)
+                    .wrap_function(wrap_function),
             };
 
             // egui outputs colors with premultiplied alpha:
@@ -253,6 +281,7 @@ impl Painter {
                 *user_texture = UserTexture {
                     pixels: vec![],
                     gl_texture: Some(texture),
+                    options: Default::default(),
                 }
             }
         }
@@ -273,10 +302,20 @@ impl Painter {
                     .map(|row| row.iter().map(|srgba| srgba.to_tuple()).collect())
                     .collect();
 
-                *user_texture = UserTexture {
-                    pixels,
-                    gl_texture: None,
-                };
+                // Keep the options, and upload the new pixels on the next frame:
+                user_texture.pixels = pixels;
+                user_texture.gl_texture = None;
+            }
+        }
+    }
+
+    /// Change how the given user texture is sampled.
+    ///
+    /// A change of [`TextureOptions::mipmaps`] only takes effect the next time the pixels are set.
+    pub fn set_user_texture_options(&mut self, id: egui::TextureId, options: TextureOptions) {
+        if let egui::TextureId::User(id) = id {
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                user_texture.options = options;
             }
         }
     }
@@ -302,12 +341,29 @@ impl Painter {
         }
     }
 
+    /// How the given texture should be sampled.
+    pub fn get_texture_options(&self, texture_id: egui::TextureId) -> TextureOptions {
+        match texture_id {
+            egui::TextureId::Egui => TextureOptions::LINEAR,
+            egui::TextureId::User(id) => self
+                .user_textures
+                .get(id as usize)
+                .and_then(|user_texture| user_texture.as_ref())
+                .map(|user_texture| user_texture.options)
+                .unwrap_or_default(),
+        }
+    }
+
     pub fn upload_pending_user_textures(&mut self, facade: &dyn glium::backend::Facade) {
         for user_texture in self.user_textures.iter_mut().flatten() {
             if user_texture.gl_texture.is_none() {
                 let pixels = std::mem::take(&mut user_texture.pixels);
                 let format = texture::SrgbFormat::U8U8U8U8;
-                let mipmaps = texture::MipmapsOption::NoMipmap;
+                let mipmaps = if user_texture.options.mipmaps {
+                    texture::MipmapsOption::AutoGeneratedMipmaps
+                } else {
+                    texture::MipmapsOption::NoMipmap
+                };
                 user_texture.gl_texture =
                     Some(SrgbTexture2d::with_format(facade, pixels, format, mipmaps).unwrap());
             }
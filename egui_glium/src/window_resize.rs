@@ -0,0 +1,82 @@
+use glium::glutin::{
+    self,
+    dpi::{PhysicalPosition, PhysicalSize},
+};
+
+/// Smallest size we allow the user to resize the window to, in physical pixels.
+const MIN_SIZE: f64 = 64.0;
+
+/// An ongoing resize of an undecorated window, started with [`epi::Frame::drag_resize_window`].
+///
+/// winit has no way of asking the OS to do this for us, so we move the edges ourselves
+/// as the cursor moves, until the mouse button is released.
+pub(crate) struct WindowResize {
+    direction: epi::ResizeDirection,
+    /// Cursor position in screen coordinates when the resize started.
+    start_cursor: PhysicalPosition<f64>,
+    start_pos: PhysicalPosition<i32>,
+    start_size: PhysicalSize<u32>,
+}
+
+impl WindowResize {
+    /// `cursor_pos` is the latest cursor position relative to the window.
+    pub fn begin(
+        window: &glutin::window::Window,
+        cursor_pos: PhysicalPosition<f64>,
+        direction: epi::ResizeDirection,
+    ) -> Option<Self> {
+        let start_pos = window.outer_position().ok()?;
+        Some(Self {
+            direction,
+            start_cursor: PhysicalPosition::new(
+                start_pos.x as f64 + cursor_pos.x,
+                start_pos.y as f64 + cursor_pos.y,
+            ),
+            start_pos,
+            start_size: window.inner_size(),
+        })
+    }
+
+    /// `cursor_pos` is the cursor position relative to the window.
+    pub fn on_cursor_moved(
+        &self,
+        window: &glutin::window::Window,
+        cursor_pos: PhysicalPosition<f64>,
+    ) {
+        use epi::ResizeDirection::*;
+
+        let window_pos = match window.outer_position() {
+            Ok(window_pos) => window_pos,
+            Err(_) => return,
+        };
+        let dx = window_pos.x as f64 + cursor_pos.x - self.start_cursor.x;
+        let dy = window_pos.y as f64 + cursor_pos.y - self.start_cursor.y;
+
+        let mut left = self.start_pos.x as f64;
+        let mut top = self.start_pos.y as f64;
+        let mut right = left + self.start_size.width as f64;
+        let mut bottom = top + self.start_size.height as f64;
+
+        if matches!(self.direction, West | NorthWest | SouthWest) {
+            left = (left + dx).min(right - MIN_SIZE);
+        }
+        if matches!(self.direction, East | NorthEast | SouthEast) {
+            right = (right + dx).max(left + MIN_SIZE);
+        }
+        if matches!(self.direction, North | NorthEast | NorthWest) {
+            top = (top + dy).min(bottom - MIN_SIZE);
+        }
+        if matches!(self.direction, South | SouthEast | SouthWest) {
+            bottom = (bottom + dy).max(top + MIN_SIZE);
+        }
+
+        let new_pos = PhysicalPosition::new(left.round() as i32, top.round() as i32);
+        if new_pos != window_pos {
+            window.set_outer_position(new_pos);
+        }
+        window.set_inner_size(PhysicalSize::new(
+            (right - left).round() as u32,
+            (bottom - top).round() as u32,
+        ));
+    }
+}
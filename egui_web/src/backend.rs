@@ -13,8 +13,6 @@ pub struct WebBackend {
 
 impl WebBackend {
     pub fn new(canvas_id: &str) -> Result<Self, JsValue> {
-        let ctx = egui::CtxRef::default();
-
         let painter: Box<dyn Painter> =
             if let Ok(webgl2_painter) = webgl2::WebGl2Painter::new(canvas_id) {
                 console_log("Using WebGL2 backend");
@@ -24,12 +22,17 @@ impl WebBackend {
                 Box::new(webgl1::WebGlPainter::new(canvas_id)?)
             };
 
-        Ok(Self {
-            egui_ctx: ctx,
+        Ok(Self::with_painter(painter))
+    }
+
+    /// Use the given painter instead of picking one, e.g. a `webgpu::WebGpuPainter`.
+    pub fn with_painter(painter: Box<dyn Painter>) -> Self {
+        Self {
+            egui_ctx: egui::CtxRef::default(),
             painter,
             previous_frame_time: None,
             frame_start: None,
-        })
+        }
     }
 
     /// id of the canvas html element containing the rendering
@@ -57,6 +60,26 @@ impl WebBackend {
         Ok((output, clipped_meshes))
     }
 
+    /// Like [`Self::end_frame`], but sends the shapes to `worker` instead of tessellating them.
+    #[cfg(feature = "tessellation_worker")]
+    pub fn end_frame_in_worker(
+        &mut self,
+        worker: &mut TessellationWorker,
+    ) -> Result<egui::Output, JsValue> {
+        let frame_start = self
+            .frame_start
+            .take()
+            .expect("unmatched calls to begin_frame/end_frame");
+
+        let (output, shapes) = self.egui_ctx.end_frame();
+        worker.post(self.egui_ctx.tessellation_job(shapes))?;
+
+        let now = now_sec();
+        self.previous_frame_time = Some((now - frame_start) as f32);
+
+        Ok(output)
+    }
+
     pub fn paint(
         &mut self,
         clear_color: egui::Rgba,
@@ -96,6 +119,7 @@ impl WebInput {
             screen_rect: Some(egui::Rect::from_min_size(Default::default(), canvas_size)),
             pixels_per_point: Some(native_pixels_per_point()), // We ALWAYS use the native pixels-per-point
             time: Some(now_sec()),
+            interpolates_colors_in_linear_space: true, // both the WebGL1 and WebGL2 vertex shaders do
             ..self.raw.take()
         }
     }
@@ -142,7 +166,15 @@ pub struct AppRunner {
     screen_reader: crate::screen_reader::ScreenReader,
     #[cfg(feature = "http")]
     http: Arc<http::WebHttp>,
+    #[cfg(feature = "dialogs")]
+    dialogs: Arc<crate::dialogs::WebDialogs>,
     pub(crate) last_text_cursor_pos: Option<egui::Pos2>,
+    pub(crate) mutable_text_under_cursor: bool,
+    repaint_settings: epi::backend::RepaintSettings,
+    /// When the last frame started, in seconds.
+    last_frame_time: f64,
+    #[cfg(feature = "tessellation_worker")]
+    pub(crate) tessellation_worker: Option<TessellationWorker>,
 }
 
 impl AppRunner {
@@ -158,19 +190,29 @@ impl AppRunner {
         }
 
         let storage = LocalStorage::default();
+        let needs_repaint: std::sync::Arc<NeedRepaint> = Default::default();
 
         let mut runner = Self {
             web_backend,
             input: Default::default(),
             app,
-            needs_repaint: Default::default(),
+            needs_repaint: needs_repaint.clone(),
             storage,
             prefer_dark_mode,
             last_save_time: now_sec(),
             screen_reader: Default::default(),
             #[cfg(feature = "http")]
             http: Arc::new(http::WebHttp {}),
+            #[cfg(feature = "dialogs")]
+            dialogs: Arc::new(crate::dialogs::WebDialogs {
+                repaint_signal: needs_repaint,
+            }),
             last_text_cursor_pos: None,
+            mutable_text_under_cursor: false,
+            repaint_settings: Default::default(),
+            last_frame_time: now_sec(),
+            #[cfg(feature = "tessellation_worker")]
+            tessellation_worker: None,
         };
 
         {
@@ -180,6 +222,8 @@ impl AppRunner {
                 tex_allocator: runner.web_backend.painter.as_tex_allocator(),
                 #[cfg(feature = "http")]
                 http: runner.http.clone(),
+                #[cfg(feature = "dialogs")]
+                dialogs: runner.dialogs.clone(),
                 output: &mut app_output,
                 repaint_signal: runner.needs_repaint.clone(),
             }
@@ -189,6 +233,7 @@ impl AppRunner {
                 &mut frame,
                 Some(&runner.storage),
             );
+            runner.repaint_settings.apply(&app_output);
         }
 
         Ok(runner)
@@ -236,13 +281,46 @@ impl AppRunner {
             cpu_usage: self.web_backend.previous_frame_time,
             seconds_since_midnight: Some(seconds_since_midnight()),
             native_pixels_per_point: Some(native_pixels_per_point()),
+            window_info: None,
+            run_mode: self.repaint_settings.run_mode,
+            max_fps: self.repaint_settings.max_fps,
+        }
+    }
+
+    /// Have we waited long enough since the last frame to stay below the fps limit?
+    pub(crate) fn is_time_for_next_frame(&self) -> bool {
+        match self.repaint_settings.min_frame_time() {
+            Some(min_frame_time) => now_sec() - self.last_frame_time >= min_frame_time as f64,
+            None => true,
         }
     }
 
     pub fn logic(&mut self) -> Result<(egui::Output, Vec<egui::ClippedMesh>), JsValue> {
+        let app_output = self.update_app();
+        let (egui_output, clipped_meshes) = self.web_backend.end_frame()?;
+        self.handle_frame_output(&egui_output, app_output);
+        Ok((egui_output, clipped_meshes))
+    }
+
+    /// Like [`Self::logic`], but the meshes are tessellated by the [`TessellationWorker`].
+    /// Paint them once they are ready.
+    #[cfg(feature = "tessellation_worker")]
+    pub(crate) fn logic_in_worker(
+        &mut self,
+        worker: &mut TessellationWorker,
+    ) -> Result<egui::Output, JsValue> {
+        let app_output = self.update_app();
+        let egui_output = self.web_backend.end_frame_in_worker(worker)?;
+        self.handle_frame_output(&egui_output, app_output);
+        Ok(egui_output)
+    }
+
+    /// Begin the egui frame and let the app update.
+    fn update_app(&mut self) -> epi::backend::AppOutput {
         resize_canvas_to_screen_size(self.web_backend.canvas_id(), self.app.max_size_points());
         let canvas_size = canvas_size_in_points(self.web_backend.canvas_id());
         let raw_input = self.input.new_frame(canvas_size);
+        self.last_frame_time = now_sec();
 
         self.web_backend.begin_frame(raw_input);
 
@@ -252,27 +330,53 @@ impl AppRunner {
             tex_allocator: self.web_backend.painter.as_tex_allocator(),
             #[cfg(feature = "http")]
             http: self.http.clone(),
+            #[cfg(feature = "dialogs")]
+            dialogs: self.dialogs.clone(),
             output: &mut app_output,
             repaint_signal: self.needs_repaint.clone(),
         }
         .build();
 
         self.app.update(&self.web_backend.egui_ctx, &mut frame);
-        let (egui_output, clipped_meshes) = self.web_backend.end_frame()?;
+        self.repaint_settings.apply(&app_output);
+        if self.repaint_settings.run_mode == epi::RunMode::Continuous {
+            self.web_backend.egui_ctx.request_repaint();
+        }
+        app_output
+    }
 
+    fn handle_frame_output(
+        &mut self,
+        egui_output: &egui::Output,
+        app_output: epi::backend::AppOutput,
+    ) {
         if self.web_backend.egui_ctx.memory().options.screen_reader {
             self.screen_reader.speak(&egui_output.events_description());
         }
-        handle_output(&egui_output, self);
+        handle_output(egui_output, self);
 
         {
             let epi::backend::AppOutput {
                 quit: _,        // Can't quit a web page
                 window_size: _, // Can't resize a web page
+                window_title,
+                window_pos: _,         // Can't move a web page
+                fullscreen: _,         // TODO: use the Fullscreen API
+                maximized: _,          // Can't maximize a web page
+                minimized: _,          // Can't minimize a web page
+                window_icon: _,        // TODO: set the favicon
+                drag_window: _,        // Can't move a web page
+                drag_resize_window: _, // Can't resize a web page
+                run_mode: _,           // handled by `repaint_settings`
+                max_fps: _,            // handled by `repaint_settings`
             } = app_output;
-        }
 
-        Ok((egui_output, clipped_meshes))
+            if let Some(window_title) = window_title {
+                if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+                    document.set_title(&window_title);
+                }
+            }
+        }
     }
 
     pub fn paint(&mut self, clipped_meshes: Vec<egui::ClippedMesh>) -> Result<(), JsValue> {
@@ -290,6 +394,46 @@ pub fn start(canvas_id: &str, app: Box<dyn epi::App>) -> Result<AppRunnerRef, Js
     start_runner(runner)
 }
 
+/// Like [`start`], but paints with WebGPU if the browser supports it, and with WebGL otherwise.
+///
+/// This is experimental: you need the `webgpu` feature and `RUSTFLAGS=--cfg=web_sys_unstable_apis`.
+/// Setting up WebGPU is asynchronous, so run this with `wasm_bindgen_futures::spawn_local`.
+#[cfg(all(feature = "webgpu", target_arch = "wasm32"))]
+pub async fn start_webgpu(
+    canvas_id: &str,
+    app: Box<dyn epi::App>,
+) -> Result<AppRunnerRef, JsValue> {
+    let backend = match webgpu::WebGpuPainter::new(canvas_id).await {
+        Ok(painter) => {
+            console_log("Using WebGPU backend");
+            WebBackend::with_painter(Box::new(painter))
+        }
+        Err(err) => {
+            console_log(format!("Falling back to WebGL: {:?}", err));
+            WebBackend::new(canvas_id)?
+        }
+    };
+    let mut runner = AppRunner::new(backend, app)?;
+    runner.warm_up()?;
+    start_runner(runner)
+}
+
+/// Like [`start`], but tessellates in a web worker running the script at `worker_script_url`.
+///
+/// See [`TessellationWorker`] for what the script should look like.
+#[cfg(feature = "tessellation_worker")]
+pub fn start_with_tessellation_worker(
+    canvas_id: &str,
+    app: Box<dyn epi::App>,
+    worker_script_url: &str,
+) -> Result<AppRunnerRef, JsValue> {
+    let backend = WebBackend::new(canvas_id)?;
+    let mut runner = AppRunner::new(backend, app)?;
+    runner.warm_up()?;
+    runner.tessellation_worker = Some(TessellationWorker::new(worker_script_url)?);
+    start_runner(runner)
+}
+
 /// Install event listeners to register different input events
 /// and starts running the given `AppRunner`.
 fn start_runner(app_runner: AppRunner) -> Result<AppRunnerRef, JsValue> {
@@ -297,6 +441,7 @@ fn start_runner(app_runner: AppRunner) -> Result<AppRunnerRef, JsValue> {
     install_canvas_events(&runner_ref)?;
     install_document_events(&runner_ref)?;
     install_text_agent(&runner_ref)?;
+    install_resize_events(&runner_ref)?;
     repaint_every_ms(&runner_ref, 1000)?; // just in case. TODO: make it a parameter
     paint_and_schedule(runner_ref.clone())?;
     Ok(runner_ref)
@@ -0,0 +1,125 @@
+//! Copy and paste using the asynchronous [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API).
+//!
+//! The Clipboard API is only available if you compile with `RUSTFLAGS=--cfg=web_sys_unstable_apis`,
+//! and the browser may still deny access to it. In that case we fall back to
+//! `document.execCommand("copy")` for copying, and to the `paste` event for pasting.
+//! Without `web_sys_unstable_apis` you can only paste into the text agent (e.g. on mobile).
+
+use crate::*;
+use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
+
+/// Did the user (or browser) deny us access to the clipboard?
+static PERMISSION_DENIED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(web_sys_unstable_apis)]
+fn on_permission_denied(action: &str, err: &JsValue) {
+    // Only warn once, or we would spam the console on every copy/paste.
+    if !PERMISSION_DENIED.swap(true, SeqCst) {
+        console_warn(format!(
+            "{} denied: {:?}. Make sure the page has the 'clipboard-read' and 'clipboard-write' permissions.",
+            action, err
+        ));
+    }
+}
+
+/// Is the asynchronous Clipboard API available (and not denied)?
+pub fn has_clipboard_api() -> bool {
+    if !cfg!(web_sys_unstable_apis) || PERMISSION_DENIED.load(SeqCst) {
+        return false;
+    }
+    web_sys::window()
+        .and_then(|window| js_sys::Reflect::has(&window.navigator(), &"clipboard".into()).ok())
+        .unwrap_or(false)
+}
+
+/// Put the given text on the clipboard.
+///
+/// This is asynchronous, so it may not be done when this function returns.
+pub fn set_clipboard_text(text: &str) {
+    #[cfg(web_sys_unstable_apis)]
+    if has_clipboard_api() {
+        if let Some(window) = web_sys::window() {
+            let promise = window.navigator().clipboard().write_text(text);
+            let future = wasm_bindgen_futures::JsFuture::from(promise);
+            let text = text.to_owned();
+            let future = async move {
+                if let Err(err) = future.await {
+                    on_permission_denied("Copy/cut action", &err);
+                    copy_with_exec_command(&text);
+                }
+            };
+            wasm_bindgen_futures::spawn_local(future);
+            return;
+        }
+    }
+
+    copy_with_exec_command(text);
+}
+
+/// Fallback for browsers without the Clipboard API.
+///
+/// Most browsers only allow this during a user gesture (e.g. a key press), so it may fail.
+fn copy_with_exec_command(text: &str) -> Option<()> {
+    use wasm_bindgen::JsCast;
+    let document = web_sys::window()?.document()?;
+    let body = document.body()?;
+    let text_area = document
+        .create_element("textarea")
+        .ok()?
+        .dyn_into::<web_sys::HtmlTextAreaElement>()
+        .ok()?;
+    text_area.set_value(text);
+    text_area.style().set_property("position", "fixed").ok()?;
+    text_area.style().set_property("opacity", "0").ok()?;
+    body.append_child(&text_area).ok()?;
+    text_area.select();
+    let copied = document
+        .dyn_into::<web_sys::HtmlDocument>()
+        .ok()?
+        .exec_command("copy")
+        .unwrap_or(false);
+    body.remove_child(&text_area).ok()?;
+    if !copied {
+        console_warn("Failed to copy text to the clipboard");
+    }
+    Some(())
+}
+
+/// Ask the browser for the clipboard contents.
+///
+/// When (and if) the browser answers, the text is added to the input
+/// as an [`egui::Event::Text`], i.e. it will arrive on a later frame.
+///
+/// Returns `false` if the Clipboard API is not available,
+/// in which case you should rely on the `paste` event instead.
+pub fn request_clipboard_text(runner_ref: &AppRunnerRef) -> bool {
+    #[cfg(web_sys_unstable_apis)]
+    if has_clipboard_api() {
+        if let Some(window) = web_sys::window() {
+            let promise = window.navigator().clipboard().read_text();
+            let future = wasm_bindgen_futures::JsFuture::from(promise);
+            let runner_ref = runner_ref.clone();
+            let future = async move {
+                match future.await {
+                    Ok(text) => {
+                        if let Some(text) = text.as_string() {
+                            if !text.is_empty() {
+                                let mut runner_lock = runner_ref.0.lock();
+                                runner_lock.input.raw.events.push(egui::Event::Text(text));
+                                runner_lock.needs_repaint.set_true();
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        on_permission_denied("Paste action", &err);
+                    }
+                }
+            };
+            wasm_bindgen_futures::spawn_local(future);
+            return true;
+        }
+    }
+
+    let _ = runner_ref;
+    false
+}
@@ -0,0 +1,211 @@
+//! File dialogs and message boxes in the browser.
+//!
+//! Browsers do not give web pages access to file paths, so files are opened with an
+//! `<input type="file">` and a `FileReader`, and saved by downloading them.
+//! Message boxes use the browser's `alert` and `confirm`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use wasm_bindgen::{prelude::*, JsCast};
+
+pub use epi::dialogs::{FileContents, FileDialog, MessageBox, MessageButtons};
+
+/// A callback that may be reached from several event listeners, but must only be called once.
+type OnFileOpened = Rc<RefCell<Option<Box<dyn FnOnce(Option<FileContents>)>>>>;
+
+fn call_once(on_done: &OnFileOpened, contents: Option<FileContents>) {
+    let on_done = on_done.borrow_mut().take();
+    if let Some(on_done) = on_done {
+        on_done(contents);
+    }
+}
+
+/// The `accept` attribute of an `<input type="file">`, e.g. `".png,.jpg"`.
+fn accept_attribute(dialog: &FileDialog) -> String {
+    let extensions: Vec<String> = dialog
+        .filters
+        .iter()
+        .flat_map(|filter| &filter.extensions)
+        .map(|extension| format!(".{}", extension))
+        .collect();
+    extensions.join(",")
+}
+
+fn open_file(dialog: &FileDialog, on_done: OnFileOpened) -> Result<(), JsValue> {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let input = document
+        .create_element("input")?
+        .dyn_into::<web_sys::HtmlInputElement>()?;
+    input.set_type("file");
+    input.set_accept(&accept_attribute(dialog));
+
+    {
+        let input_clone = input.clone();
+        let on_done = on_done.clone();
+        let closure = Closure::once(move || {
+            let file = input_clone.files().and_then(|files| files.get(0));
+            match file {
+                Some(file) => {
+                    if let Err(err) = read_file(&file, on_done.clone()) {
+                        crate::console_error(format!("Failed to read file: {:?}", err));
+                        call_once(&on_done, None);
+                    }
+                }
+                None => call_once(&on_done, None),
+            }
+        });
+        input.add_event_listener_with_callback("change", closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    {
+        // Not all browsers tell us when the user cancels, so `on_done` may never be called.
+        let closure = Closure::once(move || call_once(&on_done, None));
+        input.add_event_listener_with_callback("cancel", closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    input.click();
+    Ok(())
+}
+
+fn read_file(file: &web_sys::File, on_done: OnFileOpened) -> Result<(), JsValue> {
+    let reader = web_sys::FileReader::new()?;
+
+    let reader_clone = reader.clone();
+    let name = file.name();
+    // Called both when the read succeeds and when it fails:
+    let closure = Closure::once(move || {
+        let contents = reader_clone
+            .result()
+            .ok()
+            .filter(|buffer| !buffer.is_null())
+            .map(|buffer| FileContents {
+                name,
+                bytes: js_sys::Uint8Array::new(&buffer).to_vec(),
+            });
+        call_once(&on_done, contents);
+    });
+    reader.set_onloadend(Some(closure.as_ref().unchecked_ref()));
+    closure.forget();
+
+    reader.read_as_array_buffer(file)
+}
+
+/// Download the given bytes as a file.
+fn save_file(dialog: &FileDialog, contents: &[u8]) -> Result<(), JsValue> {
+    let window = web_sys::window().unwrap();
+    let document = window.document().unwrap();
+
+    let bytes = js_sys::Uint8Array::from(contents);
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&js_sys::Array::of1(&bytes))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<web_sys::HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    if dialog.file_name.is_empty() {
+        anchor.set_download("download");
+    } else {
+        anchor.set_download(&dialog.file_name);
+    }
+    anchor.click();
+
+    // Some browsers only start the download after `click` returns,
+    // so give them a while before we free the data:
+    let closure = Closure::once(move || {
+        web_sys::Url::revoke_object_url(&url).ok();
+    });
+    window.set_timeout_with_callback_and_timeout_and_arguments_0(
+        closure.as_ref().unchecked_ref(),
+        60_000,
+    )?;
+    closure.forget();
+    Ok(())
+}
+
+pub(crate) struct WebDialogs {
+    pub repaint_signal: Arc<dyn epi::RepaintSignal>,
+}
+
+impl epi::backend::Dialogs for WebDialogs {
+    // egui_web only runs in the browser, where apps can't ask for file paths.
+    // These are only here so that the crate also compiles natively.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn open_file_dyn(
+        &self,
+        _dialog: FileDialog,
+        on_done: Box<dyn FnOnce(Option<std::path::PathBuf>) + Send>,
+    ) {
+        on_done(None);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_file_dyn(
+        &self,
+        _dialog: FileDialog,
+        on_done: Box<dyn FnOnce(Option<std::path::PathBuf>) + Send>,
+    ) {
+        on_done(None);
+    }
+
+    fn open_file_contents_dyn(
+        &self,
+        dialog: FileDialog,
+        on_done: Box<dyn FnOnce(Option<FileContents>) + Send>,
+    ) {
+        let repaint_signal = self.repaint_signal.clone();
+        let on_done: OnFileOpened = Rc::new(RefCell::new(Some(Box::new(move |contents| {
+            on_done(contents);
+            repaint_signal.request_repaint();
+        }))));
+
+        if let Err(err) = open_file(&dialog, on_done.clone()) {
+            crate::console_error(format!("Failed to show file dialog: {:?}", err));
+            call_once(&on_done, None);
+        }
+    }
+
+    fn save_file_contents_dyn(
+        &self,
+        dialog: FileDialog,
+        contents: Vec<u8>,
+        on_done: Box<dyn FnOnce(bool) + Send>,
+    ) {
+        let saved = match save_file(&dialog, &contents) {
+            Ok(()) => true,
+            Err(err) => {
+                crate::console_error(format!("Failed to download file: {:?}", err));
+                false
+            }
+        };
+
+        on_done(saved);
+        self.repaint_signal.request_repaint();
+    }
+
+    fn message_box_dyn(&self, message: MessageBox, on_done: Box<dyn FnOnce(bool) + Send>) {
+        let text = if message.title.is_empty() {
+            message.description
+        } else {
+            format!("{}\n\n{}", message.title, message.description)
+        };
+
+        let window = web_sys::window().unwrap();
+        let ok = match message.buttons {
+            MessageButtons::Ok => {
+                window.alert_with_message(&text).ok();
+                true
+            }
+            MessageButtons::OkCancel | MessageButtons::YesNo => {
+                window.confirm_with_message(&text).unwrap_or(false)
+            }
+        };
+
+        on_done(ok);
+        self.repaint_signal.request_repaint();
+    }
+}
@@ -18,18 +18,29 @@
 //     rustdoc::missing_crate_level_docs,
 //     rustdoc::private_intra_doc_links
 // )]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "webgpu"), forbid(unsafe_code))]
+#![cfg_attr(feature = "webgpu", deny(unsafe_code))] // the WebGPU painter needs one `unsafe` to create its surface
 #![warn(clippy::all, rust_2018_idioms)]
 
 pub mod backend;
+pub mod clipboard;
+#[cfg(feature = "dialogs")]
+mod dialogs;
 #[cfg(feature = "http")]
 pub mod http;
 mod painter;
 pub mod screen_reader;
+#[cfg(feature = "tessellation_worker")]
+pub mod tessellation_worker;
 pub mod webgl1;
 pub mod webgl2;
+#[cfg(all(feature = "webgpu", target_arch = "wasm32"))]
+pub mod webgpu;
 
 pub use backend::*;
+pub use clipboard::set_clipboard_text;
+#[cfg(feature = "tessellation_worker")]
+pub use tessellation_worker::{egui_web_tessellate, TessellationWorker};
 
 use egui::mutex::Mutex;
 pub use wasm_bindgen;
@@ -129,6 +140,8 @@ pub fn button_from_mouse_event(event: &web_sys::MouseEvent) -> Option<egui::Poin
         0 => Some(egui::PointerButton::Primary),
         1 => Some(egui::PointerButton::Middle),
         2 => Some(egui::PointerButton::Secondary),
+        3 => Some(egui::PointerButton::Extra1),
+        4 => Some(egui::PointerButton::Extra2),
         _ => None,
     }
 }
@@ -210,12 +223,6 @@ pub fn resize_canvas_to_screen_size(canvas_id: &str, max_size_points: egui::Vec2
     let screen_size_points = screen_size_in_native_points()?;
     let pixels_per_point = native_pixels_per_point();
 
-    let max_size_pixels = pixels_per_point * max_size_points;
-
-    let canvas_size_pixels = pixels_per_point * screen_size_points;
-    let canvas_size_pixels = canvas_size_pixels.min(max_size_pixels);
-    let canvas_size_points = canvas_size_pixels / pixels_per_point;
-
     // Make sure that the height and width are always even numbers.
     // otherwise, the page renders blurry on some platforms.
     // See https://github.com/emilk/egui/issues/103
@@ -223,22 +230,26 @@ pub fn resize_canvas_to_screen_size(canvas_id: &str, max_size_points: egui::Vec2
         (v / 2.0).round() * 2.0
     }
 
+    let canvas_size_points = screen_size_points.min(max_size_points);
+    let canvas_size_points = egui::vec2(
+        round_to_even(canvas_size_points.x),
+        round_to_even(canvas_size_points.y),
+    );
+
+    // Derive the pixel size from the (rounded) size in points, so that each canvas pixel
+    // maps to exactly one physical pixel, also for fractional `devicePixelRatio` (browser zoom).
+    let canvas_size_pixels = pixels_per_point * canvas_size_points;
+
     canvas
         .style()
-        .set_property(
-            "width",
-            &format!("{}px", round_to_even(canvas_size_points.x)),
-        )
+        .set_property("width", &format!("{}px", canvas_size_points.x))
         .ok()?;
     canvas
         .style()
-        .set_property(
-            "height",
-            &format!("{}px", round_to_even(canvas_size_points.y)),
-        )
+        .set_property("height", &format!("{}px", canvas_size_points.y))
         .ok()?;
-    canvas.set_width(round_to_even(canvas_size_pixels.x) as u32);
-    canvas.set_height(round_to_even(canvas_size_pixels.y) as u32);
+    canvas.set_width(canvas_size_pixels.x.round() as u32);
+    canvas.set_height(canvas_size_pixels.y.round() as u32);
 
     Some(())
 }
@@ -316,6 +327,8 @@ pub fn handle_output(output: &egui::Output, runner: &mut AppRunner) {
         needs_repaint: _, // handled elsewhere
         events: _,        // we ignore these (TODO: accessibility screen reader)
         text_cursor_pos,
+        mutable_text_under_cursor,
+        window_regions: _, // there are no native window decorations on the web
     } = output;
 
     set_cursor_icon(*cursor_icon);
@@ -323,16 +336,18 @@ pub fn handle_output(output: &egui::Output, runner: &mut AppRunner) {
         crate::open_url(&open.url, open.new_tab);
     }
 
-    #[cfg(web_sys_unstable_apis)]
     if !copied_text.is_empty() {
         set_clipboard_text(copied_text);
     }
 
-    #[cfg(not(web_sys_unstable_apis))]
-    let _ = copied_text;
+    runner.mutable_text_under_cursor = *mutable_text_under_cursor;
 
     if &runner.last_text_cursor_pos != text_cursor_pos {
         move_text_cursor(text_cursor_pos, runner.canvas_id());
+        if text_cursor_pos.is_none() {
+            // No text field has focus anymore, so hide the on-screen keyboard:
+            hide_text_agent(runner.canvas_id());
+        }
         runner.last_text_cursor_pos = *text_cursor_pos;
     }
 }
@@ -346,21 +361,6 @@ pub fn set_cursor_icon(cursor: egui::CursorIcon) -> Option<()> {
         .ok()
 }
 
-#[cfg(web_sys_unstable_apis)]
-pub fn set_clipboard_text(s: &str) {
-    if let Some(window) = web_sys::window() {
-        let clipboard = window.navigator().clipboard();
-        let promise = clipboard.write_text(s);
-        let future = wasm_bindgen_futures::JsFuture::from(promise);
-        let future = async move {
-            if let Err(err) = future.await {
-                console_error(format!("Copy/cut action denied: {:?}", err));
-            }
-        };
-        wasm_bindgen_futures::spawn_local(future);
-    }
-}
-
 pub fn spawn_future<F>(future: F)
 where
     F: std::future::Future<Output = ()> + 'static,
@@ -519,15 +519,64 @@ pub struct AppRunnerRef(Arc<Mutex<AppRunner>>);
 fn paint_and_schedule(runner_ref: AppRunnerRef) -> Result<(), JsValue> {
     fn paint_if_needed(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let mut runner_lock = runner_ref.0.lock();
-        if runner_lock.needs_repaint.fetch_and_clear() {
-            let (output, clipped_meshes) = runner_lock.logic()?;
-            runner_lock.paint(clipped_meshes)?;
+
+        #[cfg(feature = "tessellation_worker")]
+        {
+            if let Some(mut worker) = runner_lock.tessellation_worker.take() {
+                let worker_ok = paint_with_worker(&mut runner_lock, &mut worker);
+                if let Ok(false) = worker_ok {
+                    console_warn("Falling back to tessellating on the main thread");
+                    // The frame the worker failed on was never painted, so paint one right away:
+                    return paint_on_main_thread(&mut runner_lock);
+                }
+                // Keep the worker, also if painting failed:
+                runner_lock.tessellation_worker = Some(worker);
+                return worker_ok.map(|_| ());
+            }
+        }
+
+        if runner_lock.is_time_for_next_frame() && runner_lock.needs_repaint.fetch_and_clear() {
+            paint_on_main_thread(&mut runner_lock)?;
+        }
+
+        Ok(())
+    }
+
+    /// Paint the meshes the worker has finished, and give it the next frame when it is time.
+    /// Returns `false` if the worker failed.
+    #[cfg(feature = "tessellation_worker")]
+    fn paint_with_worker(
+        runner: &mut AppRunner,
+        worker: &mut TessellationWorker,
+    ) -> Result<bool, JsValue> {
+        match worker.take_result() {
+            Some(Ok(clipped_meshes)) => runner.paint(clipped_meshes)?,
+            Some(Err(err)) => {
+                console_error(err);
+                return Ok(false);
+            }
+            None => {}
+        }
+        if !worker.is_busy()
+            && runner.is_time_for_next_frame()
+            && runner.needs_repaint.fetch_and_clear()
+        {
+            let output = runner.logic_in_worker(worker)?;
             if output.needs_repaint {
-                runner_lock.needs_repaint.set_true();
+                runner.needs_repaint.set_true();
             }
-            runner_lock.auto_save();
+            runner.auto_save();
         }
+        Ok(true)
+    }
 
+    fn paint_on_main_thread(runner: &mut AppRunner) -> Result<(), JsValue> {
+        let (output, clipped_meshes) = runner.logic()?;
+        runner.paint(clipped_meshes)?;
+        if output.needs_repaint {
+            runner.needs_repaint.set_true();
+        }
+        runner.auto_save();
         Ok(())
     }
 
@@ -595,6 +644,16 @@ fn install_document_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
 
             let egui_wants_keyboard = runner_lock.egui_ctx().wants_keyboard_input();
 
+            if egui_wants_keyboard
+                && modifiers.command
+                && matches!(event.key().as_str(), "v" | "V")
+                && text_agent().hidden()
+            {
+                // The pasted text arrives on a later frame:
+                drop(runner_lock);
+                clipboard::request_clipboard_text(&runner_ref);
+            }
+
             let prevent_default = if matches!(event.key().as_str(), "Tab") {
                 // Always prevent moving cursor to url bar.
                 // egui wants to use tab to move to the next text field.
@@ -649,16 +708,24 @@ fn install_document_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
     }
 
     #[cfg(web_sys_unstable_apis)]
-    // paste is handled by IME text agent!
-    if false {
+    {
         // paste
         let runner_ref = runner_ref.clone();
         let closure = Closure::wrap(Box::new(move |event: web_sys::ClipboardEvent| {
+            if !text_agent().hidden() {
+                return; // The text agent handles the paste itself.
+            }
+            if clipboard::has_clipboard_api() {
+                return; // Handled by the keydown event, using the Clipboard API.
+            }
             if let Some(data) = event.clipboard_data() {
                 if let Ok(text) = data.get_data("text") {
-                    let mut runner_lock = runner_ref.0.lock();
-                    runner_lock.input.raw.events.push(egui::Event::Text(text));
-                    runner_lock.needs_repaint.set_true();
+                    if !text.is_empty() {
+                        let mut runner_lock = runner_ref.0.lock();
+                        runner_lock.input.raw.events.push(egui::Event::Text(text));
+                        runner_lock.needs_repaint.set_true();
+                        event.prevent_default();
+                    }
                 }
             }
         }) as Box<dyn FnMut(_)>);
@@ -704,6 +771,58 @@ fn install_document_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
     Ok(())
 }
 
+/// Repaint when the canvas changes size, or when the `devicePixelRatio` changes
+/// (e.g. because of browser zoom, or the window moving to another monitor).
+fn install_resize_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
+    watch_device_pixel_ratio(runner_ref.clone())?;
+
+    #[cfg(web_sys_unstable_apis)]
+    {
+        use wasm_bindgen::JsCast;
+        let canvas = canvas_element(runner_ref.0.lock().canvas_id()).unwrap();
+        let observed: web_sys::Element = match canvas.parent_element() {
+            Some(parent) => parent,
+            None => canvas.into(),
+        };
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |_entries: js_sys::Array| {
+            runner_ref.0.lock().needs_repaint.set_true();
+        }) as Box<dyn FnMut(_)>);
+        let observer = web_sys::ResizeObserver::new(closure.as_ref().unchecked_ref())?;
+        observer.observe(&observed);
+        closure.forget();
+        std::mem::forget(observer); // Keep observing for as long as the page lives
+    }
+
+    Ok(())
+}
+
+/// There is no event for when `devicePixelRatio` changes,
+/// so we listen for the media query of the current ratio to stop matching,
+/// and then start listening for the new ratio.
+fn watch_device_pixel_ratio(runner_ref: AppRunnerRef) -> Result<(), JsValue> {
+    use wasm_bindgen::JsCast;
+    let window = web_sys::window().unwrap();
+    let query = format!("(resolution: {}dppx)", window.device_pixel_ratio());
+    if let Some(media_query_list) = window.match_media(&query)? {
+        let closure = Closure::once(move || {
+            runner_ref.0.lock().needs_repaint.set_true();
+            if let Err(err) = watch_device_pixel_ratio(runner_ref) {
+                console_error(format!("Failed to watch devicePixelRatio: {:?}", err));
+            }
+        });
+        let mut options = web_sys::AddEventListenerOptions::new();
+        options.once(true);
+        media_query_list.add_event_listener_with_callback_and_add_event_listener_options(
+            "change",
+            closure.as_ref().unchecked_ref(),
+            &options,
+        )?;
+        closure.forget();
+    }
+    Ok(())
+}
+
 /// Repaint at least every `ms` milliseconds.
 fn repaint_every_ms(runner_ref: &AppRunnerRef, milliseconds: i32) -> Result<(), JsValue> {
     assert!(milliseconds >= 0);
@@ -920,7 +1039,7 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
                     event.stop_propagation();
                     event.prevent_default();
                 }
-                manipulate_agent(runner_lock.canvas_id(), runner_lock.input.latest_touch_pos);
+                update_text_agent(&runner_lock);
             }
         }) as Box<dyn FnMut(_)>);
         canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
@@ -1029,7 +1148,7 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
                 event.prevent_default();
 
                 // Finally, focus or blur on agent to toggle keyboard
-                manipulate_agent(runner_lock.canvas_id(), runner_lock.input.latest_touch_pos);
+                update_text_agent(&runner_lock);
             }
         }) as Box<dyn FnMut(_)>);
         canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
@@ -1056,24 +1175,29 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let closure = Closure::wrap(Box::new(move |event: web_sys::WheelEvent| {
             let mut runner_lock = runner_ref.0.lock();
 
-            let scroll_multiplier = match event.delta_mode() {
-                web_sys::WheelEvent::DOM_DELTA_PAGE => {
-                    canvas_size_in_points(runner_ref.0.lock().canvas_id()).y
-                }
-                web_sys::WheelEvent::DOM_DELTA_LINE => {
-                    8.0 // magic value!
-                }
-                _ => 1.0,
+            let delta = -egui::Vec2::new(event.delta_x() as f32, event.delta_y() as f32);
+            let is_lines = event.delta_mode() == web_sys::WheelEvent::DOM_DELTA_LINE;
+            let delta = if event.delta_mode() == web_sys::WheelEvent::DOM_DELTA_PAGE {
+                canvas_size_in_points(runner_lock.canvas_id()).y * delta
+            } else {
+                delta
             };
 
-            let delta = -scroll_multiplier
-                * egui::Vec2::new(event.delta_x() as f32, event.delta_y() as f32);
-
             // Report a zoom event in case CTRL (on Windows or Linux) or CMD (on Mac) is pressed.
             // This if-statement is equivalent to how `Modifiers.command` is determined in
             // `modifiers_from_event()`, but we cannot directly use that fn for a `WheelEvent`.
+            // We can't leave this to egui, since browsers set `ctrlKey` for touchpad pinches
+            // without any key being pressed.
             if event.ctrl_key() || event.meta_key() {
-                runner_lock.input.raw.zoom_delta *= (delta.y / 200.0).exp();
+                let line_height = 8.0; // magic value!
+                let points = if is_lines {
+                    line_height * delta.y
+                } else {
+                    delta.y
+                };
+                runner_lock.input.raw.zoom_delta *= (points / 200.0).exp();
+            } else if is_lines {
+                runner_lock.input.raw.scroll_delta_lines += delta;
             } else {
                 runner_lock.input.raw.scroll_delta += delta;
             }
@@ -1086,47 +1210,162 @@ fn install_canvas_events(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         closure.forget();
     }
 
+    {
+        let event_name = "dragover";
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::DragEvent| {
+            if let Some(data_transfer) = event.data_transfer() {
+                let mut runner_lock = runner_ref.0.lock();
+                runner_lock.input.raw.hovered_files.clear();
+                let items = data_transfer.items();
+                for i in 0..items.length() {
+                    if let Some(item) = items.get(i) {
+                        runner_lock.input.raw.hovered_files.push(egui::HoveredFile {
+                            mime: item.type_(),
+                            ..Default::default()
+                        });
+                    }
+                }
+                runner_lock.needs_repaint.set_true();
+                event.stop_propagation();
+                event.prevent_default();
+            }
+        }) as Box<dyn FnMut(_)>);
+        canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    {
+        let event_name = "dragleave";
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::DragEvent| {
+            let mut runner_lock = runner_ref.0.lock();
+            runner_lock.input.raw.hovered_files.clear();
+            runner_lock.needs_repaint.set_true();
+            event.stop_propagation();
+            event.prevent_default();
+        }) as Box<dyn FnMut(_)>);
+        canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
+    {
+        let event_name = "drop";
+        let runner_ref = runner_ref.clone();
+        let closure = Closure::wrap(Box::new(move |event: web_sys::DragEvent| {
+            if let Some(data_transfer) = event.data_transfer() {
+                {
+                    let mut runner_lock = runner_ref.0.lock();
+                    runner_lock.input.raw.hovered_files.clear();
+                    runner_lock.needs_repaint.set_true();
+                }
+
+                if let Some(files) = data_transfer.files() {
+                    for i in 0..files.length() {
+                        if let Some(file) = files.get(i) {
+                            let name = file.name();
+                            let last_modified = std::time::UNIX_EPOCH
+                                + std::time::Duration::from_millis(file.last_modified() as u64);
+
+                            console_log(format!("Loading {:?} ({} bytes)…", name, file.size()));
+
+                            // Reading the file contents is async, so we add it to the input once it is done:
+                            let future = wasm_bindgen_futures::JsFuture::from(file.array_buffer());
+                            let runner_ref = runner_ref.clone();
+                            let future = async move {
+                                match future.await {
+                                    Ok(array_buffer) => {
+                                        let bytes = js_sys::Uint8Array::new(&array_buffer).to_vec();
+                                        console_log(format!(
+                                            "Loaded {:?} ({} bytes).",
+                                            name,
+                                            bytes.len()
+                                        ));
+
+                                        let mut runner_lock = runner_ref.0.lock();
+                                        runner_lock.input.raw.dropped_files.push(
+                                            egui::DroppedFile {
+                                                name,
+                                                last_modified: Some(last_modified),
+                                                bytes: Some(bytes.into()),
+                                                ..Default::default()
+                                            },
+                                        );
+                                        runner_lock.needs_repaint.set_true();
+                                    }
+                                    Err(err) => {
+                                        console_error(format!("Failed to read file: {:?}", err));
+                                    }
+                                }
+                            };
+                            wasm_bindgen_futures::spawn_local(future);
+                        }
+                    }
+                }
+                event.stop_propagation();
+                event.prevent_default();
+            }
+        }) as Box<dyn FnMut(_)>);
+        canvas.add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())?;
+        closure.forget();
+    }
+
     Ok(())
 }
 
-fn manipulate_agent(canvas_id: &str, latest_cursor: Option<egui::Pos2>) -> Option<()> {
-    use wasm_bindgen::JsCast;
-    use web_sys::HtmlInputElement;
+/// Focus or blur the text agent to toggle the on-screen keyboard on mobile devices.
+///
+/// Mobile browsers only show the keyboard when an input element is focused from a user gesture,
+/// so this must be called from a mouse or touch event handler, not from the paint loop.
+fn update_text_agent(runner: &AppRunner) -> Option<()> {
+    if runner.mutable_text_under_cursor {
+        show_text_agent(runner.canvas_id(), runner.input.latest_touch_pos)
+    } else {
+        hide_text_agent(runner.canvas_id())
+    }
+}
+
+fn show_text_agent(canvas_id: &str, latest_touch_pos: Option<egui::Pos2>) -> Option<()> {
     let window = web_sys::window()?;
-    let document = window.document()?;
-    let input: HtmlInputElement = document.get_element_by_id(AGENT_ID)?.dyn_into().unwrap();
-    let cutsor_txt = document.body()?.style().get_property_value("cursor").ok()?;
-    let style = canvas_element(canvas_id)?.style();
-    if cutsor_txt == cursor_web_name(egui::CursorIcon::Text) {
-        input.set_hidden(false);
-        input.focus().ok()?;
-        // Panning canvas so that text edit is shown at 30%
-        // Only on touch screens, when keyboard popups
-        if let Some(p) = latest_cursor {
-            let inner_height = window.inner_height().ok()?.as_f64()? as f32;
-            let current_rel = p.y / inner_height;
+    let input = text_agent();
+    input.set_hidden(false);
+    input.focus().ok()?;
 
-            if current_rel > 0.5 {
-                // probably below the keyboard
+    // Panning canvas so that text edit is shown at 30%
+    // Only on touch screens, when keyboard popups
+    if let Some(p) = latest_touch_pos {
+        let inner_height = window.inner_height().ok()?.as_f64()? as f32;
+        let current_rel = p.y / inner_height;
 
-                let target_rel = 0.3;
+        if current_rel > 0.5 {
+            // probably below the keyboard
 
-                let delta = target_rel - current_rel;
-                let new_pos_percent = (delta * 100.0).round().to_string() + "%";
+            let target_rel = 0.3;
 
-                style.set_property("position", "absolute").ok()?;
-                style.set_property("top", &new_pos_percent).ok()?;
-            }
+            let delta = target_rel - current_rel;
+            let new_pos_percent = (delta * 100.0).round().to_string() + "%";
+
+            let style = canvas_element(canvas_id)?.style();
+            style.set_property("position", "absolute").ok()?;
+            style.set_property("top", &new_pos_percent).ok()?;
         }
-    } else {
-        input.blur().ok()?;
-        input.set_hidden(true);
-        style.set_property("position", "absolute").ok()?;
-        style.set_property("top", "0%").ok()?; // move back to normal position
     }
     Some(())
 }
 
+fn hide_text_agent(canvas_id: &str) -> Option<()> {
+    let input = text_agent();
+    if input.hidden() {
+        return Some(());
+    }
+    input.blur().ok()?;
+    input.set_hidden(true);
+    let style = canvas_element(canvas_id)?.style();
+    style.set_property("position", "absolute").ok()?;
+    style.set_property("top", "0%").ok()?; // move back to normal position
+    Some(())
+}
+
 const MOBILE_DEVICE: [&str; 6] = ["Android", "iPhone", "iPad", "iPod", "webOS", "BlackBerry"];
 /// If context is running under mobile device?
 fn is_mobile() -> Option<bool> {
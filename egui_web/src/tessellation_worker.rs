@@ -0,0 +1,112 @@
+//! Tessellating in a web worker, to keep the main thread responsive for large UIs.
+
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::{prelude::*, JsCast};
+
+type TessellationResult = Result<Vec<egui::ClippedMesh>, String>;
+
+/// Tessellates egui frames in a [web worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API),
+/// so that the main thread is free to handle input and run your app.
+///
+/// The shapes of each frame are sent to the worker, and the vertex buffers are transferred back
+/// when done. This delays what is shown on screen by a frame or so.
+///
+/// The worker must load the same wasm as your app, and call [`egui_web_tessellate`] for each message.
+/// If you build with `wasm-bindgen --target no-modules`, the worker script looks like this:
+///
+/// ```js
+/// importScripts("your_app.js");
+/// wasm_bindgen("your_app_bg.wasm").then(() => {
+///     onmessage = (event) => {
+///         const meshes = wasm_bindgen.egui_web_tessellate(event.data);
+///         postMessage(meshes, [meshes.buffer]);
+///     };
+/// });
+/// ```
+///
+/// Start your app with [`crate::start_with_tessellation_worker`] to use it.
+pub struct TessellationWorker {
+    worker: web_sys::Worker,
+    /// Waiting for the worker to tessellate a frame?
+    busy: bool,
+    result: Rc<RefCell<Option<TessellationResult>>>,
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+    _on_error: Closure<dyn FnMut(JsValue)>,
+}
+
+impl TessellationWorker {
+    /// Start a web worker running the script at `script_url`.
+    pub fn new(script_url: &str) -> Result<Self, JsValue> {
+        let worker = web_sys::Worker::new(script_url)?;
+        let result: Rc<RefCell<Option<TessellationResult>>> = Default::default();
+
+        let on_message = {
+            let result = result.clone();
+            Closure::wrap(Box::new(move |event: web_sys::MessageEvent| {
+                let bytes = js_sys::Uint8Array::new(&event.data()).to_vec();
+                *result.borrow_mut() =
+                    Some(bincode::deserialize(&bytes).map_err(|err| err.to_string()));
+            }) as Box<dyn FnMut(_)>)
+        };
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        let on_error = {
+            let result = result.clone();
+            Closure::wrap(Box::new(move |event: JsValue| {
+                *result.borrow_mut() =
+                    Some(Err(format!("Tessellation worker failed: {:?}", event)));
+            }) as Box<dyn FnMut(_)>)
+        };
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            worker,
+            busy: false,
+            result,
+            _on_message: on_message,
+            _on_error: on_error,
+        })
+    }
+
+    /// Is the worker still tessellating the last frame we sent it?
+    ///
+    /// Only one frame is tessellated at a time, so don't [`Self::post`] until this is `false`.
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    /// Send a frame to the worker. Get the meshes later with [`Self::take_result`].
+    pub fn post(&mut self, job: egui::TessellationJob) -> Result<(), JsValue> {
+        let bytes = bincode::serialize(&job).map_err(|err| JsValue::from(err.to_string()))?;
+        let message = js_sys::Uint8Array::from(bytes.as_slice());
+        let transfer = js_sys::Array::of1(&message.buffer());
+        self.worker
+            .post_message_with_transfer(&message, &transfer)?;
+        self.busy = true;
+        Ok(())
+    }
+
+    /// The meshes of the frame sent with [`Self::post`], once the worker is done with them.
+    pub fn take_result(&mut self) -> Option<Result<Vec<egui::ClippedMesh>, JsValue>> {
+        let result = self.result.borrow_mut().take()?;
+        self.busy = false;
+        Some(result.map_err(JsValue::from))
+    }
+}
+
+impl Drop for TessellationWorker {
+    fn drop(&mut self) {
+        self.worker.terminate();
+    }
+}
+
+/// Tessellate a frame sent by a [`TessellationWorker`].
+///
+/// Call this from the worker script. The returned bytes should be posted back to the main thread.
+#[wasm_bindgen]
+pub fn egui_web_tessellate(job: &[u8]) -> Result<Vec<u8>, JsValue> {
+    let job: egui::TessellationJob =
+        bincode::deserialize(job).map_err(|err| JsValue::from(err.to_string()))?;
+    bincode::serialize(&job.tessellate()).map_err(|err| JsValue::from(err.to_string()))
+}
@@ -7,6 +7,7 @@ use {
 use egui::{
     emath::vec2,
     epaint::{Color32, Texture},
+    TextureFilter, TextureOptions, TextureWrapMode,
 };
 
 type Gl = WebGlRenderingContext;
@@ -37,6 +38,9 @@ struct UserTexture {
 
     /// Lazily uploaded
     gl_texture: Option<WebGlTexture>,
+
+    /// How to sample the texture.
+    options: TextureOptions,
 }
 
 impl WebGlPainter {
@@ -105,27 +109,43 @@ impl WebGlPainter {
         &mut self,
         size: (usize, usize),
         srgba_pixels: &[Color32],
+        options: TextureOptions,
     ) -> egui::TextureId {
         let index = self.alloc_user_texture_index();
+        if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
+            user_texture.options = options;
+        }
+        let id = egui::TextureId::User(index as u64);
+        self.set_user_texture(id, size, srgba_pixels);
+        id
+    }
+
+    /// Replace the pixels of a user texture. They will be uploaded on the next frame.
+    fn set_user_texture(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+    ) {
         assert_eq!(size.0 * size.1, srgba_pixels.len());
 
-        if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
-            let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
-            for srgba in srgba_pixels {
-                pixels.push(srgba.r());
-                pixels.push(srgba.g());
-                pixels.push(srgba.b());
-                pixels.push(srgba.a());
-            }
+        if let egui::TextureId::User(id) = id {
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
+                for srgba in srgba_pixels {
+                    pixels.push(srgba.r());
+                    pixels.push(srgba.g());
+                    pixels.push(srgba.b());
+                    pixels.push(srgba.a());
+                }
 
-            *user_texture = UserTexture {
-                size,
-                pixels,
-                gl_texture: None,
-            };
+                user_texture.size = size;
+                user_texture.pixels = pixels;
+                if let Some(gl_texture) = user_texture.gl_texture.take() {
+                    self.gl.delete_texture(Some(&gl_texture));
+                }
+            }
         }
-
-        egui::TextureId::User(index as u64)
     }
 
     fn free_user_texture(&mut self, id: egui::TextureId) {
@@ -148,6 +168,23 @@ impl WebGlPainter {
         }
     }
 
+    /// How the texture was set up to be sampled. The egui texture is sampled linearly.
+    fn get_texture_size_and_options(
+        &self,
+        texture_id: egui::TextureId,
+    ) -> ((usize, usize), TextureOptions) {
+        match texture_id {
+            egui::TextureId::User(id) => self
+                .user_textures
+                .get(id as usize)
+                .and_then(|user_texture| user_texture.as_ref())
+                .map_or(((0, 0), TextureOptions::LINEAR), |user_texture| {
+                    (user_texture.size, user_texture.options)
+                }),
+            egui::TextureId::Egui => ((0, 0), TextureOptions::LINEAR),
+        }
+    }
+
     fn upload_user_textures(&mut self) {
         let gl = &self.gl;
 
@@ -156,12 +193,6 @@ impl WebGlPainter {
                 let pixels = std::mem::take(&mut user_texture.pixels);
 
                 let gl_texture = gl.create_texture().unwrap();
-                gl.bind_texture(Gl::TEXTURE_2D, Some(&gl_texture));
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, Gl::LINEAR as i32);
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, Gl::LINEAR as i32);
-
                 gl.bind_texture(Gl::TEXTURE_2D, Some(&gl_texture));
 
                 // TODO: https://developer.mozilla.org/en-US/docs/Web/API/EXT_sRGB
@@ -183,6 +214,8 @@ impl WebGlPainter {
                 )
                 .unwrap();
 
+                set_texture_options(gl, user_texture.size, user_texture.options);
+
                 user_texture.gl_texture = Some(gl_texture);
             }
         }
@@ -195,6 +228,7 @@ impl WebGlPainter {
                 size: (0, 0),
                 pixels: vec![],
                 gl_texture: Some(texture),
+                options: Default::default(),
             }
         }
         egui::TextureId::User(id as u64)
@@ -322,12 +356,17 @@ impl WebGlPainter {
 }
 
 impl epi::TextureAllocator for WebGlPainter {
-    fn alloc_srgba_premultiplied(
+    fn alloc(
         &mut self,
         size: (usize, usize),
         srgba_pixels: &[egui::Color32],
+        options: TextureOptions,
     ) -> egui::TextureId {
-        self.alloc_user_texture(size, srgba_pixels)
+        self.alloc_user_texture(size, srgba_pixels, options)
+    }
+
+    fn update(&mut self, id: egui::TextureId, size: (usize, usize), srgba_pixels: &[Color32]) {
+        self.set_user_texture(id, size, srgba_pixels)
     }
 
     fn free(&mut self, id: egui::TextureId) {
@@ -469,9 +508,25 @@ impl crate::Painter for WebGlPainter {
                     clip_max_y - clip_min_y,
                 );
 
+                // `Mesh::texture_filter` overrides the filter of the texture for this mesh only:
+                let restore = mesh.texture_filter.map(|filter| {
+                    let (size, options) = self.get_texture_size_and_options(mesh.texture_id);
+                    let overridden = TextureOptions {
+                        magnification: filter,
+                        minification: filter,
+                        ..options
+                    };
+                    set_texture_filters(gl, size, overridden);
+                    (size, options)
+                });
+
                 for mesh in mesh.split_to_u16() {
                     self.paint_mesh(&mesh)?;
                 }
+
+                if let Some((size, options)) = restore {
+                    set_texture_filters(gl, size, options);
+                }
             } else {
                 crate::console_warn(format!(
                     "WebGL: Failed to find texture {:?}",
@@ -483,6 +538,51 @@ impl crate::Painter for WebGlPainter {
     }
 }
 
+/// Set the sampling parameters of the currently bound texture, generating mipmaps if requested.
+///
+/// WebGL1 only supports mipmaps and repeating for textures with power-of-two dimensions,
+/// so for other textures we fall back to clamping without mipmaps.
+fn set_texture_options(gl: &Gl, size: (usize, usize), options: TextureOptions) {
+    let power_of_two = size.0.is_power_of_two() && size.1.is_power_of_two();
+
+    let wrap = if power_of_two {
+        match options.wrap_mode {
+            TextureWrapMode::ClampToEdge => Gl::CLAMP_TO_EDGE,
+            TextureWrapMode::Repeat => Gl::REPEAT,
+            TextureWrapMode::MirroredRepeat => Gl::MIRRORED_REPEAT,
+        }
+    } else {
+        Gl::CLAMP_TO_EDGE
+    };
+    let mipmaps = options.mipmaps && power_of_two;
+
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, wrap as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, wrap as i32);
+    set_texture_filters(gl, size, options);
+
+    if mipmaps {
+        gl.generate_mipmap(Gl::TEXTURE_2D);
+    }
+}
+
+/// Set the magnification and minification filters of the currently bound texture.
+fn set_texture_filters(gl: &Gl, size: (usize, usize), options: TextureOptions) {
+    let power_of_two = size.0.is_power_of_two() && size.1.is_power_of_two();
+    let mipmaps = options.mipmaps && power_of_two;
+    let mag_filter = match options.magnification {
+        TextureFilter::Nearest => Gl::NEAREST,
+        TextureFilter::Linear => Gl::LINEAR,
+    };
+    let min_filter = match (options.minification, mipmaps) {
+        (TextureFilter::Nearest, false) => Gl::NEAREST,
+        (TextureFilter::Linear, false) => Gl::LINEAR,
+        (TextureFilter::Nearest, true) => Gl::NEAREST_MIPMAP_NEAREST,
+        (TextureFilter::Linear, true) => Gl::LINEAR_MIPMAP_LINEAR,
+    };
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, min_filter as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, mag_filter as i32);
+}
+
 fn compile_shader(
     gl: &WebGlRenderingContext,
     shader_type: u32,
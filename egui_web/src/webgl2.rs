@@ -1,14 +1,19 @@
-//! Mostly a carbon-copy of `webgl1.rs`.
+//! Mostly a carbon-copy of `webgl1.rs`, but with linear-space blending
+//! thanks to an sRGB render target (see `PostProcess`).
 
 use {
     js_sys::WebAssembly,
     wasm_bindgen::{prelude::*, JsCast},
-    web_sys::{WebGl2RenderingContext, WebGlBuffer, WebGlProgram, WebGlShader, WebGlTexture},
+    web_sys::{
+        WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlShader,
+        WebGlTexture, WebGlVertexArrayObject,
+    },
 };
 
 use egui::{
     emath::vec2,
     epaint::{Color32, Texture},
+    TextureFilter, TextureOptions, TextureWrapMode,
 };
 
 type Gl = WebGl2RenderingContext;
@@ -28,6 +33,8 @@ pub struct WebGl2Painter {
 
     /// `None` means unallocated (freed) slot.
     user_textures: Vec<Option<UserTexture>>,
+
+    post_process: PostProcess,
 }
 
 #[derive(Default)]
@@ -39,6 +46,9 @@ struct UserTexture {
 
     /// Lazily uploaded
     gl_texture: Option<WebGlTexture>,
+
+    /// How to sample the texture.
+    options: TextureOptions,
 }
 
 impl WebGl2Painter {
@@ -76,6 +86,9 @@ impl WebGl2Painter {
         let tc_buffer = gl.create_buffer().ok_or("failed to create tc_buffer")?;
         let color_buffer = gl.create_buffer().ok_or("failed to create color_buffer")?;
 
+        let post_process =
+            PostProcess::new(gl.clone(), canvas.width() as i32, canvas.height() as i32)?;
+
         Ok(WebGl2Painter {
             canvas_id: canvas_id.to_owned(),
             canvas,
@@ -88,6 +101,7 @@ impl WebGl2Painter {
             egui_texture,
             egui_texture_version: None,
             user_textures: Default::default(),
+            post_process,
         })
     }
 
@@ -107,27 +121,43 @@ impl WebGl2Painter {
         &mut self,
         size: (usize, usize),
         srgba_pixels: &[Color32],
+        options: TextureOptions,
     ) -> egui::TextureId {
         let index = self.alloc_user_texture_index();
+        if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
+            user_texture.options = options;
+        }
+        let id = egui::TextureId::User(index as u64);
+        self.set_user_texture(id, size, srgba_pixels);
+        id
+    }
+
+    /// Replace the pixels of a user texture. They will be uploaded on the next frame.
+    fn set_user_texture(
+        &mut self,
+        id: egui::TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+    ) {
         assert_eq!(size.0 * size.1, srgba_pixels.len());
 
-        if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
-            let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
-            for srgba in srgba_pixels {
-                pixels.push(srgba.r());
-                pixels.push(srgba.g());
-                pixels.push(srgba.b());
-                pixels.push(srgba.a());
-            }
+        if let egui::TextureId::User(id) = id {
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
+                for srgba in srgba_pixels {
+                    pixels.push(srgba.r());
+                    pixels.push(srgba.g());
+                    pixels.push(srgba.b());
+                    pixels.push(srgba.a());
+                }
 
-            *user_texture = UserTexture {
-                size,
-                pixels,
-                gl_texture: None,
-            };
+                user_texture.size = size;
+                user_texture.pixels = pixels;
+                if let Some(gl_texture) = user_texture.gl_texture.take() {
+                    self.gl.delete_texture(Some(&gl_texture));
+                }
+            }
         }
-
-        egui::TextureId::User(index as u64)
     }
 
     fn free_user_texture(&mut self, id: egui::TextureId) {
@@ -151,6 +181,18 @@ impl WebGl2Painter {
         }
     }
 
+    /// How the texture was set up to be sampled. The egui texture is sampled linearly.
+    fn get_texture_options(&self, texture_id: egui::TextureId) -> TextureOptions {
+        match texture_id {
+            egui::TextureId::User(id) => self
+                .user_textures
+                .get(id as usize)
+                .and_then(|user_texture| user_texture.as_ref())
+                .map_or(TextureOptions::LINEAR, |user_texture| user_texture.options),
+            egui::TextureId::Egui => TextureOptions::LINEAR,
+        }
+    }
+
     fn upload_user_textures(&mut self) {
         let gl = &self.gl;
         for user_texture in self.user_textures.iter_mut().flatten() {
@@ -158,12 +200,6 @@ impl WebGl2Painter {
                 let pixels = std::mem::take(&mut user_texture.pixels);
 
                 let gl_texture = gl.create_texture().unwrap();
-                gl.bind_texture(Gl::TEXTURE_2D, Some(&gl_texture));
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, Gl::LINEAR as i32);
-                gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, Gl::LINEAR as i32);
-
                 gl.bind_texture(Gl::TEXTURE_2D, Some(&gl_texture));
 
                 let level = 0;
@@ -185,6 +221,8 @@ impl WebGl2Painter {
                 )
                 .unwrap();
 
+                set_texture_options(gl, user_texture.options);
+
                 user_texture.gl_texture = Some(gl_texture);
             }
         }
@@ -197,6 +235,7 @@ impl WebGl2Painter {
                 size: (0, 0),
                 pixels: vec![],
                 gl_texture: Some(texture),
+                options: Default::default(),
             }
         }
         egui::TextureId::User(id as u64)
@@ -324,12 +363,17 @@ impl WebGl2Painter {
 }
 
 impl epi::TextureAllocator for WebGl2Painter {
-    fn alloc_srgba_premultiplied(
+    fn alloc(
         &mut self,
         size: (usize, usize),
         srgba_pixels: &[egui::Color32],
+        options: TextureOptions,
     ) -> egui::TextureId {
-        self.alloc_user_texture(size, srgba_pixels)
+        self.alloc_user_texture(size, srgba_pixels, options)
+    }
+
+    fn update(&mut self, id: egui::TextureId, size: (usize, usize), srgba_pixels: &[Color32]) {
+        self.set_user_texture(id, size, srgba_pixels)
     }
 
     fn free(&mut self, id: egui::TextureId) {
@@ -405,12 +449,13 @@ impl crate::Painter for WebGl2Painter {
         let height = self.canvas.height() as i32;
         gl.viewport(0, 0, width, height);
 
-        let clear_color: Color32 = clear_color.into();
+        // We paint into an sRGB framebuffer, so the clear color is in linear space:
+        self.post_process.begin(width, height);
         gl.clear_color(
-            clear_color[0] as f32 / 255.0,
-            clear_color[1] as f32 / 255.0,
-            clear_color[2] as f32 / 255.0,
-            clear_color[3] as f32 / 255.0,
+            clear_color[0],
+            clear_color[1],
+            clear_color[2],
+            clear_color[3],
         );
         gl.clear(Gl::COLOR_BUFFER_BIT);
     }
@@ -470,9 +515,25 @@ impl crate::Painter for WebGl2Painter {
                     clip_max_y - clip_min_y,
                 );
 
+                // `Mesh::texture_filter` overrides the filter of the texture for this mesh only:
+                let restore = mesh.texture_filter.map(|filter| {
+                    let options = self.get_texture_options(mesh.texture_id);
+                    let overridden = TextureOptions {
+                        magnification: filter,
+                        minification: filter,
+                        ..options
+                    };
+                    set_texture_filters(gl, overridden);
+                    options
+                });
+
                 for mesh in mesh.split_to_u16() {
                     self.paint_mesh(&mesh)?;
                 }
+
+                if let Some(options) = restore {
+                    set_texture_filters(gl, options);
+                }
             } else {
                 crate::console_warn(format!(
                     "WebGL: Failed to find texture {:?}",
@@ -480,10 +541,214 @@ impl crate::Painter for WebGl2Painter {
                 ));
             }
         }
+
+        self.post_process.end();
+
         Ok(())
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// WebGL has no way of doing blending in linear space when painting directly to the canvas.
+///
+/// So we paint everything into an `SRGB8_ALPHA8` texture (where the blending is done in linear space),
+/// and then copy that texture to the canvas, converting it to gamma space.
+struct PostProcess {
+    gl: Gl,
+    pos_buffer: WebGlBuffer,
+    index_buffer: WebGlBuffer,
+    vertex_array: WebGlVertexArrayObject,
+    program: WebGlProgram,
+    texture: WebGlTexture,
+    texture_size: (i32, i32),
+    fbo: WebGlFramebuffer,
+}
+
+impl PostProcess {
+    fn new(gl: Gl, width: i32, height: i32) -> Result<PostProcess, JsValue> {
+        let fbo = gl
+            .create_framebuffer()
+            .ok_or("failed to create framebuffer")?;
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, Some(&fbo));
+
+        let texture = gl.create_texture().unwrap();
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&texture));
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, Gl::NEAREST as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, Gl::NEAREST as i32);
+        gl.pixel_storei(Gl::UNPACK_ALIGNMENT, 1);
+        gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+            Gl::TEXTURE_2D,
+            0,
+            Gl::SRGB8_ALPHA8 as i32,
+            width,
+            height,
+            0,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE,
+            None,
+        )
+        .unwrap();
+        gl.framebuffer_texture_2d(
+            Gl::FRAMEBUFFER,
+            Gl::COLOR_ATTACHMENT0,
+            Gl::TEXTURE_2D,
+            Some(&texture),
+            0,
+        );
+
+        gl.bind_texture(Gl::TEXTURE_2D, None);
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, None);
+
+        let vert_shader = compile_shader(
+            &gl,
+            Gl::VERTEX_SHADER,
+            include_str!("shader/post_vertex_100es.glsl"),
+        )?;
+        let frag_shader = compile_shader(
+            &gl,
+            Gl::FRAGMENT_SHADER,
+            include_str!("shader/post_fragment_100es.glsl"),
+        )?;
+        let program = link_program(&gl, [vert_shader, frag_shader].iter())?;
+
+        // A full-screen quad, kept in its own vertex array so we don't disturb the mesh painting:
+        let vertex_array = gl
+            .create_vertex_array()
+            .ok_or("failed to create vertex array")?;
+        gl.bind_vertex_array(Some(&vertex_array));
+
+        let positions = vec![0u8, 0, 1, 0, 0, 1, 1, 1];
+        let indices = vec![0u8, 1, 2, 1, 2, 3];
+
+        let pos_buffer = gl.create_buffer().ok_or("failed to create pos_buffer")?;
+        gl.bind_buffer(Gl::ARRAY_BUFFER, Some(&pos_buffer));
+        gl.buffer_data_with_u8_array(Gl::ARRAY_BUFFER, &positions, Gl::STATIC_DRAW);
+
+        let a_pos_loc = gl.get_attrib_location(&program, "a_pos");
+        assert!(a_pos_loc >= 0);
+        let a_pos_loc = a_pos_loc as u32;
+        gl.vertex_attrib_pointer_with_i32(a_pos_loc, 2, Gl::UNSIGNED_BYTE, false, 0, 0);
+        gl.enable_vertex_attrib_array(a_pos_loc);
+
+        let index_buffer = gl.create_buffer().ok_or("failed to create index_buffer")?;
+        gl.bind_buffer(Gl::ELEMENT_ARRAY_BUFFER, Some(&index_buffer));
+        gl.buffer_data_with_u8_array(Gl::ELEMENT_ARRAY_BUFFER, &indices, Gl::STATIC_DRAW);
+
+        gl.bind_vertex_array(None);
+        gl.bind_buffer(Gl::ARRAY_BUFFER, None);
+        gl.bind_buffer(Gl::ELEMENT_ARRAY_BUFFER, None);
+
+        Ok(PostProcess {
+            gl,
+            pos_buffer,
+            index_buffer,
+            vertex_array,
+            program,
+            texture,
+            texture_size: (width, height),
+            fbo,
+        })
+    }
+
+    /// Start painting into the sRGB texture, resizing it if needed.
+    fn begin(&mut self, width: i32, height: i32) {
+        let gl = &self.gl;
+
+        if (width, height) != self.texture_size {
+            gl.bind_texture(Gl::TEXTURE_2D, Some(&self.texture));
+            gl.pixel_storei(Gl::UNPACK_ALIGNMENT, 1);
+            gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                Gl::TEXTURE_2D,
+                0,
+                Gl::SRGB8_ALPHA8 as i32,
+                width,
+                height,
+                0,
+                Gl::RGBA,
+                Gl::UNSIGNED_BYTE,
+                None,
+            )
+            .unwrap();
+            gl.bind_texture(Gl::TEXTURE_2D, None);
+
+            self.texture_size = (width, height);
+        }
+
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, Some(&self.fbo));
+    }
+
+    /// Copy the sRGB texture to the canvas, converting it to gamma space.
+    fn end(&self) {
+        let gl = &self.gl;
+
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, None);
+        gl.disable(Gl::SCISSOR_TEST);
+        gl.disable(Gl::BLEND);
+
+        gl.use_program(Some(&self.program));
+
+        gl.active_texture(Gl::TEXTURE0);
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&self.texture));
+        let u_sampler_loc = gl.get_uniform_location(&self.program, "u_sampler").unwrap();
+        gl.uniform1i(Some(&u_sampler_loc), 0);
+
+        gl.bind_vertex_array(Some(&self.vertex_array));
+        gl.draw_elements_with_i32(Gl::TRIANGLES, 6, Gl::UNSIGNED_BYTE, 0);
+        gl.bind_vertex_array(None);
+
+        gl.bind_texture(Gl::TEXTURE_2D, None);
+        gl.use_program(None);
+    }
+}
+
+impl Drop for PostProcess {
+    fn drop(&mut self) {
+        let gl = &self.gl;
+        gl.delete_vertex_array(Some(&self.vertex_array));
+        gl.delete_buffer(Some(&self.pos_buffer));
+        gl.delete_buffer(Some(&self.index_buffer));
+        gl.delete_program(Some(&self.program));
+        gl.delete_framebuffer(Some(&self.fbo));
+        gl.delete_texture(Some(&self.texture));
+    }
+}
+
+/// Set the sampling parameters of the currently bound texture, generating mipmaps if requested.
+fn set_texture_options(gl: &Gl, options: TextureOptions) {
+    let wrap = match options.wrap_mode {
+        TextureWrapMode::ClampToEdge => Gl::CLAMP_TO_EDGE,
+        TextureWrapMode::Repeat => Gl::REPEAT,
+        TextureWrapMode::MirroredRepeat => Gl::MIRRORED_REPEAT,
+    };
+
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, wrap as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, wrap as i32);
+    set_texture_filters(gl, options);
+
+    if options.mipmaps {
+        gl.generate_mipmap(Gl::TEXTURE_2D);
+    }
+}
+
+/// Set the magnification and minification filters of the currently bound texture.
+fn set_texture_filters(gl: &Gl, options: TextureOptions) {
+    let mag_filter = match options.magnification {
+        TextureFilter::Nearest => Gl::NEAREST,
+        TextureFilter::Linear => Gl::LINEAR,
+    };
+    let min_filter = match (options.minification, options.mipmaps) {
+        (TextureFilter::Nearest, false) => Gl::NEAREST,
+        (TextureFilter::Linear, false) => Gl::LINEAR,
+        (TextureFilter::Nearest, true) => Gl::NEAREST_MIPMAP_NEAREST,
+        (TextureFilter::Linear, true) => Gl::LINEAR_MIPMAP_LINEAR,
+    };
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, min_filter as i32);
+    gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, mag_filter as i32);
+}
+
 fn compile_shader(
     gl: &WebGl2RenderingContext,
     shader_type: u32,
@@ -0,0 +1,157 @@
+//! An experimental painter using [WebGPU](https://gpuweb.github.io/gpuweb/) via [`egui_wgpu`].
+//!
+//! Few browsers support WebGPU yet, and `web-sys` only has bindings for it
+//! if you compile with `RUSTFLAGS=--cfg=web_sys_unstable_apis`.
+//! Use [`crate::start_webgpu`] to fall back to WebGL when WebGPU is not available.
+
+use wasm_bindgen::prelude::JsValue;
+
+pub struct WebGpuPainter {
+    canvas_id: String,
+    canvas: web_sys::HtmlCanvasElement,
+    adapter_info: wgpu::AdapterInfo,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    surface: wgpu::Surface,
+    surface_config: wgpu::SurfaceConfiguration,
+    painter: egui_wgpu::Painter,
+
+    /// Set by `clear`, and used by the next `paint_meshes`.
+    clear_color: Option<egui::Rgba>,
+}
+
+impl WebGpuPainter {
+    /// Fails if the browser does not support WebGPU.
+    ///
+    /// The canvas is left untouched until we know that WebGPU works,
+    /// so you can still paint into it with WebGL if this fails.
+    pub async fn new(canvas_id: &str) -> Result<WebGpuPainter, JsValue> {
+        let canvas = crate::canvas_element_or_die(canvas_id);
+
+        let has_webgpu = web_sys::window()
+            .and_then(|window| js_sys::Reflect::has(&window.navigator(), &"gpu".into()).ok())
+            .unwrap_or(false);
+        if !has_webgpu {
+            return Err("This browser does not support WebGPU".into());
+        }
+
+        let instance = wgpu::Instance::new(wgpu::Backends::BROWSER_WEBGPU);
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .ok_or_else(|| JsValue::from("Failed to find a WebGPU adapter"))?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .map_err(|err| JsValue::from(format!("Failed to create WebGPU device: {}", err)))?;
+
+        // SAFETY: `canvas` is a canvas element, and we keep it around for as long as the surface.
+        #[allow(unsafe_code)]
+        let surface = unsafe { instance.create_surface_from_canvas(&canvas) };
+        let format = surface
+            .get_preferred_format(&adapter)
+            .ok_or_else(|| JsValue::from("The canvas can't be painted by the WebGPU adapter"))?;
+        let surface_config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: canvas.width(),
+            height: canvas.height(),
+            present_mode: wgpu::PresentMode::Fifo,
+        };
+        surface.configure(&device, &surface_config);
+
+        let painter = egui_wgpu::Painter::new(&device, format, 1);
+
+        Ok(WebGpuPainter {
+            canvas_id: canvas_id.to_owned(),
+            canvas,
+            adapter_info: adapter.get_info(),
+            device,
+            queue,
+            surface,
+            surface_config,
+            painter,
+            clear_color: None,
+        })
+    }
+}
+
+impl crate::Painter for WebGpuPainter {
+    fn as_tex_allocator(&mut self) -> &mut dyn epi::TextureAllocator {
+        &mut self.painter
+    }
+
+    fn debug_info(&self) -> String {
+        format!(
+            "WebGPU adapter: {:?}\n\
+             Stored canvas size: {} x {}\n\
+             Surface size: {} x {}",
+            self.adapter_info.name,
+            self.canvas.width(),
+            self.canvas.height(),
+            self.surface_config.width,
+            self.surface_config.height,
+        )
+    }
+
+    /// id of the canvas html element containing the rendering
+    fn canvas_id(&self) -> &str {
+        &self.canvas_id
+    }
+
+    fn upload_egui_texture(&mut self, texture: &egui::Texture) {
+        self.painter
+            .upload_egui_texture(&self.device, &self.queue, texture);
+    }
+
+    fn clear(&mut self, clear_color: egui::Rgba) {
+        // WebGPU clears as part of the render pass:
+        self.clear_color = Some(clear_color);
+    }
+
+    fn paint_meshes(
+        &mut self,
+        clipped_meshes: Vec<egui::ClippedMesh>,
+        pixels_per_point: f32,
+    ) -> Result<(), JsValue> {
+        let (width, height) = (self.canvas.width(), self.canvas.height());
+        if width == 0 || height == 0 {
+            return Ok(()); // WebGPU can't paint into nothing.
+        }
+        if self.surface_config.width != width || self.surface_config.height != height {
+            self.surface_config.width = width;
+            self.surface_config.height = height;
+            self.surface.configure(&self.device, &self.surface_config);
+        }
+
+        let frame = self
+            .surface
+            .get_current_texture()
+            .map_err(|err| JsValue::from(format!("Failed to get WebGPU frame: {}", err)))?;
+        let view = frame
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("egui_web_encoder"),
+            });
+        self.painter.paint_meshes(
+            &self.device,
+            &self.queue,
+            &mut encoder,
+            &view,
+            &clipped_meshes,
+            &egui_wgpu::ScreenDescriptor {
+                size_in_pixels: [width, height],
+                pixels_per_point,
+            },
+            self.clear_color.take(),
+        );
+        self.queue.submit(std::iter::once(encoder.finish()));
+        frame.present();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+//! [`egui`] painter for [`wgpu`](https://github.com/gfx-rs/wgpu).
+//!
+//! The main type you want to use is [`Painter`].
+//!
+//! This crate only does the painting.
+//! You need to gather the input for egui yourself, e.g. from `winit`.
+
+// Forbid warnings in release builds:
+#![cfg_attr(not(debug_assertions), deny(warnings))]
+// Disabled so we can support rust 1.51:
+// #![deny(
+//     rustdoc::broken_intra_doc_links,
+//     rustdoc::invalid_codeblock_attributes,
+//     rustdoc::missing_crate_level_docs,
+//     rustdoc::private_intra_doc_links
+// )]
+#![forbid(unsafe_code)]
+#![warn(clippy::all, rust_2018_idioms)]
+#![allow(clippy::manual_range_contains)]
+
+mod painter;
+
+pub use painter::{Painter, ScreenDescriptor};
+
+pub use wgpu;
@@ -0,0 +1,672 @@
+use std::num::NonZeroU32;
+
+use egui::{
+    epaint::{Color32, Mesh, Vertex},
+    ClippedMesh, Rgba, TextureFilter, TextureId, TextureOptions, TextureWrapMode,
+};
+use wgpu::util::DeviceExt as _;
+
+/// Size of one [`Vertex`] on the GPU: position, uv and color.
+const VERTEX_SIZE: usize = 2 * 4 + 2 * 4 + 4;
+
+/// Information about the screen we are painting to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScreenDescriptor {
+    /// Size of the target texture, in physical pixels.
+    pub size_in_pixels: [u32; 2],
+
+    /// The same as [`egui::Context::pixels_per_point`].
+    pub pixels_per_point: f32,
+}
+
+impl ScreenDescriptor {
+    fn size_in_points(&self) -> [f32; 2] {
+        [
+            self.size_in_pixels[0] as f32 / self.pixels_per_point,
+            self.size_in_pixels[1] as f32 / self.pixels_per_point,
+        ]
+    }
+}
+
+/// Paints egui meshes using [`wgpu`].
+///
+/// User textures are allocated with the [`egui::epaint::TextureAllocator`] trait,
+/// or with [`Self::register_native_texture`].
+pub struct Painter {
+    pipeline: wgpu::RenderPipeline,
+    locals_buffer: wgpu::Buffer,
+    locals_bind_group: wgpu::BindGroup,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+
+    egui_texture: Option<TextureBindGroups>,
+    egui_texture_version: Option<u64>,
+
+    /// `None` means unallocated (freed) slot.
+    user_textures: Vec<Option<UserTexture>>,
+}
+
+#[derive(Default)]
+struct UserTexture {
+    size: (usize, usize),
+
+    /// Pending upload (will be emptied later).
+    pixels: Vec<u8>,
+
+    /// How to sample the texture.
+    options: TextureOptions,
+
+    /// Lazily uploaded
+    bind_groups: Option<TextureBindGroups>,
+}
+
+/// The sampler is part of the bind group, so each texture gets one bind group
+/// per way it can be sampled.
+struct TextureBindGroups {
+    /// Samples the texture as set up by its [`TextureOptions`].
+    default: wgpu::BindGroup,
+
+    /// For meshes with [`Mesh::texture_filter`] set to [`TextureFilter::Nearest`].
+    nearest: wgpu::BindGroup,
+
+    /// For meshes with [`Mesh::texture_filter`] set to [`TextureFilter::Linear`].
+    linear: wgpu::BindGroup,
+}
+
+impl TextureBindGroups {
+    fn get(&self, texture_filter: Option<TextureFilter>) -> &wgpu::BindGroup {
+        match texture_filter {
+            None => &self.default,
+            Some(TextureFilter::Nearest) => &self.nearest,
+            Some(TextureFilter::Linear) => &self.linear,
+        }
+    }
+}
+
+impl Painter {
+    /// Create a painter that paints into textures of the given format.
+    ///
+    /// If the format is an sRGB format (recommended) the blending is done in linear space.
+    /// `msaa_samples` must match the sample count of the target you paint into.
+    pub fn new(
+        device: &wgpu::Device,
+        output_format: wgpu::TextureFormat,
+        msaa_samples: u32,
+    ) -> Painter {
+        let shader = device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+            label: Some("egui_shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shader/egui.wgsl").into()),
+        });
+
+        let locals_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("egui_locals_buffer"),
+            contents: &locals_bytes([0.0, 0.0]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let locals_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("egui_locals_bind_group_layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+
+        let locals_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("egui_locals_bind_group"),
+            layout: &locals_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: locals_buffer.as_entire_binding(),
+            }],
+        });
+
+        let texture_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("egui_texture_bind_group_layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler {
+                            filtering: true,
+                            comparison: false,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("egui_pipeline_layout"),
+            bind_group_layouts: &[&locals_bind_group_layout, &texture_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // egui outputs colors with premultiplied alpha:
+        let color_blend = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        // Less important, but this is technically the correct alpha blend function
+        // when you want to make use of the framebuffer alpha (for screenshots, compositing, etc).
+        let alpha_blend = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::OneMinusDstAlpha,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("egui_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: VERTEX_SIZE as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    // pos, uv, color:
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Unorm8x4],
+                }],
+            },
+            // egui outputs mesh in both winding orders, so no backface culling:
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: msaa_samples,
+                ..Default::default()
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[wgpu::ColorTargetState {
+                    format: output_format,
+                    blend: Some(wgpu::BlendState {
+                        color: color_blend,
+                        alpha: alpha_blend,
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                }],
+            }),
+        });
+
+        Painter {
+            pipeline,
+            locals_buffer,
+            locals_bind_group,
+            texture_bind_group_layout,
+            egui_texture: None,
+            egui_texture_version: None,
+            user_textures: Default::default(),
+        }
+    }
+
+    /// Upload the egui font texture, if it has changed.
+    pub fn upload_egui_texture(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texture: &egui::Texture,
+    ) {
+        if self.egui_texture_version == Some(texture.version) {
+            return; // No change
+        }
+
+        let mut pixels: Vec<u8> = Vec::with_capacity(texture.pixels.len() * 4);
+        for srgba in texture.srgba_pixels() {
+            pixels.extend_from_slice(&srgba.to_array());
+        }
+
+        // The texture coordinates for text are so that both nearest and linear should work with the egui font texture.
+        self.egui_texture = Some(self.create_texture_bind_groups(
+            device,
+            queue,
+            (texture.width, texture.height),
+            pixels,
+            TextureOptions::LINEAR,
+        ));
+        self.egui_texture_version = Some(texture.version);
+    }
+
+    /// Paint the given meshes into `target`.
+    ///
+    /// If `clear_color` is `Some`, the target is cleared first,
+    /// otherwise egui is painted on top of what is already there.
+    #[allow(clippy::too_many_arguments)]
+    pub fn paint_meshes(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        clipped_meshes: &[ClippedMesh],
+        screen_descriptor: &ScreenDescriptor,
+        clear_color: Option<Rgba>,
+    ) {
+        self.upload_user_textures(device, queue);
+
+        queue.write_buffer(
+            &self.locals_buffer,
+            0,
+            &locals_bytes(screen_descriptor.size_in_points()),
+        );
+
+        // The buffers must outlive the render pass, so we create them all up front.
+        // TODO: we should probably reuse the buffers instead of allocating new ones each frame.
+        let buffers: Vec<_> = clipped_meshes
+            .iter()
+            .map(|ClippedMesh(_, mesh)| create_mesh_buffers(device, mesh))
+            .collect();
+
+        let load = match clear_color {
+            Some(color) => wgpu::LoadOp::Clear(wgpu::Color {
+                r: color.r() as f64,
+                g: color.g() as f64,
+                b: color.b() as f64,
+                a: color.a() as f64,
+            }),
+            None => wgpu::LoadOp::Load,
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("egui_render_pass"),
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations { load, store: true },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.locals_bind_group, &[]);
+
+        let [width_in_pixels, height_in_pixels] = screen_descriptor.size_in_pixels;
+        let pixels_per_point = screen_descriptor.pixels_per_point;
+
+        for (ClippedMesh(clip_rect, mesh), (vertex_buffer, index_buffer)) in
+            clipped_meshes.iter().zip(&buffers)
+        {
+            if mesh.indices.is_empty() {
+                continue;
+            }
+
+            // `Mesh::texture_filter` overrides the filter of the texture for this mesh only:
+            let bind_group = match self.get_bind_groups(mesh.texture_id) {
+                Some(bind_groups) => bind_groups.get(mesh.texture_filter),
+                None => continue,
+            };
+
+            // Transform clip rect to physical pixels:
+            let clip_min_x = pixels_per_point * clip_rect.min.x;
+            let clip_min_y = pixels_per_point * clip_rect.min.y;
+            let clip_max_x = pixels_per_point * clip_rect.max.x;
+            let clip_max_y = pixels_per_point * clip_rect.max.y;
+
+            // Make sure clip rect can fit within the target:
+            let clip_min_x = clip_min_x.clamp(0.0, width_in_pixels as f32);
+            let clip_min_y = clip_min_y.clamp(0.0, height_in_pixels as f32);
+            let clip_max_x = clip_max_x.clamp(clip_min_x, width_in_pixels as f32);
+            let clip_max_y = clip_max_y.clamp(clip_min_y, height_in_pixels as f32);
+
+            let clip_min_x = clip_min_x.round() as u32;
+            let clip_min_y = clip_min_y.round() as u32;
+            let clip_max_x = clip_max_x.round() as u32;
+            let clip_max_y = clip_max_y.round() as u32;
+
+            if clip_max_x <= clip_min_x || clip_max_y <= clip_min_y {
+                continue; // Nothing visible
+            }
+
+            // wgpu has the origin of the scissor rect in the top left corner:
+            render_pass.set_scissor_rect(
+                clip_min_x,
+                clip_min_y,
+                clip_max_x - clip_min_x,
+                clip_max_y - clip_min_y,
+            );
+            render_pass.set_bind_group(1, bind_group, &[]);
+            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+            render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            render_pass.draw_indexed(0..mesh.indices.len() as u32, 0, 0..1);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // user textures
+
+    fn alloc_user_texture_index(&mut self) -> usize {
+        for (index, tex) in self.user_textures.iter_mut().enumerate() {
+            if tex.is_none() {
+                *tex = Some(Default::default());
+                return index;
+            }
+        }
+        let index = self.user_textures.len();
+        self.user_textures.push(Some(Default::default()));
+        index
+    }
+
+    /// Replace the pixels of a user texture. They will be uploaded on the next [`Self::paint_meshes`].
+    pub fn set_user_texture(
+        &mut self,
+        id: TextureId,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+    ) {
+        assert_eq!(size.0 * size.1, srgba_pixels.len());
+
+        if let TextureId::User(id) = id {
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(id as usize) {
+                let mut pixels: Vec<u8> = Vec::with_capacity(srgba_pixels.len() * 4);
+                for srgba in srgba_pixels {
+                    pixels.extend_from_slice(&srgba.to_array());
+                }
+
+                user_texture.size = size;
+                user_texture.pixels = pixels;
+                user_texture.bind_groups = None;
+            }
+        }
+    }
+
+    pub fn free_user_texture(&mut self, id: TextureId) {
+        if let TextureId::User(id) = id {
+            let index = id as usize;
+            if index < self.user_textures.len() {
+                self.user_textures[index] = None;
+            }
+        }
+    }
+
+    /// Register a texture view you have created yourself (e.g. one you render a 3D scene into),
+    /// so you can show it with e.g. [`egui::Image`].
+    ///
+    /// The texture must have a filterable float format, e.g. `Rgba8UnormSrgb`.
+    /// [`TextureOptions::mipmaps`] is ignored: the view decides the mip levels.
+    pub fn register_native_texture(
+        &mut self,
+        device: &wgpu::Device,
+        texture_view: &wgpu::TextureView,
+        options: TextureOptions,
+    ) -> TextureId {
+        let bind_groups = self.create_bind_groups(device, texture_view, options);
+        let index = self.alloc_user_texture_index();
+        if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
+            *user_texture = UserTexture {
+                size: (0, 0),
+                pixels: vec![],
+                options,
+                bind_groups: Some(bind_groups),
+            };
+        }
+        TextureId::User(index as u64)
+    }
+
+    fn get_bind_groups(&self, texture_id: TextureId) -> Option<&TextureBindGroups> {
+        match texture_id {
+            TextureId::Egui => self.egui_texture.as_ref(),
+            TextureId::User(id) => self
+                .user_textures
+                .get(id as usize)?
+                .as_ref()?
+                .bind_groups
+                .as_ref(),
+        }
+    }
+
+    fn upload_user_textures(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let pending: Vec<_> = self
+            .user_textures
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, user_texture)| {
+                let user_texture = user_texture.as_mut()?;
+                // wgpu doesn't allow empty textures:
+                let has_pixels = user_texture.size.0 > 0 && user_texture.size.1 > 0;
+                if user_texture.bind_groups.is_none() && has_pixels {
+                    let pixels = std::mem::take(&mut user_texture.pixels);
+                    Some((index, user_texture.size, pixels, user_texture.options))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        for (index, size, pixels, options) in pending {
+            let bind_groups = self.create_texture_bind_groups(device, queue, size, pixels, options);
+            if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
+                user_texture.bind_groups = Some(bind_groups);
+            }
+        }
+    }
+
+    /// Create and upload an `Rgba8UnormSrgb` texture, and the bind groups to sample it with.
+    fn create_texture_bind_groups(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (usize, usize),
+        pixels: Vec<u8>,
+        options: TextureOptions,
+    ) -> TextureBindGroups {
+        let mut mip_levels = vec![((size.0 as u32, size.1 as u32), pixels)];
+        if options.mipmaps {
+            while let Some(((width, height), pixels)) = mip_levels.last() {
+                if *width <= 1 && *height <= 1 {
+                    break;
+                }
+                let next = downsample((*width, *height), pixels);
+                mip_levels.push(next);
+            }
+        }
+
+        let (width, height) = mip_levels[0].0;
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("egui_user_texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: mip_levels.len() as u32,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        });
+
+        for (mip_level, ((width, height), pixels)) in mip_levels.iter().enumerate() {
+            queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: mip_level as u32,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                pixels,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(4 * width),
+                    rows_per_image: NonZeroU32::new(*height),
+                },
+                wgpu::Extent3d {
+                    width: *width,
+                    height: *height,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        self.create_bind_groups(device, &view, options)
+    }
+
+    fn create_bind_groups(
+        &self,
+        device: &wgpu::Device,
+        view: &wgpu::TextureView,
+        options: TextureOptions,
+    ) -> TextureBindGroups {
+        let with_filter = |filter| TextureOptions {
+            magnification: filter,
+            minification: filter,
+            ..options
+        };
+        TextureBindGroups {
+            default: self.create_bind_group(device, view, options),
+            nearest: self.create_bind_group(device, view, with_filter(TextureFilter::Nearest)),
+            linear: self.create_bind_group(device, view, with_filter(TextureFilter::Linear)),
+        }
+    }
+
+    fn create_bind_group(
+        &self,
+        device: &wgpu::Device,
+        view: &wgpu::TextureView,
+        options: TextureOptions,
+    ) -> wgpu::BindGroup {
+        let address_mode = match options.wrap_mode {
+            TextureWrapMode::ClampToEdge => wgpu::AddressMode::ClampToEdge,
+            TextureWrapMode::Repeat => wgpu::AddressMode::Repeat,
+            TextureWrapMode::MirroredRepeat => wgpu::AddressMode::MirrorRepeat,
+        };
+        let filter_mode = |filter: TextureFilter| match filter {
+            TextureFilter::Nearest => wgpu::FilterMode::Nearest,
+            TextureFilter::Linear => wgpu::FilterMode::Linear,
+        };
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("egui_sampler"),
+            address_mode_u: address_mode,
+            address_mode_v: address_mode,
+            address_mode_w: address_mode,
+            mag_filter: filter_mode(options.magnification),
+            min_filter: filter_mode(options.minification),
+            mipmap_filter: filter_mode(options.minification),
+            ..Default::default()
+        });
+
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("egui_texture_bind_group"),
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        })
+    }
+}
+
+impl egui::epaint::TextureAllocator for Painter {
+    fn alloc(
+        &mut self,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+        options: TextureOptions,
+    ) -> TextureId {
+        let index = self.alloc_user_texture_index();
+        if let Some(Some(user_texture)) = self.user_textures.get_mut(index) {
+            user_texture.options = options;
+        }
+        let id = TextureId::User(index as u64);
+        self.set_user_texture(id, size, srgba_pixels);
+        id
+    }
+
+    fn update(&mut self, id: TextureId, size: (usize, usize), srgba_pixels: &[Color32]) {
+        self.set_user_texture(id, size, srgba_pixels)
+    }
+
+    fn free(&mut self, id: TextureId) {
+        self.free_user_texture(id)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The contents of the uniform buffer.
+fn locals_bytes(screen_size_in_points: [f32; 2]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16);
+    bytes.extend_from_slice(&screen_size_in_points[0].to_ne_bytes());
+    bytes.extend_from_slice(&screen_size_in_points[1].to_ne_bytes());
+    bytes.extend_from_slice(&[0; 8]); // padding
+    bytes
+}
+
+fn create_mesh_buffers(device: &wgpu::Device, mesh: &Mesh) -> (wgpu::Buffer, wgpu::Buffer) {
+    debug_assert!(mesh.is_valid());
+
+    let mut vertices: Vec<u8> = Vec::with_capacity(mesh.vertices.len() * VERTEX_SIZE);
+    for Vertex { pos, uv, color } in &mesh.vertices {
+        vertices.extend_from_slice(&pos.x.to_ne_bytes());
+        vertices.extend_from_slice(&pos.y.to_ne_bytes());
+        vertices.extend_from_slice(&uv.x.to_ne_bytes());
+        vertices.extend_from_slice(&uv.y.to_ne_bytes());
+        vertices.extend_from_slice(&color.to_array());
+    }
+
+    let mut indices: Vec<u8> = Vec::with_capacity(mesh.indices.len() * 4);
+    for index in &mesh.indices {
+        indices.extend_from_slice(&index.to_ne_bytes());
+    }
+
+    let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("egui_vertex_buffer"),
+        contents: &vertices,
+        usage: wgpu::BufferUsages::VERTEX,
+    });
+    let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("egui_index_buffer"),
+        contents: &indices,
+        usage: wgpu::BufferUsages::INDEX,
+    });
+    (vertex_buffer, index_buffer)
+}
+
+/// Halve the size of an sRGBA image with a box filter, for generating mipmaps.
+///
+/// The averaging is done in gamma space, which is fast but not quite correct.
+fn downsample((width, height): (u32, u32), pixels: &[u8]) -> ((u32, u32), Vec<u8>) {
+    let new_width = (width / 2).max(1);
+    let new_height = (height / 2).max(1);
+    let mut new_pixels = Vec::with_capacity((new_width * new_height * 4) as usize);
+    for y in 0..new_height {
+        for x in 0..new_width {
+            for channel in 0..4 {
+                let mut sum = 0_u32;
+                for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)].iter() {
+                    let sx = (2 * x + dx).min(width - 1);
+                    let sy = (2 * y + dy).min(height - 1);
+                    sum += pixels[((sy * width + sx) * 4 + channel) as usize] as u32;
+                }
+                new_pixels.push(((sum + 2) / 4) as u8);
+            }
+        }
+    }
+    ((new_width, new_height), new_pixels)
+}
@@ -0,0 +1,229 @@
+use crate::*;
+
+// The transform is the matrix:
+//
+// | x_axis.x  y_axis.x  translation.x |
+// | x_axis.y  y_axis.y  translation.y |
+// |    0         0            1       |
+//
+/// A 2D affine transform: a linear part (rotation, scale, shear) followed by a translation.
+///
+/// Use this when a [`RectTransform`] is not enough, e.g. for rotating
+/// or for zooming around a point on a canvas.
+///
+/// Transforms are combined with `*`, where `a * b` means "first apply `b`, then `a`":
+///
+/// ```
+/// # use emath::*;
+/// let zoom = Affine2::zoom_around(pos2(10.0, 10.0), 2.0);
+/// let pan = Affine2::from_translation(vec2(5.0, 0.0));
+/// let transform = pan * zoom;
+/// assert_eq!(transform * pos2(10.0, 10.0), pos2(15.0, 10.0));
+/// assert_eq!(transform.inverse() * pos2(15.0, 10.0), pos2(10.0, 10.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Affine2 {
+    /// Where the X axis ends up (before translation).
+    pub x_axis: Vec2,
+
+    /// Where the Y axis ends up (before translation).
+    pub y_axis: Vec2,
+
+    /// Added after the linear part.
+    pub translation: Vec2,
+}
+
+/// Identity transform
+impl Default for Affine2 {
+    /// Identity transform
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    /// The identity transform: nothing moves
+    pub const IDENTITY: Self = Self {
+        x_axis: Vec2::X,
+        y_axis: Vec2::Y,
+        translation: Vec2::ZERO,
+    };
+
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Uniform scaling around the origin.
+    pub fn from_scale(scale: f32) -> Self {
+        Self::from_scale_vec(Vec2::splat(scale))
+    }
+
+    /// Scale `x` and `y` separately, around the origin.
+    pub fn from_scale_vec(scale: Vec2) -> Self {
+        Self {
+            x_axis: vec2(scale.x, 0.0),
+            y_axis: vec2(0.0, scale.y),
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// Rotation (and maybe scaling) around the origin.
+    pub fn from_rotation(rot: Rot2) -> Self {
+        Self {
+            x_axis: rot * Vec2::X,
+            y_axis: rot * Vec2::Y,
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// Scale by `zoom` while keeping `pos` fixed.
+    pub fn zoom_around(pos: Pos2, zoom: f32) -> Self {
+        Self::from_translation(pos.to_vec2())
+            * Self::from_scale(zoom)
+            * Self::from_translation(-pos.to_vec2())
+    }
+
+    /// Rotate by `rot` around `pos`.
+    pub fn rotate_around(pos: Pos2, rot: Rot2) -> Self {
+        Self::from_translation(pos.to_vec2())
+            * Self::from_rotation(rot)
+            * Self::from_translation(-pos.to_vec2())
+    }
+
+    /// The determinant of the linear part.
+    ///
+    /// If this is zero, the transform is not invertible.
+    /// If it is negative, the transform mirrors.
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.translation.is_finite()
+    }
+
+    /// The transform that undoes this one.
+    ///
+    /// Will contain NaN or infinities if the transform is not invertible
+    /// (see [`Self::determinant`]).
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let det = self.determinant();
+        let x_axis = vec2(self.y_axis.y, -self.x_axis.y) / det;
+        let y_axis = vec2(-self.y_axis.x, self.x_axis.x) / det;
+        let linear = Self {
+            x_axis,
+            y_axis,
+            translation: Vec2::ZERO,
+        };
+        Self {
+            translation: -(linear * self.translation),
+            ..linear
+        }
+    }
+
+    /// Transforms the given vector, ignoring the translation.
+    pub fn transform_vec(&self, v: Vec2) -> Vec2 {
+        v.x * self.x_axis + v.y * self.y_axis
+    }
+
+    pub fn transform_pos(&self, pos: Pos2) -> Pos2 {
+        Pos2::ZERO + self.transform_vec(pos.to_vec2()) + self.translation
+    }
+
+    /// The smallest [`Rect`] that contains the transformed corners of `rect`.
+    ///
+    /// This is exact unless the transform rotates or shears.
+    pub fn transform_rect(&self, rect: Rect) -> Rect {
+        let mut result = Rect::NOTHING;
+        for &corner in &[
+            rect.left_top(),
+            rect.right_top(),
+            rect.left_bottom(),
+            rect.right_bottom(),
+        ] {
+            result.extend_with(self.transform_pos(corner));
+        }
+        result
+    }
+}
+
+impl From<RectTransform> for Affine2 {
+    fn from(rect_transform: RectTransform) -> Self {
+        let scale = rect_transform.scale();
+        let from = rect_transform.from().min;
+        let to = rect_transform.to().min;
+        Self::from_translation(to.to_vec2())
+            * Self::from_scale_vec(scale)
+            * Self::from_translation(-from.to_vec2())
+    }
+}
+
+/// Compose two transforms: `a * b` first applies `b`, then `a`.
+impl std::ops::Mul<Affine2> for Affine2 {
+    type Output = Affine2;
+    // Composing affine transforms adds their (transformed) translations:
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, b: Affine2) -> Affine2 {
+        Affine2 {
+            x_axis: self.transform_vec(b.x_axis),
+            y_axis: self.transform_vec(b.y_axis),
+            translation: self.transform_vec(b.translation) + self.translation,
+        }
+    }
+}
+
+/// Transforms the position.
+impl std::ops::Mul<Pos2> for Affine2 {
+    type Output = Pos2;
+    fn mul(self, pos: Pos2) -> Pos2 {
+        self.transform_pos(pos)
+    }
+}
+
+/// Transforms the vector, ignoring the translation.
+impl std::ops::Mul<Vec2> for Affine2 {
+    type Output = Vec2;
+    fn mul(self, v: Vec2) -> Vec2 {
+        self.transform_vec(v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(a: Pos2, b: Pos2) {
+        assert!((a - b).length() < 1e-4, "Expected {:?} to equal {:?}", a, b);
+    }
+
+    #[test]
+    fn test_affine2() {
+        let transform = Affine2::from_translation(vec2(3.0, -2.0))
+            * Affine2::rotate_around(pos2(1.0, 1.0), Rot2::from_angle(0.7))
+            * Affine2::from_scale_vec(vec2(2.0, 0.5));
+
+        for &p in &[pos2(0.0, 0.0), pos2(1.0, 2.0), pos2(-5.0, 3.5)] {
+            assert_close(transform.inverse() * (transform * p), p);
+            assert_close(transform * (transform.inverse() * p), p);
+        }
+
+        let rect_transform = RectTransform::from_to(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(2.0, 4.0)),
+            Rect::from_min_max(pos2(10.0, 10.0), pos2(20.0, 30.0)),
+        );
+        let affine = Affine2::from(rect_transform);
+        for &p in &[pos2(0.0, 0.0), pos2(1.0, 2.0), pos2(2.0, 4.0)] {
+            assert_close(affine * p, rect_transform * p);
+        }
+
+        let rotated = Affine2::from_rotation(Rot2::from_angle(std::f32::consts::TAU / 4.0));
+        let rect = rotated.transform_rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(2.0, 1.0)));
+        assert_close(rect.min, pos2(-1.0, 0.0));
+        assert_close(rect.max, pos2(0.0, 2.0));
+    }
+}
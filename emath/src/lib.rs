@@ -92,6 +92,7 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 
 // ----------------------------------------------------------------------------
 
+mod affine2;
 pub mod align;
 mod numeric;
 mod pos2;
@@ -102,6 +103,7 @@ pub mod smart_aim;
 mod vec2;
 
 pub use {
+    affine2::Affine2,
     align::{Align, Align2},
     numeric::*,
     pos2::*,
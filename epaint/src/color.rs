@@ -3,6 +3,8 @@
 //! If you want a compact color representation, use [`Color32`].
 //! If you want to manipulate RGBA colors use [`Rgba`].
 //! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`].
+//! For perceptually even blends, lightness changes and generated palettes, use [`Oklab`] and the helpers
+//! [`lerp_oklab`], [`lighten`], [`darken`], [`contrast_ratio`] and [`categorical_palette`].
 
 #![allow(clippy::wrong_self_convention)]
 
@@ -255,6 +257,12 @@ impl Rgba {
         ])
     }
 
+    /// Linear interpolation in linear space: `t = 0` gives `self`, `t = 1` gives `other`.
+    #[inline(always)]
+    pub fn lerp(self, other: Rgba, t: f32) -> Self {
+        self * (1.0 - t) + other * t
+    }
+
     #[inline(always)]
     pub fn r(&self) -> f32 {
         self.0[0]
@@ -751,6 +759,188 @@ impl From<Hsva> for HsvaGamma {
 
 // ----------------------------------------------------------------------------
 
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) color space,
+/// where distances roughly match how different colors look.
+///
+/// `l` is the perceived lightness (0-1), `a` goes from green to red and `b` from blue to yellow.
+/// `alpha` is linear and not premultiplied.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl Oklab {
+    /// From lightness, chroma (colorfulness, roughly 0-0.3) and hue (radians).
+    pub fn from_lch(l: f32, chroma: f32, hue: f32) -> Self {
+        Self {
+            l,
+            a: chroma * hue.cos(),
+            b: chroma * hue.sin(),
+            alpha: 1.0,
+        }
+    }
+
+    /// Perceptual interpolation: `t = 0` gives `self`, `t = 1` gives `other`.
+    pub fn lerp(self, other: Oklab, t: f32) -> Self {
+        let lerp = |a: f32, b: f32| a + t * (b - a);
+        Self {
+            l: lerp(self.l, other.l),
+            a: lerp(self.a, other.a),
+            b: lerp(self.b, other.b),
+            alpha: lerp(self.alpha, other.alpha),
+        }
+    }
+}
+
+#[allow(clippy::excessive_precision)]
+impl From<Rgba> for Oklab {
+    fn from(rgba: Rgba) -> Oklab {
+        let alpha = rgba.a();
+        let [r, g, b] = if alpha > 0.0 {
+            [rgba.r() / alpha, rgba.g() / alpha, rgba.b() / alpha]
+        } else {
+            [0.0, 0.0, 0.0]
+        };
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        Oklab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        }
+    }
+}
+
+#[allow(clippy::excessive_precision)]
+impl From<Oklab> for Rgba {
+    /// Colors outside of the sRGB gamut are clamped.
+    fn from(oklab: Oklab) -> Rgba {
+        let Oklab { l, a, b, alpha } = oklab;
+
+        let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+        let r = 4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_;
+        let g = -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_;
+        let b = -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_;
+
+        let alpha = alpha.clamp(0.0, 1.0);
+        Rgba::from_rgba_premultiplied(
+            r.clamp(0.0, 1.0) * alpha,
+            g.clamp(0.0, 1.0) * alpha,
+            b.clamp(0.0, 1.0) * alpha,
+            alpha,
+        )
+    }
+}
+
+impl From<Color32> for Oklab {
+    fn from(srgba: Color32) -> Oklab {
+        Rgba::from(srgba).into()
+    }
+}
+
+impl From<Oklab> for Color32 {
+    fn from(oklab: Oklab) -> Color32 {
+        Rgba::from(oklab).into()
+    }
+}
+
+/// Blend two colors in linear space: `t = 0` gives `a`, `t = 1` gives `b`.
+///
+/// This is physically correct (it is how light mixes), but midpoints can look too bright.
+pub fn lerp_linear(a: Color32, b: Color32, t: f32) -> Color32 {
+    Rgba::from(a).lerp(Rgba::from(b), t).into()
+}
+
+/// Blend two colors in [`Oklab`] space: `t = 0` gives `a`, `t = 1` gives `b`.
+///
+/// The steps in between look evenly spaced, which makes this good for gradients and color maps.
+pub fn lerp_oklab(a: Color32, b: Color32, t: f32) -> Color32 {
+    Oklab::from(a).lerp(Oklab::from(b), t).into()
+}
+
+/// Make a color lighter by increasing its perceived lightness by `amount` (0-1), keeping its hue.
+pub fn lighten(color: Color32, amount: f32) -> Color32 {
+    let mut oklab = Oklab::from(color);
+    oklab.l = (oklab.l + amount).clamp(0.0, 1.0);
+    oklab.into()
+}
+
+/// Make a color darker by decreasing its perceived lightness by `amount` (0-1), keeping its hue.
+pub fn darken(color: Color32, amount: f32) -> Color32 {
+    lighten(color, -amount)
+}
+
+/// The relative luminance of a color, as defined by
+/// [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+/// 0 for black, 1 for white. Alpha is ignored.
+pub fn relative_luminance(color: Color32) -> f32 {
+    let rgba = Rgba::from(color.to_opaque());
+    0.2126 * rgba.r() + 0.7152 * rgba.g() + 0.0722 * rgba.b()
+}
+
+/// The [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between two colors,
+/// from 1 (no contrast) to 21 (black on white).
+///
+/// Text should have a contrast ratio of at least 4.5 to its background.
+pub fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The `index`:th color of a categorical palette, e.g. for lines in a plot.
+///
+/// The colors have the same lightness and colorfulness,
+/// and consecutive colors have very different hues.
+pub fn categorical_color(index: usize) -> Color32 {
+    let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+    let hue = (index as f32 * golden_ratio).fract() * std::f32::consts::TAU;
+    Oklab::from_lch(0.7, 0.15, hue).into()
+}
+
+/// The first `n` colors from [`categorical_color`].
+pub fn categorical_palette(n: usize) -> Vec<Color32> {
+    (0..n).map(categorical_color).collect()
+}
+
+#[test]
+fn test_oklab_roundtrip() {
+    for &color in &[
+        Color32::WHITE,
+        Color32::BLACK,
+        Color32::RED,
+        Color32::from_rgb(12, 120, 240),
+        Color32::from_rgba_unmultiplied(200, 100, 50, 128),
+    ] {
+        let roundtrip = Color32::from(Oklab::from(color));
+        for i in 0..4 {
+            assert!(
+                (color[i] as i32 - roundtrip[i] as i32).abs() <= 1,
+                "{:?} became {:?}",
+                color,
+                roundtrip
+            );
+        }
+    }
+}
+
+#[test]
+fn test_contrast_ratio() {
+    assert!((contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(Color32::RED, Color32::RED) - 1.0).abs() < 1e-6);
+}
+
+// ----------------------------------------------------------------------------
+
 /// Cheap and ugly.
 /// Made for graying out disabled `Ui`:s.
 pub fn tint_color_towards(color: Color32, target: Color32) -> Color32 {
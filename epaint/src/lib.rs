@@ -1,4 +1,25 @@
 //! 2D graphics/rendering. Fonts, textures, color, geometry, tessellation etc.
+//!
+//! ## Serializing the paint output
+//! With the `persistence` feature, the paint output of a frame ([`ClippedShape`], [`ClippedMesh`])
+//! and the font [`Texture`] can be serialized with [`serde`](https://serde.rs/),
+//! e.g. to run egui on one machine and paint it on another.
+//!
+//! The data layout is:
+//! * All positions and clip rectangles are in points (logical pixels), with (0, 0) in the top left corner.
+//!   Multiply by `pixels_per_point` to get physical pixels.
+//! * [`Mesh::indices`] are triangles (three indices per triangle) into [`Mesh::vertices`].
+//!   Triangles can have either winding order.
+//! * [`Vertex::uv`] is normalized: (0, 0) is the top left and (1, 1) is the bottom right of the texture.
+//! * [`Color32`] is sRGBA (gamma space) with premultiplied alpha.
+//!   Vertex colors should be converted to linear space before they are interpolated,
+//!   and blending should happen in linear space.
+//!   If you can't do that, see [`TessellationOptions::subdivide_gradients`].
+//! * [`Texture::pixels`] are the alpha (coverage) values of a white texture, row by row, top to bottom.
+//!   Check [`Texture::version`] to only send the texture when it has changed.
+//! * [`TextureId::User`] refers to textures owned by the integration, which are not part of the data.
+//!
+//! Changes to this layout are breaking changes and will be noted in the changelog.
 
 // Forbid warnings in release builds:
 #![cfg_attr(not(debug_assertions), deny(warnings))]
@@ -82,6 +103,9 @@
 pub mod color;
 mod mesh;
 pub mod mutex;
+mod nine_slice;
+#[cfg(feature = "software_rasterizer")]
+pub mod rasterizer;
 mod shadow;
 mod shape;
 pub mod shape_transform;
@@ -90,17 +114,20 @@ mod stroke;
 pub mod tessellator;
 pub mod text;
 mod texture_atlas;
+pub mod textures;
 
 pub use {
     color::{Color32, Rgba},
     mesh::{Mesh, Mesh16, Vertex},
+    nine_slice::{Insets, NineSlice},
     shadow::Shadow,
     shape::Shape,
     stats::PaintStats,
     stroke::Stroke,
-    tessellator::{TessellationOptions, Tessellator},
+    tessellator::{TessellationJob, TessellationOptions, Tessellator},
     text::{Galley, TextStyle},
     texture_atlas::{Texture, TextureAtlas},
+    textures::{TextureAllocator, TextureFilter, TextureOptions, TextureWrapMode},
 };
 
 pub use ahash;
@@ -114,6 +141,7 @@ pub const WHITE_UV: emath::Pos2 = emath::pos2(0.0, 0.0);
 
 /// What texture to use in a [`Mesh`] mesh.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub enum TextureId {
     /// The egui font texture.
     /// If you don't want to use a texture, pick this and the [`WHITE_UV`] for uv-coord.
@@ -142,6 +170,7 @@ pub(crate) struct PaintRect {
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedShape(
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Shape`] that falls within this.
@@ -154,6 +183,7 @@ pub struct ClippedShape(
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedMesh(
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Mesh`] that falls within this.
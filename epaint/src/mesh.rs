@@ -6,6 +6,7 @@ use emath::*;
 /// Should be friendly to send to GPU as is.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Vertex {
     /// Logical pixel coordinates (points).
     /// (0,0) is the top left corner of the screen.
@@ -22,6 +23,7 @@ pub struct Vertex {
 
 /// Textured triangles in two dimensions.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Mesh {
     /// Draw as triangles (i.e. the length is always multiple of three).
     ///
@@ -35,6 +37,13 @@ pub struct Mesh {
 
     /// The texture to use when drawing these triangles.
     pub texture_id: TextureId,
+
+    /// If set, the texture is sampled with this filter (both when magnified and minified)
+    /// instead of the [`TextureOptions`] it was allocated with.
+    ///
+    /// Use [`TextureFilter::Nearest`] to keep pixel art and zoomed-in data crisp.
+    /// Not all integrations support this.
+    pub texture_filter: Option<TextureFilter>,
 }
 
 impl Mesh {
@@ -72,6 +81,15 @@ impl Mesh {
         self.indices.is_empty() && self.vertices.is_empty()
     }
 
+    /// The smallest rectangle containing all the vertices.
+    pub fn calc_bounds(&self) -> Rect {
+        let mut bounds = Rect::NOTHING;
+        for v in &self.vertices {
+            bounds.extend_with(v.pos);
+        }
+        bounds
+    }
+
     /// Append all the indices and vertices of `other` to `self`.
     pub fn append(&mut self, other: Mesh) {
         crate::epaint_assert!(other.is_valid());
@@ -83,6 +101,10 @@ impl Mesh {
                 self.texture_id, other.texture_id,
                 "Can't merge Mesh using different textures"
             );
+            assert_eq!(
+                self.texture_filter, other.texture_filter,
+                "Can't merge Mesh using different texture filters"
+            );
 
             let index_offset = self.vertices.len() as u32;
             for index in &other.indices {
@@ -226,6 +248,89 @@ impl Mesh {
         output
     }
 
+    /// Make the vertex colors look as if they were interpolated in linear space,
+    /// even when painted by an integration that interpolates them in gamma (sRGB) space.
+    ///
+    /// Edges along which the color (or alpha) changes by more than a few steps are split in half,
+    /// with the color of the new vertex computed in linear space, up to `max_splits` times.
+    /// Edges shared by two triangles are split the same way for both, so no cracks appear.
+    ///
+    /// This is used by [`crate::TessellationOptions::subdivide_gradients`].
+    pub fn subdivide_gradients(&mut self, max_splits: usize) {
+        for _ in 0..max_splits {
+            if !self.subdivide_gradients_once() {
+                break;
+            }
+        }
+    }
+
+    /// Returns `false` if nothing needed splitting.
+    fn subdivide_gradients_once(&mut self) -> bool {
+        /// How much any channel (in gamma space) may change along an edge before we split it.
+        const MAX_COLOR_STEP: i32 = 32;
+
+        let vertices = &mut self.vertices;
+        let mut midpoints: ahash::AHashMap<(u32, u32), Option<u32>> = Default::default();
+        let mut midpoint = |a: u32, b: u32| -> Option<u32> {
+            let key = (a.min(b), a.max(b));
+            *midpoints.entry(key).or_insert_with(|| {
+                let (va, vb) = (vertices[a as usize], vertices[b as usize]);
+                let step = (0..4)
+                    .map(|i| (i32::from(va.color[i]) - i32::from(vb.color[i])).abs())
+                    .max()
+                    .unwrap_or_default();
+                if step <= MAX_COLOR_STEP {
+                    return None;
+                }
+                vertices.push(Vertex {
+                    pos: va.pos + 0.5 * (vb.pos - va.pos),
+                    uv: va.uv + 0.5 * (vb.uv - va.uv),
+                    color: crate::color::lerp_linear(va.color, vb.color, 0.5),
+                });
+                Some(vertices.len() as u32 - 1)
+            })
+        };
+
+        let old_indices = std::mem::take(&mut self.indices);
+        let mut indices = Vec::with_capacity(old_indices.len());
+        for triangle in old_indices.chunks_exact(3) {
+            let v = [triangle[0], triangle[1], triangle[2]];
+            // m[i] is the midpoint between v[i] and v[i + 1]:
+            let m = [
+                midpoint(v[0], v[1]),
+                midpoint(v[1], v[2]),
+                midpoint(v[2], v[0]),
+            ];
+
+            match m.iter().filter(|m| m.is_some()).count() {
+                0 => indices.extend_from_slice(&v),
+                1 => {
+                    // Rotate so that the split edge is v[0]-v[1]:
+                    let r = m.iter().position(Option::is_some).unwrap();
+                    let (a, b, c) = (v[r], v[(r + 1) % 3], v[(r + 2) % 3]);
+                    let ab = m[r].unwrap();
+                    indices.extend_from_slice(&[a, ab, c, ab, b, c]);
+                }
+                2 => {
+                    // Rotate so that the edge that is not split is v[2]-v[0]:
+                    let r = (m.iter().position(Option::is_none).unwrap() + 1) % 3;
+                    let (a, b, c) = (v[r], v[(r + 1) % 3], v[(r + 2) % 3]);
+                    let (ab, bc) = (m[r].unwrap(), m[(r + 1) % 3].unwrap());
+                    indices.extend_from_slice(&[ab, b, bc, a, ab, bc, a, bc, c]);
+                }
+                _ => {
+                    let (a, b, c) = (v[0], v[1], v[2]);
+                    let (ab, bc, ca) = (m[0].unwrap(), m[1].unwrap(), m[2].unwrap());
+                    indices.extend_from_slice(&[a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca]);
+                }
+            }
+        }
+
+        let any_split = indices.len() != old_indices.len();
+        self.indices = indices;
+        any_split
+    }
+
     /// Translate location by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         for v in &mut self.vertices {
@@ -263,3 +368,42 @@ impl Mesh16 {
         }
     }
 }
+
+#[test]
+fn test_subdivide_gradients() {
+    let mut mesh = Mesh::default();
+    mesh.add_rect_with_uv(
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0)),
+        Rect::from_min_max(WHITE_UV, WHITE_UV),
+        Color32::BLACK,
+    );
+    // Make it a horizontal gradient from black to white:
+    mesh.vertices[1].color = Color32::WHITE;
+    mesh.vertices[3].color = Color32::WHITE;
+
+    mesh.subdivide_gradients(1);
+    assert!(mesh.is_valid());
+    // The diagonal is shared by both triangles, so we get three new vertices, not four:
+    assert_eq!(mesh.vertices.len(), 4 + 3);
+    assert_eq!(mesh.indices.len(), 3 * 6);
+
+    let top_middle = mesh
+        .vertices
+        .iter()
+        .find(|v| v.pos == pos2(5.0, 0.0))
+        .unwrap();
+    assert_eq!(
+        top_middle.color,
+        crate::color::lerp_linear(Color32::BLACK, Color32::WHITE, 0.5)
+    );
+
+    // A solid color is left alone:
+    let mut solid = Mesh::default();
+    solid.add_colored_rect(
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0)),
+        Color32::RED,
+    );
+    let before = solid.clone();
+    solid.subdivide_gradients(2);
+    assert_eq!(solid, before);
+}
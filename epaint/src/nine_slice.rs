@@ -0,0 +1,228 @@
+use crate::*;
+use emath::*;
+
+/// Distances in from the left, right, top and bottom edges of a rectangle.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct Insets {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl Insets {
+    /// The same distance in from all four edges.
+    pub fn same(inset: f32) -> Self {
+        Self {
+            left: inset,
+            right: inset,
+            top: inset,
+            bottom: inset,
+        }
+    }
+
+    /// `x` in from the left and right edges, `y` in from the top and bottom edges.
+    pub fn symmetric(x: f32, y: f32) -> Self {
+        Self {
+            left: x,
+            right: x,
+            top: y,
+            bottom: y,
+        }
+    }
+
+    /// `left + right`
+    pub fn sum_x(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// `top + bottom`
+    pub fn sum_y(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+/// An image that can be scaled without stretching its borders (also known as a 9-patch).
+///
+/// The image is cut into a 3x3 grid by the borders.
+/// The four corners keep their size, the four edges are only stretched along the edge,
+/// and the center is stretched in both directions.
+/// This is useful for skinning panels and buttons with artwork.
+///
+/// If `rect` is too small to fit the borders, they are shrunk to fit.
+///
+/// ```
+/// # use epaint::{emath::*, *};
+/// let texture_id = TextureId::User(0);
+/// let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+/// // The texture is 64x64 texels with a border of 16 texels, which we paint 8 points wide:
+/// let panel = NineSlice::new(
+///     Rect::from_min_size(pos2(10.0, 10.0), vec2(200.0, 100.0)),
+///     texture_id,
+///     uv,
+///     Insets::same(16.0 / 64.0),
+///     Insets::same(8.0),
+/// );
+/// let shape = Shape::NineSlice(panel);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct NineSlice {
+    /// Where to paint the image.
+    pub rect: Rect,
+
+    pub texture_id: TextureId,
+
+    /// The part of the texture to use, in normalized texture coordinates.
+    pub uv: Rect,
+
+    /// The size of the borders in the texture, in normalized texture coordinates.
+    pub uv_border: Insets,
+
+    /// The size of the borders on screen, in points.
+    pub border: Insets,
+
+    /// Multiply the texture colors with this. Default: [`Color32::WHITE`] (no tint).
+    pub tint: Color32,
+
+    /// See [`Mesh::texture_filter`]. Default: `None`.
+    pub texture_filter: Option<TextureFilter>,
+}
+
+impl NineSlice {
+    pub fn new(
+        rect: Rect,
+        texture_id: TextureId,
+        uv: Rect,
+        uv_border: Insets,
+        border: Insets,
+    ) -> Self {
+        Self {
+            rect,
+            texture_id,
+            uv,
+            uv_border,
+            border,
+            tint: Color32::WHITE,
+            texture_filter: None,
+        }
+    }
+
+    /// Multiply the texture colors with this.
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Sample the texture with this filter, see [`Mesh::texture_filter`].
+    pub fn texture_filter(mut self, texture_filter: TextureFilter) -> Self {
+        self.texture_filter = Some(texture_filter);
+        self
+    }
+
+    /// The nine quads of the image, skipping the ones with no area.
+    pub fn to_mesh(&self) -> Mesh {
+        let Self {
+            rect,
+            texture_id,
+            uv,
+            uv_border,
+            mut border,
+            tint,
+            texture_filter,
+        } = *self;
+
+        // Shrink the borders if they don't fit:
+        if border.sum_x() > rect.width() && border.sum_x() > 0.0 {
+            let factor = rect.width() / border.sum_x();
+            border.left *= factor;
+            border.right *= factor;
+        }
+        if border.sum_y() > rect.height() && border.sum_y() > 0.0 {
+            let factor = rect.height() / border.sum_y();
+            border.top *= factor;
+            border.bottom *= factor;
+        }
+
+        let xs = [
+            rect.left(),
+            rect.left() + border.left,
+            rect.right() - border.right,
+            rect.right(),
+        ];
+        let ys = [
+            rect.top(),
+            rect.top() + border.top,
+            rect.bottom() - border.bottom,
+            rect.bottom(),
+        ];
+        let us = [
+            uv.left(),
+            uv.left() + uv_border.left,
+            uv.right() - uv_border.right,
+            uv.right(),
+        ];
+        let vs = [
+            uv.top(),
+            uv.top() + uv_border.top,
+            uv.bottom() - uv_border.bottom,
+            uv.bottom(),
+        ];
+
+        let mut mesh = Mesh::with_texture(texture_id);
+        mesh.texture_filter = texture_filter;
+        for row in 0..3 {
+            for column in 0..3 {
+                if xs[column] < xs[column + 1] && ys[row] < ys[row + 1] {
+                    mesh.add_rect_with_uv(
+                        Rect::from_min_max(
+                            pos2(xs[column], ys[row]),
+                            pos2(xs[column + 1], ys[row + 1]),
+                        ),
+                        Rect::from_min_max(
+                            pos2(us[column], vs[row]),
+                            pos2(us[column + 1], vs[row + 1]),
+                        ),
+                        tint,
+                    );
+                }
+            }
+        }
+        mesh
+    }
+}
+
+#[test]
+fn test_nine_slice() {
+    let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0));
+    let nine_slice = NineSlice::new(
+        rect,
+        TextureId::User(1),
+        uv,
+        Insets::same(0.25),
+        Insets::same(10.0),
+    );
+    let mesh = nine_slice.to_mesh();
+    assert_eq!(mesh.texture_id, TextureId::User(1));
+    assert_eq!(mesh.vertices.len(), 9 * 4);
+    assert_eq!(mesh.calc_bounds(), rect);
+
+    // The corners keep their size, and map to the corners of the texture:
+    let top_left = &mesh.vertices[0..4];
+    assert_eq!(top_left[3].pos, pos2(10.0, 10.0));
+    assert_eq!(top_left[3].uv, pos2(0.25, 0.25));
+    let bottom_right = &mesh.vertices[8 * 4..];
+    assert_eq!(bottom_right[0].pos, pos2(90.0, 40.0));
+    assert_eq!(bottom_right[0].uv, pos2(0.75, 0.75));
+
+    // Borders that don't fit are shrunk, and empty quads skipped:
+    let small = NineSlice {
+        rect: Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 20.0)),
+        ..nine_slice
+    };
+    let mesh = small.to_mesh();
+    assert_eq!(mesh.vertices.len(), 4 * 4);
+    assert_eq!(mesh.vertices[3].pos, pos2(5.0, 10.0));
+}
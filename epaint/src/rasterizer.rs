@@ -0,0 +1,171 @@
+//! A simple software rasterizer, for testing and taking screenshots without a GPU.
+//!
+//! This is slow and only meant for small images, e.g. in unit tests or visual regression tests.
+
+use crate::{ClippedMesh, Color32, Texture, TextureId};
+use emath::{pos2, Pos2, Rect};
+
+/// An image in sRGBA premultiplied space, as produced by [`rasterize`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Image {
+    /// Width and height in pixels.
+    pub size: [usize; 2],
+
+    /// `sRGBA` premultiplied pixels, row by row, top to bottom.
+    pub pixels: Vec<Color32>,
+}
+
+impl Image {
+    /// A fully transparent image.
+    pub fn new(size: [usize; 2]) -> Self {
+        Self {
+            size,
+            pixels: vec![Color32::TRANSPARENT; size[0] * size[1]],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.size[0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.size[1]
+    }
+
+    /// The pixel at the given coordinate.
+    pub fn get(&self, x: usize, y: usize) -> Option<Color32> {
+        if x < self.width() && y < self.height() {
+            Some(self.pixels[y * self.width() + x])
+        } else {
+            None
+        }
+    }
+}
+
+/// Paint the given meshes onto a new image of the given size (in pixels).
+///
+/// Only the egui font [`Texture`] is sampled. Meshes using [`TextureId::User`] are painted
+/// as if their texture was plain white, i.e. with only the vertex colors.
+///
+/// Blending is done in gamma space with premultiplied alpha, just like the WebGL1 backend.
+pub fn rasterize(
+    size: [usize; 2],
+    pixels_per_point: f32,
+    clear_color: Color32,
+    clipped_meshes: &[ClippedMesh],
+    texture: &Texture,
+) -> Image {
+    let mut image = Image {
+        size,
+        pixels: vec![clear_color; size[0] * size[1]],
+    };
+    let screen_rect = Rect::from_min_max(Pos2::ZERO, pos2(size[0] as f32, size[1] as f32));
+
+    for ClippedMesh(clip_rect, mesh) in clipped_meshes {
+        let clip_rect = Rect::from_min_max(
+            (clip_rect.min.to_vec2() * pixels_per_point).to_pos2(),
+            (clip_rect.max.to_vec2() * pixels_per_point).to_pos2(),
+        )
+        .intersect(screen_rect);
+        if clip_rect.width() <= 0.0 || clip_rect.height() <= 0.0 {
+            continue;
+        }
+
+        let texture = match mesh.texture_id {
+            TextureId::Egui => Some(texture),
+            TextureId::User(_) => None,
+        };
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [
+                &mesh.vertices[triangle[0] as usize],
+                &mesh.vertices[triangle[1] as usize],
+                &mesh.vertices[triangle[2] as usize],
+            ];
+            let [pa, pb, pc] = [
+                (a.pos.to_vec2() * pixels_per_point).to_pos2(),
+                (b.pos.to_vec2() * pixels_per_point).to_pos2(),
+                (c.pos.to_vec2() * pixels_per_point).to_pos2(),
+            ];
+
+            let area = edge(pa, pb, pc);
+            if area == 0.0 {
+                continue;
+            }
+
+            let bounds = Rect::from_min_max(pa, pa)
+                .union(Rect::from_min_max(pb, pb))
+                .union(Rect::from_min_max(pc, pc))
+                .intersect(clip_rect);
+            if bounds.width() < 0.0 || bounds.height() < 0.0 {
+                continue;
+            }
+
+            let x_range = (bounds.min.x.floor() as usize)..(bounds.max.x.ceil() as usize);
+            let y_range = (bounds.min.y.floor() as usize)..(bounds.max.y.ceil() as usize);
+
+            for y in y_range {
+                for x in x_range.clone() {
+                    // Sample at the pixel center:
+                    let p = pos2(x as f32 + 0.5, y as f32 + 0.5);
+                    if !clip_rect.contains(p) {
+                        continue;
+                    }
+
+                    // Barycentric coordinates (egui is not strict about winding order):
+                    let wa = edge(pb, pc, p) / area;
+                    let wb = edge(pc, pa, p) / area;
+                    let wc = edge(pa, pb, p) / area;
+                    if wa < 0.0 || wb < 0.0 || wc < 0.0 {
+                        continue;
+                    }
+
+                    let mut color = [0.0; 4];
+                    for (i, channel) in color.iter_mut().enumerate() {
+                        *channel = wa * a.color[i] as f32
+                            + wb * b.color[i] as f32
+                            + wc * c.color[i] as f32;
+                    }
+
+                    if let Some(texture) = texture {
+                        let uv = a.uv.to_vec2() * wa + b.uv.to_vec2() * wb + c.uv.to_vec2() * wc;
+                        let alpha = sample(texture, uv.x, uv.y) as f32 / 255.0;
+                        for channel in &mut color {
+                            *channel *= alpha;
+                        }
+                    }
+
+                    let dst = &mut image.pixels[y * size[0] + x];
+                    *dst = blend(color, *dst);
+                }
+            }
+        }
+    }
+
+    image
+}
+
+/// Twice the signed area of the triangle `a, b, c`.
+fn edge(a: Pos2, b: Pos2, c: Pos2) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Nearest-neighbor sampling of the font texture, clamping to the edges.
+fn sample(texture: &Texture, u: f32, v: f32) -> u8 {
+    if texture.width == 0 || texture.height == 0 {
+        return 255;
+    }
+    let x = (u * texture.width as f32).floor().max(0.0) as usize;
+    let y = (v * texture.height as f32).floor().max(0.0) as usize;
+    texture[(x.min(texture.width - 1), y.min(texture.height - 1))]
+}
+
+/// Premultiplied "over" blending, in gamma space.
+fn blend(src: [f32; 4], dst: Color32) -> Color32 {
+    let src_alpha = src[3] / 255.0;
+    let channel = |i: usize| {
+        let value = src[i] + dst[i] as f32 * (1.0 - src_alpha);
+        value.round().clamp(0.0, 255.0) as u8
+    };
+    Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3))
+}
@@ -1,6 +1,6 @@
 use crate::{
     text::{Fonts, Galley, TextStyle},
-    Color32, Mesh, Stroke,
+    Color32, Mesh, NineSlice, Stroke,
 };
 use emath::*;
 
@@ -8,6 +8,7 @@ use emath::*;
 /// Coordinates are all screen space points (not physical pixels).
 #[must_use = "Add a Shape to a Painter"]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub enum Shape {
     /// Paint nothing. This can be useful as a placeholder.
     Noop,
@@ -51,6 +52,8 @@ pub enum Shape {
         fake_italics: bool,
     },
     Mesh(Mesh),
+    /// An image that is scaled without stretching its borders, see [`NineSlice`].
+    NineSlice(NineSlice),
 }
 
 /// ## Constructors
@@ -262,10 +265,56 @@ impl Shape {
 
     #[inline(always)]
     pub fn texture_id(&self) -> super::TextureId {
-        if let Shape::Mesh(mesh) = self {
-            mesh.texture_id
-        } else {
-            super::TextureId::Egui
+        match self {
+            Shape::Mesh(mesh) => mesh.texture_id,
+            Shape::NineSlice(nine_slice) => nine_slice.texture_id,
+            _ => super::TextureId::Egui,
+        }
+    }
+
+    /// See [`Mesh::texture_filter`].
+    #[inline(always)]
+    pub fn texture_filter(&self) -> Option<super::TextureFilter> {
+        match self {
+            Shape::Mesh(mesh) => mesh.texture_filter,
+            Shape::NineSlice(nine_slice) => nine_slice.texture_filter,
+            _ => None,
+        }
+    }
+
+    /// The smallest rectangle containing everything the shape paints, including strokes.
+    ///
+    /// Returns [`Rect::NOTHING`] for [`Self::Noop`].
+    pub fn visual_bounding_rect(&self) -> Rect {
+        match self {
+            Shape::Noop => Rect::NOTHING,
+            Shape::Vec(shapes) => {
+                let mut rect = Rect::NOTHING;
+                for shape in shapes {
+                    rect = rect.union(shape.visual_bounding_rect());
+                }
+                rect
+            }
+            Shape::Circle {
+                center,
+                radius,
+                stroke,
+                ..
+            } => Rect::from_center_size(*center, Vec2::splat(2.0 * radius + stroke.width)),
+            Shape::LineSegment { points, stroke } => {
+                Rect::from_two_pos(points[0], points[1]).expand(0.5 * stroke.width)
+            }
+            Shape::Path { points, stroke, .. } => {
+                let mut rect = Rect::NOTHING;
+                for &point in points {
+                    rect.extend_with(point);
+                }
+                rect.expand(0.5 * stroke.width)
+            }
+            Shape::Rect { rect, stroke, .. } => rect.expand(0.5 * stroke.width),
+            Shape::Text { pos, galley, .. } => Rect::from_min_size(*pos, galley.size),
+            Shape::Mesh(mesh) => mesh.calc_bounds(),
+            Shape::NineSlice(nine_slice) => nine_slice.rect,
         }
     }
 
@@ -300,6 +349,9 @@ impl Shape {
             Shape::Mesh(mesh) => {
                 mesh.translate(delta);
             }
+            Shape::NineSlice(nine_slice) => {
+                nine_slice.rect = nine_slice.rect.translate(delta);
+            }
         }
     }
 }
@@ -32,5 +32,8 @@ pub fn adjust_colors(shape: &mut Shape, adjust_color: &impl Fn(&mut Color32)) {
                 adjust_color(&mut v.color);
             }
         }
+        Shape::NineSlice(nine_slice) => {
+            adjust_color(&mut nine_slice.tint);
+        }
     }
 }
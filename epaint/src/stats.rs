@@ -173,9 +173,11 @@ impl PaintStats {
                     self.add(shape);
                 }
             }
-            Shape::Noop | Shape::Circle { .. } | Shape::LineSegment { .. } | Shape::Rect { .. } => {
-                Default::default()
-            }
+            Shape::Noop
+            | Shape::Circle { .. }
+            | Shape::LineSegment { .. }
+            | Shape::Rect { .. }
+            | Shape::NineSlice(_) => Default::default(),
             Shape::Path { points, .. } => {
                 self.shape_path += AllocInfo::from_slice(points);
             }
@@ -242,10 +242,20 @@ pub struct TessellationOptions {
     pub anti_alias: bool,
     /// If `true` (default) cull certain primitives before tessellating them
     pub coarse_tessellation_culling: bool,
+    /// Subdivide triangles whose vertex colors differ a lot, computing the new colors in linear space
+    /// (see [`Mesh::subdivide_gradients`]).
+    ///
+    /// egui expects integrations to interpolate vertex colors in linear space.
+    /// Turn this on if yours interpolates them in gamma (sRGB) space,
+    /// which makes gradients band and feathered (anti-aliased) edges too dark.
+    /// This costs more triangles. Default: `false`.
+    pub subdivide_gradients: bool,
     /// Output the clip rectangles to be painted?
     pub debug_paint_clip_rects: bool,
     /// Output the text-containing rectangles
     pub debug_paint_text_rects: bool,
+    /// Output the outline of every triangle, to diagnose overdraw and tessellation artifacts
+    pub debug_paint_wireframe: bool,
     /// If true, no clipping will be done
     pub debug_ignore_clip_rects: bool,
 }
@@ -257,8 +267,10 @@ impl Default for TessellationOptions {
             aa_size: 1.0,
             anti_alias: true,
             coarse_tessellation_culling: true,
+            subdivide_gradients: false,
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
+            debug_paint_wireframe: false,
             debug_ignore_clip_rects: false,
         }
     }
@@ -531,6 +543,12 @@ impl Tessellator {
                     crate::epaint_assert!(false, "Invalid Mesh in Shape::Mesh");
                 }
             }
+            Shape::NineSlice(nine_slice) => {
+                if options.coarse_tessellation_culling && !clip_rect.intersects(nine_slice.rect) {
+                    return;
+                }
+                out.append(nine_slice.to_mesh());
+            }
             Shape::LineSegment { points, stroke } => {
                 let path = &mut self.scratchpad_path;
                 path.clear();
@@ -731,6 +749,29 @@ impl Tessellator {
     }
 }
 
+/// Everything needed to tessellate a frame, as plain data.
+///
+/// This lets you tessellate somewhere else than where the shapes were produced,
+/// e.g. on another thread or in a web worker.
+/// With the `persistence` feature it can be serialized and sent there.
+///
+/// In egui you get one from `Context::tessellation_job`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct TessellationJob {
+    pub shapes: Vec<ClippedShape>,
+    pub options: TessellationOptions,
+    /// Size of the font texture (required to normalize glyph uv rectangles).
+    pub tex_size: [usize; 2],
+}
+
+impl TessellationJob {
+    /// See [`tessellate_shapes`].
+    pub fn tessellate(self) -> Vec<ClippedMesh> {
+        tessellate_shapes(self.shapes, self.options, self.tex_size)
+    }
+}
+
 /// Turns [`Shape`]:s into sets of triangles.
 ///
 /// The given shapes will be painted back-to-front (painters algorithm).
@@ -758,7 +799,11 @@ pub fn tessellate_shapes(
 
         let start_new_mesh = match clipped_meshes.last() {
             None => true,
-            Some(cm) => cm.0 != clip_rect || cm.1.texture_id != shape.texture_id(),
+            Some(cm) => {
+                cm.0 != clip_rect
+                    || cm.1.texture_id != shape.texture_id()
+                    || cm.1.texture_filter != shape.texture_filter()
+            }
         };
 
         if start_new_mesh {
@@ -770,6 +815,12 @@ pub fn tessellate_shapes(
         tessellator.tessellate_shape(tex_size, shape, out);
     }
 
+    if options.subdivide_gradients {
+        for ClippedMesh(_, mesh) in &mut clipped_meshes {
+            mesh.subdivide_gradients(2);
+        }
+    }
+
     if options.debug_paint_clip_rects {
         for ClippedMesh(clip_rect, mesh) in &mut clipped_meshes {
             tessellator.clip_rect = Rect::EVERYTHING;
@@ -786,6 +837,31 @@ pub fn tessellate_shapes(
         }
     }
 
+    if options.debug_paint_wireframe {
+        let stroke = Stroke::new(
+            1.0 / options.pixels_per_point,
+            Color32::from_rgba_premultiplied(200, 0, 200, 200),
+        );
+        let mut wireframes = Vec::with_capacity(clipped_meshes.len());
+        for ClippedMesh(clip_rect, mesh) in &clipped_meshes {
+            let mut wireframe = Mesh::default();
+            tessellator.clip_rect = *clip_rect;
+            for triangle in mesh.indices.chunks_exact(3) {
+                let points = triangle
+                    .iter()
+                    .map(|&i| mesh.vertices[i as usize].pos)
+                    .collect();
+                tessellator.tessellate_shape(
+                    tex_size,
+                    Shape::closed_line(points, stroke),
+                    &mut wireframe,
+                );
+            }
+            wireframes.push(ClippedMesh(*clip_rect, wireframe));
+        }
+        clipped_meshes.extend(wireframes);
+    }
+
     if options.debug_ignore_clip_rects {
         for ClippedMesh(clip_rect, _) in &mut clipped_meshes {
             *clip_rect = Rect::EVERYTHING;
@@ -798,3 +874,26 @@ pub fn tessellate_shapes(
 
     clipped_meshes
 }
+
+#[test]
+fn test_texture_filter_splits_meshes() {
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 10.0));
+    let image = |texture_filter| {
+        let mut mesh = Mesh::with_texture(TextureId::User(0));
+        mesh.texture_filter = texture_filter;
+        mesh.add_rect_with_uv(rect, rect, Color32::WHITE);
+        ClippedShape(Rect::EVERYTHING, Shape::mesh(mesh))
+    };
+    let shapes = vec![
+        image(None),
+        image(None),
+        image(Some(TextureFilter::Nearest)),
+    ];
+    let clipped_meshes = tessellate_shapes(shapes, Default::default(), [1, 1]);
+    assert_eq!(clipped_meshes.len(), 2);
+    assert_eq!(clipped_meshes[0].1.vertices.len(), 8);
+    assert_eq!(
+        clipped_meshes[1].1.texture_filter,
+        Some(TextureFilter::Nearest)
+    );
+}
@@ -1,7 +1,10 @@
 use std::{
     collections::BTreeMap,
     hash::{Hash, Hasher},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 
 use ahash::AHashMap;
@@ -207,6 +210,40 @@ impl Default for FontDefinitions {
     }
 }
 
+impl FontDefinitions {
+    /// Install a `.ttf` or `.otf` font as the primary font of `family`,
+    /// keeping the previous fonts of that family as fallbacks.
+    ///
+    /// Unlike inserting into [`Self::font_data`] directly, this checks the font first,
+    /// so you can safely install fonts picked by the user at runtime.
+    ///
+    /// # Errors
+    /// If `data` can't be parsed as a font, in which case nothing is changed.
+    pub fn insert_primary_font(
+        &mut self,
+        name: impl Into<String>,
+        data: FontData,
+        family: FontFamily,
+    ) -> Result<(), String> {
+        let name = name.into();
+        ab_glyph::FontRef::try_from_slice(&data)
+            .map_err(|err| format!("Error parsing {:?} TTF/OTF font file: {}", name, err))?;
+
+        self.font_data.insert(name.clone(), data);
+        let fonts = self.fonts_for_family.entry(family).or_default();
+        fonts.retain(|font| font != &name);
+        fonts.insert(0, name);
+        Ok(())
+    }
+}
+
+/// When the font texture atlas needs to grow taller than this,
+/// it is time to evict glyphs (see [`Fonts::atlas_is_full`]).
+const MAX_ATLAS_HEIGHT: usize = 4096;
+
+/// A new [`Fonts`] is not considered full during its first frames, see [`Fonts::atlas_is_full`].
+const MIN_FRAMES_BETWEEN_EVICTIONS: u64 = 60;
+
 /// The collection of fonts used by `epaint`.
 pub struct Fonts {
     pixels_per_point: f32,
@@ -218,6 +255,9 @@ pub struct Fonts {
     buffered_texture: Mutex<Arc<Texture>>,
 
     galley_cache: Mutex<GalleyCache>,
+
+    /// Number of calls to [`Self::end_frame`] so far.
+    num_frames: AtomicU64,
 }
 
 impl Fonts {
@@ -277,6 +317,7 @@ impl Fonts {
             atlas,
             buffered_texture: Default::default(), //atlas.lock().texture().clone();
             galley_cache: Default::default(),
+            num_frames: Default::default(),
         }
     }
 
@@ -305,6 +346,31 @@ impl Fonts {
         buffered_texture.clone()
     }
 
+    /// Has the font texture atlas grown so large that it is time to start over?
+    ///
+    /// This can happen when using many different font sizes, or large character sets (e.g. CJK).
+    /// Glyphs are never removed from the atlas, so recreate the `Fonts` with
+    /// [`Self::from_definitions`] to evict all of them.
+    /// The ones still in use will be added back as they are needed.
+    /// Galleys laid out before then point into the old atlas, so lay them out again.
+    ///
+    /// This is never `true` during the first frames (counted by [`Self::end_frame`]),
+    /// so if the glyphs needed in a single frame don't fit, the atlas grows instead of
+    /// being recreated every frame.
+    ///
+    /// `egui` does this automatically at the start of the next frame.
+    pub fn atlas_is_full(&self) -> bool {
+        self.num_frames.load(Ordering::Relaxed) >= MIN_FRAMES_BETWEEN_EVICTIONS
+            && self.atlas.lock().texture().height > MAX_ATLAS_HEIGHT
+    }
+
+    /// The [`TextureAtlas::generation`] of the font texture atlas.
+    ///
+    /// Use this to tell if galleys kept from an earlier frame still point into the current atlas.
+    pub fn atlas_generation(&self) -> u64 {
+        self.atlas.lock().generation()
+    }
+
     /// Width of this character in points.
     pub fn glyph_width(&self, text_style: TextStyle, c: char) -> f32 {
         self.fonts[&text_style].glyph_width(c)
@@ -386,7 +452,8 @@ impl Fonts {
 
     /// Must be called once per frame to clear the [`Galley`] cache.
     pub fn end_frame(&self) {
-        self.galley_cache.lock().end_frame()
+        self.galley_cache.lock().end_frame();
+        self.num_frames.fetch_add(1, Ordering::Relaxed);
     }
 }
 
@@ -550,3 +617,51 @@ impl FontImplCache {
         font_impl
     }
 }
+
+#[test]
+fn test_insert_primary_font() {
+    let mut definitions = FontDefinitions::default();
+    let garbage = FontData::Borrowed(b"not a font");
+    assert!(definitions
+        .insert_primary_font("garbage", garbage, FontFamily::Proportional)
+        .is_err());
+    assert!(!definitions.font_data.contains_key("garbage"));
+
+    let font = FontData::Borrowed(include_bytes!("../../fonts/ProggyClean.ttf"));
+    definitions
+        .insert_primary_font("my_font", font, FontFamily::Proportional)
+        .unwrap();
+    assert_eq!(
+        definitions.fonts_for_family[&FontFamily::Proportional][0],
+        "my_font"
+    );
+
+    // The new font is used:
+    let fonts = Fonts::from_definitions(1.0, definitions);
+    assert!(
+        fonts
+            .layout_single_line(TextStyle::Body, "Hello".into())
+            .size
+            .x
+            > 0.0
+    );
+}
+
+#[test]
+fn test_atlas_is_not_full_during_first_frames() {
+    let fonts = Fonts::from_definitions(20.0, FontDefinitions::default());
+    assert_ne!(
+        fonts.atlas_generation(),
+        Fonts::from_definitions(1.0, FontDefinitions::default()).atlas_generation()
+    );
+
+    let text: String = ('A'..='Z').collect();
+    fonts.layout_no_wrap(TextStyle::Heading, text);
+    assert!(fonts.texture().height > MAX_ATLAS_HEIGHT);
+    assert!(!fonts.atlas_is_full());
+
+    for _ in 0..MIN_FRAMES_BETWEEN_EVICTIONS {
+        fonts.end_frame();
+    }
+    assert!(fonts.atlas_is_full());
+}
@@ -24,6 +24,7 @@ use emath::{pos2, NumExt, Rect, Vec2};
 
 /// A collection of text locked into place.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Galley {
     /// The [`crate::TextStyle`] (font) used.
     pub text_style: crate::TextStyle,
@@ -43,6 +44,7 @@ pub struct Galley {
 
 /// A typeset piece of text on a single row.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Row {
     /// The start of each character, probably starting at zero.
     /// The last element is the end of the last character.
@@ -269,6 +271,86 @@ impl Galley {
     }
 }
 
+/// ## Hit testing
+impl Galley {
+    /// The index of the row at the given y coordinate (relative to the galley),
+    /// or the closest row if `y` is above or below all rows.
+    pub fn row_at(&self, y: f32) -> usize {
+        self.rows
+            .iter()
+            .position(|row| y < row.y_max)
+            .unwrap_or_else(|| self.rows.len().saturating_sub(1))
+    }
+
+    /// The rectangles covering the text between two cursors, one per row,
+    /// relative to the galley.
+    ///
+    /// Useful for painting a selection. The cursors can be in any order.
+    /// A selected newline is shown as a bit of extra width at the end of its row.
+    pub fn selection_rects(&self, a: &Cursor, b: &Cursor) -> Vec<Rect> {
+        let (min, max) = if a.ccursor.index <= b.ccursor.index {
+            (a.rcursor, b.rcursor)
+        } else {
+            (b.rcursor, a.rcursor)
+        };
+
+        let mut rects = vec![];
+        for ri in min.row..=max.row.min(self.rows.len().saturating_sub(1)) {
+            let row = &self.rows[ri];
+            let left = if ri == min.row {
+                row.x_offset(min.column)
+            } else {
+                row.min_x()
+            };
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                let newline_size = if row.ends_with_newline {
+                    row.height() / 2.0 // visualize that we select the newline
+                } else {
+                    0.0
+                };
+                row.max_x() + newline_size
+            };
+            rects.push(Rect::from_min_max(
+                pos2(left, row.y_min),
+                pos2(right, row.y_max),
+            ));
+        }
+        rects
+    }
+
+    /// The byte offset into [`Self::text`] of the given character cursor.
+    pub fn byte_index_from_ccursor(&self, ccursor: CCursor) -> usize {
+        self.text
+            .char_indices()
+            .nth(ccursor.index)
+            .map_or(self.text.len(), |(byte_index, _)| byte_index)
+    }
+
+    /// The character cursor at the given byte offset into [`Self::text`].
+    ///
+    /// A byte offset in the middle of a character gives the cursor before that character.
+    pub fn ccursor_from_byte_index(&self, byte_index: usize) -> CCursor {
+        let index = self
+            .text
+            .char_indices()
+            .take_while(|(char_byte_index, chr)| char_byte_index + chr.len_utf8() <= byte_index)
+            .count();
+        CCursor::new(index)
+    }
+
+    /// The text between two cursors. The cursors can be in any order.
+    pub fn text_between(&self, a: &Cursor, b: &Cursor) -> &str {
+        let (min, max) = if a.ccursor.index <= b.ccursor.index {
+            (a.ccursor, b.ccursor)
+        } else {
+            (b.ccursor, a.ccursor)
+        };
+        &self.text[self.byte_index_from_ccursor(min)..self.byte_index_from_ccursor(max)]
+    }
+}
+
 /// ## Cursor positions
 impl Galley {
     /// Cursor to one-past last character.
@@ -818,4 +900,26 @@ fn test_text_layout() {
             }
         );
     }
+
+    {
+        // Test hit testing:
+        let galley = font.layout_multiline("héllo\nwörld".to_owned(), 1024.0);
+        assert_eq!(galley.rows.len(), 2);
+        assert_eq!(galley.row_at(-10.0), 0);
+        assert_eq!(galley.row_at(galley.rows[1].y_min + 1.0), 1);
+        assert_eq!(galley.row_at(1000.0), 1);
+
+        // "wö|rld"
+        assert_eq!(galley.byte_index_from_ccursor(CCursor::new(8)), 10);
+        assert_eq!(galley.ccursor_from_byte_index(10), CCursor::new(8));
+        // In the middle of 'ö':
+        assert_eq!(galley.ccursor_from_byte_index(9), CCursor::new(7));
+
+        let a = galley.from_ccursor(CCursor::new(1));
+        let b = galley.from_ccursor(CCursor::new(8));
+        assert_eq!(galley.text_between(&b, &a), "éllo\nwö");
+        let rects = galley.selection_rects(&a, &b);
+        assert_eq!(rects.len(), 2);
+        assert_eq!(rects[1].min.x, 0.0);
+    }
 }
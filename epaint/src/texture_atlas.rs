@@ -1,6 +1,9 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 // TODO: `TextureData` or similar?
 /// An 8-bit texture containing font data.
 #[derive(Clone, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 pub struct Texture {
     /// e.g. a hash of the data. Use this to detect changes!
     /// If the texture changes, this too will change.
@@ -57,10 +60,13 @@ pub struct TextureAtlas {
     /// Used for when allocating new rectangles.
     cursor: (usize, usize),
     row_height: usize,
+
+    generation: u64,
 }
 
 impl TextureAtlas {
     pub fn new(width: usize, height: usize) -> Self {
+        static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
         Self {
             texture: Texture {
                 version: 0,
@@ -68,6 +74,7 @@ impl TextureAtlas {
                 height,
                 pixels: vec![0; width * height],
             },
+            generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
             ..Default::default()
         }
     }
@@ -76,6 +83,14 @@ impl TextureAtlas {
         &self.texture
     }
 
+    /// Unique for each atlas created with [`Self::new`].
+    ///
+    /// Rectangles allocated in one atlas mean nothing in another,
+    /// so use this to tell if something refers to this atlas, e.g. a [`crate::Galley`] kept across frames.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     pub fn texture_mut(&mut self) -> &mut Texture {
         self.texture.version += 1;
         &mut self.texture
@@ -0,0 +1,126 @@
+//! How the integrations allocate and sample user textures (images).
+
+use crate::{Color32, TextureId};
+
+/// How a texture should be sampled when it is magnified or minified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextureFilter {
+    /// Pick the closest texel. Good for pixel art.
+    Nearest,
+
+    /// Blend the closest texels. Good for photos and most other images.
+    Linear,
+}
+
+impl Default for TextureFilter {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+/// What happens when a texture is sampled outside of `[0, 1]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextureWrapMode {
+    /// Stretch the edge texels.
+    ClampToEdge,
+
+    /// Tile the texture.
+    Repeat,
+
+    /// Tile the texture, mirroring every other tile.
+    MirroredRepeat,
+}
+
+impl Default for TextureWrapMode {
+    fn default() -> Self {
+        Self::ClampToEdge
+    }
+}
+
+/// How a user texture should be sampled by the integration.
+///
+/// The default is linear filtering, clamping and no mipmaps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextureOptions {
+    /// How to sample when the texture is shown larger than its size in pixels.
+    pub magnification: TextureFilter,
+
+    /// How to sample when the texture is shown smaller than its size in pixels.
+    pub minification: TextureFilter,
+
+    /// What to do with texture coordinates outside of `[0, 1]`.
+    pub wrap_mode: TextureWrapMode,
+
+    /// Generate mipmaps, so that the texture looks good when it is shown much smaller than its size.
+    ///
+    /// Some integrations (e.g. WebGL1) only support this for textures
+    /// with power-of-two dimensions, and will silently ignore it otherwise.
+    pub mipmaps: bool,
+}
+
+impl TextureOptions {
+    /// Linear filtering, clamping and no mipmaps.
+    pub const LINEAR: Self = Self {
+        magnification: TextureFilter::Linear,
+        minification: TextureFilter::Linear,
+        wrap_mode: TextureWrapMode::ClampToEdge,
+        mipmaps: false,
+    };
+
+    /// Nearest filtering, clamping and no mipmaps. Good for pixel art.
+    pub const NEAREST: Self = Self {
+        magnification: TextureFilter::Nearest,
+        minification: TextureFilter::Nearest,
+        wrap_mode: TextureWrapMode::ClampToEdge,
+        mipmaps: false,
+    };
+
+    /// Set the [`TextureWrapMode`].
+    pub fn wrap_mode(mut self, wrap_mode: TextureWrapMode) -> Self {
+        self.wrap_mode = wrap_mode;
+        self
+    }
+
+    /// Generate mipmaps or not.
+    pub fn mipmaps(mut self, mipmaps: bool) -> Self {
+        self.mipmaps = mipmaps;
+        self
+    }
+}
+
+/// How to allocate textures (images) to use in egui.
+///
+/// This is implemented by each integration (e.g. `egui_glium` and `egui_web`),
+/// so app code that registers images does not need to know which integration it is running in.
+///
+/// The pixels are expected to be in sRGB space with premultiplied alpha.
+pub trait TextureAllocator {
+    /// Allocate a new user texture with linear filtering, clamping and no mipmaps.
+    fn alloc_srgba_premultiplied(
+        &mut self,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+    ) -> TextureId {
+        self.alloc(size, srgba_pixels, TextureOptions::default())
+    }
+
+    /// Allocate a new user texture, sampled according to the given [`TextureOptions`].
+    fn alloc(
+        &mut self,
+        size: (usize, usize),
+        srgba_pixels: &[Color32],
+        options: TextureOptions,
+    ) -> TextureId;
+
+    /// Replace the contents of a texture previously allocated with [`Self::alloc`].
+    ///
+    /// The size may change. The [`TextureOptions`] stay the same.
+    /// Does nothing if `id` is not an allocated user texture.
+    fn update(&mut self, id: TextureId, size: (usize, usize), srgba_pixels: &[Color32]);
+
+    /// Free the given texture.
+    fn free(&mut self, id: TextureId);
+}
@@ -0,0 +1,292 @@
+//! Load images in the background, from files, bytes or URLs, and cache them as textures.
+//!
+//! You must enable the "image" feature for this.
+//! Loading from URLs also requires the "http" feature.
+//!
+//! ```
+//! # fn foo(ui: &mut egui::Ui, frame: &mut epi::Frame<'_>, image_loader: &mut epi::image_loader::ImageLoader) {
+//! for path in ["thumbnails/cat.png", "thumbnails/dog.jpg"].iter() {
+//!     // Shows a spinner until the image is loaded:
+//!     image_loader.show(ui, frame, path, egui::vec2(64.0, 64.0));
+//! }
+//!
+//! // At the end of the frame, free the textures of images that are no longer shown:
+//! image_loader.end_frame(frame);
+//! # }
+//! ```
+
+use crate::Frame;
+use egui::{Color32, TextureId, Vec2};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// A decoded image in sRGBA premultiplied space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    /// Width and height in pixels.
+    pub size: (usize, usize),
+
+    /// `sRGBA` premultiplied pixels, row by row, top to bottom.
+    pub pixels: Vec<Color32>,
+}
+
+impl Image {
+    /// Decode a PNG or JPEG image.
+    pub fn decode(bytes: &[u8]) -> Result<Image, String> {
+        use image::GenericImageView as _;
+        let image = image::load_from_memory(bytes).map_err(|err| err.to_string())?;
+        let size = (image.width() as usize, image.height() as usize);
+        let pixels = image
+            .to_rgba8()
+            .into_vec()
+            .chunks_exact(4)
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        Ok(Image { size, pixels })
+    }
+}
+
+/// The state of an image in the [`ImageLoader`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImageState {
+    /// Still loading or decoding.
+    Loading,
+
+    /// Ready to be shown with e.g. [`egui::Ui::image`].
+    Ready {
+        texture_id: TextureId,
+        /// Size in pixels.
+        size: Vec2,
+    },
+
+    /// Loading or decoding failed.
+    Failed(String),
+}
+
+/// Where the background loading puts its result.
+type Pending = Arc<Mutex<Option<Result<Image, String>>>>;
+
+struct CachedImage {
+    state: ImageState,
+    pending: Option<Pending>,
+
+    /// The frame we were last asked for this image.
+    last_used: u64,
+}
+
+/// Loads images in the background and caches them as textures by their URI.
+///
+/// The URI can be:
+/// * `https://…` or `http://…` (requires the "http" feature)
+/// * `file://…` or a plain path (not supported on the web)
+/// * any name you have given to bytes added with [`Self::insert_bytes`]
+///
+/// Call [`Self::end_frame`] once per frame to free the textures of images
+/// that haven't been used for a while.
+pub struct ImageLoader {
+    cache: HashMap<String, CachedImage>,
+    frame_nr: u64,
+    max_unused_frames: u64,
+}
+
+impl Default for ImageLoader {
+    fn default() -> Self {
+        Self {
+            cache: Default::default(),
+            frame_nr: 0,
+            max_unused_frames: 60,
+        }
+    }
+}
+
+impl ImageLoader {
+    /// Free the texture of an image after it hasn't been used for this many frames. Default: 60.
+    pub fn max_unused_frames(mut self, max_unused_frames: u64) -> Self {
+        self.max_unused_frames = max_unused_frames;
+        self
+    }
+
+    /// Get the image with the given URI, starting to load it if needed.
+    pub fn load(&mut self, frame: &mut Frame<'_>, uri: &str) -> ImageState {
+        if !self.cache.contains_key(uri) {
+            let pending = Pending::default();
+            start_loading(frame, uri, pending.clone());
+            self.insert_pending(uri.to_owned(), pending);
+        }
+
+        let cached = self.cache.get_mut(uri).unwrap();
+        cached.last_used = self.frame_nr;
+
+        if let Some(pending) = &cached.pending {
+            let result = pending.lock().unwrap().take();
+            if let Some(result) = result {
+                cached.pending = None;
+                cached.state = match result {
+                    Ok(image) => ImageState::Ready {
+                        texture_id: frame
+                            .tex_allocator()
+                            .alloc_srgba_premultiplied(image.size, &image.pixels),
+                        size: Vec2::new(image.size.0 as f32, image.size.1 as f32),
+                    },
+                    Err(err) => ImageState::Failed(err),
+                };
+            }
+        }
+
+        cached.state.clone()
+    }
+
+    /// Add the encoded (e.g. PNG) bytes of an image, to be decoded in the background.
+    ///
+    /// You can then show it with [`Self::load`] or [`Self::show`] using the same `uri`.
+    /// Replaces any previous image with the same `uri`.
+    pub fn insert_bytes(&mut self, frame: &mut Frame<'_>, uri: impl Into<String>, bytes: Vec<u8>) {
+        let uri = uri.into();
+        self.forget(frame, &uri);
+
+        let pending = Pending::default();
+        let repaint_signal = frame.repaint_signal();
+        let decode = {
+            let pending = pending.clone();
+            move || {
+                *pending.lock().unwrap() = Some(Image::decode(&bytes));
+                repaint_signal.request_repaint();
+            }
+        };
+
+        // There are no threads on the web, so there we decode right away:
+        #[cfg(target_arch = "wasm32")]
+        decode();
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::spawn(decode);
+
+        self.insert_pending(uri, pending);
+    }
+
+    /// Remove the image from the cache, freeing its texture.
+    pub fn forget(&mut self, frame: &mut Frame<'_>, uri: &str) {
+        if let Some(cached) = self.cache.remove(uri) {
+            if let ImageState::Ready { texture_id, .. } = cached.state {
+                frame.tex_allocator().free(texture_id);
+            }
+        }
+    }
+
+    /// Show the image, scaled down to fit within `max_size`.
+    ///
+    /// Shows a spinner while the image is loading, and an error message if it failed.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        frame: &mut Frame<'_>,
+        uri: &str,
+        max_size: Vec2,
+    ) -> egui::Response {
+        match self.load(frame, uri) {
+            ImageState::Loading => spinner(ui, max_size.min(Vec2::splat(32.0))),
+            ImageState::Ready { texture_id, size } => {
+                let scale = (max_size.x / size.x).min(max_size.y / size.y).min(1.0);
+                ui.image(texture_id, size * scale)
+            }
+            ImageState::Failed(err) => ui
+                .colored_label(egui::Color32::RED, "⚠")
+                .on_hover_text(format!("Failed to load {:?}: {}", uri, err)),
+        }
+    }
+
+    /// Free the textures of images that haven't been used for a while.
+    ///
+    /// Call this once at the end of each frame.
+    pub fn end_frame(&mut self, frame: &mut Frame<'_>) {
+        let frame_nr = self.frame_nr;
+        let max_unused_frames = self.max_unused_frames;
+        let tex_allocator = frame.tex_allocator();
+        self.cache.retain(|_uri, cached| {
+            let keep = frame_nr - cached.last_used <= max_unused_frames;
+            if !keep {
+                if let ImageState::Ready { texture_id, .. } = cached.state {
+                    tex_allocator.free(texture_id);
+                }
+            }
+            keep
+        });
+        self.frame_nr += 1;
+    }
+
+    fn insert_pending(&mut self, uri: String, pending: Pending) {
+        self.cache.insert(
+            uri,
+            CachedImage {
+                state: ImageState::Loading,
+                pending: Some(pending),
+                last_used: self.frame_nr,
+            },
+        );
+    }
+}
+
+fn start_loading(frame: &Frame<'_>, uri: &str, pending: Pending) {
+    let repaint_signal = frame.repaint_signal();
+    let set_result = move |result: Result<Image, String>| {
+        *pending.lock().unwrap() = Some(result);
+        repaint_signal.request_repaint();
+    };
+
+    if uri.starts_with("http://") || uri.starts_with("https://") {
+        #[cfg(feature = "http")]
+        frame.http_fetch(crate::http::Request::get(uri), move |response| {
+            set_result(response.and_then(|response| {
+                if response.ok {
+                    Image::decode(&response.bytes)
+                } else {
+                    Err(format!("{} {}", response.status, response.status_text))
+                }
+            }));
+        });
+        #[cfg(not(feature = "http"))]
+        set_result(Err(
+            "Loading images from URLs requires the \"http\" feature".to_owned(),
+        ));
+    } else {
+        let path = uri.strip_prefix("file://").unwrap_or(uri);
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = path;
+            set_result(Err("Loading files is not supported on the web".to_owned()));
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let path = std::path::PathBuf::from(path);
+            std::thread::spawn(move || {
+                let result = std::fs::read(&path)
+                    .map_err(|err| format!("{}: {}", path.display(), err))
+                    .and_then(|bytes| Image::decode(&bytes));
+                set_result(result);
+            });
+        }
+    }
+}
+
+/// A spinning arc, shown while an image is loading.
+fn spinner(ui: &mut egui::Ui, size: Vec2) -> egui::Response {
+    use std::f32::consts::TAU;
+
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let radius = 0.4 * rect.width().min(rect.height());
+    let start_angle = ui.input().time as f32 * TAU;
+    let points: Vec<egui::Pos2> = (0..=24)
+        .map(|i| {
+            let angle = start_angle + 0.75 * TAU * i as f32 / 24.0;
+            rect.center() + radius * Vec2::angled(angle)
+        })
+        .collect();
+    ui.painter().add(egui::Shape::line(
+        points,
+        ui.visuals().widgets.noninteractive.fg_stroke,
+    ));
+    ui.ctx().request_repaint();
+    response
+}
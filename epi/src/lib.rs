@@ -87,6 +87,9 @@
 
 pub use egui; // Re-export for user convenience
 
+#[cfg(feature = "image")]
+pub mod image_loader;
+
 // ----------------------------------------------------------------------------
 
 /// Implement this trait to write apps that can be compiled both natively using the [`egui_glium`](https://crates.io/crates/egui_glium) crate,
@@ -166,7 +169,8 @@ pub struct NativeOptions {
     pub always_on_top: bool,
 
     /// On desktop: add window decorations (i.e. a frame around your app)?
-    /// If false it will be difficult to move and resize the app.
+    /// If false it will be difficult to move and resize the app,
+    /// unless you draw your own title bar and call [`Frame::drag_window`] and [`Frame::drag_resize_window`].
     pub decorated: bool,
 
     /// On Windows: enable drag and drop support.
@@ -204,7 +208,7 @@ impl Default for NativeOptions {
 }
 
 /// Image data for the icon.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct IconData {
     /// RGBA pixels.
     pub rgba: Vec<u8>,
@@ -249,6 +253,83 @@ impl<'a> Frame<'a> {
         self.0.output.window_size = Some(size);
     }
 
+    /// Set the title of the native window.
+    ///
+    /// On the web this sets the title of the page.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set_window_title(&mut self, title: impl ToString) {
+        self.0.output.window_title = Some(title.to_string());
+    }
+
+    /// Move the top-left corner of the native window to this position (in screen points).
+    ///
+    /// The current position is in [`IntegrationInfo::window_info`].
+    /// This does nothing for web apps.
+    pub fn set_window_pos(&mut self, pos: egui::Pos2) {
+        self.0.output.window_pos = Some(pos);
+    }
+
+    /// Enter or leave (borderless) fullscreen mode.
+    /// This does nothing for web apps.
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.0.output.fullscreen = Some(fullscreen);
+    }
+
+    /// Maximize or restore the native window.
+    /// This does nothing for web apps.
+    pub fn set_maximized(&mut self, maximized: bool) {
+        self.0.output.maximized = Some(maximized);
+    }
+
+    /// Minimize or restore the native window.
+    /// This does nothing for web apps.
+    pub fn set_minimized(&mut self, minimized: bool) {
+        self.0.output.minimized = Some(minimized);
+    }
+
+    /// Change the window icon (e.g. in the task bar).
+    /// This does nothing for web apps.
+    pub fn set_window_icon(&mut self, icon: IconData) {
+        self.0.output.window_icon = Some(icon);
+    }
+
+    /// Start moving the native window with the mouse.
+    ///
+    /// Call this when the user starts dragging your custom title bar
+    /// (e.g. when [`egui::Response::drag_started`] is true).
+    /// The move ends when the mouse button is released.
+    /// This does nothing for web apps.
+    pub fn drag_window(&mut self) {
+        self.0.output.drag_window = true;
+    }
+
+    /// Start resizing the native window with the mouse, by dragging the given edge or corner.
+    ///
+    /// Use this together with [`NativeOptions::decorated`] set to `false`
+    /// to implement your own window frame.
+    /// The resize ends when the mouse button is released.
+    /// This does nothing for web apps.
+    pub fn drag_resize_window(&mut self, direction: ResizeDirection) {
+        self.0.output.drag_resize_window = Some(direction);
+    }
+
+    /// Switch between only repainting when needed ([`RunMode::Reactive`])
+    /// and repainting as often as possible ([`RunMode::Continuous`]).
+    ///
+    /// The current mode is in [`IntegrationInfo::run_mode`].
+    pub fn set_run_mode(&mut self, run_mode: RunMode) {
+        self.0.output.run_mode = Some(run_mode);
+    }
+
+    /// Never repaint more often than this many times per second.
+    /// `None` means no limit (other than vsync).
+    ///
+    /// Use this to save battery, e.g. for apps with a lot of animations.
+    /// The current limit is in [`IntegrationInfo::max_fps`].
+    pub fn set_max_fps(&mut self, max_fps: Option<f32>) {
+        self.0.output.max_fps = Some(max_fps.unwrap_or(f32::INFINITY));
+    }
+
     /// If you need to request a repaint from another thread, clone this and send it to that other thread.
     pub fn repaint_signal(&self) -> std::sync::Arc<dyn RepaintSignal> {
         self.0.repaint_signal.clone()
@@ -266,8 +347,141 @@ impl<'a> Frame<'a> {
     ) {
         self.0.http.fetch_dyn(request, Box::new(on_done))
     }
+
+    /// Show a native "Open file" dialog.
+    ///
+    /// The dialog does not block your app.
+    /// The given callback is called from another thread when the user picks a file (`Some`)
+    /// or cancels (`None`), after which a repaint is requested.
+    ///
+    /// Web apps don't get to see file paths, so this is not available there.
+    /// Use [`Self::open_file_contents_dialog`] if you want to support the web too.
+    ///
+    /// You must enable the "dialogs" feature for this.
+    #[cfg(all(feature = "dialogs", not(target_arch = "wasm32")))]
+    pub fn open_file_dialog(
+        &self,
+        dialog: dialogs::FileDialog,
+        on_done: impl 'static + Send + FnOnce(Option<std::path::PathBuf>),
+    ) {
+        self.0.dialogs.open_file_dyn(dialog, Box::new(on_done))
+    }
+
+    /// Show a native "Save file" dialog.
+    ///
+    /// Works like [`Self::open_file_dialog`].
+    /// Use [`Self::save_file_contents_dialog`] if you want to support the web too.
+    ///
+    /// You must enable the "dialogs" feature for this.
+    #[cfg(all(feature = "dialogs", not(target_arch = "wasm32")))]
+    pub fn save_file_dialog(
+        &self,
+        dialog: dialogs::FileDialog,
+        on_done: impl 'static + Send + FnOnce(Option<std::path::PathBuf>),
+    ) {
+        self.0.dialogs.save_file_dyn(dialog, Box::new(on_done))
+    }
+
+    /// Let the user pick a file, and read it.
+    ///
+    /// The given callback is called with the contents of the file, or with `None` if the user
+    /// cancelled or the file could not be read. A repaint is requested afterwards.
+    ///
+    /// Natively, the callback is called from another thread.
+    /// On the web this uses an `<input type="file">`, and the callback is called on the main thread
+    /// once the browser has read the file. Some browsers don't tell us if the user cancels,
+    /// in which case the callback is never called.
+    ///
+    /// You must enable the "dialogs" feature for this.
+    #[cfg(feature = "dialogs")]
+    pub fn open_file_contents_dialog(
+        &self,
+        dialog: dialogs::FileDialog,
+        on_done: impl 'static + Send + FnOnce(Option<dialogs::FileContents>),
+    ) {
+        self.0
+            .dialogs
+            .open_file_contents_dyn(dialog, Box::new(on_done))
+    }
+
+    /// Let the user save the given bytes to a file.
+    ///
+    /// The given callback is called with `true` if the file was saved,
+    /// and `false` if the user cancelled or the file could not be written.
+    /// A repaint is requested afterwards.
+    ///
+    /// Natively, the callback is called from another thread.
+    /// On the web the bytes are downloaded as [`dialogs::FileDialog::file_name`],
+    /// the browser decides where they end up, and the callback is called straight away.
+    ///
+    /// You must enable the "dialogs" feature for this.
+    #[cfg(feature = "dialogs")]
+    pub fn save_file_contents_dialog(
+        &self,
+        dialog: dialogs::FileDialog,
+        contents: Vec<u8>,
+        on_done: impl 'static + Send + FnOnce(bool),
+    ) {
+        self.0
+            .dialogs
+            .save_file_contents_dyn(dialog, contents, Box::new(on_done))
+    }
+
+    /// Show a native message box.
+    ///
+    /// The given callback is called with `true` if the user pressed
+    /// "Ok" or "Yes", and `false` otherwise. A repaint is requested afterwards.
+    ///
+    /// Natively, the message box does not block your app and the callback is called from another thread.
+    /// On the web this uses the browser's `alert` or `confirm`, which block the page until the user
+    /// answers, and the callback is called before this returns.
+    ///
+    /// You must enable the "dialogs" feature for this.
+    #[cfg(feature = "dialogs")]
+    pub fn message_box(
+        &self,
+        message: dialogs::MessageBox,
+        on_done: impl 'static + Send + FnOnce(bool),
+    ) {
+        self.0.dialogs.message_box_dyn(message, Box::new(on_done))
+    }
+}
+
+/// How often the integration repaints the app. Set with [`Frame::set_run_mode`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RunMode {
+    /// This is the default.
+    ///
+    /// If this is selected, egui is only updated if are input events
+    /// (like mouse movements) or there are some animations in the GUI.
+    ///
+    /// Reactive mode saves CPU.
+    ///
+    /// The downside is that the UI can become out-of-date if something it is supposed to monitor changes.
+    /// For instance, a GUI for a thermostat need to repaint each time the temperature changes.
+    /// To ensure the UI is up to date you need to call `egui::Context::request_repaint()` each
+    /// time such an event happens.
+    Reactive,
+
+    /// Repaint as soon as possible after each frame.
+    ///
+    /// On most platforms this will mean that egui will run at the display refresh rate of e.g. 60 Hz,
+    /// unless limited with [`Frame::set_max_fps`].
+    ///
+    /// For games or other interactive apps, this is probably what you want to do.
+    /// It will guarantee that egui is always up-to-date.
+    Continuous,
 }
 
+impl Default for RunMode {
+    fn default() -> Self {
+        RunMode::Reactive
+    }
+}
+
+/// Which edge or corner of the window to drag in [`Frame::drag_resize_window`].
+pub use egui::ResizeDirection;
+
 /// Information about the web environment (if applicable).
 #[derive(Clone, Debug)]
 pub struct WebInfo {
@@ -277,6 +491,20 @@ pub struct WebInfo {
     pub web_location_hash: String,
 }
 
+/// Information about the native window (if applicable).
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowInfo {
+    /// Position of the top-left corner of the window on screen, in points.
+    /// `None` if the platform does not support it.
+    pub position: Option<egui::Pos2>,
+
+    /// Inner size of the window, in points.
+    pub size: egui::Vec2,
+
+    /// Is the window in fullscreen mode?
+    pub fullscreen: bool,
+}
+
 /// Information about the integration passed to the use app each frame.
 #[derive(Clone, Debug)]
 pub struct IntegrationInfo {
@@ -297,24 +525,22 @@ pub struct IntegrationInfo {
 
     /// The OS native pixels-per-point
     pub native_pixels_per_point: Option<f32>,
-}
 
-/// How to allocate textures (images) to use in [`egui`].
-pub trait TextureAllocator {
-    /// Allocate a new user texture.
-    ///
-    /// There is no way to change a texture.
-    /// Instead allocate a new texture and free the previous one with [`Self::free`].
-    fn alloc_srgba_premultiplied(
-        &mut self,
-        size: (usize, usize),
-        srgba_pixels: &[egui::Color32],
-    ) -> egui::TextureId;
+    /// Information about the native window. `None` on the web.
+    pub window_info: Option<WindowInfo>,
 
-    /// Free the given texture.
-    fn free(&mut self, id: egui::TextureId);
+    /// How often the app is being repainted.
+    pub run_mode: RunMode,
+
+    /// The maximum number of frames per second, if limited.
+    pub max_fps: Option<f32>,
 }
 
+/// How to allocate textures (images) to use in [`egui`].
+///
+/// This is defined in [`egui::epaint`] and re-exported here for convenience.
+pub use egui::epaint::TextureAllocator;
+
 /// How to signal the [`egui`] integration that a repaint is required.
 pub trait RepaintSignal: Send + Sync {
     /// This signals the [`egui`] integration that a repaint is required.
@@ -437,6 +663,134 @@ pub mod http {
 
 // ----------------------------------------------------------------------------
 
+#[cfg(feature = "dialogs")]
+/// `epi` supports native file dialogs and message boxes with [`Frame::open_file_dialog`],
+/// [`Frame::save_file_dialog`] and [`Frame::message_box`].
+///
+/// You must enable the "dialogs" feature for this.
+pub mod dialogs {
+    use std::path::PathBuf;
+
+    /// Settings for an "Open file" or "Save file" dialog.
+    #[derive(Clone, Debug, Default)]
+    pub struct FileDialog {
+        /// Title of the dialog window. Empty means platform default.
+        pub title: String,
+        /// The directory the dialog starts in.
+        pub directory: Option<PathBuf>,
+        /// Suggested file name (for save dialogs).
+        pub file_name: String,
+        /// Only show files matching one of these filters. Empty means show all files.
+        pub filters: Vec<FileFilter>,
+    }
+
+    impl FileDialog {
+        /// Title of the dialog window.
+        #[allow(clippy::needless_pass_by_value)]
+        pub fn title(mut self, title: impl ToString) -> Self {
+            self.title = title.to_string();
+            self
+        }
+
+        /// The directory the dialog starts in.
+        pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+            self.directory = Some(directory.into());
+            self
+        }
+
+        /// Suggested file name (for save dialogs).
+        #[allow(clippy::needless_pass_by_value)]
+        pub fn file_name(mut self, file_name: impl ToString) -> Self {
+            self.file_name = file_name.to_string();
+            self
+        }
+
+        /// Add a filter, e.g. `.filter("Images", &["png", "jpg"])`.
+        #[allow(clippy::needless_pass_by_value)]
+        pub fn filter(mut self, name: impl ToString, extensions: &[&str]) -> Self {
+            self.filters.push(FileFilter {
+                name: name.to_string(),
+                extensions: extensions.iter().map(|ext| ext.to_string()).collect(),
+            });
+            self
+        }
+    }
+
+    /// A file picked with [`crate::Frame::open_file_contents_dialog`].
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FileContents {
+        /// The name of the file, without the directory, e.g. `"notes.txt"`.
+        pub name: String,
+        /// The contents of the file.
+        pub bytes: Vec<u8>,
+    }
+
+    /// A named list of file extensions (without the leading `.`).
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FileFilter {
+        /// e.g. "Images"
+        pub name: String,
+        /// e.g. `["png", "jpg"]`
+        pub extensions: Vec<String>,
+    }
+
+    /// What icon to show in a [`MessageBox`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum MessageLevel {
+        /// Just some information.
+        Info,
+        /// Something may be wrong.
+        Warning,
+        /// Something went wrong.
+        Error,
+    }
+
+    /// What buttons to show in a [`MessageBox`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum MessageButtons {
+        /// A single "Ok" button.
+        Ok,
+        /// "Ok" and "Cancel".
+        OkCancel,
+        /// "Yes" and "No".
+        YesNo,
+    }
+
+    /// A simple native message box.
+    #[derive(Clone, Debug)]
+    pub struct MessageBox {
+        /// Title of the message box window.
+        pub title: String,
+        /// The message.
+        pub description: String,
+        /// What icon to show.
+        pub level: MessageLevel,
+        /// What buttons to show.
+        pub buttons: MessageButtons,
+    }
+
+    impl MessageBox {
+        /// A message box with a single "Ok" button.
+        #[allow(clippy::needless_pass_by_value)]
+        pub fn new(level: MessageLevel, title: impl ToString, description: impl ToString) -> Self {
+            Self {
+                title: title.to_string(),
+                description: description.to_string(),
+                level,
+                buttons: MessageButtons::Ok,
+            }
+        }
+
+        /// What buttons to show.
+        pub fn buttons(mut self, buttons: MessageButtons) -> Self {
+            self.buttons = buttons;
+            self
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// You only need to look here if you are writing a backend for `epi`.
 pub mod backend {
     use super::*;
@@ -452,6 +806,51 @@ pub mod backend {
         );
     }
 
+    /// Implements native file dialogs and message boxes.
+    ///
+    /// Implementations should call `on_done` once the user has answered,
+    /// and then request a repaint.
+    #[cfg(feature = "dialogs")]
+    pub trait Dialogs {
+        /// Show an "Open file" dialog.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn open_file_dyn(
+            &self,
+            dialog: dialogs::FileDialog,
+            on_done: Box<dyn FnOnce(Option<std::path::PathBuf>) + Send>,
+        );
+
+        /// Show a "Save file" dialog.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn save_file_dyn(
+            &self,
+            dialog: dialogs::FileDialog,
+            on_done: Box<dyn FnOnce(Option<std::path::PathBuf>) + Send>,
+        );
+
+        /// Let the user pick a file, and read it.
+        fn open_file_contents_dyn(
+            &self,
+            dialog: dialogs::FileDialog,
+            on_done: Box<dyn FnOnce(Option<dialogs::FileContents>) + Send>,
+        );
+
+        /// Let the user save `contents` to a file. Call `on_done` with `true` if it was saved.
+        fn save_file_contents_dyn(
+            &self,
+            dialog: dialogs::FileDialog,
+            contents: Vec<u8>,
+            on_done: Box<dyn FnOnce(bool) + Send>,
+        );
+
+        /// Show a message box. Call `on_done` with `true` for "Ok" or "Yes".
+        fn message_box_dyn(
+            &self,
+            message: dialogs::MessageBox,
+            on_done: Box<dyn FnOnce(bool) + Send>,
+        );
+    }
+
     /// The data required by [`Frame`] each frame.
     pub struct FrameBuilder<'a> {
         /// Information about the integration.
@@ -461,6 +860,9 @@ pub mod backend {
         /// Do http requests.
         #[cfg(feature = "http")]
         pub http: std::sync::Arc<dyn backend::Http>,
+        /// Show file dialogs and message boxes.
+        #[cfg(feature = "dialogs")]
+        pub dialogs: std::sync::Arc<dyn backend::Dialogs>,
         /// Where the app can issue commands back to the integration.
         pub output: &'a mut AppOutput,
         /// If you need to request a repaint from another thread, clone this and send it to that other thread.
@@ -474,8 +876,35 @@ pub mod backend {
         }
     }
 
-    /// Action that can be taken by the user app.
+    /// How often to repaint, as set by the app with [`Frame::set_run_mode`] and [`Frame::set_max_fps`].
     #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct RepaintSettings {
+        /// Repaint only when needed, or all the time?
+        pub run_mode: RunMode,
+        /// Frames per second limit, if any.
+        pub max_fps: Option<f32>,
+    }
+
+    impl RepaintSettings {
+        /// Apply the changes requested by the app in [`AppOutput`].
+        pub fn apply(&mut self, output: &AppOutput) {
+            if let Some(run_mode) = output.run_mode {
+                self.run_mode = run_mode;
+            }
+            if let Some(max_fps) = output.max_fps {
+                self.max_fps =
+                    Some(max_fps).filter(|max_fps| max_fps.is_finite() && *max_fps > 0.0);
+            }
+        }
+
+        /// The shortest allowed time between the start of two frames, in seconds.
+        pub fn min_frame_time(&self) -> Option<f32> {
+            self.max_fps.map(|max_fps| 1.0 / max_fps)
+        }
+    }
+
+    /// Action that can be taken by the user app.
+    #[derive(Clone, Debug, Default, PartialEq)]
     pub struct AppOutput {
         /// Set to `true` to stop the app.
         /// This does nothing for web apps.
@@ -483,5 +912,43 @@ pub mod backend {
 
         /// Set to some size to resize the outer window (e.g. glium window) to this size.
         pub window_size: Option<egui::Vec2>,
+
+        /// Set to some string to rename the outer window (e.g. glium window) to this title.
+        pub window_title: Option<String>,
+
+        /// Set to some position to move the outer window (e.g. glium window) to this position.
+        /// This does nothing for web apps.
+        pub window_pos: Option<egui::Pos2>,
+
+        /// Set to enter (`true`) or leave (`false`) fullscreen mode.
+        /// This does nothing for web apps.
+        pub fullscreen: Option<bool>,
+
+        /// Set to maximize (`true`) or restore (`false`) the window.
+        /// This does nothing for web apps.
+        pub maximized: Option<bool>,
+
+        /// Set to minimize (`true`) or restore (`false`) the window.
+        /// This does nothing for web apps.
+        pub minimized: Option<bool>,
+
+        /// Set to change the window icon.
+        /// This does nothing for web apps.
+        pub window_icon: Option<IconData>,
+
+        /// Set to `true` to start moving the window with the mouse.
+        /// This does nothing for web apps.
+        pub drag_window: bool,
+
+        /// Set to start resizing the window with the mouse.
+        /// This does nothing for web apps.
+        pub drag_resize_window: Option<ResizeDirection>,
+
+        /// Set to change how often the app is repainted.
+        pub run_mode: Option<RunMode>,
+
+        /// Set to change the maximum number of frames per second.
+        /// `f32::INFINITY` means no limit.
+        pub max_fps: Option<f32>,
     }
 }